@@ -1,8 +1,12 @@
-use mini_redis::{client, DEFAULT_PORT};
+use mini_redis::client::{self, TimeToLive};
+use mini_redis::DEFAULT_PORT;
 
 use bytes::Bytes;
+use std::ffi::OsStr;
 use std::{num::ParseIntError, str, time::Duration};
 use structopt::StructOpt;
+use tokio::signal;
+use tokio_stream::StreamExt;
 
 #[derive(StructOpt)]
 struct Cli {
@@ -21,19 +25,77 @@ enum Command {
     Get {
         /// 가져올 값의 키
         key: String,
+
+        /// 값을 16진수 문자열로 출력한다.
+        #[structopt(long = "--hex")]
+        hex: bool,
     },
     /// 키와 값을 묶어 저장한다.
     Set {
         /// 저장할 키 이름
         key: String,
 
-        /// 저장할 값
-        #[structopt(parse(from_str = bytes_from_str))]
+        /// 저장할 값. 셸이 전달한 바이트를 그대로 저장한다.
+        #[structopt(parse(from_os_str = bytes_from_os_str))]
         value: Bytes,
 
         /// 값 만료 시간 값
         #[structopt(parse(try_from_str = duration_from_ms_str))]
         expires: Option<Duration>,
+
+        /// 값을 16진수 문자열로 해석하여 디코딩한 바이트를 저장한다.
+        #[structopt(long = "--hex", conflicts_with = "base64")]
+        hex: bool,
+
+        /// 값을 base64 문자열로 해석하여 디코딩한 바이트를 저장한다.
+        #[structopt(long = "--base64")]
+        base64: bool,
+    },
+    /// 키를 삭제한다.
+    Del {
+        /// 삭제할 키
+        #[structopt(required = true)]
+        keys: Vec<String>,
+    },
+    /// 존재하는 키의 수를 가져온다.
+    Exists {
+        /// 확인할 키
+        #[structopt(required = true)]
+        keys: Vec<String>,
+    },
+    /// 키에 저장된 정수 값을 1 증가시킨다.
+    Incr {
+        /// 증가시킬 값의 키
+        key: String,
+    },
+    /// 키의 만료 시간을 설정한다.
+    Expire {
+        /// 만료 시간을 설정할 키
+        key: String,
+
+        /// 만료 시간(초)
+        #[structopt(parse(try_from_str = duration_from_secs_str))]
+        seconds: Duration,
+    },
+    /// 키의 남은 만료 시간을 초 단위로 가져온다.
+    Ttl {
+        /// 만료 시간을 가져올 키
+        key: String,
+    },
+    /// 채널에 메시지를 발행한다.
+    Publish {
+        /// 메시지를 발행할 채널
+        channel: String,
+
+        /// 발행할 메시지. 셸이 전달한 바이트를 그대로 발행한다.
+        #[structopt(parse(from_os_str = bytes_from_os_str))]
+        message: Bytes,
+    },
+    /// 채널을 구독하고, Ctrl-C를 입력할 때까지 수신한 메시지를 출력한다.
+    Subscribe {
+        /// 구독할 채널
+        #[structopt(required = true)]
+        channels: Vec<String>,
     },
 }
 
@@ -46,7 +108,7 @@ enum Command {
 /// 위함이다. 멀티쓰레드를 사용하는 대신 가벼움을 취함으로써 CLI 툴의 유즈케이스의
 /// 이점을 더욱 살리도록 한다.
 #[tokio::main(flavor = "current_thread")]
-async fn main() -> mini_redis::Result<()> {
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // 로깅을 활성화한다.
     tracing_subscriber::fmt::try_init()?;
 
@@ -61,9 +123,11 @@ async fn main() -> mini_redis::Result<()> {
 
     // 요청 커맨드를 수행한다.
     match cli.command {
-        Command::Get { key } => {
+        Command::Get { key, hex } => {
             if let Some(value) = client.get(&key).await? {
-                if let Ok(string) = str::from_utf8(&value) {
+                if hex {
+                    println!("{}", encode_hex(&value));
+                } else if let Ok(string) = str::from_utf8(&value) {
                     println!("\"{}\"", string);
                 } else {
                     println!("{:?}", value);
@@ -75,18 +139,76 @@ async fn main() -> mini_redis::Result<()> {
         Command::Set {
             key,
             value,
-            expires: None,
+            expires,
+            hex,
+            base64,
         } => {
-            client.set(&key, value).await?;
+            let value = if hex {
+                decode_hex(&value)?
+            } else if base64 {
+                decode_base64(&value)?
+            } else {
+                value
+            };
+
+            match expires {
+                Some(expires) => client.set_expires(&key, value, expires).await?,
+                None => client.set(&key, value).await?,
+            }
             println!("OK");
         }
-        Command::Set {
-            key,
-            value,
-            expires: Some(expires),
-        } => {
-            client.set_expires(&key, value, expires).await?;
-            println!("OK");
+        Command::Del { keys } => {
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            println!("(integer) {}", client.del(&keys).await?);
+        }
+        Command::Exists { keys } => {
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            println!("(integer) {}", client.exists(&keys).await?);
+        }
+        Command::Incr { key } => {
+            println!("(integer) {}", client.incr(&key).await?);
+        }
+        Command::Expire { key, seconds } => {
+            let set = client.expire(&key, seconds).await?;
+            println!("(integer) {}", set as u8);
+        }
+        Command::Ttl { key } => {
+            // 레디스와 같이 키가 없으면 -2, 만료 시간이 없으면 -1을 출력한다.
+            let ttl = match client.ttl(&key).await? {
+                TimeToLive::NotFound => -2,
+                TimeToLive::Persistent => -1,
+                TimeToLive::ExpiresIn(ttl) => ttl.as_secs() as i64,
+            };
+            println!("(integer) {}", ttl);
+        }
+        Command::Publish { channel, message } => {
+            println!("(integer) {}", client.publish(&channel, message).await?);
+        }
+        Command::Subscribe { channels } => {
+            let subscriber = client.subscribe(channels).await?;
+
+            // 메시지 스트림과 Ctrl-C를 함께 기다린다. Ctrl-C를 입력하면 커넥션을 닫고 종료한다.
+            let messages = subscriber.into_stream();
+            tokio::pin!(messages);
+
+            loop {
+                let message = tokio::select! {
+                    message = messages.next() => message,
+                    _ = signal::ctrl_c() => break,
+                };
+
+                // 서버가 커넥션을 닫으면 스트림이 끝난다.
+                let message = match message {
+                    Some(message) => message?,
+                    None => break,
+                };
+
+                if let Ok(string) = str::from_utf8(&message.content) {
+                    println!("{}: {}", message.channel, string);
+                } else {
+                    println!("{}: {:?}", message.channel, message.content);
+                }
+            }
         }
     }
 
@@ -98,6 +220,86 @@ fn duration_from_ms_str(src: &str) -> Result<Duration, ParseIntError> {
     Ok(Duration::from_millis(ms))
 }
 
-fn bytes_from_str(src: &str) -> Bytes {
-    Bytes::from(src.to_string())
+fn duration_from_secs_str(src: &str) -> Result<Duration, ParseIntError> {
+    let secs = src.parse::<u64>()?;
+    Ok(Duration::from_secs(secs))
+}
+
+/// 셸이 전달한 아규먼트를 바이트로 변환한다. 유닉스에서는 UTF-8이 아닌 아규먼트도 그대로 변환한다.
+#[cfg(unix)]
+fn bytes_from_os_str(src: &OsStr) -> Bytes {
+    use std::os::unix::ffi::OsStrExt;
+
+    Bytes::copy_from_slice(src.as_bytes())
+}
+
+/// 셸이 전달한 아규먼트를 바이트로 변환한다. 유닉스가 아니라면 UTF-8로 변환할 수 없는 문자는
+/// 대체 문자로 바뀐다. 이런 값은 '--hex'나 '--base64'로 전달한다.
+#[cfg(not(unix))]
+fn bytes_from_os_str(src: &OsStr) -> Bytes {
+    Bytes::from(src.to_string_lossy().into_owned())
+}
+
+/// 바이트를 소문자 16진수 문자열로 변환한다.
+fn encode_hex(src: &[u8]) -> String {
+    src.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// 16진수 문자열을 바이트로 디코딩한다.
+fn decode_hex(src: &[u8]) -> Result<Bytes, String> {
+    let invalid = || "invalid hex value".to_string();
+
+    if !src.len().is_multiple_of(2) {
+        return Err(invalid());
+    }
+
+    src.chunks(2)
+        .map(|pair| {
+            let pair = str::from_utf8(pair).map_err(|_| invalid())?;
+            u8::from_str_radix(pair, 16).map_err(|_| invalid())
+        })
+        .collect::<Result<Vec<u8>, String>>()
+        .map(Bytes::from)
+}
+
+/// 표준 알파벳의 base64 문자열을 바이트로 디코딩한다. 끝의 '=' 패딩은 생략할 수 있다.
+fn decode_base64(src: &[u8]) -> Result<Bytes, String> {
+    let invalid = || "invalid base64 value".to_string();
+
+    let sextet = |byte: u8| match byte {
+        b'A'..=b'Z' => Ok(byte - b'A'),
+        b'a'..=b'z' => Ok(byte - b'a' + 26),
+        b'0'..=b'9' => Ok(byte - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(invalid()),
+    };
+
+    let end = src.iter().rposition(|&byte| byte != b'=').map_or(0, |i| i + 1);
+    let (src, padding) = src.split_at(end);
+
+    // 4개의 문자가 3바이트가 된다. 마지막 묶음이 한 문자뿐이라면 바이트를 만들 수 없다.
+    if padding.len() > 2 || src.len() % 4 == 1 {
+        return Err(invalid());
+    }
+
+    // 패딩이 있다면 패딩을 포함한 길이가 4의 배수여야 한다.
+    if !padding.is_empty() && !(src.len() + padding.len()).is_multiple_of(4) {
+        return Err(invalid());
+    }
+
+    let mut dst = Vec::with_capacity(src.len() * 3 / 4);
+
+    for chunk in src.chunks(4) {
+        let mut bits = 0u32;
+        for &byte in chunk {
+            bits = bits << 6 | sextet(byte)? as u32;
+        }
+        bits <<= 6 * (4 - chunk.len()) as u32;
+
+        let bytes = bits.to_be_bytes();
+        dst.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+
+    Ok(Bytes::from(dst))
 }
\ No newline at end of file
@@ -7,25 +7,114 @@
 
 use mini_redis::{server, DEFAULT_PORT};
 
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::time::Duration;
 use structopt::StructOpt;
 use tokio::net::TcpListener;
 use tokio::signal;
 
 #[tokio::main]
-pub async fn main() -> mini_redis::Result<()> {
+pub async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // 로깅을 활성화한다.
     // 자세한 내용: https://docs.rs/tracing
     tracing_subscriber::fmt::try_init()?;
 
     let cli = Cli::from_args();
-    let port = cli.port.as_deref().unwrap_or(DEFAULT_PORT);
+    let port: u16 = cli.port.as_deref().unwrap_or(DEFAULT_PORT).parse()?;
 
-    let listener = TcpListener::bind(&format!("127.0.0.1:{}", port)).await?;
+    // 지정되지 않은 설정은 기본값을 사용한다.
+    let mut config = server::Config::default();
+    if let Some(max_connections) = cli.max_connections {
+        config.max_connections = max_connections;
+    }
+    if let Some(bind) = cli.bind {
+        config.bind_addr = bind;
+    }
+    if let Some(databases) = cli.databases {
+        config.databases = databases;
+    }
+    if let Some(max_frame_size) = cli.max_frame_size {
+        config.max_frame_size = max_frame_size;
+    }
+    if let Some(timeout) = cli.timeout {
+        config.idle_timeout = Some(Duration::from_secs(timeout));
+    }
+    if let Some(maxmemory) = cli.maxmemory {
+        config.maxmemory = maxmemory;
+    }
+    if let Some(maxmemory_policy) = cli.maxmemory_policy {
+        config.maxmemory_policy = maxmemory_policy;
+    }
+    if let Some(shutdown_timeout) = cli.shutdown_timeout {
+        config.shutdown_timeout = Duration::from_secs(shutdown_timeout);
+    }
+    config.requirepass = cli.requirepass;
+    config.notify_keyspace_events = cli.notify_keyspace_events;
+    config.dbfilename = cli.dbfilename;
 
-    server::run(listener, signal::ctrl_c()).await
+    let listener = TcpListener::bind((config.bind_addr, port)).await?;
+
+    server::run_with_config(listener, signal::ctrl_c(), config).await?;
+
+    Ok(())
 }
 #[derive(StructOpt)]
 #[structopt(name = "mini-redis-sever", version = env!("CARGO_PKG_VERSION"), author = env!("CARGO_PKG_AUTHORS"), about = "A Redis server")]
 struct Cli {
     port: Option<String>,
-}
\ No newline at end of file
+
+    /// 동시에 수용하는 최대 커넥션 수
+    #[structopt(long = "max-connections")]
+    max_connections: Option<usize>,
+
+    /// 클라이언트로부터 수신할 수 있는 프레임의 최대 크기(바이트)
+    #[structopt(long = "max-frame-size")]
+    max_frame_size: Option<usize>,
+
+    /// 요청이 없는 커넥션을 종료하기까지의 시간(초). 0이면 종료하지 않는다
+    #[structopt(long = "timeout")]
+    timeout: Option<u64>,
+
+    /// 키 공간이 사용할 수 있는 최대 메모리(바이트). 0이면 제한이 없다
+    #[structopt(long = "maxmemory")]
+    maxmemory: Option<u64>,
+
+    /// 최대 메모리를 넘었을 때의 정책. 'noeviction' 혹은 'allkeys-lru'
+    #[structopt(long = "maxmemory-policy")]
+    maxmemory_policy: Option<server::MaxMemoryPolicy>,
+
+    /// 셧다운 시 커넥션들이 처리를 마치기를 기다리는 최대 시간(초)
+    #[structopt(long = "shutdown-timeout")]
+    shutdown_timeout: Option<u64>,
+
+    /// 서버가 수신할 주소. IPv6 주소는 '[::1]'과 같이 괄호로 감쌀 수 있다.
+    #[structopt(long = "bind", parse(try_from_str = ip_addr_from_str))]
+    bind: Option<IpAddr>,
+
+    /// 논리 데이터베이스의 수
+    #[structopt(long = "databases")]
+    databases: Option<usize>,
+
+    /// 클라이언트가 'AUTH'로 인증해야 하는 비밀번호
+    #[structopt(long = "requirepass")]
+    requirepass: Option<String>,
+
+    /// 키가 변경될 때 키 공간 알림을 pub/sub으로 발행한다
+    #[structopt(long = "notify-keyspace-events")]
+    notify_keyspace_events: bool,
+
+    /// 'SAVE'가 키 공간을 저장하고, 가동 시 키 공간을 복원하는 스냅샷 파일의 경로
+    #[structopt(long = "dbfilename", parse(from_os_str))]
+    dbfilename: Option<PathBuf>,
+}
+
+// IP 주소를 파싱한다. IPv6 주소를 감싸는 괄호는 제거한다.
+fn ip_addr_from_str(src: &str) -> Result<IpAddr, std::net::AddrParseError> {
+    let src = src
+        .strip_prefix('[')
+        .and_then(|src| src.strip_suffix(']'))
+        .unwrap_or(src);
+
+    src.parse()
+}
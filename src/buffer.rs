@@ -2,6 +2,8 @@ use crate::client::Client;
 use crate::Result;
 
 use bytes::Bytes;
+use std::io;
+use std::time::Duration;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::sync::oneshot;
 
@@ -17,7 +19,8 @@ use tokio::sync::oneshot;
 /// 꺼내서 Redis 커넥션에 수행한다. 커맨드에 대한 응답을 수신하면, 이를 원 요청자에게 
 /// 전달한다.
 /// 
-/// 응답 'Buffer' 핸들은 새 핸들을 개별 태스크에 전달하기 전에 clone될 수 있다.
+/// 응답 'Buffer' 핸들은 새 핸들을 개별 태스크에 전달하기 전에 clone될 수 있다. 커넥션 태스크는
+/// 모든 핸들이 drop된 뒤에 종료된다.
 pub fn buffer(client: Client) -> Buffer {
     // 메시지 수 제한을 32로 하드 코딩한다. 실제 어플리케이션에서는 이 크기를
     // 설정할 수 있도록 해야하지만, 여기서는 필요치 않다.
@@ -34,6 +37,24 @@ pub fn buffer(client: Client) -> Buffer {
 enum Command {
     Get(String),
     Set(String, Bytes),
+    SetExpires(String, Bytes, Duration),
+    Publish(String, Bytes),
+    Del(Vec<String>),
+}
+
+/// 커넥션 태스크가 원 요청자에게 전달하는 응답
+/// 
+/// 커맨드마다 응답 타입이 다르므로, 각 'Buffer' 메서드는 자신이 기대하는 변형을 꺼내 사용한다.
+#[derive(Debug)]
+enum Response {
+    /// 'GET'이 반환한 값
+    Value(Option<Bytes>),
+
+    /// 'PUBLISH'의 구독자 수, 'DEL'의 삭제된 키 수
+    Count(u64),
+
+    /// 응답 값이 없는 커맨드('SET')의 성공
+    Done,
 }
 
 /// 채널을 통해 커넥션 태스크에 전송된 메시지 타입
@@ -42,7 +63,7 @@ enum Command {
 /// 
 /// 'oneshot::Sender'는 **단일**값을 전송하는 채널으로, 여기서는 커넥션으로부터
 /// 수신한 응답을 원 요청자에게 전달하기 위해 사용한다.
-type Message = (Command, oneshot::Sender<Result<Option<Bytes>>>);
+type Message = (Command, oneshot::Sender<Result<Response>>);
 
 
 /// 채널을 통해 전송된 커맨드를 수신하고, 이를 Client(커넥션)에 전달한다. 커맨드 
@@ -53,8 +74,19 @@ async fn run(mut client: Client, mut rx: Receiver<Message>) {
     while let Some((cmd, tx)) = rx.recv().await {
         // 커맨드를 커넥션에 전달한다.
         let response = match cmd {
-            Command::Get(key) => client.get(&key).await,
-            Command::Set(key, value) => client.set(&key, value).await.map(|_| None),
+            Command::Get(key) => client.get(&key).await.map(Response::Value),
+            Command::Set(key, value) => client.set(&key, value).await.map(|_| Response::Done),
+            Command::SetExpires(key, value, expiration) => client
+                .set_expires(&key, value, expiration)
+                .await
+                .map(|_| Response::Done),
+            Command::Publish(channel, message) => {
+                client.publish(&channel, message).await.map(Response::Count)
+            }
+            Command::Del(keys) => {
+                let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+                client.del(&keys).await.map(Response::Count)
+            }
         };
 
         // 응답을 호출자에게 전송한다.
@@ -65,6 +97,15 @@ async fn run(mut client: Client, mut rx: Receiver<Message>) {
     }
 }
 
+/// 'buffer'로 생성한 커넥션 태스크에 요청을 전달하는 핸들
+/// 
+/// 'Buffer'는 clone할 수 있으며, clone된 핸들은 모두 같은 커넥션 태스크를 공유한다. 각 태스크에
+/// 핸들을 하나씩 전달하여 단일 커넥션으로 동시에 요청을 보낼 수 있다.
+/// 
+/// 커넥션 태스크는 모든 핸들이 drop되고, 이미 채널에 들어간 요청을 모두 처리한 뒤에 종료된다.
+/// 태스크가 종료되면 'Client'도 drop되어 커넥션이 닫힌다. 즉 커넥션을 닫으려면 모든 핸들을 drop해야
+/// 한다.
+#[derive(Clone)]
 pub struct Buffer {
     tx: Sender<Message>,
 }
@@ -79,38 +120,94 @@ impl Buffer {
         // 채널을 통해 전송할 새로운 'Get'커맨드를 초기화한다.
         let get = Command::Get(key.into());
 
-        // 커넥션으로부터 응답을 수신하기 위한 새로운 oneshot을 초기화한다.
-        let (tx, rx) = oneshot::channel();
-
-        // 요청을 전송한다.
-        self.tx.send((get, tx)).await?;
-
-        // 응답을 기다린다.
-        match rx.await {
-            Ok(res) => res,
-            Err(err) => Err(err.into()),
+        match self.request(get).await? {
+            Response::Value(value) => Ok(value),
+            response => Err(unexpected(response)),
         }
     }
 
     /// 키와 값을 연결하여 세팅한다.
     /// 
-    ///      * 'Client::set'과 같지만, 요청이 자신과 연결된 커넥션에 전송 가능할 때까지
+    /// 'Client::set'과 같지만, 요청이 자신과 연결된 커넥션에 전송 가능할 때까지
     /// **버퍼링**된다.
-    /// 
     pub async fn set(&mut self, key: &str, value: Bytes) -> Result<()> {
         // 채널을 통해 전송할 새로운 'Set'커맨드를 초기화한다.
         let set = Command::Set(key.into(), value);
 
+        match self.request(set).await? {
+            Response::Done => Ok(()),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// 키와 값을 연결하여 세팅한다. 값은 'expiration' 후에 만료된다.
+    /// 
+    /// 'Client::set_expires'와 같지만, 요청이 자신과 연결된 커넥션에 전송 가능할 때까지
+    /// **버퍼링**된다.
+    pub async fn set_expires(
+        &mut self,
+        key: &str,
+        value: Bytes,
+        expiration: Duration,
+    ) -> Result<()> {
+        let set = Command::SetExpires(key.into(), value, expiration);
+
+        match self.request(set).await? {
+            Response::Done => Ok(()),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// 채널에 메시지를 발행하고, 메시지를 수신한 구독자의 수를 반환한다.
+    /// 
+    /// 'Client::publish'와 같지만, 요청이 자신과 연결된 커넥션에 전송 가능할 때까지
+    /// **버퍼링**된다.
+    pub async fn publish(&mut self, channel: &str, message: Bytes) -> Result<u64> {
+        let publish = Command::Publish(channel.into(), message);
+
+        match self.request(publish).await? {
+            Response::Count(num) => Ok(num),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// 키들을 삭제하고, 실제로 삭제된 키의 수를 반환한다.
+    /// 
+    /// 'Client::del'과 같지만, 요청이 자신과 연결된 커넥션에 전송 가능할 때까지
+    /// **버퍼링**된다.
+    pub async fn del(&mut self, keys: &[&str]) -> Result<u64> {
+        let del = Command::Del(keys.iter().map(|key| key.to_string()).collect());
+
+        match self.request(del).await? {
+            Response::Count(num) => Ok(num),
+            response => Err(unexpected(response)),
+        }
+    }
+
+    /// 커맨드를 커넥션 태스크에 전송하고, 응답을 기다린다.
+    async fn request(&mut self, cmd: Command) -> Result<Response> {
         // 커넥션으로부터 응답을 수신하기 위한 새로운 oneshot을 초기화한다.
         let (tx, rx) = oneshot::channel();
 
-        // 요청을 전송한다.
-        self.tx.send((set, tx)).await?;
+        // 요청을 전송한다. 커넥션 태스크가 종료되었다면 전송에 실패한다.
+        if self.tx.send((cmd, tx)).await.is_err() {
+            return Err(shut_down());
+        }
 
-        // 응답을 기다린다.
+        // 응답을 기다린다. 커넥션 태스크가 응답하지 않고 종료되었다면 실패한다.
         match rx.await {
-            Ok(res) => res.map(|_| ()),
-            Err(err) => Err(err.into()),
+            Ok(res) => res,
+            Err(_) => Err(shut_down()),
         }
     }
-}
\ No newline at end of file
+}
+
+/// 커넥션 태스크가 종료되어 요청을 처리할 수 없을 때의 에러
+fn shut_down() -> crate::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "connection task has shut down").into()
+}
+
+/// 커맨드와 맞지 않는 응답을 에러로 변환한다. 커넥션 태스크가 올바르게 동작한다면 발생하지 않는다.
+fn unexpected(response: Response) -> crate::Error {
+    format!("unexpected response: {:?}", response).into()
+}
@@ -1,19 +1,48 @@
 
-use crate::cmd::{Get, Publish, Set, Subscribe, Unsubscribe};
-use crate::{Connection, Frame};
+use crate::cmd::{
+    Append, Auth, BgSave, BitCount, BPop, ClientCommand, Config, Copy, DbSize, Debug, Decr, Del,
+    Discard, Exec, Exists, Expire, ExpireAt, Expiry, Flush, Get, GetBit, GetDel, GetEx,
+    GetRange, GetSet, HDel, HGet, HGetAll, HIncrBy, HIncrByFloat, HLen, HRandField, HSet, Incr,
+    Info, Keys, LInsert, LLen, LMove, LPos, LRange, LRem, LSet, LTrim, MGet, Monitor, MSet,
+    Multi, Object, Persist, Ping, Pop, PSubscribe, PUnsubscribe, Quit, PubSub, Publish, Push,
+    RandomKey, Rename, Reset, SAdd, Save, SCard, SIsMember, SMembers, SRandMember, SPublish,
+    SRem, SSubscribe, SUnsubscribe, Scan, Select, Set, SetAlgebra, SetAlgebraStore, SetBit,
+    SetEx, SetNx, SetRange, SlowLog, Strlen, Subscribe, Touch, Ttl, Type, Unlink, Unsubscribe,
+    Unwatch, Wait, Watch, ZAdd, ZRange, ZRangeByScore, ZRank, ZScore,
+};
+use crate::db::SetOp;
+use crate::{Connection, Frame, MiniRedisError};
 
 use async_stream::try_stream;
+use std::collections::HashMap;
 use std::io::{Error, ErrorKind};
+use std::net::SocketAddr;
+use std::ops::Bound;
+#[cfg(unix)]
+use std::path::Path;
 use bytes::Bytes;
-use tokio::net::{TcpStream, ToSocketAddrs};
-use std::time::Duration;
+use tokio::net::{lookup_host, TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use tokio::net::UnixStream;
+use tokio::time;
+use std::time::{Duration, SystemTime};
 use tokio_stream::Stream;
+#[cfg(feature = "tls")]
+use std::{convert::TryFrom, sync::Arc};
+#[cfg(feature = "tls")]
+use tokio_rustls::rustls::{pki_types::ServerName, ClientConfig};
+#[cfg(feature = "tls")]
+use tokio_rustls::TlsConnector;
 use tracing::{debug, instrument};
 
+mod pool;
+pub use pool::{Pool, PoolBuilder, PooledClient};
+
 /// Redis 서버와 커넥션을 수립한다.
 /// 
 /// 'Client'는 'TcpStream' 하나를 기반으로 기본적인 네트워크 클라이언트 기능(no pooling, 재시도, ...)
-/// 을 제공한다. 커넥션은 ['connect'](fn@connect) 함수를 통해 수립한다.
+/// 을 제공한다. 커넥션은 ['connect'](fn@connect) 함수를 통해 수립한다. 여러 태스크에서 커넥션을
+/// 재사용하려면 ['Pool'](struct@Pool)을 사용한다.
 /// 
 /// 요청(requests)은 'Client'의 다양한 메서드를 통해 이루어진다.
 pub struct Client {
@@ -25,8 +54,62 @@ pub struct Client {
     /// 'Connection'은 핸들러로 하여금 "프레임" 수준의 연산을 가능하게 하고, 바이트 레벨 프로토콜
     /// 파싱의 세부 내용을 'Connection' 안에 캡슐화한다.    
     connection: Connection,
+
+    /// 재연결 상태. ['connect_with_retry'](fn@connect_with_retry)로 생성한 클라이언트만 갖는다.
+    /// 'None'이라면 커넥션이 끊어진 뒤의 요청은 모두 실패한다.
+    reconnect: Option<Reconnect>,
+
+    /// 요청 하나를 보내고 응답을 받기까지 기다리는 최대 시간. 'None'이라면 제한 없이 기다린다.
+    timeout: Option<Duration>,
+}
+
+/// 'connect_with_retry'로 생성한 클라이언트의 재연결 정책
+/// 
+/// 커넥션을 다시 수립할 때는 서버의 'accept'와 같은 지수 백오프를 사용한다. 첫 시도가 실패하면
+/// 'initial_backoff'만큼 기다리고, 이어지는 실패마다 대기 시간을 두 배로 늘리되 'max_backoff'를
+/// 넘지 않는다.
+/// 
+/// 'Default' 구현은 서버의 'accept'와 같이 1초부터 64초까지 기다리며 최대 8번 시도한다.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 커넥션 수립과 요청을 시도하는 최대 횟수. 첫 시도를 포함한다.
+    pub max_attempts: u32,
+
+    /// 첫 실패 후의 대기 시간
+    pub initial_backoff: Duration,
+
+    /// 대기 시간의 최댓값
+    pub max_backoff: Duration,
+}
+
+/// 커넥션을 다시 수립하고, 끊어진 커넥션에 적용되어 있던 상태를 복원하기 위한 정보
+#[derive(Debug)]
+struct Reconnect {
+    /// 서버의 주소. 'connect_with_retry' 호출 시점에 해석해둔다.
+    addrs: Vec<SocketAddr>,
+
+    /// 재연결 정책
+    policy: RetryPolicy,
+
+    /// 마지막으로 인증에 성공한 비밀번호. 재연결 후 'AUTH'로 다시 인증한다.
+    password: Option<String>,
+
+    /// 마지막으로 'SELECT'한 데이터베이스 번호. 재연결 후 다시 선택한다.
+    database: usize,
 }
 
+/// 커넥션이 끊어졌을 때 재전송해도 안전한 커맨드
+/// 
+/// 서버가 커맨드를 처리한 뒤 응답하기 전에 커넥션이 끊어졌을 수 있으므로, 두 번 수행되어도 결과가
+/// 같은 커맨드만 재전송한다. 'INCR', 'LPUSH'와 같은 커맨드는 에러를 그대로 반환한다.
+const IDEMPOTENT_COMMANDS: &[&str] = &[
+    "bitcount", "dbsize", "exists", "flushdb", "get", "getbit", "getrange", "hget", "hgetall",
+    "hlen", "hrandfield", "info", "keys", "llen", "lpos", "lrange", "lset", "mget", "mset",
+    "object", "persist", "pexpireat", "ping", "psetex", "pttl", "pubsub", "randomkey", "scan",
+    "scard", "select", "set", "sismember", "smembers", "srandmember", "strlen", "touch", "ttl",
+    "type", "zrange", "zrangebyscore", "zrank", "zscore",
+];
+
 /// pub/sub 모드로 진입한 클라이언트
 /// 
 /// 한 번 채널을 구독한 클라이언트는 pub/sub 관련 커맨드만을 수행할 가능성이 있다. 'Client' 타입은
@@ -37,12 +120,89 @@ pub struct Subscriber {
     
     /// 현재 'Subscriber'를 통해 구독하는 채널의 모음
     subscribed_channels: Vec<String>,
+
+    /// 현재 'Subscriber'를 통해 구독하는 패턴의 모음
+    subscribed_patterns: Vec<String>,
+
+    /// 현재 'Subscriber'를 통해 구독하는 샤드 채널의 모음
+    subscribed_shard_channels: Vec<String>,
+}
+
+/// 'MONITOR' 상태로 진입한 클라이언트
+/// 
+/// 서버가 수신하는 모든 커맨드를 한 줄씩 수신한다. 'Subscriber'와 마찬가지로 'Client' 타입은
+/// 'Monitoring'으로 전이되어 다른 커맨드를 수행하지 못한다.
+pub struct Monitoring {
+    /// 모니터 클라이언트
+    client: Client,
 }
 
 /// 구독 중인 채널을 통해 수신되는 메시지
 pub struct Message {
+    /// 메시지가 발행된 채널
     pub channel: String,
+
+    /// 메시지 내용
     pub content: Bytes,
+
+    /// 패턴 구독으로 수신한 메시지라면, 메시지와 매칭된 패턴. 채널 구독으로 수신한 메시지라면
+    /// 'None'이다.
+    pub pattern: Option<String>,
+}
+
+/// 'Subscriber'가 수신하는 이벤트
+/// 
+/// 발행된 메시지와 함께, 구독 상태의 변화를 알리는 서버의 확인 응답을 수신한 순서대로 표현한다.
+/// 패턴 구독과 해지의 확인 응답도 'Subscribed', 'Unsubscribed'로 표현하며, 이 경우 'channel'은
+/// 패턴이 된다. 샤드 채널 구독과 해지의 확인 응답도 마찬가지이며, 이 경우 'count'는 구독 중인 샤드
+/// 채널의 수이다.
+pub enum Event {
+    /// 구독 중인 채널 혹은 패턴으로 수신한 메시지
+    Message(Message),
+
+    /// 채널 혹은 패턴의 구독이 확인되었다. 'count'는 이 시점에 구독 중인 채널과 패턴의 수이다.
+    Subscribed { channel: String, count: u64 },
+
+    /// 채널 혹은 패턴의 구독 해지가 확인되었다. 'count'는 이 시점에 구독 중인 채널과 패턴의 수이다.
+    Unsubscribed { channel: String, count: u64 },
+}
+
+/// 'TTL', 'PTTL' 커맨드로 가져온 키의 만료 상태
+/// 
+/// 서버는 만료 상태를 -2, -1과 같은 특별한 값으로 응답한다. 'TimeToLive'는 호출자가 이러한
+/// 값을 직접 해석하지 않아도 되도록 응답을 표현한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeToLive {
+    /// 키가 존재하지 않는다.
+    NotFound,
+
+    /// 키가 존재하지만 만료 시간이 없다.
+    Persistent,
+
+    /// 키가 존재하며, 주어진 시간 후에 만료된다.
+    ExpiresIn(Duration),
+}
+
+/// 'SLOWLOG GET'으로 가져온 슬로우 로그의 기록 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowLogEntry {
+    /// 기록마다 1씩 증가하는 식별자
+    pub id: u64,
+
+    /// 커맨드를 기록한 시각(유닉스 시간, 초)
+    pub timestamp: u64,
+
+    /// 커맨드를 처리하는 데 걸린 시간
+    pub duration: Duration,
+
+    /// 커맨드 이름을 포함한 아규먼트. 서버는 아규먼트가 많거나 길다면 줄여서 기록한다.
+    pub args: Vec<Bytes>,
+
+    /// 커맨드를 전송한 클라이언트의 주소
+    pub addr: String,
+
+    /// 커맨드를 전송한 클라이언트의 이름. 이름이 없다면 빈 문자열이다.
+    pub name: String,
 }
 
 /// 'addr'에 위치한 Redis 서버와의 연결을 수립한다.
@@ -72,15 +232,265 @@ pub async fn connect<T: ToSocketAddrs>(addr: T) -> crate::Result<Client> {
 
     // 연결 상태를 초기화한다. 이 작업은 레디스 프로토콜 프레임 파싱을 위한 읽기/쓰기
     // 버퍼를 할당한다.
-    let connection = Connection::new(socket);
+    let connection = Connection::boxed(socket);
+
+    Ok(Client {
+        connection,
+        reconnect: None,
+        timeout: None,
+    })
+}
+
+/// 'addr'에 위치한 Redis 서버와의 연결을 수립한다. 반환된 클라이언트는 커넥션이 끊어지면 'policy'에
+/// 따라 다시 연결한다.
+/// 
+/// 서버가 재시작되는 등의 이유로 요청 중 읽기/쓰기 에러가 발생하면, 클라이언트는 지수 백오프로
+/// 커넥션을 다시 수립한다. 재연결 후에는 이전에 수행한 'AUTH'와 'SELECT'를 다시 적용한다. 실패한
+/// 요청이 두 번 수행되어도 안전한 커맨드('GET', 'SET' 등)라면 새 커넥션으로 재전송하며, 그렇지 않은
+/// 커맨드('INCR' 등)라면 에러를 반환한다. 이 경우 커넥션은 다음 요청에서 다시 수립된다.
+/// 
+/// 최초 연결에도 같은 정책을 적용한다.
+/// 
+/// # Example
+/// 
+/// ```no_run
+/// use mini_redis::client::{self, RetryPolicy};
+/// 
+/// #[tokio::main]
+/// async fn main() {
+///     let client = match client::connect_with_retry("localhost:6379", RetryPolicy::default()).await {
+///         Ok(client) => client,
+///         Err(_) => panic!("failed to establish connection"),
+///     };
+/// # drop(client);
+/// }
+/// ```
+pub async fn connect_with_retry<T: ToSocketAddrs>(
+    addr: T,
+    policy: RetryPolicy,
+) -> crate::Result<Client> {
+    // 재연결할 때마다 DNS 룩업을 수행하지 않도록 주소를 미리 해석해둔다.
+    let addrs: Vec<SocketAddr> = lookup_host(addr).await?.collect();
+
+    let reconnect = Reconnect {
+        addrs,
+        policy,
+        password: None,
+        database: 0,
+    };
+
+    let socket = reconnect.connect().await?;
+
+    Ok(Client {
+        connection: Connection::boxed(socket),
+        reconnect: Some(reconnect),
+        timeout: None,
+    })
+}
+
+/// 'path'의 유닉스 도메인 소켓으로 Redis 서버와의 연결을 수립한다.
+/// 
+/// 서버는 ['server::run_uds'](fn@crate::server::run_uds)로 같은 경로에서 커넥션을 수락하고 있어야 한다.
+/// 연결 방식을 제외한 동작은 ['connect'](fn@connect)와 같다.
+/// 
+/// # Example
+/// 
+/// ```no_run
+/// use mini_redis::client;
+/// 
+/// #[tokio::main]
+/// async fn main() {
+///     let client = match client::connect_unix("/tmp/mini-redis.sock").await {
+///         Ok(client) => client,
+///         Err(_) => panic!("failed to establish connection"),
+///     };
+/// # drop(client);
+/// }
+/// ```
+#[cfg(unix)]
+pub async fn connect_unix<P: AsRef<Path>>(path: P) -> crate::Result<Client> {
+    let socket = UnixStream::connect(path).await?;
+
+    Ok(Client {
+        connection: Connection::boxed(socket),
+        reconnect: None,
+        timeout: None,
+    })
+}
+
+/// 'addr'에 위치한 Redis 서버와 TLS 커넥션을 수립한다.
+/// 
+/// TCP 커넥션을 수립한 뒤 'config'로 TLS 핸드셰이크를 수행한다. 'server_name'은 서버 인증서를 검증하는
+/// 데 사용하는 호스트 이름이다. 'server_name'이 유효한 이름이 아니거나 핸드셰이크에 실패하면 에러를
+/// 반환한다.
+/// 
+/// 'tls' feature가 필요하다.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// use mini_redis::client;
+/// use mini_redis::tokio_rustls::rustls::{ClientConfig, RootCertStore};
+/// use std::sync::Arc;
+/// 
+/// #[tokio::main]
+/// async fn main() {
+///     let roots = RootCertStore::empty();
+///     // 'roots'에 신뢰할 인증서를 추가한다.
+///     let config = ClientConfig::builder()
+///         .with_root_certificates(roots)
+///         .with_no_client_auth();
+/// 
+///     let client = match client::connect_tls("localhost:6379", "localhost", Arc::new(config)).await {
+///         Ok(client) => client,
+///         Err(_) => panic!("failed to establish connection"),
+///     };
+/// # drop(client);
+/// }
+/// ```
+#[cfg(feature = "tls")]
+pub async fn connect_tls<T: ToSocketAddrs>(
+    addr: T,
+    server_name: &str,
+    config: Arc<ClientConfig>,
+) -> crate::Result<Client> {
+    let server_name = ServerName::try_from(server_name.to_string())
+        .map_err(|err| Error::new(ErrorKind::InvalidInput, err))?;
+
+    let socket = TcpStream::connect(addr).await?;
+
+    // TLS 핸드셰이크를 수행한다. 이후의 프레임은 암호화된 스트림 위에서 읽고 쓴다.
+    let stream = TlsConnector::from(config).connect(server_name, socket).await?;
+
+    Ok(Client {
+        connection: Connection::boxed(stream),
+        reconnect: None,
+        timeout: None,
+    })
+}
+
+/// 'addr'에 위치한 Redis 서버와의 연결을 수립하고, 'password'로 인증한다.
+/// 
+/// 'requirepass'가 설정된 서버에 연결할 때 사용한다. 연결 직후 'AUTH'를 전송하며, 인증에 실패하면
+/// 에러를 반환한다.
+/// 
+/// # Example
+/// 
+/// ```no_run
+/// use mini_redis::client;
+/// 
+/// #[tokio::main]
+/// async fn main() {
+///     let client = match client::connect_with_auth("localhost:6379", "secret").await {
+///         Ok(client) => client,
+///         Err(_) => panic!("failed to establish connection"),
+///     };
+/// # drop(client);
+/// }
+/// ```
+pub async fn connect_with_auth<T: ToSocketAddrs>(
+    addr: T,
+    password: &str,
+) -> crate::Result<Client> {
+    let mut client = connect(addr).await?;
+
+    client.auth(password).await?;
 
-    Ok(Client { connection })
+    Ok(client)
 }
 
 impl Client {
+    /// 요청 하나를 보내고 응답을 받기까지 기다리는 최대 시간을 지정한다.
+    /// 
+    /// 시간 내에 응답을 받지 못하면 요청은 'ErrorKind::TimedOut' 종류의 'MiniRedisError::Io'로 실패한다.
+    /// 응답을 읽는 도중에 중단되었을 수 있으므로 커넥션은 더 이상 사용할 수 없게 된다. 이후의 요청은
+    /// 실패하며, ['connect_with_retry'](fn@connect_with_retry)로 생성한 클라이언트라면 다음 요청에서
+    /// 커넥션을 다시 수립한다.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use mini_redis::{client, MiniRedisError};
+    /// use std::io::ErrorKind;
+    /// use std::time::Duration;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379")
+    ///         .await
+    ///         .unwrap()
+    ///         .with_timeout(Duration::from_millis(100));
+    /// 
+    ///     match client.get("foo").await {
+    ///         Err(MiniRedisError::Io(err)) if err.kind() == ErrorKind::TimedOut => println!("timed out"),
+    ///         Err(err) => println!("error: {}", err),
+    ///         Ok(_) => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Client {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 'password'로 커넥션을 인증한다.
+    /// 
+    /// 서버에 설정된 비밀번호와 다르거나, 서버에 비밀번호가 설정되지 않았다면 에러를 반환한다.
+    #[instrument(skip(self, password))]
+    pub async fn auth(&mut self, password: &str) -> crate::Result<()> {
+        let frame = Auth::new(password).into_frame();
+
+        // 비밀번호가 로그에 남지 않도록 프레임은 로깅하지 않는다.
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => {
+                if let Some(reconnect) = &mut self.reconnect {
+                    reconnect.password = Some(password.to_string());
+                }
+
+                Ok(())
+            }
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버에 PING을 보낸다.
+    /// 
+    /// 'msg'가 주어지지 않으면 서버는 "PONG"을 응답하며, 주어지면 'msg'를 그대로 응답한다.
+    /// 커넥션이 살아있는지 확인하거나 지연 시간을 측정하는 데 사용할 수 있다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     let pong = client.ping(None).await.unwrap();
+    ///     assert_eq!(&b"PONG"[..], &pong[..]);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn ping(&mut self, msg: Option<Bytes>) -> crate::Result<Bytes> {
+        let frame = Ping::new(msg).into_frame();
+
+        debug!(request = ?frame);
+
+        // 메시지가 없으면 'Simple' 프레임으로, 있으면 'Bulk' 프레임으로 응답을 받는다.
+        match self.request(&frame).await? {
+            Frame::Simple(value) => Ok(value.into()),
+            Frame::Bulk(value) => Ok(value),
+            frame => Err(frame.to_error()),
+        }
+    }
+
     /// 키에 해당하는 값을 얻는다.
     /// 
     /// 존재하지 않는 키라면, 특별한 값인 'None'을 반환한다.
+    /// 키에 문자열이 아닌 값이 저장되어 있다면 'MiniRedisError::WrongType' 에러를 반환한다.
     /// 
     /// # Examples
     /// 
@@ -89,6 +499,7 @@ impl Client {
     /// ```no_run
     /// use mini_redis::client;
     /// 
+    /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = client::connect("localhost:6379").await.unwrap();
     /// 
@@ -96,20 +507,18 @@ impl Client {
     ///     println!("Got = {:?}", val);
     /// }
     /// ```
+    #[instrument(skip(self))]
     pub async fn get(&mut self, key: &str) -> crate::Result<Option<Bytes>> {
         // 'key'의 'Get' 커맨드를 생성하고, 이를 프레임으로 변환한다.
         let frame = Get::new(key).into_frame();
 
         debug!(request = ?frame);
 
-        // 프레임을 소켓에 쓴다(write). 완전한 프레임을 소켓에 쓰며, 필요할 경우 대기한다. 
-        self.connection.write_frame(&frame).await?;
-
         // 서버로부터 응답을 기다린다.
         // 
         // 'Simple', 'Bulk' 프레임을 받는다. 'Null'은 키가 없음을 의미하며, 'None'을
         // 반환한다.
-        match self.read_response().await? {
+        match self.request(&frame).await? {
             Frame::Simple(value) => Ok(Some(value.into())),
             Frame::Bulk(value) => Ok(Some(value)),
             Frame::Null => Ok(None),
@@ -117,6 +526,120 @@ impl Client {
         }
     }
 
+    /// 'key'의 값을 가져오고, 키를 삭제한다.
+    /// 
+    /// 값의 조회와 삭제는 원자적으로 이루어진다. 키가 없다면 'None'을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     client.set("token", "secret".into()).await.unwrap();
+    /// 
+    ///     let val = client.getdel("token").await.unwrap();
+    ///     assert_eq!(val.unwrap(), "secret");
+    /// 
+    ///     // 키는 삭제되었다.
+    ///     assert!(client.get("token").await.unwrap().is_none());
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn getdel(&mut self, key: &str) -> crate::Result<Option<Bytes>> {
+        let frame = GetDel::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        // 'Get'과 마찬가지로 'Null'은 키가 없음을 의미한다.
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(Some(value)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 값을 가져오고, 'expiry'에 따라 키의 만료 시간을 변경한다.
+    /// 
+    /// 'expiry'가 'None'이면 'get'과 같다. 키가 없다면 아무것도 변경하지 않고 'None'을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// use mini_redis::cmd::Expiry;
+    /// use std::time::Duration;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     client.set_expires("token", "secret".into(), Duration::from_secs(60)).await.unwrap();
+    /// 
+    ///     let val = client.getex("token", Some(Expiry::Persist)).await.unwrap();
+    ///     assert_eq!(val.unwrap(), "secret");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn getex(
+        &mut self,
+        key: &str,
+        expiry: Option<Expiry>,
+    ) -> crate::Result<Option<Bytes>> {
+        let frame = GetEx::new(key, expiry).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(Some(value)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 'value'를 저장하고, 이전 값을 반환한다.
+    /// 
+    /// 키에 설정되어 있던 만료 시간은 폐기된다. 키가 없었다면 'None'을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     let prev = client.getset("counter", "0".into()).await.unwrap();
+    ///     assert!(prev.is_none());
+    /// 
+    ///     let prev = client.getset("counter", "1".into()).await.unwrap();
+    ///     assert_eq!(prev.unwrap(), "0");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn getset(&mut self, key: &str, value: Bytes) -> crate::Result<Option<Bytes>> {
+        let frame = GetSet::new(key, value).into_frame();
+
+        debug!(request = ?frame);
+
+        // 'Null'은 키에 이전 값이 없었음을 의미한다.
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(Some(value)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
     /// 'key'를 주어진 'value'에 묶어 세팅한다.
     /// 
     /// 'value'와 'key'의 연결은 'key'가 다른 'set' 호출로 덮어씌어지거나, 삭제될 때까지 
@@ -143,6 +666,7 @@ impl Client {
     ///      assert_eq!(val, "bar");
     /// }
     /// ```
+    #[instrument(skip(self))]
     pub async fn set(&mut self, key: &str, value: Bytes) -> crate::Result<()> {
         //  'Set' 커맨드를 생성하고 'set_cmd'에 전달한다. 값과 만료 시간을 함께 설정
         //  하기 위한 메서드가 따로 분리되어 있다. 두 함수의 공통부는 'set_cmd'에 구현
@@ -190,6 +714,7 @@ impl Client {
     ///      assert!(val.is_some());
     /// }
     /// ```
+    #[instrument(skip(self))]
     pub async fn set_expires(
         &mut self,
         key: &str,
@@ -202,25 +727,2465 @@ impl Client {
         self.set_cmd(Set::new(key, value, Some(expiration))).await
     }
 
-    // 'SET'의 핵심 로직. 'set', 'set_expires'에서 사용한다.
-    async fn set_cmd(&mut self, cmd: Set) -> crate::Result<()> {
-        // 'Set' 커맨드를 프레임으로 변환한다.
-        let frame = cmd.into_frame();
-
-        debug!(request = ?frame);
-
-        // 프레임을 소켓에 쓴다. 이 쓰기 작업은 완전한 프레임을 소켓에 쓴다.
-        // 필요에 따라 대기한다.
-        self.connection.write_frame(&frame).await?;
-
+    /// 'key'가 존재하지 않을 때만 'key'를 주어진 'value'에 묶어 세팅한다.
+    /// 
+    /// 값이 저장되었다면 'true'를, 키가 이미 존재하여 저장되지 않았다면 'false'를 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     assert!(client.set_nx("lock", "owner-1".into()).await.unwrap());
+    ///     assert!(!client.set_nx("lock", "owner-2".into()).await.unwrap());
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn set_nx(&mut self, key: &str, value: Bytes) -> crate::Result<bool> {
+        let frame = SetNx::new(key, value).into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 값이 저장되었다면 1을, 그렇지 않다면 0을 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response == 1),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 'value'를 저장하고, 'expiration' 후에 만료되도록 설정한다.
+    /// 
+    /// 'set_expires'와 같지만 'SET' 대신 'PSETEX' 커맨드를 사용한다. 만료 시간은 밀리초 단위로
+    /// 전송되며, 0이라면 서버는 에러를 응답한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// use std::time::Duration;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     client.setex("session", "data".into(), Duration::from_secs(60)).await.unwrap();
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn setex(
+        &mut self,
+        key: &str,
+        value: Bytes,
+        expiration: Duration,
+    ) -> crate::Result<()> {
+        let frame = SetEx::new(key, value, expiration).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    // 'SET'의 핵심 로직. 'set', 'set_expires'에서 사용한다.
+    async fn set_cmd(&mut self, cmd: Set) -> crate::Result<()> {
+        // 'Set' 커맨드를 프레임으로 변환한다.
+        let frame = cmd.into_frame();
+
+        debug!(request = ?frame);
+
         // 서버로부터 응답을 기다린다. 응답이 성공일 경우 서버는 간단히 "OK"로
         // 응답한다. 이 외에 다른 응답은 에러를 나타낸다.
-        match self.read_response().await? {
+        match self.request(&frame).await? {
             Frame::Simple(response) if response == "OK" => Ok(()),
             frame => Err(frame.to_error()),
         }
     }
 
+    /// 주어진 키들을 삭제한다.
+    /// 
+    /// 실제로 삭제된 키의 수를 반환한다. 존재하지 않는 키는 무시된다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let num = client.del(&["foo", "baz"]).await.unwrap();
+    ///      assert_eq!(num, 1);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn del(&mut self, keys: &[&str]) -> crate::Result<u64> {
+        // 'Del' 커맨드를 프레임으로 변환한다.
+        let frame = Del::new(keys).into_frame();
+
+        debug!(request = ?frame);
+
+        // 응답을 읽는다. 서버는 삭제된 키의 수를 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'del'과 같이 주어진 키들을 삭제한다. 삭제된 값의 메모리는 서버가 락을 해제한 뒤에 회수한다.
+    /// 
+    /// 실제로 삭제된 키의 수를 반환한다. 존재하지 않는 키는 무시된다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let num = client.unlink(&["foo", "baz"]).await.unwrap();
+    ///      assert_eq!(num, 1);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn unlink(&mut self, keys: &[&str]) -> crate::Result<u64> {
+        let frame = Unlink::new(keys).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 주어진 키들의 접근 시각을 갱신한다. 값은 가져오지 않는다.
+    /// 
+    /// 존재하는 키의 수를 반환한다. 존재하지 않는 키는 무시되며, 생성되지 않는다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let num = client.touch(&["foo", "baz"]).await.unwrap();
+    ///      assert_eq!(num, 1);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn touch(&mut self, keys: &[&str]) -> crate::Result<u64> {
+        let frame = Touch::new(keys).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 주어진 키들 중 존재하는 키의 수를 반환한다.
+    /// 
+    /// 값을 가져오지 않고 존재 여부만을 확인한다. 같은 키를 여러 번 지정하면 그 횟수만큼
+    /// 중복하여 센다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let num = client.exists(&["foo", "foo", "baz"]).await.unwrap();
+    ///      assert_eq!(num, 2);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn exists(&mut self, keys: &[&str]) -> crate::Result<u64> {
+        // 'Exists' 커맨드를 프레임으로 변환한다.
+        let frame = Exists::new(keys).into_frame();
+
+        debug!(request = ?frame);
+
+        // 응답을 읽는다. 서버는 존재하는 키의 수를 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 끝에 'value'를 덧붙이고, 덧붙인 뒤의 값의 길이를 반환한다.
+    /// 
+    /// 키가 존재하지 않으면 'value'를 값으로 하는 새로운 키를 생성한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      assert_eq!(client.append("log", "hello".into()).await.unwrap(), 5);
+    ///      assert_eq!(client.append("log", " world".into()).await.unwrap(), 11);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn append(&mut self, key: &str, value: Bytes) -> crate::Result<u64> {
+        let frame = Append::new(key, value).into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 덧붙인 뒤의 값의 길이를 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 길이를 바이트 단위로 반환한다. 키가 존재하지 않으면 0을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      assert_eq!(client.strlen("foo").await.unwrap(), 3);
+    ///      assert_eq!(client.strlen("baz").await.unwrap(), 0);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn strlen(&mut self, key: &str) -> crate::Result<u64> {
+        let frame = Strlen::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 'start'부터 'end'까지를 가져온다.
+    /// 
+    /// 'start'와 'end'는 양 끝을 포함하며, 음수는 값의 끝에서부터의 위치를 나타낸다. 범위가 비어있거나
+    /// 키가 존재하지 않으면 빈 값을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "hello".into()).await.unwrap();
+    /// 
+    ///      assert_eq!(client.getrange("foo", -3, -1).await.unwrap(), "llo");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn getrange(&mut self, key: &str, start: i64, end: i64) -> crate::Result<Bytes> {
+        let frame = GetRange::new(key, start, end).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(value),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값에서 1로 설정된 비트의 수를 반환한다. 키가 없다면 0을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "foobar".into()).await.unwrap();
+    /// 
+    ///      assert_eq!(client.bitcount("foo").await.unwrap(), 26);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn bitcount(&mut self, key: &str) -> crate::Result<u64> {
+        self.bitcount_cmd(BitCount::new(key, None, false).into_frame()).await
+    }
+
+    /// 'key'에 저장된 값의 'start'부터 'end'까지에서 1로 설정된 비트의 수를 반환한다.
+    /// 
+    /// 'start'와 'end'는 양 끝을 포함하며, 음수는 값의 끝에서부터의 위치를 나타낸다. 'bit'가 'true'라면
+    /// 범위를 바이트가 아닌 비트 오프셋으로 해석한다.
+    #[instrument(skip(self))]
+    pub async fn bitcount_range(
+        &mut self,
+        key: &str,
+        start: i64,
+        end: i64,
+        bit: bool,
+    ) -> crate::Result<u64> {
+        self.bitcount_cmd(BitCount::new(key, Some((start, end)), bit).into_frame()).await
+    }
+
+    // 'bitcount', 'bitcount_range'의 핵심 로직.
+    async fn bitcount_cmd(&mut self, frame: Frame) -> crate::Result<u64> {
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(count) => Ok(count as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 'offset' 위치의 비트를 'value'로 설정하고, 설정하기 전의 비트를 반환한다.
+    /// 
+    /// 'offset'이 값을 벗어나면 필요한 만큼 0 바이트로 채운다. 키가 존재하지 않으면 빈 값으로
+    /// 간주한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      assert!(!client.setbit("foo", 7, true).await.unwrap());
+    ///      assert!(client.getbit("foo", 7).await.unwrap());
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn setbit(&mut self, key: &str, offset: u64, value: bool) -> crate::Result<bool> {
+        let frame = SetBit::new(key, offset, value).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(prev) => Ok(prev != 0),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 'offset' 위치의 비트를 가져온다.
+    /// 
+    /// 'offset'이 값을 벗어나거나 키가 존재하지 않으면 'false'를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn getbit(&mut self, key: &str, offset: u64) -> crate::Result<bool> {
+        let frame = GetBit::new(key, offset).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(bit) => Ok(bit != 0),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 'offset' 위치부터를 'value'로 덮어쓰고, 덮어쓴 뒤의 값의 길이를 반환한다.
+    /// 
+    /// 'offset'이 값의 길이보다 크면 그 사이는 0 바이트로 채워진다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "hello".into()).await.unwrap();
+    /// 
+    ///      assert_eq!(client.setrange("foo", 1, "ipp".into()).await.unwrap(), 5);
+    ///      assert_eq!(client.get("foo").await.unwrap().unwrap(), "hippo");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn setrange(&mut self, key: &str, offset: u64, value: Bytes) -> crate::Result<u64> {
+        let frame = SetRange::new(key, offset, value).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 타입 이름을 반환한다. 키가 존재하지 않으면 "none"을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      assert_eq!(client.key_type("foo").await.unwrap(), "string");
+    ///      assert_eq!(client.key_type("baz").await.unwrap(), "none");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn key_type(&mut self, key: &str) -> crate::Result<String> {
+        let frame = Type::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) => Ok(response),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 저장된 값의 내부 인코딩 이름을 반환한다. 키가 존재하지 않으면 에러를 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "12".into()).await.unwrap();
+    /// 
+    ///      assert_eq!(client.object_encoding("foo").await.unwrap(), "int");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn object_encoding(&mut self, key: &str) -> crate::Result<String> {
+        let frame = Object::Encoding(key.to_string()).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(response) => Ok(String::from_utf8_lossy(&response).into_owned()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'에 마지막으로 접근한 뒤 지난 시간(초)을 반환한다. 키가 존재하지 않으면 에러를 반환한다.
+    /// 
+    /// 이 메서드의 호출은 키에 접근한 것으로 보지 않는다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let idle = client.object_idletime("foo").await.unwrap();
+    ///      println!("idle for {}s", idle);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn object_idletime(&mut self, key: &str) -> crate::Result<u64> {
+        let frame = Object::IdleTime(key.to_string()).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// glob 패턴 'pattern'과 매칭되는 모든 키를 가져온다. 순서는 정해져 있지 않다.
+    /// 
+    /// 서버는 전체 키 공간을 확인한다. 키가 많은 서버에서는 비용이 크므로 주의해야 한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("user:1", "alice".into()).await.unwrap();
+    ///      client.set("user:2", "bob".into()).await.unwrap();
+    /// 
+    ///      let keys = client.keys("user:*").await.unwrap();
+    ///      assert_eq!(keys.len(), 2);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn keys(&mut self, pattern: &str) -> crate::Result<Vec<String>> {
+        let frame = Keys::new(pattern).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(key) => Ok(String::from_utf8(key.to_vec())?),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'cursor'부터 키 공간을 순회하고, 다음 커서와 이번에 반환된 키들을 반환한다.
+    /// 
+    /// 커서 0으로 순회를 시작하고, 반환된 커서가 0이 될 때까지 반환된 커서로 다시 호출한다. 'pattern'이
+    /// 주어지면 매칭되는 키만 반환된다. 'count'는 한 번에 확인할 키 수의 힌트이다.
+    /// 
+    /// # Examples
+    /// 
+    /// 모든 키를 순회하는 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let mut cursor = 0;
+    ///      loop {
+    ///          let (next, keys) = client.scan(cursor, Some("user:*"), None).await.unwrap();
+    ///          for key in keys {
+    ///              println!("{}", key);
+    ///          }
+    /// 
+    ///          if next == 0 {
+    ///              break;
+    ///          }
+    ///          cursor = next;
+    ///      }
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn scan(
+        &mut self,
+        cursor: u64,
+        pattern: Option<&str>,
+        count: Option<u64>,
+    ) -> crate::Result<(u64, Vec<String>)> {
+        let frame = Scan::new(cursor, pattern.map(str::to_string), count).into_frame();
+
+        debug!(request = ?frame);
+
+        let response = self.request(&frame).await?;
+
+        // 서버는 '[cursor, [key ...]]' 배열을 응답한다.
+        match response {
+            Frame::Array(ref frames) => match frames.as_slice() {
+                [Frame::Bulk(cursor), Frame::Array(keys)] => {
+                    let cursor = std::str::from_utf8(cursor)?.parse::<u64>()?;
+                    let keys = keys
+                        .iter()
+                        .map(|frame| match frame {
+                            Frame::Bulk(key) => Ok(String::from_utf8(key.to_vec())?),
+                            frame => Err(frame.to_error()),
+                        })
+                        .collect::<crate::Result<_>>()?;
+
+                    Ok((cursor, keys))
+                }
+                _ => Err(response.to_error()),
+            },
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 이 커넥션이 사용할 논리 데이터베이스를 선택한다.
+    /// 
+    /// 이후의 커맨드는 선택한 데이터베이스에 수행된다. 'index'가 서버에 설정된 데이터베이스 수 이상이면
+    /// 에러를 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.select(1).await.unwrap();
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      client.select(0).await.unwrap();
+    ///      assert!(client.get("foo").await.unwrap().is_none());
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn select(&mut self, index: usize) -> crate::Result<()> {
+        let frame = Select::new(index as i64).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => {
+                if let Some(reconnect) = &mut self.reconnect {
+                    reconnect.database = index;
+                }
+
+                Ok(())
+            }
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 커넥션을 처음 수립되었을 때의 상태로 되돌린다.
+    /// 
+    /// 진행 중인 트랜잭션과 감시하는 키를 버리고, 선택한 데이터베이스를 0번으로 되돌린다. 서버에
+    /// 비밀번호가 설정되어 있다면 다시 'auth'를 호출해야 한다. 재연결 시에도 초기화된 상태를 따른다.
+    #[instrument(skip(self))]
+    pub async fn reset(&mut self) -> crate::Result<()> {
+        let frame = Reset::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "RESET" => {
+                if let Some(reconnect) = &mut self.reconnect {
+                    reconnect.database = 0;
+                    reconnect.password = None;
+                }
+
+                Ok(())
+            }
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버에게 커넥션을 닫도록 요청하고, 클라이언트를 소비한다.
+    /// 
+    /// 서버가 'OK'를 응답하면 커넥션을 drop한다. 서버는 응답을 쓴 뒤 소켓을 닫는다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    ///      client.quit().await.unwrap();
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn quit(mut self) -> crate::Result<()> {
+        let frame = Quit::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 커넥션에 이름을 붙인다. 빈 문자열은 이름을 지운다.
+    /// 
+    /// 공백이나 개행 문자를 포함한 이름은 서버가 거부한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.client_setname("worker-1").await.unwrap();
+    ///      assert_eq!(client.client_getname().await.unwrap(), "worker-1");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn client_setname(&mut self, name: &str) -> crate::Result<()> {
+        let frame = ClientCommand::SetName(name.to_string()).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 커넥션의 이름을 반환한다. 이름이 없다면 빈 문자열을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn client_getname(&mut self) -> crate::Result<String> {
+        let frame = ClientCommand::GetName.into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(response) => Ok(String::from_utf8_lossy(&response).into_owned()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버에 연결된 모든 클라이언트의 정보를 반환한다.
+    /// 
+    /// 클라이언트마다 한 줄씩, 'id=3 addr=127.0.0.1:50312 name= age=10 idle=0 db=0 cmd=get'과 같은
+    /// 형식의 문자열을 담는다.
+    #[instrument(skip(self))]
+    pub async fn client_list(&mut self) -> crate::Result<String> {
+        let frame = ClientCommand::List.into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(response) => Ok(String::from_utf8_lossy(&response).into_owned()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'id' 클라이언트의 커넥션을 닫는다. 클라이언트가 있었다면 'true'를 반환한다.
+    /// 
+    /// 클라이언트의 식별자는 'client_list'로 확인할 수 있다.
+    #[instrument(skip(self))]
+    pub async fn client_kill(&mut self, id: u64) -> crate::Result<bool> {
+        let frame = ClientCommand::KillId(id).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response == 1),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'addr' 주소('ip:port')의 클라이언트의 커넥션을 닫는다. 클라이언트가 없다면 에러를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn client_kill_addr(&mut self, addr: &str) -> crate::Result<()> {
+        let frame = ClientCommand::KillAddr(addr.to_string()).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버에 저장된 키의 수를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn dbsize(&mut self) -> crate::Result<u64> {
+        let frame = DbSize::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버에 저장된 키 중 하나를 무작위로 반환한다. 키가 없다면 'None'을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn randomkey(&mut self) -> crate::Result<Option<String>> {
+        let frame = RandomKey::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(response) => Ok(Some(String::from_utf8_lossy(&response).into_owned())),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버가 'duration' 동안 이 커넥션을 붙잡아둔 뒤 응답하도록 한다.
+    /// 
+    /// 'DEBUG SLEEP'을 사용하여 느린 서버를 흉내낸다. 타임아웃 등을 시험하기 위한 용도이다.
+    #[instrument(skip(self))]
+    pub async fn debug_sleep(&mut self, duration: Duration) -> crate::Result<()> {
+        let frame = Debug::Sleep(duration).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'pattern'과 매칭되는 서버 설정 파라미터의 이름과 값을 가져온다.
+    ///
+    /// 'pattern'은 glob 패턴이다. 매칭되는 파라미터가 없다면 빈 목록을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn config_get(&mut self, pattern: &str) -> crate::Result<Vec<(String, String)>> {
+        let frame = Config::Get(pattern.to_string()).into_frame();
+
+        debug!(request = ?frame);
+
+        let frames = match self.request(&frame).await? {
+            Frame::Array(frames) if frames.len() % 2 == 0 => frames,
+            frame => return Err(frame.to_error()),
+        };
+
+        let mut frames = frames.into_iter();
+        let mut pairs = vec![];
+        while let (Some(name), Some(value)) = (frames.next(), frames.next()) {
+            match (name, value) {
+                (Frame::Bulk(name), Frame::Bulk(value)) => pairs.push((
+                    String::from_utf8(name.to_vec())?,
+                    String::from_utf8(value.to_vec())?,
+                )),
+                (name, _) => return Err(name.to_error()),
+            }
+        }
+
+        Ok(pairs)
+    }
+
+    /// 서버 설정 파라미터 'parameter'의 값을 'value'로 변경한다.
+    #[instrument(skip(self))]
+    pub async fn config_set(&mut self, parameter: &str, value: &str) -> crate::Result<()> {
+        let frame = Config::Set(parameter.to_string(), value.to_string()).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 슬로우 로그의 기록을 최근의 것부터 최대 'count'개 가져온다. 'None'은 모든 기록을 가져온다.
+    #[instrument(skip(self))]
+    pub async fn slowlog_get(&mut self, count: Option<usize>) -> crate::Result<Vec<SlowLogEntry>> {
+        let frame = SlowLog::Get(count).into_frame();
+
+        debug!(request = ?frame);
+
+        let frames = match self.request(&frame).await? {
+            Frame::Array(frames) => frames,
+            frame => return Err(frame.to_error()),
+        };
+
+        frames.into_iter().map(slowlog_entry_from_frame).collect()
+    }
+
+    /// 슬로우 로그의 기록의 수를 가져온다.
+    #[instrument(skip(self))]
+    pub async fn slowlog_len(&mut self) -> crate::Result<u64> {
+        let frame = SlowLog::Len.into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 슬로우 로그의 모든 기록을 지운다.
+    #[instrument(skip(self))]
+    pub async fn slowlog_reset(&mut self) -> crate::Result<()> {
+        let frame = SlowLog::Reset.into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버의 상태와 통계 정보를 가져온다.
+    /// 
+    /// 'section'이 주어지면 해당 섹션만을 가져온다. 반환 값은 'key:value' 형식의 줄로 이루어진
+    /// 원본 텍스트이다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let info = client.info(Some("clients")).await.unwrap();
+    ///      println!("{}", info);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn info(&mut self, section: Option<&str>) -> crate::Result<String> {
+        let frame = Info::new(section.map(|section| section.to_string())).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) => Ok(response),
+            Frame::Bulk(response) => Ok(String::from_utf8(response.to_vec())?),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버의 키 공간을 스냅샷 파일에 저장한다.
+    /// 
+    /// 서버에 스냅샷 파일의 경로가 설정되어 있어야 한다. 저장이 끝난 뒤에 반환한다.
+    #[instrument(skip(self))]
+    pub async fn save(&mut self) -> crate::Result<()> {
+        let frame = Save::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버가 백그라운드에서 키 공간을 스냅샷 파일에 저장하도록 한다.
+    /// 
+    /// 저장의 완료를 기다리지 않고, 서버가 응답한 상태 메시지를 반환한다. 마지막 저장 이후 변경이
+    /// 없다면 서버는 저장을 생략한다.
+    #[instrument(skip(self))]
+    pub async fn bgsave(&mut self) -> crate::Result<String> {
+        let frame = BgSave::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) => Ok(response),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 이전의 쓰기 커맨드들이 'numreplicas'개의 복제본에 전달될 때까지 최대 'timeout' 동안 기다리고,
+    /// 전달을 확인한 복제본의 수를 반환한다.
+    ///
+    /// 서버는 복제를 지원하지 않으므로 항상 기다리지 않고 0을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn wait(&mut self, numreplicas: u64, timeout: Duration) -> crate::Result<u64> {
+        let frame = Wait::new(numreplicas, timeout).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 트랜잭션을 시작한다.
+    /// 
+    /// 이후 'exec'를 호출할 때까지 서버는 커맨드를 수행하지 않고 큐에 쌓는다. 큐에 쌓인 커맨드에
+    /// 서버는 'QUEUED'로 응답하므로, 트랜잭션 중에는 응답을 해석하는 다른 메서드가 에러를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn multi(&mut self) -> crate::Result<()> {
+        let frame = Multi::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 트랜잭션에 쌓인 커맨드를 수행하고, 각 커맨드의 응답을 순서대로 반환한다.
+    /// 
+    /// 응답 중 에러 프레임은 'Err'로 변환되지 않고 그대로 포함된다. 큐에 쌓는 도중 에러가 발생했다면
+    /// 어떤 커맨드도 수행되지 않고 'Err'을 반환한다. 'watch'로 감시하는 키가 변경되어 트랜잭션이
+    /// 수행되지 않았다면 'None'을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn exec(&mut self) -> crate::Result<Option<Vec<Frame>>> {
+        let frame = Exec::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(responses) => Ok(Some(responses)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'keys'의 변경을 감시한다.
+    /// 
+    /// 이후 'exec' 전에 감시하는 키가 변경되면, 트랜잭션은 수행되지 않는다. 감시는 'exec', 'discard',
+    /// 'unwatch'로 해제된다.
+    #[instrument(skip(self))]
+    pub async fn watch(&mut self, keys: &[&str]) -> crate::Result<()> {
+        let frame = Watch::new(keys).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 감시하는 모든 키의 감시를 해제한다.
+    #[instrument(skip(self))]
+    pub async fn unwatch(&mut self) -> crate::Result<()> {
+        let frame = Unwatch::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 트랜잭션에 쌓인 커맨드를 수행하지 않고 버린다.
+    #[instrument(skip(self))]
+    pub async fn discard(&mut self) -> crate::Result<()> {
+        let frame = Discard::new().into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 서버에 저장된 모든 키를 삭제한다.
+    /// 
+    /// 테스트의 준비와 정리 단계에서 유용하다. pub/sub 채널의 구독은 영향을 받지 않는다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    ///      client.flushdb().await.unwrap();
+    /// 
+    ///      assert_eq!(client.dbsize().await.unwrap(), 0);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn flushdb(&mut self) -> crate::Result<()> {
+        let frame = Flush::new(false).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 여러 키의 값을 한 번에 가져온다.
+    /// 
+    /// 반환 값의 순서는 'keys'의 순서와 같다. 존재하지 않는 키의 값은 'None'이 된다.
+    /// 'get'을 여러 번 호출하는 것과 달리, 한 번의 왕복으로 모든 값을 가져온다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let values = client.mget(&["foo", "baz"]).await.unwrap();
+    ///      assert_eq!(values, vec![Some("bar".into()), None]);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn mget(&mut self, keys: &[&str]) -> crate::Result<Vec<Option<Bytes>>> {
+        // 'MGet' 커맨드를 프레임으로 변환한다.
+        let frame = MGet::new(keys).into_frame();
+
+        debug!(request = ?frame);
+
+        // 응답을 읽는다. 서버는 각 키의 값을 담은 배열을 응답한다. 'Null' 원소는 키가
+        // 없음을 의미한다.
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Simple(value) => Ok(Some(value.into())),
+                    Frame::Bulk(value) => Ok(Some(value)),
+                    Frame::Null => Ok(None),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 여러 키-값 쌍을 한 번에 저장한다.
+    /// 
+    /// 'set'과 마찬가지로 이미 키에 연결된 값이 있으면 값을 덮어쓰며, 저장된 키는 만료 시간을
+    /// 가지지 않는다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.mset(&[("foo", "1".into()), ("bar", "2".into())]).await.unwrap();
+    /// 
+    ///      let values = client.mget(&["foo", "bar"]).await.unwrap();
+    ///      assert_eq!(values, vec![Some("1".into()), Some("2".into())]);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn mset(&mut self, pairs: &[(&str, Bytes)]) -> crate::Result<()> {
+        // 'MSet' 커맨드를 프레임으로 변환한다.
+        let frame = MSet::new(pairs).into_frame();
+
+        debug!(request = ?frame);
+
+        // 응답이 성공일 경우 서버는 간단히 "OK"로 응답한다.
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 이미 존재하는 키에 만료 시간을 설정한다. 'expiration'으로 지정한 시간이 지나면
+    /// 키는 삭제된다.
+    /// 
+    /// 키가 존재하여 만료 시간이 설정되었다면 'true'를, 키가 존재하지 않는다면 'false'를
+    /// 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// use std::time::Duration;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let updated = client.expire("foo", Duration::from_secs(10)).await.unwrap();
+    ///      assert!(updated);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn expire(&mut self, key: &str, expiration: Duration) -> crate::Result<bool> {
+        self.expire_cmd(Expire::new(key, expiration).into_frame()).await
+    }
+
+    /// 이미 존재하는 키가 'at' 시각에 만료되도록 설정한다. 이미 지난 시각이라면 키는 바로 삭제된다.
+    /// 
+    /// 키가 존재하여 만료 시각이 설정되었거나 삭제되었다면 'true'를, 키가 존재하지 않는다면 'false'를
+    /// 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// use std::time::{Duration, SystemTime};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let at = SystemTime::now() + Duration::from_secs(10);
+    ///      let updated = client.expire_at("foo", at).await.unwrap();
+    ///      assert!(updated);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn expire_at(&mut self, key: &str, at: SystemTime) -> crate::Result<bool> {
+        self.expire_cmd(ExpireAt::new(key, at).into_frame()).await
+    }
+
+    /// 키에 설정된 만료 시간을 제거한다.
+    /// 
+    /// 만료 시간이 제거되었다면 'true'를, 키가 존재하지 않거나 만료 시간이 없는 키라면
+    /// 'false'를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn persist(&mut self, key: &str) -> crate::Result<bool> {
+        self.expire_cmd(Persist::new(key).into_frame()).await
+    }
+
+    /// 'key'의 이름을 'newkey'로 변경한다. 값과 남은 만료 시간은 그대로 유지된다.
+    /// 
+    /// 'newkey'에 이미 값이 있다면 덮어쓴다. 'key'가 존재하지 않으면 에러를 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    ///      client.rename("foo", "baz").await.unwrap();
+    /// 
+    ///      let val = client.get("baz").await.unwrap().unwrap();
+    ///      assert_eq!(val, "bar");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn rename(&mut self, key: &str, newkey: &str) -> crate::Result<()> {
+        let frame = Rename::new(key, newkey, false).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'newkey'가 존재하지 않을 때만 'key'의 이름을 'newkey'로 변경한다.
+    /// 
+    /// 이름이 변경되었다면 'true'를, 'newkey'가 이미 존재한다면 'false'를 반환한다. 'key'가 존재하지
+    /// 않으면 에러를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn renamenx(&mut self, key: &str, newkey: &str) -> crate::Result<bool> {
+        let frame = Rename::new(key, newkey, true).into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 이름이 변경되었다면 1을, 그렇지 않다면 0을 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response == 1),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'source'의 값을 'destination'에 복사한다. 남은 만료 시간도 함께 복사된다.
+    /// 
+    /// 'destination'에 이미 값이 있다면 'replace'가 'true'일 때만 덮어쓴다. 값이 복사되었다면 'true'를,
+    /// 'source'가 존재하지 않거나 'destination'이 이미 존재하여 복사하지 않았다면 'false'를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn copy(
+        &mut self,
+        source: &str,
+        destination: &str,
+        replace: bool,
+    ) -> crate::Result<bool> {
+        let frame = Copy::new(source, destination, replace).into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 값이 복사되었다면 1을, 그렇지 않다면 0을 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response == 1),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 키의 남은 만료 시간을 초 단위로 가져온다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client::{self, TimeToLive};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.set("foo", "bar".into()).await.unwrap();
+    /// 
+    ///      let ttl = client.ttl("foo").await.unwrap();
+    ///      assert_eq!(ttl, TimeToLive::Persistent);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn ttl(&mut self, key: &str) -> crate::Result<TimeToLive> {
+        self.ttl_cmd(Ttl::new(key, false), Duration::from_secs).await
+    }
+
+    /// 키의 남은 만료 시간을 밀리초 단위로 가져온다.
+    /// 
+    /// 'ttl'과 같지만, 더 정밀한 값을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn pttl(&mut self, key: &str) -> crate::Result<TimeToLive> {
+        self.ttl_cmd(Ttl::new(key, true), Duration::from_millis).await
+    }
+
+    // 'TTL', 'PTTL'의 핵심 로직. 'to_duration'으로 서버의 응답 값을 만료 시간으로 변환한다.
+    async fn ttl_cmd(
+        &mut self,
+        cmd: Ttl,
+        to_duration: fn(u64) -> Duration,
+    ) -> crate::Result<TimeToLive> {
+        let frame = cmd.into_frame();
+
+        debug!(request = ?frame);
+
+        // 응답을 읽는다. 음수 값은 특별한 상태를 나타낸다.
+        match self.request(&frame).await? {
+            Frame::Integer(-2) => Ok(TimeToLive::NotFound),
+            Frame::Integer(-1) => Ok(TimeToLive::Persistent),
+            Frame::Integer(response) if response >= 0 => {
+                Ok(TimeToLive::ExpiresIn(to_duration(response as u64)))
+            }
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    // 'EXPIRE', 'PERSIST'의 핵심 로직. 두 커맨드는 같은 형태의 응답을 받는다.
+    async fn expire_cmd(&mut self, frame: Frame) -> crate::Result<bool> {
+        debug!(request = ?frame);
+
+        // 응답을 읽는다. 서버는 만료 시간이 변경되었다면 1, 그렇지 않으면 0으로 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response == 1),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 키에 저장된 정수 값을 1 증가시키고, 증가된 값을 반환한다.
+    /// 
+    /// 키가 존재하지 않으면 0에서 시작한다. 저장된 값이 정수가 아니라면 에러를 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let val = client.incr("counter").await.unwrap();
+    ///      assert_eq!(val, 1);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn incr(&mut self, key: &str) -> crate::Result<i64> {
+        self.incr_cmd(Incr::new(key).into_frame()).await
+    }
+
+    /// 키에 저장된 정수 값을 1 감소시키고, 감소된 값을 반환한다.
+    /// 
+    /// 키가 존재하지 않으면 0에서 시작한다. 저장된 값이 정수가 아니라면 에러를 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let val = client.decr("counter").await.unwrap();
+    ///      assert_eq!(val, -1);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn decr(&mut self, key: &str) -> crate::Result<i64> {
+        self.incr_cmd(Decr::new(key).into_frame()).await
+    }
+
+    // 'INCR', 'DECR'의 핵심 로직. 두 커맨드는 같은 형태의 응답을 받는다.
+    async fn incr_cmd(&mut self, frame: Frame) -> crate::Result<i64> {
+        debug!(request = ?frame);
+
+        // 응답을 읽는다. 서버는 연산 결과 값을 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트의 앞에 'values'를 차례로 삽입하고, 삽입 후의 리스트의 길이를 반환한다.
+    /// 
+    /// 값들은 하나씩 앞에 삽입되므로, 리스트에서의 순서는 'values'의 역순이 된다. 키가 존재하지
+    /// 않으면 새로운 리스트가 생성된다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let len = client.lpush("list", vec!["a".into(), "b".into()]).await.unwrap();
+    ///      assert_eq!(len, 2);
+    /// 
+    ///      let values = client.lrange("list", 0, -1).await.unwrap();
+    ///      assert_eq!(values, vec!["b", "a"]);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn lpush(&mut self, key: &str, values: Vec<Bytes>) -> crate::Result<u64> {
+        self.push_cmd(Push::new(key, values, true)).await
+    }
+
+    /// 'key'의 리스트의 뒤에 'values'를 차례로 삽입하고, 삽입 후의 리스트의 길이를 반환한다.
+    /// 
+    /// 키가 존재하지 않으면 새로운 리스트가 생성된다.
+    #[instrument(skip(self))]
+    pub async fn rpush(&mut self, key: &str, values: Vec<Bytes>) -> crate::Result<u64> {
+        self.push_cmd(Push::new(key, values, false)).await
+    }
+
+    // 'LPUSH', 'RPUSH'의 핵심 로직.
+    async fn push_cmd(&mut self, cmd: Push) -> crate::Result<u64> {
+        let frame = cmd.into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 삽입 후의 리스트의 길이를 응답한다.
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트의 앞에서 원소 하나를 꺼낸다. 리스트가 존재하지 않으면 'None'을 반환한다.
+    /// 
+    /// 리스트의 마지막 원소를 꺼내면 키는 삭제된다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      client.rpush("queue", vec!["job-1".into()]).await.unwrap();
+    /// 
+    ///      let job = client.lpop("queue").await.unwrap();
+    ///      assert_eq!(job.unwrap(), "job-1");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn lpop(&mut self, key: &str) -> crate::Result<Option<Bytes>> {
+        self.pop_cmd(Pop::new(key, None, true)).await
+    }
+
+    /// 'key'의 리스트의 뒤에서 원소 하나를 꺼낸다. 리스트가 존재하지 않으면 'None'을 반환한다.
+    /// 
+    /// 리스트의 마지막 원소를 꺼내면 키는 삭제된다.
+    #[instrument(skip(self))]
+    pub async fn rpop(&mut self, key: &str) -> crate::Result<Option<Bytes>> {
+        self.pop_cmd(Pop::new(key, None, false)).await
+    }
+
+    // 'LPOP', 'RPOP'의 핵심 로직.
+    async fn pop_cmd(&mut self, cmd: Pop) -> crate::Result<Option<Bytes>> {
+        let frame = cmd.into_frame();
+
+        debug!(request = ?frame);
+
+        // 'Null'은 리스트가 존재하지 않음을 의미한다.
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(Some(value)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'keys'의 리스트 중 처음으로 찾은 비어있지 않은 리스트의 앞에서 원소 하나를 꺼낸다.
+    /// 
+    /// 모든 리스트가 비어있다면 원소가 삽입되거나 'timeout'이 지날 때까지 기다린다. 'timeout'이
+    /// 'None'이면 무한히 기다린다. 원소를 꺼낸 키와 원소를 반환하며, 시간이 초과되면 'None'을
+    /// 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// use std::time::Duration;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let popped = client
+    ///          .blpop(&["jobs"], Some(Duration::from_secs(1)))
+    ///          .await
+    ///          .unwrap();
+    /// 
+    ///      if let Some((key, job)) = popped {
+    ///          println!("got {:?} from {}", job, key);
+    ///      }
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn blpop(
+        &mut self,
+        keys: &[&str],
+        timeout: Option<Duration>,
+    ) -> crate::Result<Option<(String, Bytes)>> {
+        self.bpop_cmd(BPop::new(keys, timeout, true)).await
+    }
+
+    /// 'blpop'과 같지만, 리스트의 뒤에서 원소를 꺼낸다.
+    #[instrument(skip(self))]
+    pub async fn brpop(
+        &mut self,
+        keys: &[&str],
+        timeout: Option<Duration>,
+    ) -> crate::Result<Option<(String, Bytes)>> {
+        self.bpop_cmd(BPop::new(keys, timeout, false)).await
+    }
+
+    // 'BLPOP', 'BRPOP'의 핵심 로직.
+    async fn bpop_cmd(&mut self, cmd: BPop) -> crate::Result<Option<(String, Bytes)>> {
+        let frame = cmd.into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 '[key, element]' 배열을 응답한다. 'Null'은 시간이 초과되었음을 의미한다.
+        match self.request(&frame).await? {
+            Frame::Null => Ok(None),
+            frame => match frame {
+                Frame::Array(ref frames) => match frames.as_slice() {
+                    [Frame::Bulk(key), Frame::Bulk(value)] => {
+                        Ok(Some((String::from_utf8(key.to_vec())?, value.clone())))
+                    }
+                    _ => Err(frame.to_error()),
+                },
+                frame => Err(frame.to_error()),
+            },
+        }
+    }
+
+    /// 'key'의 리스트의 길이를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn llen(&mut self, key: &str) -> crate::Result<u64> {
+        let frame = LLen::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트의 'start'부터 'stop'까지의 원소를 반환한다.
+    /// 
+    /// 'start'와 'stop'은 양 끝을 포함하며, 음수는 리스트의 끝에서부터의 위치를 나타낸다. 범위가
+    /// 비어있거나 키가 존재하지 않으면 빈 목록을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn lrange(&mut self, key: &str, start: i64, stop: i64) -> crate::Result<Vec<Bytes>> {
+        let frame = LRange::new(key, start, stop).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(value) => Ok(value),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트에서 'pivot'과 같은 첫 원소의 앞('before'가 'true'인 경우) 혹은 뒤에 'value'를
+    /// 삽입하고, 삽입 후의 리스트의 길이를 반환한다.
+    /// 
+    /// 'pivot'을 찾지 못하면 -1을, 키가 존재하지 않으면 0을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn linsert(
+        &mut self,
+        key: &str,
+        before: bool,
+        pivot: Bytes,
+        value: Bytes,
+    ) -> crate::Result<i64> {
+        let frame = LInsert::new(key, before, pivot, value).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트의 'index' 위치의 원소를 'value'로 바꾼다.
+    /// 
+    /// 음수 인덱스는 리스트의 끝에서부터의 위치를 나타낸다. 키가 존재하지 않거나 인덱스가 리스트의
+    /// 범위를 벗어나면 에러를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn lset(&mut self, key: &str, index: i64, value: Bytes) -> crate::Result<()> {
+        let frame = LSet::new(key, index, value).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트에서 'element'와 같은 원소를 최대 'count'개 삭제하고, 삭제한 원소의 수를
+    /// 반환한다.
+    /// 
+    /// 'count'가 양수이면 리스트의 앞에서부터, 음수이면 뒤에서부터 삭제하며, 0이면 같은 원소를 모두
+    /// 삭제한다.
+    #[instrument(skip(self))]
+    pub async fn lrem(&mut self, key: &str, count: i64, element: Bytes) -> crate::Result<u64> {
+        let frame = LRem::new(key, count, element).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트를 'start'부터 'stop'까지의 원소만 남도록 자른다.
+    /// 
+    /// 'start'와 'stop'은 'lrange'와 같이 해석한다. 남는 원소가 없으면 키는 삭제된다.
+    #[instrument(skip(self))]
+    pub async fn ltrim(&mut self, key: &str, start: i64, stop: i64) -> crate::Result<()> {
+        let frame = LTrim::new(key, start, stop).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'source' 리스트의 앞('from_left'가 'true'인 경우) 혹은 뒤에서 원소 하나를 꺼내,
+    /// 'destination' 리스트의 앞('to_left'가 'true'인 경우) 혹은 뒤에 삽입한다.
+    /// 
+    /// 원소를 꺼내고 삽입하는 일은 원자적으로 이루어진다. 두 리스트가 같다면 리스트를 회전시킨다.
+    /// 옮긴 원소를 반환하며, 'source' 리스트가 존재하지 않으면 'None'을 반환한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      // 처리할 작업을 'processing' 리스트로 옮긴다.
+    ///      let job = client.lmove("jobs", "processing", false, true).await.unwrap();
+    ///      println!("processing {:?}", job);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn lmove(
+        &mut self,
+        source: &str,
+        destination: &str,
+        from_left: bool,
+        to_left: bool,
+    ) -> crate::Result<Option<Bytes>> {
+        self.lmove_cmd(LMove::new(source, destination, from_left, to_left)).await
+    }
+
+    /// 'source' 리스트의 뒤에서 원소 하나를 꺼내 'destination' 리스트의 앞에 삽입한다.
+    /// 
+    /// 'lmove(source, destination, false, true)'와 같다.
+    #[instrument(skip(self))]
+    pub async fn rpoplpush(
+        &mut self,
+        source: &str,
+        destination: &str,
+    ) -> crate::Result<Option<Bytes>> {
+        self.lmove_cmd(LMove::rpoplpush(source, destination)).await
+    }
+
+    // 'LMOVE', 'RPOPLPUSH'의 핵심 로직.
+    async fn lmove_cmd(&mut self, cmd: LMove) -> crate::Result<Option<Bytes>> {
+        let frame = cmd.into_frame();
+
+        debug!(request = ?frame);
+
+        // 'Null'은 원본 리스트가 존재하지 않음을 의미한다.
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(Some(value)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 리스트에서 'element'와 같은 원소의 인덱스를 반환한다. 일치하는 원소가 없거나 키가
+    /// 존재하지 않으면 'None'을 반환한다.
+    /// 
+    /// 'rank'번째로 일치하는 원소를 찾는다. 1은 첫 번째로 일치하는 원소이며, 음수이면 리스트의
+    /// 뒤에서부터 찾는다. 'rank'는 0이 될 수 없다.
+    #[instrument(skip(self))]
+    pub async fn lpos(
+        &mut self,
+        key: &str,
+        element: Bytes,
+        rank: i64,
+    ) -> crate::Result<Option<u64>> {
+        let frame = LPos::new(key, element, rank, None).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(pos) => Ok(Some(pos as u64)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'lpos'와 같지만, 일치하는 원소의 인덱스를 최대 'count'개 반환한다. 'count'가 0이면 일치하는
+    /// 모든 원소의 인덱스를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn lpos_count(
+        &mut self,
+        key: &str,
+        element: Bytes,
+        rank: i64,
+        count: u64,
+    ) -> crate::Result<Vec<u64>> {
+        let frame = LPos::new(key, element, rank, Some(count)).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Integer(pos) => Ok(pos as u64),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시에 필드-값 쌍들을 저장하고, 새로 생성된 필드의 수를 반환한다.
+    /// 
+    /// 이미 존재하는 필드의 값은 덮어쓴다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let created = client
+    ///          .hset("user:1", &[("name", "alice".into()), ("age", "30".into())])
+    ///          .await
+    ///          .unwrap();
+    ///      assert_eq!(created, 2);
+    /// 
+    ///      let name = client.hget("user:1", "name").await.unwrap();
+    ///      assert_eq!(name.unwrap(), "alice");
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn hset(&mut self, key: &str, pairs: &[(&str, Bytes)]) -> crate::Result<u64> {
+        let frame = HSet::new(key, pairs).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시에서 'field'의 값을 가져온다. 키나 필드가 없다면 'None'을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hget(&mut self, key: &str, field: &str) -> crate::Result<Option<Bytes>> {
+        let frame = HGet::new(key, field).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => Ok(Some(value)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시에서 'fields'를 삭제하고, 실제로 삭제된 필드의 수를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hdel(&mut self, key: &str, fields: &[&str]) -> crate::Result<u64> {
+        let frame = HDel::new(key, fields).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시에서 'field'의 정수 값을 'increment'만큼 증가시키고, 증가된 값을 반환한다.
+    ///
+    /// 필드나 키가 없다면 0에서 시작한다. 필드의 값이 정수가 아니라면 에러를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hincrby(&mut self, key: &str, field: &str, increment: i64) -> crate::Result<i64> {
+        let frame = HIncrBy::new(key, field, increment).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시에서 'field'의 실수 값을 'increment'만큼 증가시키고, 증가된 값을 반환한다.
+    ///
+    /// 필드나 키가 없다면 0에서 시작한다. 필드의 값이 실수가 아니거나 결과가 NaN 또는 무한대라면 에러를
+    /// 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hincrbyfloat(
+        &mut self,
+        key: &str,
+        field: &str,
+        increment: f64,
+    ) -> crate::Result<f64> {
+        let frame = HIncrByFloat::new(key, field, increment).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(value) => std::str::from_utf8(&value)
+                .ok()
+                .and_then(|value| value.parse::<f64>().ok())
+                .ok_or_else(|| "protocol error; invalid float".into()),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시의 필드 수를 반환한다. 키가 없다면 0을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hlen(&mut self, key: &str) -> crate::Result<u64> {
+        let frame = HLen::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시의 모든 필드-값 쌍을 가져온다. 키가 없다면 빈 맵을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hgetall(&mut self, key: &str) -> crate::Result<HashMap<String, Bytes>> {
+        let frame = HGetAll::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        // RESP2에서는 필드와 값이 번갈아 나오는 배열을, RESP3에서는 맵을 응답받는다.
+        let pairs = match self.request(&frame).await? {
+            Frame::Array(frames) if frames.len() % 2 == 0 => {
+                let mut frames = frames.into_iter();
+                let mut pairs = vec![];
+                while let (Some(field), Some(value)) = (frames.next(), frames.next()) {
+                    pairs.push((field, value));
+                }
+                pairs
+            }
+            Frame::Map(pairs) => pairs,
+            frame => return Err(frame.to_error()),
+        };
+
+        pairs
+            .into_iter()
+            .map(|pair| match pair {
+                (Frame::Bulk(field), Frame::Bulk(value)) => {
+                    Ok((String::from_utf8(field.to_vec())?, value))
+                }
+                (field, _) => Err(field.to_error()),
+            })
+            .collect()
+    }
+
+    /// 'key'의 해시에서 무작위로 고른 필드 하나를 반환한다. 키가 없다면 'None'을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hrandfield(&mut self, key: &str) -> crate::Result<Option<String>> {
+        let frame = HRandField::new(key, None, false).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(field) => Ok(Some(String::from_utf8(field.to_vec())?)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 해시에서 무작위로 고른 필드들을 반환한다.
+    ///
+    /// 'count'가 양수라면 서로 다른 필드를 최대 'count'개, 음수라면 중복을 허용하여 정확히 '-count'개를
+    /// 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hrandfield_count(&mut self, key: &str, count: i64) -> crate::Result<Vec<String>> {
+        let frame = HRandField::new(key, Some(count), false).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(field) => Ok(String::from_utf8(field.to_vec())?),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'hrandfield_count'와 같지만, 고른 필드의 값을 함께 반환한다.
+    #[instrument(skip(self))]
+    pub async fn hrandfield_withvalues(
+        &mut self,
+        key: &str,
+        count: i64,
+    ) -> crate::Result<Vec<(String, Bytes)>> {
+        let frame = HRandField::new(key, Some(count), true).into_frame();
+
+        debug!(request = ?frame);
+
+        // RESP2에서는 필드와 값이 번갈아 나오는 배열을, RESP3에서는 쌍의 배열을 응답받는다.
+        let frames = match self.request(&frame).await? {
+            Frame::Array(frames) => frames,
+            frame => return Err(frame.to_error()),
+        };
+
+        let mut pairs = vec![];
+        let mut frames = frames.into_iter();
+        while let Some(frame) = frames.next() {
+            let pair = match frame {
+                Frame::Array(pair) => {
+                    let mut pair = pair.into_iter();
+                    (pair.next(), pair.next())
+                }
+                field => (Some(field), frames.next()),
+            };
+
+            match pair {
+                (Some(Frame::Bulk(field)), Some(Frame::Bulk(value))) => {
+                    pairs.push((String::from_utf8(field.to_vec())?, value))
+                }
+                _ => return Err("protocol error; invalid field-value pair".into()),
+            }
+        }
+
+        Ok(pairs)
+    }
+
+    /// 'key'의 셋에 'members'를 추가하고, 새로 추가된 멤버의 수를 반환한다.
+    /// 
+    /// 이미 셋에 존재하는 멤버는 추가된 수에 포함되지 않는다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let added = client.sadd("tags", vec!["a".into(), "b".into(), "a".into()]).await.unwrap();
+    ///      assert_eq!(added, 2);
+    /// 
+    ///      assert!(client.sismember("tags", "a".into()).await.unwrap());
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn sadd(&mut self, key: &str, members: Vec<Bytes>) -> crate::Result<u64> {
+        let frame = SAdd::new(key, members).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 셋에서 'members'를 삭제하고, 실제로 삭제된 멤버의 수를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn srem(&mut self, key: &str, members: Vec<Bytes>) -> crate::Result<u64> {
+        let frame = SRem::new(key, members).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 셋의 모든 멤버를 가져온다. 순서는 정해져 있지 않으며, 키가 없다면 빈 목록을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn smembers(&mut self, key: &str) -> crate::Result<Vec<Bytes>> {
+        let frame = SMembers::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        // RESP2에서는 배열을, RESP3에서는 셋을 응답받는다.
+        match self.request(&frame).await? {
+            Frame::Array(frames) | Frame::Set(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(value) => Ok(value),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 셋에서 무작위로 고른 멤버 하나를 반환한다. 키가 없다면 'None'을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn srandmember(&mut self, key: &str) -> crate::Result<Option<Bytes>> {
+        let frame = SRandMember::new(key, None).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Bulk(member) => Ok(Some(member)),
+            Frame::Null => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 셋에서 무작위로 고른 멤버들을 반환한다.
+    ///
+    /// 'count'가 양수라면 서로 다른 멤버를 최대 'count'개, 음수라면 중복을 허용하여 정확히 '-count'개를
+    /// 반환한다.
+    #[instrument(skip(self))]
+    pub async fn srandmember_count(&mut self, key: &str, count: i64) -> crate::Result<Vec<Bytes>> {
+        let frame = SRandMember::new(key, Some(count)).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(member) => Ok(member),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 셋에 'member'가 포함되어 있는지 확인한다.
+    #[instrument(skip(self))]
+    pub async fn sismember(&mut self, key: &str, member: Bytes) -> crate::Result<bool> {
+        let frame = SIsMember::new(key, member).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response == 1),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 셋의 멤버 수를 반환한다. 키가 없다면 0을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn scard(&mut self, key: &str) -> crate::Result<u64> {
+        let frame = SCard::new(key).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'keys'의 셋들의 교집합을 가져온다. 존재하지 않는 키는 빈 셋으로 취급한다.
+    #[instrument(skip(self))]
+    pub async fn sinter(&mut self, keys: &[&str]) -> crate::Result<Vec<Bytes>> {
+        self.set_algebra(SetOp::Inter, keys).await
+    }
+
+    /// 'keys'의 셋들의 합집합을 가져온다. 존재하지 않는 키는 빈 셋으로 취급한다.
+    #[instrument(skip(self))]
+    pub async fn sunion(&mut self, keys: &[&str]) -> crate::Result<Vec<Bytes>> {
+        self.set_algebra(SetOp::Union, keys).await
+    }
+
+    /// 첫 번째 키의 셋에서 나머지 키의 셋들의 멤버를 뺀 차집합을 가져온다. 존재하지 않는 키는 빈 셋으로
+    /// 취급한다.
+    #[instrument(skip(self))]
+    pub async fn sdiff(&mut self, keys: &[&str]) -> crate::Result<Vec<Bytes>> {
+        self.set_algebra(SetOp::Diff, keys).await
+    }
+
+    /// 'sinter'의 결과를 'destination'에 저장하고, 저장한 셋의 멤버 수를 반환한다.
+    /// 
+    /// 결과가 빈 셋이라면 'destination'은 삭제된다.
+    #[instrument(skip(self))]
+    pub async fn sinterstore(&mut self, destination: &str, keys: &[&str]) -> crate::Result<u64> {
+        self.set_algebra_store(SetOp::Inter, destination, keys).await
+    }
+
+    /// 'sunion'의 결과를 'destination'에 저장하고, 저장한 셋의 멤버 수를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn sunionstore(&mut self, destination: &str, keys: &[&str]) -> crate::Result<u64> {
+        self.set_algebra_store(SetOp::Union, destination, keys).await
+    }
+
+    /// 'sdiff'의 결과를 'destination'에 저장하고, 저장한 셋의 멤버 수를 반환한다.
+    /// 
+    /// 결과가 빈 셋이라면 'destination'은 삭제된다.
+    #[instrument(skip(self))]
+    pub async fn sdiffstore(&mut self, destination: &str, keys: &[&str]) -> crate::Result<u64> {
+        self.set_algebra_store(SetOp::Diff, destination, keys).await
+    }
+
+    /// 'sinter', 'sunion', 'sdiff'의 구현
+    async fn set_algebra(&mut self, op: SetOp, keys: &[&str]) -> crate::Result<Vec<Bytes>> {
+        let keys = keys.iter().map(|key| key.to_string()).collect();
+        let frame = SetAlgebra::new(op, keys).into_frame();
+
+        debug!(request = ?frame);
+
+        // 'smembers'와 같이 RESP2에서는 배열을, RESP3에서는 셋을 응답받는다.
+        match self.request(&frame).await? {
+            Frame::Array(frames) | Frame::Set(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(value) => Ok(value),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'sinterstore', 'sunionstore', 'sdiffstore'의 구현
+    async fn set_algebra_store(
+        &mut self,
+        op: SetOp,
+        destination: &str,
+        keys: &[&str],
+    ) -> crate::Result<u64> {
+        let keys = keys.iter().map(|key| key.to_string()).collect();
+        let frame = SetAlgebraStore::new(op, destination, keys).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 정렬된 셋에 점수와 멤버의 쌍 'pairs'를 추가하고, 새로 추가된 멤버의 수를 반환한다.
+    /// 
+    /// 이미 존재하는 멤버는 점수가 갱신된다.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     let added = client
+    ///         .zadd("board", vec![(10.0, "alice".into()), (20.0, "bob".into())])
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(added, 2);
+    /// 
+    ///     let top = client.zrange("board", -1, -1).await.unwrap();
+    ///     assert_eq!(top, vec!["bob"]);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn zadd(&mut self, key: &str, pairs: Vec<(f64, Bytes)>) -> crate::Result<u64> {
+        let frame = ZAdd::new(key, pairs).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 정렬된 셋에서 'member'의 점수를 가져온다. 키나 멤버가 없다면 'None'을 반환한다.
+    #[instrument(skip(self))]
+    pub async fn zscore(&mut self, key: &str, member: Bytes) -> crate::Result<Option<f64>> {
+        let frame = ZScore::new(key, member).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Null | Frame::Null3 => Ok(None),
+            frame => score_from_frame(frame).map(Some),
+        }
+    }
+
+    /// 'key'의 정렬된 셋에서 'start'부터 'stop'까지 위치의 멤버를 정렬된 순서로 가져온다.
+    /// 
+    /// 위치는 양 끝을 포함하며, 음수는 끝에서부터의 위치를 나타낸다.
+    #[instrument(skip(self))]
+    pub async fn zrange(&mut self, key: &str, start: i64, stop: i64) -> crate::Result<Vec<Bytes>> {
+        let frame = ZRange::new(key, start, stop, false).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(value) => Ok(value),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'zrange'와 같지만, 멤버와 점수의 쌍을 가져온다.
+    #[instrument(skip(self))]
+    pub async fn zrange_withscores(
+        &mut self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> crate::Result<Vec<(Bytes, f64)>> {
+        let frame = ZRange::new(key, start, stop, true).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => pairs_with_scores(frames),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 정렬된 셋에서 점수가 'min'과 'max' 사이인 멤버를 정렬된 순서로 가져온다.
+    /// 
+    /// 'Bound::Excluded'는 끝을 포함하지 않으며, 'Bound::Unbounded'는 범위의 한쪽을 열어둔다. 'limit'이
+    /// 주어지면 범위 안의 멤버 중 'offset'개를 건너뛰고 최대 'count'개를 가져온다. 'count'가 음수이면
+    /// 나머지를 모두 가져온다.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// use std::ops::Bound;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     // 점수가 1 초과 5 이하인 멤버
+    ///     let members = client
+    ///         .zrangebyscore("board", Bound::Excluded(1.0), Bound::Included(5.0), None)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", members);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn zrangebyscore(
+        &mut self,
+        key: &str,
+        min: Bound<f64>,
+        max: Bound<f64>,
+        limit: Option<(i64, i64)>,
+    ) -> crate::Result<Vec<Bytes>> {
+        let frame = ZRangeByScore::new(key, min, max, false, limit).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Bulk(value) => Ok(value),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'zrangebyscore'와 같지만, 멤버와 점수의 쌍을 가져온다.
+    #[instrument(skip(self))]
+    pub async fn zrangebyscore_withscores(
+        &mut self,
+        key: &str,
+        min: Bound<f64>,
+        max: Bound<f64>,
+        limit: Option<(i64, i64)>,
+    ) -> crate::Result<Vec<(Bytes, f64)>> {
+        let frame = ZRangeByScore::new(key, min, max, true, limit).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Array(frames) => pairs_with_scores(frames),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 'key'의 정렬된 셋에서 'member'의 위치(0부터 시작)를 가져온다. 키나 멤버가 없다면 'None'을
+    /// 반환한다.
+    #[instrument(skip(self))]
+    pub async fn zrank(&mut self, key: &str, member: Bytes) -> crate::Result<Option<u64>> {
+        let frame = ZRank::new(key, member).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(Some(response as u64)),
+            Frame::Null | Frame::Null3 => Ok(None),
+            frame => Err(frame.to_error()),
+        }
+    }
+
     /// 'message'를 주어진 'channel'에 발행(전송)한다.
     /// 
     /// 현재 채널에 구독 중인 구독자 수를 반환한다. 이 모든 구독자가 실제로 메시지를
@@ -237,22 +3202,75 @@ impl Client {
     /// async fn main() {
     ///      let mut client = client::connect("localhost:6379").await.unwrap();
     /// 
-    ///      let val = client.publish("foo").await.unwrap().unwrap();
-    ///      assert!("Got = {:?}", val);
+    ///      let val = client.publish("foo", "bar".into()).await.unwrap();
+    ///      println!("Got = {:?}", val);
     /// }
     /// ```
+    #[instrument(skip(self))]
     pub async fn publish(&mut self, channel: &str, message: Bytes) -> crate::Result<u64> {
         // 'Publish' 커맨드를 프레임으로 변환한다.
         let frame = Publish::new(channel, message).into_frame();
 
         debug!(request = ?frame);
 
-        // 프레임을 소켓에 쓴다.
-        self.connection.write_frame(&frame).await?;
-
         // 응답을 읽는다.
-        match self.read_response().await? {
-            Frame::Integer(response) => Ok(response),
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 샤드 채널에 'message'를 발행한다.
+    /// 
+    /// 'ssubscribe'로 샤드 채널을 구독한 구독자만이 메시지를 수신한다. 메시지를 수신한 구독자의
+    /// 수를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn spublish(&mut self, channel: &str, message: Bytes) -> crate::Result<u64> {
+        let frame = SPublish::new(channel, message).into_frame();
+
+        debug!(request = ?frame);
+
+        match self.request(&frame).await? {
+            Frame::Integer(response) => Ok(response as u64),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    /// 구독자가 있는 채널 목록을 가져온다.
+    /// 
+    /// 'pattern'이 주어지면 패턴과 매칭되는 채널만을 가져온다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///     let channels = client.pubsub_channels(Some("news.*")).await.unwrap();
+    ///     println!("Active channels = {:?}", channels);
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn pubsub_channels(&mut self, pattern: Option<&str>) -> crate::Result<Vec<String>> {
+        let frame = PubSub::Channels(pattern.map(|pattern| pattern.to_string())).into_frame();
+
+        debug!(request = ?frame);
+
+        // 서버는 채널 이름을 담은 배열로 응답한다.
+        match self.request(&frame).await? {
+            Frame::Array(frames) => frames
+                .into_iter()
+                .map(|frame| match frame {
+                    Frame::Simple(channel) => Ok(channel),
+                    Frame::Bulk(channel) => Ok(String::from_utf8(channel.to_vec())?),
+                    frame => Err(frame.to_error()),
+                })
+                .collect(),
             frame => Err(frame.to_error()),
         }
     }
@@ -266,30 +3284,108 @@ impl Client {
      * 'Subscriber' 값을 사용하여 메시지를 수신하고 클라이언트가 구독 중인 채널 목록을
      * 관리한다.
      */
+    #[instrument(skip(self))]
     pub async fn subscribe(mut self, channels: Vec<String>) -> crate::Result<Subscriber> {
         // 서버에 구독 커맨드를 수행하고 확인을 기다린다. 클라이언트는 "구독자" 상태로
         // 변하고, 이 시점부터 pub/sub 커맨드만 수행할 수 있다.
-        self.subscribe_cmd(&channels).await?;
+        let frame = Subscribe::new(&channels).into_frame();
+        self.subscribe_cmd(frame, "subscribe", &channels).await?;
 
         // 'Subscriber' 타입을 반환한다.
         Ok(Subscriber {
             client: self,
             subscribed_channels: channels,
+            subscribed_patterns: vec![],
+            subscribed_shard_channels: vec![],
         })
     }
 
-    // 'SUBSCRIBE'의 핵심 로직. 구독 함수들이 사용한다.
-    async fn subscribe_cmd(&mut self, channels: &[String]) -> crate::Result<()> {
-        // 'Subscribe' 커맨드를 프레임으로 변환한다.
-        let frame = Subscribe::new(&channels).into_frame();
+    /// 클라이언트가 glob 패턴을 구독한다.
+    /// 
+    /// 패턴과 매칭되는 모든 채널에 발행된 메시지를 수신한다. 패턴은 '*', '?', '[...]'를
+    /// 지원한다. 'subscribe'와 마찬가지로 'self'를 소비하여 'Subscriber'를 반환한다.
+    /// 패턴 구독으로 수신한 메시지는 'Message::pattern'에 매칭된 패턴을 담는다.
+    #[instrument(skip(self))]
+    pub async fn psubscribe(mut self, patterns: Vec<String>) -> crate::Result<Subscriber> {
+        let frame = PSubscribe::new(&patterns).into_frame();
+        self.subscribe_cmd(frame, "psubscribe", &patterns).await?;
+
+        Ok(Subscriber {
+            client: self,
+            subscribed_channels: vec![],
+            subscribed_patterns: patterns,
+            subscribed_shard_channels: vec![],
+        })
+    }
+
+    /// 클라이언트가 샤드 채널을 구독한다.
+    /// 
+    /// 샤드 채널은 'spublish'로 발행된 메시지만을 수신하며, 같은 이름의 일반 채널과 구분된다.
+    /// 'subscribe'와 마찬가지로 'self'를 소비하여 'Subscriber'를 반환한다.
+    #[instrument(skip(self))]
+    pub async fn ssubscribe(mut self, channels: Vec<String>) -> crate::Result<Subscriber> {
+        let frame = SSubscribe::new(&channels).into_frame();
+        self.subscribe_cmd(frame, "ssubscribe", &channels).await?;
+
+        Ok(Subscriber {
+            client: self,
+            subscribed_channels: vec![],
+            subscribed_patterns: vec![],
+            subscribed_shard_channels: channels,
+        })
+    }
+
+    /// 서버가 수신하는 모든 커맨드를 스트리밍하도록 요청한다.
+    /// 
+    /// 'self'를 소비하여 'Monitoring'을 반환한다. 각 커맨드는
+    /// '1339518083.107412 [0 127.0.0.1:60866] "SET" "key" "value"'와 같은 한 줄의 문자열로 수신한다.
+    /// 
+    /// # Examples
+    /// 
+    /// 기본적인 사용 예시.
+    /// 
+    /// ```no_run
+    /// use mini_redis::client;
+    /// 
+    /// #[tokio::main]
+    /// async fn main() {
+    ///      let client = client::connect("localhost:6379").await.unwrap();
+    /// 
+    ///      let mut monitor = client.monitor().await.unwrap();
+    ///      while let Some(line) = monitor.next_command().await.unwrap() {
+    ///          println!("{}", line);
+    ///      }
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn monitor(mut self) -> crate::Result<Monitoring> {
+        let frame = Monitor::new().into_frame();
 
         debug!(request = ?frame);
 
+        match self.request(&frame).await? {
+            Frame::Simple(response) if response == "OK" => Ok(Monitoring { client: self }),
+            frame => Err(frame.to_error()),
+        }
+    }
+
+    // 'SUBSCRIBE', 'PSUBSCRIBE', 'SSUBSCRIBE'의 핵심 로직. 구독 함수들이 사용한다.
+    // 
+    // 'frame'을 전송하고, 'names'의 각 채널 혹은 패턴에 대해 'kind' 타입의 구독 확인 응답을
+    // 기다린다.
+    async fn subscribe_cmd(
+        &mut self,
+        frame: Frame,
+        kind: &str,
+        names: &[String],
+    ) -> crate::Result<()> {
+        debug!(request = ?frame);
+
         // 프레임을 소켓에 쓴다.
         self.connection.write_frame(&frame).await?;
 
         // 서버는 구독 중인 각 채널에 대해 구독이 확인되었음을 메시지로 응답한다.
-        for channel in channels {
+        for channel in names {
             // 응답을 읽는다.
             let response = self.read_response().await?;
 
@@ -305,7 +3401,7 @@ impl Client {
                     // channel은 채널의 이름이며, num-subscribed는 클라이언트가 현재
                     // 구독 중인 채널의 수이다.
                     [subscribe, schannel, ..]
-                        if *subscribe == "subscribe" && *schannel == channel => {}
+                        if *subscribe == kind && *schannel == channel => {}
                     _ => return Err(response.to_error()),
                 },
                 frame => return Err(frame.to_error()),
@@ -315,6 +3411,105 @@ impl Client {
         Ok(())
     }
 
+    /// 요청 프레임을 소켓에 쓰고, 응답을 읽는다.
+    /// 
+    /// 재연결 정책이 있는 클라이언트라면, 끊어진 커넥션을 먼저 다시 수립한다. 요청 중 읽기/쓰기 에러가
+    /// 발생하면 재전송해도 안전한 커맨드에 한해 정책이 허용하는 횟수만큼 다시 시도한다. 타임아웃이
+    /// 지정되어 있다면 요청마다 적용한다.
+    async fn request(&mut self, frame: &Frame) -> crate::Result<Frame> {
+        let mut attempts = 1;
+
+        loop {
+            // 끊어진 커넥션에 요청을 쓰면 이전 요청의 응답을 읽게 될 수 있다. 재연결 정책이 없다면
+            // 요청을 보내지 않고 실패한다.
+            if self.connection.is_broken() {
+                if self.reconnect.is_none() {
+                    let err = Error::new(ErrorKind::NotConnected, "connection is no longer usable");
+
+                    return Err(err.into());
+                }
+
+                self.reconnect().await?;
+            }
+
+            let res = match self.timeout {
+                Some(timeout) => match time::timeout(timeout, self.round_trip(frame)).await {
+                    Ok(res) => res,
+                    Err(_) => {
+                        // 프레임의 일부만 읽었을 수 있으므로 커넥션을 재사용하지 않는다. 서버가
+                        // 응답하지 않는 상황이므로 재전송하지 않고 곧바로 에러를 반환한다.
+                        self.connection.poison();
+
+                        let err = Error::new(ErrorKind::TimedOut, "request timed out");
+
+                        return Err(err.into());
+                    }
+                },
+                None => self.round_trip(frame).await,
+            };
+
+            // 서버가 응답했다면 에러 프레임이라도 커넥션은 멀쩡하다. 재시도하지 않는다.
+            let retry = match &self.reconnect {
+                Some(reconnect) => {
+                    res.is_err()
+                        && self.connection.is_broken()
+                        && attempts < reconnect.policy.max_attempts
+                        && is_idempotent(frame)
+                }
+                None => false,
+            };
+
+            if !retry {
+                return res;
+            }
+
+            debug!(attempts, "connection lost, retrying request");
+            attempts += 1;
+        }
+    }
+
+    /// 프레임을 소켓에 쓰고 응답 하나를 읽는다.
+    async fn round_trip(&mut self, frame: &Frame) -> crate::Result<Frame> {
+        // 프레임을 소켓에 쓴다. 완전한 프레임을 소켓에 쓰며, 필요할 경우 대기한다.
+        self.connection.write_frame(frame).await?;
+
+        self.read_response().await
+    }
+
+    /// 커넥션을 다시 수립하고, 이전 커넥션에 적용되어 있던 'AUTH'와 'SELECT'를 다시 적용한다.
+    async fn reconnect(&mut self) -> crate::Result<()> {
+        let reconnect = match &self.reconnect {
+            Some(reconnect) => reconnect,
+            None => return Err("client was not created with a retry policy".into()),
+        };
+
+        let socket = reconnect.connect().await?;
+        let password = reconnect.password.clone();
+        let database = reconnect.database;
+
+        self.connection = Connection::boxed(socket);
+
+        if let Some(password) = password {
+            let frame = Auth::new(&password).into_frame();
+
+            match self.round_trip(&frame).await? {
+                Frame::Simple(response) if response == "OK" => {}
+                frame => return Err(frame.to_error()),
+            }
+        }
+
+        if database != 0 {
+            let frame = Select::new(database as i64).into_frame();
+
+            match self.round_trip(&frame).await? {
+                Frame::Simple(response) if response == "OK" => {}
+                frame => return Err(frame.to_error()),
+            }
+        }
+
+        Ok(())
+    }
+
     /// 소켓으로부터 응답을 읽는다.
     /// 
     /// 'Error' 프레임을 수신하면 'Err'로 변환한다.
@@ -324,21 +3519,106 @@ impl Client {
         debug!(?response);
 
         match response {
-            // 에러 프레임은 'Err'로 변환한다.
+            // 에러 프레임은 'Err'로 변환한다. 메시지의 접두어('WRONGTYPE', 'NOAUTH' 등)에 따라
+            // 'MiniRedisError'의 변형이 결정된다.
             Some(Frame::Error(msg)) => Err(msg.into()),
             Some(frame) => Ok(frame),
             None => {
                 // 여기서 'None'을 수신한다는 것은 서버가 프레임을 전송하지 않고
-                // 연결을 종료했음을 나타낸다. 이는 예상치 못한 동작이며, "connection reset by server"
+                // 연결을 종료했음을 나타낸다. 이는 예상치 못한 동작이며, 'ConnectionReset'
                 // 에러로 표시한다.
-                let err = Error::new(ErrorKind::ConnectionReset, "connection reset by server");
+                Err(MiniRedisError::ConnectionReset)
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 8,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(64),
+        }
+    }
+}
+
+impl Reconnect {
+    /// 정책에 따라 지수 백오프로 서버와의 TCP 커넥션 수립을 시도한다.
+    /// 
+    /// 'max_attempts'번 모두 실패하면 마지막 에러를 반환한다.
+    async fn connect(&self) -> crate::Result<TcpStream> {
+        let mut backoff = self.policy.initial_backoff;
+        let mut attempts = 1;
 
-                Err(err.into())
+        loop {
+            match TcpStream::connect(&self.addrs[..]).await {
+                Ok(socket) => return Ok(socket),
+                Err(err) => {
+                    if attempts >= self.policy.max_attempts {
+                        // 너무 많이 실패했다. 에러를 반환한다.
+                        return Err(err.into());
+                    }
+
+                    debug!(%err, attempts, "failed to reconnect");
+                }
             }
+
+            // 백오프 시간에 도달할 때까지 실행을 멈춘다.
+            time::sleep(backoff).await;
+
+            // 백오프 시간을 두 배로 늘리되, 최댓값을 넘지 않는다.
+            backoff = (backoff * 2).min(self.policy.max_backoff);
+            attempts += 1;
         }
     }
 }
 
+/// 점수 응답을 해석한다. RESP3에서는 double을, RESP2에서는 벌크 문자열을 응답받는다.
+fn score_from_frame(frame: Frame) -> crate::Result<f64> {
+    match frame {
+        Frame::Double(score) => Ok(score),
+        Frame::Bulk(data) => std::str::from_utf8(&data)
+            .ok()
+            .and_then(|score| score.parse().ok())
+            .ok_or_else(|| "protocol error; invalid score".into()),
+        frame => Err(frame.to_error()),
+    }
+}
+
+/// 멤버와 점수가 번갈아 담긴 응답을 멤버와 점수의 쌍 목록으로 해석한다.
+fn pairs_with_scores(frames: Vec<Frame>) -> crate::Result<Vec<(Bytes, f64)>> {
+    let mut frames = frames.into_iter();
+    let mut pairs = vec![];
+
+    while let Some(member) = frames.next() {
+        let member = match member {
+            Frame::Bulk(member) => member,
+            frame => return Err(frame.to_error()),
+        };
+
+        let score = frames.next().ok_or("protocol error; missing score")?;
+        pairs.push((member, score_from_frame(score)?));
+    }
+
+    Ok(pairs)
+}
+
+/// 'frame'이 재전송해도 안전한 커맨드인지 확인한다.
+fn is_idempotent(frame: &Frame) -> bool {
+    let name = match frame {
+        Frame::Array(frames) => match frames.first() {
+            Some(Frame::Bulk(name)) => name,
+            _ => return false,
+        },
+        _ => return false,
+    };
+
+    std::str::from_utf8(name)
+        .map(|name| IDEMPOTENT_COMMANDS.contains(&&*name.to_lowercase()))
+        .unwrap_or(false)
+}
+
 impl Subscriber {
 
     // 현재 구독 중인 채널 목록을 반환한다.
@@ -346,29 +3626,49 @@ impl Subscriber {
         &self.subscribed_channels
     }
 
+    // 현재 구독 중인 패턴 목록을 반환한다.
+    pub fn get_subscribed_patterns(&self) -> &[String] {
+        &self.subscribed_patterns
+    }
+
+    // 현재 구독 중인 샤드 채널 목록을 반환한다.
+    pub fn get_subscribed_shard_channels(&self) -> &[String] {
+        &self.subscribed_shard_channels
+    }
+
     /// 구독 채널에 발행된 다음 메시지를 수신한다. 필요에 따라 대기한다.
     /// 
-    /// 'None'은 구독이 중단되었음을 나타낸다.
+    /// 'None'은 구독이 중단되었음을 나타낸다. 메시지가 아닌 프레임을 수신하면 에러를 반환한다. 구독
+    /// 확인 응답도 함께 수신하려면 'next_event'를 사용한다.
     pub async fn next_message(&mut self) -> crate::Result<Option<Message>> {
         match self.client.connection.read_frame().await? {
             Some(mframe) => {
                 debug!(?mframe);
 
-                match mframe {
-                    Frame::Array(ref frame) => match frame.as_slice() {
-                        [message, channel, content] if *message == "message" => Ok(Some(Message {
-                            channel: channel.to_string(),
-                            content: Bytes::from(content.to_string()),
-                        })),
-                        _ => Err(mframe.to_error()),
-                    },
-                    frame => Err(frame.to_error()),
+                match event_from_frame(&mframe)? {
+                    Event::Message(message) => Ok(Some(message)),
+                    _ => Err(mframe.to_error()),
                 }
             }
             None => Ok(None),
         }
     }
 
+    /// 다음 이벤트를 수신한다. 필요에 따라 대기한다.
+    /// 
+    /// 발행된 메시지뿐 아니라 구독과 구독 해지의 확인 응답도 수신한 순서대로 반환한다. 'None'은
+    /// 구독이 중단되었음을 나타낸다.
+    pub async fn next_event(&mut self) -> crate::Result<Option<Event>> {
+        match self.client.connection.read_frame().await? {
+            Some(frame) => {
+                debug!(?frame);
+
+                event_from_frame(&frame).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
     /// subscriber를 'Stream'으로 변환한다. 이 'Stream'은 구독 채널에 발행된 
     /// 메시지를 생산한다.
     /// 
@@ -387,64 +3687,304 @@ impl Subscriber {
         }
     }
 
+    /// subscriber를 'Event'를 생산하는 'Stream'으로 변환한다.
+    /// 
+    /// 'into_stream'과 달리 메시지 사이에 도착하는 구독과 구독 해지의 확인 응답도 생산하므로, 소비자가
+    /// 구독 상태의 변화를 추적할 수 있다.
+    pub fn into_event_stream(mut self) -> impl Stream<Item = crate::Result<Event>> {
+        try_stream! {
+            while let Some(event) = self.next_event().await? {
+                yield event;
+            }
+        }
+    }
+
     /// 채널 목록을 구독한다.
     pub async fn subscribe(&mut self, channels: &[String]) -> crate::Result<()> {
         // 구독 커맨드를 수행한다.
-        self.client.subscribe_cmd(channels).await?;
+        let frame = Subscribe::new(channels).into_frame();
+        self.client.subscribe_cmd(frame, "subscribe", channels).await?;
+
+        // 구독 채널 목록을 갱신한다. 서버는 이미 구독 중인 채널을 다시 구독하지 않는다.
+        for channel in channels {
+            if !self.subscribed_channels.contains(channel) {
+                self.subscribed_channels.push(channel.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 패턴 목록을 구독한다.
+    pub async fn psubscribe(&mut self, patterns: &[String]) -> crate::Result<()> {
+        let frame = PSubscribe::new(patterns).into_frame();
+        self.client.subscribe_cmd(frame, "psubscribe", patterns).await?;
+
+        // 구독 패턴 목록을 갱신한다. 채널과 마찬가지로 중복하여 추가하지 않는다.
+        for pattern in patterns {
+            if !self.subscribed_patterns.contains(pattern) {
+                self.subscribed_patterns.push(pattern.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 샤드 채널 목록을 구독한다.
+    pub async fn ssubscribe(&mut self, channels: &[String]) -> crate::Result<()> {
+        let frame = SSubscribe::new(channels).into_frame();
+        self.client.subscribe_cmd(frame, "ssubscribe", channels).await?;
+
+        for channel in channels {
+            if !self.subscribed_shard_channels.contains(channel) {
+                self.subscribed_shard_channels.push(channel.clone());
+            }
+        }
 
-        // 구독 채널 목록을 갱신한다.
-        self.subscribed_channels
-            .extend(channels.iter().map(Clone::clone));
-        
         Ok(())
     }
 
     /// 채널 목록으로 구독을 해지한다.
     pub async fn unsubscribe(&mut self, channels: &[String]) -> crate::Result<()> {
-        let frame = Unsubscribe::new(&channels).into_frame();
+        let frame = Unsubscribe::new(channels).into_frame();
+
+        unsubscribe_cmd(
+            &mut self.client,
+            &mut self.subscribed_channels,
+            frame,
+            "unsubscribe",
+            channels,
+        )
+        .await
+    }
+
+    /// 패턴 목록으로 구독을 해지한다.
+    pub async fn punsubscribe(&mut self, patterns: &[String]) -> crate::Result<()> {
+        let frame = PUnsubscribe::new(patterns).into_frame();
+
+        unsubscribe_cmd(
+            &mut self.client,
+            &mut self.subscribed_patterns,
+            frame,
+            "punsubscribe",
+            patterns,
+        )
+        .await
+    }
+
+    /// 샤드 채널 목록으로 구독을 해지한다.
+    pub async fn sunsubscribe(&mut self, channels: &[String]) -> crate::Result<()> {
+        let frame = SUnsubscribe::new(channels).into_frame();
+
+        unsubscribe_cmd(
+            &mut self.client,
+            &mut self.subscribed_shard_channels,
+            frame,
+            "sunsubscribe",
+            channels,
+        )
+        .await
+    }
+
+    /// 구독을 마치고 서버에게 커넥션을 닫도록 요청한다.
+    /// 
+    /// 'OK' 응답을 받기 전에 도착한 메시지는 버린다.
+    pub async fn quit(mut self) -> crate::Result<()> {
+        let frame = Quit::new().into_frame();
 
         debug!(request = ?frame);
 
-        // 프레임을 소켓에 쓴다.
         self.client.connection.write_frame(&frame).await?;
 
-        // 인풋 채널 목록이 비어있다면 서버는 모든 구독 채널로부터의 구독을 해지한다.
-        // 때문에 수신한 해지 목록과 클라이언트의 구독 채널 목록을 비교한다.
-        let num = if channels.is_empty() {
-            self.subscribed_channels.len()
-        } else {
-            channels.len()
-        };
+        loop {
+            match self.client.read_response().await? {
+                Frame::Simple(response) if response == "OK" => return Ok(()),
+                frame => match event_from_frame(&frame)? {
+                    Event::Message(_) => {}
+                    _ => return Err(frame.to_error()),
+                },
+            }
+        }
+    }
+}
 
-        // 응답을 읽는다.
-        for _ in 0..num {
-            let response = self.client.read_response().await?;
+impl Monitoring {
+    /// 서버가 수신한 다음 커맨드를 반환한다. 필요에 따라 대기한다.
+    /// 
+    /// 'None'은 커넥션이 닫혔음을 나타낸다.
+    pub async fn next_command(&mut self) -> crate::Result<Option<String>> {
+        match self.client.connection.read_frame().await? {
+            Some(Frame::Simple(line)) => Ok(Some(line)),
+            Some(frame) => Err(frame.to_error()),
+            None => Ok(None),
+        }
+    }
 
-            match response {
-                Frame::Array(ref frame) => match frame.as_slice() {
-                    [unsubscribe, channel, ..] if *unsubscribe == "unsubscribe" => {
-                        let len = self.subscribed_channels.len();
-
-                        if len == 0 {
-                            // 최소 1개의 채널이 있어야 한다.
-                            return Err(response.to_error());
-                        }
-
-                        // 이 시점에는 해지된 채널이 아직 구독 목록에 남아있다.
-                        // 해지된 채널을 목록에서 제거한다.
-                        self.subscribed_channels.retain(|c| *channel != &c[..]);
-
-                        // 구독 채널 목록에서 삭제된 채널은 단 하나여야 한다.
-                        if self.subscribed_channels.len() != len - 1 {
-                            return Err(response.to_error());
-                        }
-                    }
-                    _ => return Err(response.to_error()),
-                },
+    /// 모니터링을 마치고 서버에게 커넥션을 닫도록 요청한다.
+    /// 
+    /// 'OK' 응답을 받기 전에 도착한 커맨드는 버린다. 커맨드와 'OK' 응답은 모두 단순 문자열이므로,
+    /// 'OK'와 같은 줄은 커맨드일 수 없다는 점을 이용한다.
+    pub async fn quit(mut self) -> crate::Result<()> {
+        let frame = Quit::new().into_frame();
+
+        debug!(request = ?frame);
+
+        self.client.connection.write_frame(&frame).await?;
+
+        loop {
+            match self.client.read_response().await? {
+                Frame::Simple(response) if response == "OK" => return Ok(()),
+                Frame::Simple(_) => {}
                 frame => return Err(frame.to_error()),
-            };
+            }
         }
+    }
+}
 
-        Ok(())
+// 'SLOWLOG GET'이 응답한 기록 하나를 'SlowLogEntry'로 변환한다.
+// 
+// 기록은 '[id, timestamp, duration, [arg ...], addr, name]' 배열이다.
+fn slowlog_entry_from_frame(frame: Frame) -> crate::Result<SlowLogEntry> {
+    let parts = match frame {
+        Frame::Array(parts) => parts,
+        frame => return Err(frame.to_error()),
+    };
+
+    let mut parts = parts.into_iter();
+    match (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) {
+        (
+            Some(Frame::Integer(id)),
+            Some(Frame::Integer(timestamp)),
+            Some(Frame::Integer(duration)),
+            Some(Frame::Array(args)),
+            Some(Frame::Bulk(addr)),
+            Some(Frame::Bulk(name)),
+        ) => {
+            let args = args
+                .into_iter()
+                .map(|arg| match arg {
+                    Frame::Bulk(arg) => Ok(arg),
+                    frame => Err(frame.to_error()),
+                })
+                .collect::<crate::Result<_>>()?;
+
+            Ok(SlowLogEntry {
+                id: id as u64,
+                timestamp: timestamp as u64,
+                duration: Duration::from_micros(duration as u64),
+                args,
+                addr: String::from_utf8(addr.to_vec())?,
+                name: String::from_utf8(name.to_vec())?,
+            })
+        }
+        _ => Err("protocol error; invalid slowlog entry".into()),
+    }
+}
+
+// 구독 상태에서 수신한 프레임을 'Event'로 변환한다.
+// 
+// 서버는 메시지와 확인 응답을 모두 배열 프레임으로 보내며, 첫 번째 원소로 종류를 구분한다.
+fn event_from_frame(frame: &Frame) -> crate::Result<Event> {
+    let frames = match frame {
+        Frame::Array(frames) => frames,
+        frame => return Err(frame.to_error()),
+    };
+
+    match frames.as_slice() {
+        [message, channel, content] if *message == "message" || *message == "smessage" => {
+            Ok(Event::Message(Message {
+                channel: channel.to_string(),
+                content: Bytes::from(content.to_string()),
+                pattern: None,
+            }))
+        }
+        // 패턴 구독으로 수신한 메시지는 매칭된 패턴을 함께 담는다.
+        [message, pattern, channel, content] if *message == "pmessage" => {
+            Ok(Event::Message(Message {
+                channel: channel.to_string(),
+                content: Bytes::from(content.to_string()),
+                pattern: Some(pattern.to_string()),
+            }))
+        }
+        [kind, channel, Frame::Integer(count)]
+            if *kind == "subscribe" || *kind == "psubscribe" || *kind == "ssubscribe" =>
+        {
+            Ok(Event::Subscribed {
+                channel: channel.to_string(),
+                count: *count as u64,
+            })
+        }
+        [kind, channel, Frame::Integer(count)]
+            if *kind == "unsubscribe" || *kind == "punsubscribe" || *kind == "sunsubscribe" =>
+        {
+            Ok(Event::Unsubscribed {
+                channel: channel.to_string(),
+                count: *count as u64,
+            })
+        }
+        _ => Err(frame.to_error()),
+    }
+}
+
+// 'UNSUBSCRIBE', 'PUNSUBSCRIBE', 'SUNSUBSCRIBE'의 핵심 로직.
+// 
+// 'frame'을 전송하고, 'kind' 타입의 구독 해지 응답을 받을 때마다 'subscribed'에서 해지된 채널
+// 혹은 패턴을 제거한다.
+async fn unsubscribe_cmd(
+    client: &mut Client,
+    subscribed: &mut Vec<String>,
+    frame: Frame,
+    kind: &str,
+    names: &[String],
+) -> crate::Result<()> {
+    debug!(request = ?frame);
+
+    // 프레임을 소켓에 쓴다.
+    client.connection.write_frame(&frame).await?;
+
+    // 인풋 목록이 비어있다면 서버는 모든 구독으로부터의 구독을 해지한다.
+    // 때문에 수신한 해지 목록과 클라이언트의 구독 목록을 비교한다.
+    let num = if names.is_empty() {
+        subscribed.len()
+    } else {
+        names.len()
+    };
+
+    // 응답을 읽는다.
+    for _ in 0..num {
+        let response = client.read_response().await?;
+
+        match response {
+            Frame::Array(ref frame) => match frame.as_slice() {
+                [unsubscribe, channel, ..] if *unsubscribe == kind => {
+                    let len = subscribed.len();
+
+                    if len == 0 {
+                        // 최소 1개의 구독이 있어야 한다.
+                        return Err(response.to_error());
+                    }
+
+                    // 이 시점에는 해지된 구독이 아직 구독 목록에 남아있다.
+                    // 해지된 구독을 목록에서 제거한다.
+                    subscribed.retain(|c| *channel != &c[..]);
+
+                    // 구독 목록에서 삭제된 구독은 단 하나여야 한다.
+                    if subscribed.len() != len - 1 {
+                        return Err(response.to_error());
+                    }
+                }
+                _ => return Err(response.to_error()),
+            },
+            frame => return Err(frame.to_error()),
+        };
     }
-}
\ No newline at end of file
+
+    Ok(())
+}
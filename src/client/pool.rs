@@ -0,0 +1,162 @@
+use crate::client::{self, Client};
+
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+/// 'Pool::builder'가 사용하는 유휴 커넥션 수의 기본 최댓값
+const DEFAULT_MAX_SIZE: usize = 8;
+
+/// 한 주소의 Redis 서버에 대한 클라이언트 커넥션 풀
+///
+/// 'Client'는 커넥션 하나를 뮤터블하게 점유하므로, 여러 태스크가 동시에 요청을 보내려면 태스크마다
+/// 커넥션이 필요하다. 요청마다 새 커넥션을 수립하는 대신, 'Pool'은 사용이 끝난 커넥션을 최대
+/// 'max_size'개까지 보관해두었다가 다음 'get' 호출에 다시 내어준다.
+///
+/// 'Pool'은 내부적으로 'Arc'를 사용하므로, 값싸게 clone하여 여러 태스크에 전달할 수 있다.
+///
+/// # Example
+///
+/// ```no_run
+/// use mini_redis::client::Pool;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let pool = Pool::builder("localhost:6379").max_size(4).build();
+///
+///     let mut client = pool.get().await.unwrap();
+///     client.set("hello", "world".into()).await.unwrap();
+///
+///     // 'client'가 drop되면 커넥션은 풀로 반환된다.
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Pool {
+    shared: Arc<Shared>,
+}
+
+/// 'Pool'을 설정하여 생성한다.
+///
+/// ['Pool::builder'](fn@Pool::builder)로 얻는다.
+#[derive(Debug)]
+pub struct PoolBuilder {
+    addr: String,
+    max_size: usize,
+}
+
+/// 'Pool::get'으로 풀에서 꺼낸 클라이언트
+///
+/// 'Client'로 deref되므로 'Client'의 메서드를 그대로 호출할 수 있다. 'PooledClient'가 drop되면
+/// 클라이언트는 풀로 반환된다. 단, 읽기/쓰기 중 에러가 발생한 커넥션은 재사용하지 않고 닫는다.
+pub struct PooledClient {
+    /// 풀로 반환할 클라이언트. drop 시점에 꺼내기 위해 'Option'으로 감싼다.
+    client: Option<Client>,
+
+    /// 클라이언트를 꺼내온 풀
+    shared: Arc<Shared>,
+}
+
+/// 모든 'Pool' 핸들과 'PooledClient'가 공유하는 상태
+struct Shared {
+    /// 커넥션을 수립할 서버의 주소
+    addr: String,
+
+    /// 보관할 유휴 커넥션 수의 최댓값
+    max_size: usize,
+
+    /// 유휴 커넥션 목록. 락 안에서 await하는 일이 없으므로 'std::sync::Mutex'로 충분하다.
+    idle: Mutex<Vec<Client>>,
+}
+
+impl Pool {
+    /// 'addr'의 서버에 대한 풀을 설정하는 'PoolBuilder'를 반환한다.
+    pub fn builder(addr: impl Into<String>) -> PoolBuilder {
+        PoolBuilder {
+            addr: addr.into(),
+            max_size: DEFAULT_MAX_SIZE,
+        }
+    }
+
+    /// 풀에서 클라이언트 하나를 꺼낸다.
+    ///
+    /// 유휴 커넥션이 있다면 이를 재사용하고, 없다면 새 커넥션을 수립한다. 커넥션 수립에 실패하면
+    /// 에러를 반환한다.
+    pub async fn get(&self) -> crate::Result<PooledClient> {
+        // 락을 쥔 채로 await하지 않도록, 유휴 커넥션을 꺼내는 즉시 락을 해제한다.
+        let idle = self.shared.idle.lock().unwrap().pop();
+
+        let client = match idle {
+            Some(client) => client,
+            None => {
+                debug!(addr = %self.shared.addr, "opening new pooled connection");
+                client::connect(self.shared.addr.as_str()).await?
+            }
+        };
+
+        Ok(PooledClient {
+            client: Some(client),
+            shared: self.shared.clone(),
+        })
+    }
+
+    /// 현재 풀에 보관된 유휴 커넥션의 수를 반환한다.
+    pub fn idle_count(&self) -> usize {
+        self.shared.idle.lock().unwrap().len()
+    }
+}
+
+impl PoolBuilder {
+    /// 풀에 보관할 유휴 커넥션 수의 최댓값을 지정한다.
+    ///
+    /// 동시에 사용 중인 커넥션 수를 제한하지는 않는다. 최댓값을 넘어서 반환되는 커넥션은 닫힌다.
+    pub fn max_size(mut self, max_size: usize) -> PoolBuilder {
+        self.max_size = max_size;
+        self
+    }
+
+    /// 설정에 따라 'Pool'을 생성한다. 커넥션은 'Pool::get'이 호출될 때 수립된다.
+    pub fn build(self) -> Pool {
+        Pool {
+            shared: Arc::new(Shared {
+                addr: self.addr,
+                max_size: self.max_size,
+                idle: Mutex::new(Vec::with_capacity(self.max_size)),
+            }),
+        }
+    }
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        self.client.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Client {
+        self.client.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledClient {
+    fn drop(&mut self) {
+        let client = match self.client.take() {
+            Some(client) => client,
+            None => return,
+        };
+
+        // 에러가 발생한 커넥션은 스트림의 상태를 신뢰할 수 없으므로 풀로 반환하지 않는다.
+        if client.connection.is_broken() {
+            debug!("discarding broken pooled connection");
+            return;
+        }
+
+        let mut idle = self.shared.idle.lock().unwrap();
+
+        if idle.len() < self.shared.max_size {
+            idle.push(client);
+        }
+    }
+}
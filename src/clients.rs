@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::Notify;
+
+/// 서버에 연결된 클라이언트의 목록. 'CLIENT' 커맨드가 사용한다.
+///
+/// 리스너와 모든 커넥션 핸들러가 'Arc'로 공유한다. 핸들러는 생성될 때 자신을 등록하고, drop될 때
+/// 등록을 해지한다. 키는 리스너가 커넥션마다 부여하는 식별자이다.
+#[derive(Debug, Default)]
+pub(crate) struct Clients {
+    clients: Mutex<HashMap<u64, ClientInfo>>,
+}
+
+/// 연결된 클라이언트 하나의 정보
+#[derive(Debug)]
+struct ClientInfo {
+    /// 클라이언트의 주소. TCP 커넥션이라면 'ip:port'이다.
+    addr: String,
+
+    /// 'CLIENT SETNAME'으로 붙인 이름
+    name: Option<String>,
+
+    /// 마지막 커맨드를 수행할 때 선택되어 있던 데이터베이스
+    db: usize,
+
+    /// 커넥션이 수립된 시각
+    connected_at: Instant,
+
+    /// 마지막으로 수행한 커맨드의 이름. 아직 커맨드를 수행하지 않았다면 'NULL'이다.
+    last_command: String,
+
+    /// 마지막 커맨드를 수신한 시각
+    last_command_at: Instant,
+
+    /// 'CLIENT KILL'이 커넥션의 핸들러에게 종료를 알린다. 핸들러가 같은 값을 가진다.
+    kill: Arc<Notify>,
+}
+
+impl Clients {
+    /// 빈 클라이언트 목록을 생성한다.
+    pub(crate) fn new() -> Clients {
+        Clients::default()
+    }
+
+    /// 'addr'에서 연결된 클라이언트를 'id'로 등록한다.
+    ///
+    /// 'CLIENT KILL'로 이 클라이언트를 종료할 때 알림을 받을 'Notify'를 반환한다.
+    pub(crate) fn register(&self, id: u64, addr: String) -> Arc<Notify> {
+        let kill = Arc::new(Notify::new());
+        let now = Instant::now();
+        let info = ClientInfo {
+            addr,
+            name: None,
+            db: 0,
+            connected_at: now,
+            last_command: "NULL".to_string(),
+            last_command_at: now,
+            kill: kill.clone(),
+        };
+
+        self.clients.lock().unwrap().insert(id, info);
+
+        kill
+    }
+
+    /// 'id' 클라이언트의 등록을 해지한다.
+    pub(crate) fn unregister(&self, id: u64) {
+        self.clients.lock().unwrap().remove(&id);
+    }
+
+    /// 'id' 클라이언트가 'db' 데이터베이스에서 'command'를 수신했음을 기록한다.
+    ///
+    /// 커맨드를 수행하기 전에 호출하므로, 오래 걸리는 커맨드를 수행 중인 클라이언트도 그 커맨드로
+    /// 표시된다.
+    pub(crate) fn command_received(&self, id: u64, command: &str, db: usize) {
+        if let Some(info) = self.clients.lock().unwrap().get_mut(&id) {
+            info.last_command.clear();
+            info.last_command.push_str(command);
+            info.last_command_at = Instant::now();
+            info.db = db;
+        }
+    }
+
+    /// 'id' 클라이언트의 이름을 반환한다.
+    pub(crate) fn name(&self, id: u64) -> Option<String> {
+        self.clients
+            .lock()
+            .unwrap()
+            .get(&id)
+            .and_then(|info| info.name.clone())
+    }
+
+    /// 'id' 클라이언트의 이름을 변경한다. 'None'은 이름을 지운다.
+    pub(crate) fn set_name(&self, id: u64, name: Option<String>) {
+        if let Some(info) = self.clients.lock().unwrap().get_mut(&id) {
+            info.name = name;
+        }
+    }
+
+    /// 'id' 클라이언트의 핸들러에게 커넥션을 종료하도록 알린다. 클라이언트가 있었다면 'true'를 반환한다.
+    ///
+    /// 핸들러가 알림을 기다리고 있지 않더라도 알림은 저장되며, 핸들러는 다음에 알림을 확인할 때
+    /// 커넥션을 종료한다.
+    pub(crate) fn kill(&self, id: u64) -> bool {
+        match self.clients.lock().unwrap().get(&id) {
+            Some(info) => {
+                info.kill.notify_one();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 'addr' 주소의 클라이언트의 핸들러에게 커넥션을 종료하도록 알린다. 클라이언트가 있었다면 'true'를
+    /// 반환한다.
+    pub(crate) fn kill_addr(&self, addr: &str) -> bool {
+        let clients = self.clients.lock().unwrap();
+        let mut killed = false;
+
+        for info in clients.values().filter(|info| info.addr == addr) {
+            info.kill.notify_one();
+            killed = true;
+        }
+
+        killed
+    }
+
+    /// 'CLIENT LIST'가 응답하는 형식으로 모든 클라이언트의 정보를 반환한다.
+    ///
+    /// 클라이언트마다 한 줄씩, 식별자의 순서로 나열한다. 'age'와 'idle'은 각각 커넥션이 수립된 뒤와
+    /// 마지막 커맨드를 수신한 뒤 지난 시간(초)이다.
+    pub(crate) fn list(&self) -> String {
+        let clients = self.clients.lock().unwrap();
+        let now = Instant::now();
+
+        let mut ids: Vec<_> = clients.keys().copied().collect();
+        ids.sort_unstable();
+
+        let mut list = String::new();
+        for id in ids {
+            let info = &clients[&id];
+
+            // 'String'에 쓰는 것은 실패하지 않는다.
+            let _ = writeln!(
+                list,
+                "id={} addr={} name={} age={} idle={} db={} cmd={}",
+                id,
+                info.addr,
+                info.name.as_deref().unwrap_or(""),
+                now.duration_since(info.connected_at).as_secs(),
+                now.duration_since(info.last_command_at).as_secs(),
+                info.db,
+                info.last_command,
+            );
+        }
+
+        list
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Connection, Frame, Parse};
+
+use bytes::Bytes;
+use std::fmt;
+use tracing::{debug, instrument};
+
+/// 커넥션을 인증한다.
+///
+/// 서버에 비밀번호('requirepass')가 설정되어 있다면, 커넥션은 'AUTH'로 인증하기 전까지 'AUTH'와
+/// 'HELLO' 외의 커맨드를 수행할 수 없다. 비밀번호가 맞으면 'OK'를, 틀리면 에러를 응답한다.
+///
+/// 커넥션의 인증 상태는 핸들러가 가지므로, 이 커맨드는 'Command::apply'가 아닌 핸들러가 직접 수행한다.
+pub struct Auth {
+    password: String,
+}
+
+// 커맨드는 서버와 클라이언트에서 로깅되므로, 비밀번호가 로그에 남지 않도록 직접 구현한다.
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Auth").field("password", &"<redacted>").finish()
+    }
+}
+
+impl Auth {
+    /// 'password'로 인증하는 'Auth' 커맨드를 생성한다.
+    pub fn new(password: impl ToString) -> Auth {
+        Auth {
+            password: password.to_string(),
+        }
+    }
+
+    /// 수신한 프레임으로부터 'Auth' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'AUTH' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Auth' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// AUTH password
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Auth> {
+        let password = parse.next_string()?;
+
+        Ok(Auth { password })
+    }
+
+    /// 'Auth' 커맨드를 수행한다.
+    ///
+    /// 'requirepass'는 서버에 설정된 비밀번호이다. 응답은 'dst'에 쓰여진다. 인증에 성공했다면 'true'를
+    /// 반환한다. 인증에 실패해도 커넥션의 기존 인증 상태는 유지된다.
+    #[instrument(skip(self, requirepass, dst))]
+    pub(crate) async fn apply(
+        self,
+        requirepass: Option<&str>,
+        dst: &mut Connection,
+    ) -> crate::Result<bool> {
+        let (authenticated, response) = match requirepass {
+            Some(password) if password == self.password => {
+                (true, Frame::Simple("OK".to_string()))
+            }
+            Some(_) => (false, Frame::Error("ERR invalid password".to_string())),
+            None => (
+                false,
+                Frame::Error("ERR Client sent AUTH, but no password is set".to_string()),
+            ),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(authenticated)
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Auth'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("auth".as_bytes()));
+        frame.push_bulk(Bytes::from(self.password.into_bytes()));
+        frame
+    }
+}
@@ -0,0 +1,77 @@
+use crate::snapshot::Persistence;
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// 백그라운드 태스크에서 키 공간을 스냅샷 파일에 저장한다.
+///
+/// 'SAVE'와 달리 저장의 완료를 기다리지 않고 바로 응답하므로, 커넥션은 저장 중에도 다른 커맨드를
+/// 수행할 수 있다. 저장의 진행 여부는 'INFO persistence'의 'rdb_bgsave_in_progress'로 확인한다.
+///
+/// 마지막 저장 이후 키 공간이 변경되지 않았다면 저장을 생략한다. 다른 저장이 진행 중이라면 에러를
+/// 응답한다.
+#[derive(Debug, Default)]
+pub struct BgSave {}
+
+impl BgSave {
+    /// 새로운 'BgSave' 커맨드를 생성한다.
+    pub fn new() -> BgSave {
+        BgSave {}
+    }
+
+    /// 수신한 프레임으로부터 'BgSave' 인스턴스를 파싱한다.
+    ///
+    /// 'BGSAVE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// BGSAVE
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<BgSave> {
+        Ok(BgSave {})
+    }
+
+    /// 'BgSave' 커맨드를 수행한다.
+    ///
+    /// 'SAVE'와 마찬가지로 서버에 설정된 스냅샷 경로를 필요로 하므로, 핸들러가 직접 이 함수를 호출한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, db, persistence, dst))]
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        persistence: Option<&Arc<Persistence>>,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match persistence {
+            // 저장할 변경이 없다.
+            Some(_) if db.changes_since_save() == 0 => {
+                Frame::Simple("Background saving skipped, no changes since last save".to_string())
+            }
+            Some(persistence) => match persistence.bgsave(db.clone()) {
+                Ok(()) => Frame::Simple("Background saving started".to_string()),
+                Err(err) => Frame::Error(err.to_string()),
+            },
+            None => Frame::Error("ERR no snapshot file configured".to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'BgSave'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("bgsave".as_bytes()));
+        frame
+    }
+}
@@ -0,0 +1,132 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 값에서 1로 설정된 비트의 수를 반환한다.
+///
+/// 범위가 지정되지 않으면 값 전체를 센다. 'start'와 'end'는 양 끝을 포함하는 오프셋이며, 'GETRANGE'와
+/// 같이 음수는 값의 끝에서부터의 위치를 나타낸다. 오프셋은 기본적으로 바이트 단위이고, 'BIT'가
+/// 지정되면 비트 단위가 된다. 키가 존재하지 않으면 0을 반환한다.
+#[derive(Debug)]
+pub struct BitCount {
+    /// 비트를 셀 키
+    key: String,
+
+    /// 셀 범위의 시작과 끝 오프셋. 'None'이라면 값 전체를 센다.
+    range: Option<(i64, i64)>,
+
+    /// 범위를 비트 오프셋으로 해석할지 여부
+    bit: bool,
+}
+
+impl BitCount {
+    /// 'key'에 저장된 값의 비트를 세는 'BitCount' 커맨드를 생성한다.
+    ///
+    /// 'bit'는 'range'가 지정된 경우에만 사용된다.
+    pub fn new(key: impl ToString, range: Option<(i64, i64)>, bit: bool) -> BitCount {
+        BitCount {
+            key: key.to_string(),
+            range,
+            bit: bit && range.is_some(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'BitCount' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'BITCOUNT' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'BitCount' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// BITCOUNT key [start end [BYTE|BIT]]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<BitCount> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        let start = match parse.next_signed_int() {
+            Ok(start) => start,
+            Err(EndOfStream) => {
+                return Ok(BitCount {
+                    key,
+                    range: None,
+                    bit: false,
+                })
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        //  'start'만 지정할 수는 없다.
+        let end = match parse.next_signed_int() {
+            Ok(end) => end,
+            Err(EndOfStream) => return Err("ERR syntax error".into()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let bit = match parse.next_string() {
+            Ok(unit) => match &unit.to_uppercase()[..] {
+                "BYTE" => false,
+                "BIT" => true,
+                _ => return Err("ERR syntax error".into()),
+            },
+            Err(EndOfStream) => false,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(BitCount {
+            key,
+            range: Some((start, end)),
+            bit,
+        })
+    }
+
+    /// 'BitCount' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.bitcount(&self.key, self.range, self.bit) {
+            Ok(count) => Frame::Integer(count as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'BitCount'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("bitcount".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        if let Some((start, end)) = self.range {
+            frame.push_int(start);
+            frame.push_int(end);
+        }
+        if self.bit {
+            frame.push_bulk(Bytes::from("bit".as_bytes()));
+        }
+        frame
+    }
+}
@@ -0,0 +1,168 @@
+use crate::clients::Clients;
+use crate::cmd::Parse;
+use crate::{Connection, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 커넥션의 클라이언트 정보를 조회하거나 변경한다.
+///
+/// 다음의 서브커맨드를 지원한다:
+///
+/// * SETNAME 'name' -- 커넥션에 이름을 붙이고 OK를 응답한다. 빈 문자열은 이름을 지운다. 공백이나
+///   개행 문자를 포함한 이름은 거부한다.
+/// * GETNAME -- 커넥션의 이름을 응답한다. 이름이 없다면 빈 문자열을 응답한다.
+/// * LIST -- 연결된 모든 클라이언트의 정보를 한 줄에 하나씩 담은 문자열을 응답한다. 각 줄은
+///   'id=3 addr=127.0.0.1:50312 name= age=10 idle=0 db=0 cmd=get'과 같은 형식이다.
+/// * KILL ID 'id' -- 'id' 클라이언트의 커넥션을 닫는다. 닫은 커넥션의 수를 응답한다.
+/// * KILL ADDR 'ip:port' -- 'ip:port' 주소의 클라이언트의 커넥션을 닫는다. OK를 응답하며,
+///   클라이언트가 없다면 에러를 응답한다.
+///
+/// 커넥션은 핸들러가 수행 중인 커맨드를 중단하고 닫힌다. 자신의 커넥션을 닫을 수도 있으며, 이 경우
+/// 응답을 쓴 뒤에 닫힌다.
+///
+/// 이름은 커넥션이 닫히면 사라진다.
+#[derive(Debug)]
+pub enum ClientCommand {
+    /// 'CLIENT SETNAME name'
+    SetName(String),
+
+    /// 'CLIENT GETNAME'
+    GetName,
+
+    /// 'CLIENT LIST'
+    List,
+
+    /// 'CLIENT KILL ID id'
+    KillId(u64),
+
+    /// 'CLIENT KILL ADDR ip:port'
+    KillAddr(String),
+}
+
+impl ClientCommand {
+    /// 수신한 프레임으로부터 'ClientCommand' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'CLIENT' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'ClientCommand' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// CLIENT SETNAME name
+    /// CLIENT GETNAME
+    /// CLIENT LIST
+    /// CLIENT KILL ID id
+    /// CLIENT KILL ADDR ip:port
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<ClientCommand> {
+        let subcommand = parse.next_string()?.to_lowercase();
+
+        match &subcommand[..] {
+            "setname" => Ok(ClientCommand::SetName(parse.next_string()?)),
+            "getname" => Ok(ClientCommand::GetName),
+            "list" => Ok(ClientCommand::List),
+            "kill" => {
+                let filter = parse.next_string()?.to_lowercase();
+
+                match &filter[..] {
+                    "id" => {
+                        let id = parse
+                            .next_string()?
+                            .parse::<u64>()
+                            .map_err(|_| "ERR client-id should be greater than 0")?;
+
+                        Ok(ClientCommand::KillId(id))
+                    }
+                    "addr" => Ok(ClientCommand::KillAddr(parse.next_string()?)),
+                    _ => Err("ERR syntax error".into()),
+                }
+            }
+            _ => Err(format!("ERR unknown subcommand '{}' for 'client'", subcommand).into()),
+        }
+    }
+
+    /// 'ClientCommand' 커맨드를 수행한다.
+    ///
+    /// 클라이언트 목록은 모든 커넥션이 공유하는 서버 상태이므로, 'Command::apply'를 거치지 않고
+    /// 핸들러가 직접 이 함수를 호출한다. 'id'는 커맨드를 수신한 커넥션의 식별자이다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, clients, dst))]
+    pub(crate) async fn apply(
+        self,
+        clients: &Clients,
+        id: u64,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match self {
+            ClientCommand::SetName(new_name) => {
+                if new_name.chars().any(|ch| ch.is_whitespace() || ch.is_control()) {
+                    Frame::Error(
+                        "ERR Client names cannot contain spaces, newlines or special characters."
+                            .to_string(),
+                    )
+                } else {
+                    clients.set_name(id, Some(new_name).filter(|name| !name.is_empty()));
+                    Frame::Simple("OK".to_string())
+                }
+            }
+            ClientCommand::GetName => {
+                let name = clients.name(id).unwrap_or_default();
+                Frame::Bulk(Bytes::from(name.into_bytes()))
+            }
+            ClientCommand::List => Frame::Bulk(Bytes::from(clients.list().into_bytes())),
+            ClientCommand::KillId(id) => Frame::Integer(clients.kill(id) as i64),
+            ClientCommand::KillAddr(addr) => {
+                if clients.kill_addr(&addr) {
+                    Frame::Simple("OK".to_string())
+                } else {
+                    Frame::Error("ERR No such client".to_string())
+                }
+            }
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ClientCommand'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("client".as_bytes()));
+        match self {
+            ClientCommand::SetName(name) => {
+                frame.push_bulk(Bytes::from("setname".as_bytes()));
+                frame.push_bulk(Bytes::from(name.into_bytes()));
+            }
+            ClientCommand::GetName => {
+                frame.push_bulk(Bytes::from("getname".as_bytes()));
+            }
+            ClientCommand::List => {
+                frame.push_bulk(Bytes::from("list".as_bytes()));
+            }
+            ClientCommand::KillId(id) => {
+                frame.push_bulk(Bytes::from("kill".as_bytes()));
+                frame.push_bulk(Bytes::from("id".as_bytes()));
+                frame.push_bulk(Bytes::from(id.to_string().into_bytes()));
+            }
+            ClientCommand::KillAddr(addr) => {
+                frame.push_bulk(Bytes::from("kill".as_bytes()));
+                frame.push_bulk(Bytes::from("addr".as_bytes()));
+                frame.push_bulk(Bytes::from(addr.into_bytes()));
+            }
+        }
+        frame
+    }
+}
@@ -0,0 +1,156 @@
+use crate::cmd::{CommandSpec, Parse, ParseError, COMMANDS};
+use crate::{Connection, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 서버가 지원하는 커맨드의 정보를 조회한다.
+///
+/// 클라이언트 라이브러리가 연결 시 서버의 기능을 확인하기 위해 사용한다. 커맨드의 정보는 'COMMANDS'
+/// 목록으로부터 만들어지므로, 목록에 등록된 커맨드는 자동으로 응답에 포함된다. 다음의 형태를 지원한다:
+///
+/// * COMMAND -- 모든 커맨드의 '[name, arity]' 배열
+/// * COMMAND COUNT -- 커맨드의 수
+/// * COMMAND INFO ['name' ...] -- 주어진 커맨드의 '[name, arity]' 배열. 지원하지 않는 커맨드는 nil이
+///   된다.
+/// * COMMAND DOCS ['name' ...] -- 커맨드의 문서. mini-redis는 문서를 제공하지 않으므로 항상 빈 맵을
+///   응답한다.
+#[derive(Debug)]
+pub enum CommandInfo {
+    /// 'COMMAND'
+    List,
+
+    /// 'COMMAND COUNT'
+    Count,
+
+    /// 'COMMAND INFO [name ...]'
+    Info(Vec<String>),
+
+    /// 'COMMAND DOCS [name ...]'
+    Docs,
+}
+
+impl CommandInfo {
+    /// 수신한 프레임으로부터 'CommandInfo' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'COMMAND' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'CommandInfo' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// COMMAND
+    /// COMMAND COUNT
+    /// COMMAND INFO [name ...]
+    /// COMMAND DOCS [name ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<CommandInfo> {
+        use ParseError::EndOfStream;
+
+        let subcommand = match parse.next_string() {
+            Ok(subcommand) => subcommand.to_lowercase(),
+            Err(EndOfStream) => return Ok(CommandInfo::List),
+            Err(err) => return Err(err.into()),
+        };
+
+        // 'INFO'와 'DOCS'는 커맨드 이름 목록을 받는다. 목록은 비어있을 수 있다.
+        let mut names = vec![];
+        if subcommand == "info" || subcommand == "docs" {
+            loop {
+                match parse.next_string() {
+                    Ok(name) => names.push(name.to_lowercase()),
+                    Err(EndOfStream) => break,
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+
+        match &subcommand[..] {
+            "count" => Ok(CommandInfo::Count),
+            "info" => Ok(CommandInfo::Info(names)),
+            "docs" => Ok(CommandInfo::Docs),
+            _ => Err(format!("ERR unknown subcommand '{}' for 'command'", subcommand).into()),
+        }
+    }
+
+    /// 'CommandInfo' 커맨드를 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, dst))]
+    pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
+        let response = match self {
+            CommandInfo::List => Frame::Array(COMMANDS.iter().map(describe).collect()),
+            CommandInfo::Count => Frame::Integer(COMMANDS.len() as i64),
+            CommandInfo::Info(names) => Frame::Array(
+                names
+                    .iter()
+                    .map(|name| match COMMANDS.iter().find(|spec| spec.name == name) {
+                        Some(spec) => describe(spec),
+                        None => Frame::Null,
+                    })
+                    .collect(),
+            ),
+            CommandInfo::Docs => Frame::Map(vec![]),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+}
+
+/// 커맨드 하나의 정보를 '[name, arity]' 배열로 만든다.
+fn describe(spec: &CommandSpec) -> Frame {
+    Frame::Array(vec![
+        Frame::Bulk(Bytes::from_static(spec.name.as_bytes())),
+        Frame::Integer(spec.arity),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_sorted_and_unique() {
+        for pair in COMMANDS.windows(2) {
+            // 엄격한 오름차순이므로 같은 이름이 두 번 등록될 수 없다.
+            assert!(pair[0].name < pair[1].name, "{} >= {}", pair[0].name, pair[1].name);
+        }
+
+        for spec in COMMANDS {
+            assert_eq!(spec.name, spec.name.to_lowercase());
+        }
+    }
+
+    #[tokio::test]
+    async fn info_describes_registered_command() {
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let mut connection = Connection::boxed(client);
+        let mut peer = Connection::new(server);
+
+        let names = vec!["lpos".to_string(), "nosuchcommand".to_string()];
+        CommandInfo::Info(names).apply(&mut connection).await.unwrap();
+        connection.flush().await.unwrap();
+
+        let infos = match peer.read_frame().await.unwrap() {
+            Some(Frame::Array(infos)) => infos,
+            frame => panic!("unexpected frame: {:?}", frame),
+        };
+        match &infos[..] {
+            [Frame::Array(lpos), Frame::Null] => match &lpos[..] {
+                [name, Frame::Integer(-3)] => assert_eq!(*name, "lpos"),
+                info => panic!("unexpected info: {:?}", info),
+            },
+            infos => panic!("unexpected infos: {:?}", infos),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::cmd::Parse;
+use crate::config::RuntimeConfig;
+use crate::{Connection, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 가동 중인 서버의 설정을 조회하거나 변경한다.
+///
+/// 다음의 서브커맨드를 지원한다:
+///
+/// * GET 'pattern' -- 'pattern'과 매칭되는 파라미터의 이름과 값을 번갈아 담은 배열을 응답한다.
+///   'pattern'은 glob 패턴이다.
+/// * SET 'parameter' 'value' -- 파라미터의 값을 변경하고 OK를 응답한다.
+///
+/// 다음의 파라미터를 지원한다:
+///
+/// * maxclients -- 동시에 수용하는 최대 커넥션 수. 줄이더라도 이미 수립된 커넥션은 종료하지 않는다.
+/// * maxmemory -- 사용할 수 있는 최대 메모리(바이트). 0이면 제한이 없다.
+/// * maxmemory-policy -- 메모리 사용량이 'maxmemory'를 넘었을 때의 정책. 'noeviction' 혹은
+///   'allkeys-lru'이다.
+/// * timeout -- 유휴 커넥션을 종료하기까지의 시간(초). 0이면 종료하지 않는다.
+#[derive(Debug)]
+pub enum Config {
+    /// 'CONFIG GET pattern'
+    Get(String),
+
+    /// 'CONFIG SET parameter value'
+    Set(String, String),
+}
+
+impl Config {
+    /// 수신한 프레임으로부터 'Config' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'CONFIG' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Config' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// CONFIG GET pattern
+    /// CONFIG SET parameter value
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Config> {
+        let subcommand = parse.next_string()?.to_lowercase();
+
+        match &subcommand[..] {
+            "get" => Ok(Config::Get(parse.next_string()?)),
+            "set" => {
+                let parameter = parse.next_string()?;
+                let value = parse.next_string()?;
+
+                Ok(Config::Set(parameter, value))
+            }
+            _ => Err(format!("ERR unknown subcommand '{}' for 'config'", subcommand).into()),
+        }
+    }
+
+    /// 'Config' 커맨드를 수행한다.
+    ///
+    /// 설정은 모든 커넥션이 공유하는 서버 상태이므로, 'Command::apply'를 거치지 않고 핸들러가 직접 이
+    /// 함수를 호출한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, config, dst))]
+    pub(crate) async fn apply(
+        self,
+        config: &RuntimeConfig,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match self {
+            Config::Get(pattern) => {
+                let mut response = Frame::array();
+                for (name, value) in config.get(&pattern) {
+                    response.push_bulk(Bytes::from(name.as_bytes()));
+                    response.push_bulk(Bytes::from(value.into_bytes()));
+                }
+                response
+            }
+            Config::Set(parameter, value) => match config.set(&parameter, &value) {
+                Ok(()) => Frame::Simple("OK".to_string()),
+                Err(msg) => Frame::Error(msg),
+            },
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Config'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("config".as_bytes()));
+        match self {
+            Config::Get(pattern) => {
+                frame.push_bulk(Bytes::from("get".as_bytes()));
+                frame.push_bulk(Bytes::from(pattern.into_bytes()));
+            }
+            Config::Set(parameter, value) => {
+                frame.push_bulk(Bytes::from("set".as_bytes()));
+                frame.push_bulk(Bytes::from(parameter.into_bytes()));
+                frame.push_bulk(Bytes::from(value.into_bytes()));
+            }
+        }
+        frame
+    }
+}
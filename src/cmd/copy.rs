@@ -0,0 +1,106 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키의 값을 다른 키에 복사한다.
+///
+/// 레디스와 같이 남은 만료 시간도 함께 복사된다. 대상 키에 이미 값이 있다면 'REPLACE'가 주어졌을
+/// 때만 덮어쓴다. 값을 복사했다면 1을, 원본 키가 존재하지 않거나 대상 키가 이미 존재하여 복사하지
+/// 않았다면 0을 응답한다. 원본과 대상이 같은 키라면 에러로 응답한다.
+#[derive(Debug)]
+pub struct Copy {
+    /// 복사할 키
+    source: String,
+
+    /// 값을 복사받을 키
+    destination: String,
+
+    /// 'true'이면 대상 키에 이미 있는 값을 덮어쓴다.
+    replace: bool,
+}
+
+impl Copy {
+    /// 'source'의 값을 'destination'에 복사하는 새로운 'Copy' 커맨드를 생성한다.
+    ///
+    /// 'replace'가 'true'이면 'destination'에 이미 있는 값을 덮어쓴다.
+    pub fn new(source: impl ToString, destination: impl ToString, replace: bool) -> Copy {
+        Copy {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            replace,
+        }
+    }
+
+    /// 복사할 키를 가져온다.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// 값을 복사받을 키를 가져온다.
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// 수신한 프레임으로부터 'Copy' 인스턴스를 파싱한다.
+    ///
+    /// 'COPY' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 혹은 네 개의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// COPY source destination [REPLACE]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Copy> {
+        use ParseError::EndOfStream;
+
+        let source = parse.next_string()?;
+        let destination = parse.next_string()?;
+
+        let replace = match parse.next_string() {
+            Ok(option) if option.to_uppercase() == "REPLACE" => true,
+            Ok(_) => return Err("ERR syntax error".into()),
+            Err(EndOfStream) => false,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Copy {
+            source,
+            destination,
+            replace,
+        })
+    }
+
+    /// 'Copy' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.copy(&self.source, &self.destination, self.replace) {
+            Ok(copied) => Frame::Integer(copied as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Copy'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("copy".as_bytes()));
+        frame.push_bulk(Bytes::from(self.source.into_bytes()));
+        frame.push_bulk(Bytes::from(self.destination.into_bytes()));
+        if self.replace {
+            frame.push_bulk(Bytes::from("replace".as_bytes()));
+        }
+        frame
+    }
+}
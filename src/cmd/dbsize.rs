@@ -0,0 +1,52 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 데이터베이스에 저장된 키의 수를 반환한다. 만료된 키는 포함하지 않는다.
+#[derive(Debug, Default)]
+pub struct DbSize {}
+
+impl DbSize {
+    /// 새로운 'DbSize' 커맨드를 생성한다.
+    pub fn new() -> DbSize {
+        DbSize {}
+    }
+
+    /// 수신한 프레임으로부터 'DbSize' 인스턴스를 파싱한다.
+    ///
+    /// 'DBSIZE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// DBSIZE
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<DbSize> {
+        Ok(DbSize {})
+    }
+
+    /// 'DbSize' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = Frame::Integer(db.dbsize() as i64);
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'DbSize'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("dbsize".as_bytes()));
+        frame
+    }
+}
@@ -0,0 +1,99 @@
+use crate::cmd::Parse;
+use crate::{Connection, Frame, Shutdown};
+
+use bytes::Bytes;
+use std::time::Duration;
+use tokio::time;
+use tracing::{debug, instrument};
+
+/// 서버의 동작을 시험하기 위한 디버깅 커맨드
+///
+/// 다음의 서브커맨드를 지원한다:
+///
+/// * SLEEP 'seconds' -- 주어진 시간(초) 동안 커넥션을 붙잡아둔 뒤 OK를 응답한다. 소수점 이하의
+///   값을 허용한다.
+///
+/// 'SLEEP'은 느린 서버를 흉내내어 클라이언트의 타임아웃이나 배압(backpressure)을 시험하는 데 쓰인다.
+#[derive(Debug)]
+pub enum Debug {
+    /// 'DEBUG SLEEP seconds'
+    Sleep(Duration),
+}
+
+impl Debug {
+    /// 수신한 프레임으로부터 'Debug' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'DEBUG' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Debug' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// DEBUG SLEEP seconds
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Debug> {
+        let subcommand = parse.next_string()?.to_lowercase();
+
+        match &subcommand[..] {
+            "sleep" => {
+                let seconds = parse
+                    .next_string()?
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+                    .ok_or("ERR value is not a valid float")?;
+
+                Ok(Debug::Sleep(Duration::from_secs_f64(seconds)))
+            }
+            _ => Err(format!("ERR unknown subcommand '{}' for 'debug'", subcommand).into()),
+        }
+    }
+
+    /// 'Debug' 커맨드를 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    ///
+    /// 기다리는 동안 셧다운 시그널을 수신하면 응답 없이 반환한다.
+    #[instrument(skip(self, dst, shutdown))]
+    pub(crate) async fn apply(
+        self,
+        dst: &mut Connection,
+        shutdown: &mut Shutdown,
+    ) -> crate::Result<()> {
+        let response = match self {
+            Debug::Sleep(duration) => {
+                tokio::select! {
+                    _ = time::sleep(duration) => Frame::Simple("OK".to_string()),
+                    _ = shutdown.recv() => return Ok(()),
+                }
+            }
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Debug'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("debug".as_bytes()));
+        match self {
+            Debug::Sleep(duration) => {
+                frame.push_bulk(Bytes::from("sleep".as_bytes()));
+                frame.push_bulk(Bytes::from(duration.as_secs_f64().to_string().into_bytes()));
+            }
+        }
+        frame
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 정수 값을 1 감소시킨다.
+///
+/// 키가 존재하지 않으면 연산 전에 값을 0으로 간주한다. 키에 저장된 값이 정수로 표현될 수 없는
+/// 문자열이거나 연산 결과가 64비트 부호 있는 정수의 범위를 벗어나면 에러를 반환한다.
+///
+/// 키에 설정된 만료 시간은 유지된다.
+#[derive(Debug)]
+pub struct Decr {
+    /// 감소시킬 키
+    key: String,
+}
+
+impl Decr {
+    /// 'key'의 값을 감소시키는 새로운 'Decr' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> Decr {
+        Decr {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'Decr' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'DECR' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Decr' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// DECR key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Decr> {
+        let key = parse.next_string()?;
+
+        Ok(Decr { key })
+    }
+
+    /// 'Decr' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 공유 데이터베이스 상태의 값을 감소시킨다. 값이 정수가 아니라면 에러 프레임으로 응답한다.
+        // 이 에러는 커넥션을 중단시키지 않는다.
+        let response = match db.incr_by(&self.key, -1) {
+            Ok(value) => Frame::Integer(value),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Decr'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("decr".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
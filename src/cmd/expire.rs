@@ -0,0 +1,262 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, instrument};
+
+/// 이미 존재하는 키에 만료 시간을 설정한다.
+///
+/// 만료 시간이 지나면 키는 자동으로 삭제된다. 키에 이미 만료 시간이 설정되어 있다면 새로운
+/// 만료 시간으로 덮어쓴다.
+///
+/// 'EXPIRE'는 만료 시간을 초 단위로, 'PEXPIRE'는 밀리초 단위로 지정한다. 두 커맨드는 모두
+/// 'Expire'로 파싱된다.
+#[derive(Debug)]
+pub struct Expire {
+    /// 만료 시간을 설정할 키
+    key: String,
+
+    /// 만료 시간
+    expire: Duration,
+}
+
+/// 이미 존재하는 키가 주어진 시각에 만료되도록 설정한다.
+///
+/// 'EXPIRE'와 같지만, 만료 시간 대신 만료될 시각을 유닉스 시간으로 지정한다. 이미 지난 시각을
+/// 지정하면 키는 바로 삭제된다.
+///
+/// 'EXPIREAT'는 시각을 초 단위로, 'PEXPIREAT'는 밀리초 단위로 지정한다. 두 커맨드는 모두
+/// 'ExpireAt'으로 파싱된다.
+#[derive(Debug)]
+pub struct ExpireAt {
+    /// 만료 시각을 설정할 키
+    key: String,
+
+    /// 만료 시각
+    at: SystemTime,
+}
+
+/// 키에 설정된 만료 시간을 제거한다.
+///
+/// 만료 시간이 제거된 키는 삭제되거나 덮어씌워질 때까지 유지된다.
+#[derive(Debug)]
+pub struct Persist {
+    /// 만료 시간을 제거할 키
+    key: String,
+}
+
+impl Expire {
+    /// 'key'가 'expire' 이후에 만료되도록 설정하는 새로운 'Expire' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, expire: Duration) -> Expire {
+        Expire {
+            key: key.to_string(),
+            expire,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 만료 시간을 가져온다.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// 수신한 프레임으로부터 'Expire' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'EXPIRE' 혹은 'PEXPIRE' 문자열은 이미 소비되었다. 두 커맨드는 만료 시간의 단위만 다르다.
+    /// 'to_duration'은 파싱된 정수를 만료 시간으로 변환한다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Expire' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// EXPIRE key seconds
+    /// PEXPIRE key milliseconds
+    /// ```
+    pub(crate) fn parse_frames(
+        parse: &mut Parse,
+        to_duration: fn(u64) -> Duration,
+    ) -> crate::Result<Expire> {
+        let key = parse.next_string()?;
+        let expire = to_duration(parse.next_int()?);
+
+        Ok(Expire { key, expire })
+    }
+
+    /// 'Expire' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 키가 존재하여 만료 시간이 설정되면 1, 그렇지 않으면 0으로 응답한다.
+        let updated = db.expire(&self.key, Some(self.expire));
+
+        let response = Frame::Integer(updated as i64);
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Expire'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        // 'SET'과 마찬가지로, 값을 더 정밀하게 표현할 수 있는 밀리초 단위의 'PEXPIRE'를 사용한다.
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("pexpire".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.expire.as_millis() as i64);
+        frame
+    }
+}
+
+impl ExpireAt {
+    /// 'key'가 'at' 시각에 만료되도록 설정하는 새로운 'ExpireAt' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, at: SystemTime) -> ExpireAt {
+        ExpireAt {
+            key: key.to_string(),
+            at,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 만료 시각을 가져온다.
+    pub fn at(&self) -> SystemTime {
+        self.at
+    }
+
+    /// 수신한 프레임으로부터 'ExpireAt' 인스턴스를 파싱한다.
+    ///
+    /// 'EXPIREAT' 혹은 'PEXPIREAT' 문자열은 이미 소비되었다. 두 커맨드는 시각의 단위만 다르다.
+    /// 'to_duration'은 파싱된 정수를 유닉스 에포크로부터의 시간으로 변환한다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'ExpireAt' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// EXPIREAT key unix-time-seconds
+    /// PEXPIREAT key unix-time-milliseconds
+    /// ```
+    pub(crate) fn parse_frames(
+        parse: &mut Parse,
+        to_duration: fn(u64) -> Duration,
+    ) -> crate::Result<ExpireAt> {
+        let key = parse.next_string()?;
+        let at = UNIX_EPOCH
+            .checked_add(to_duration(parse.next_int()?))
+            .ok_or("ERR invalid expire time")?;
+
+        Ok(ExpireAt { key, at })
+    }
+
+    /// 'ExpireAt' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 키가 존재하여 만료 시각이 설정되거나 삭제되면 1, 그렇지 않으면 0으로 응답한다.
+        let updated = db.expire_at(&self.key, self.at);
+
+        let response = Frame::Integer(updated as i64);
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ExpireAt'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        // 'Expire'와 마찬가지로 밀리초 단위의 'PEXPIREAT'를 사용한다. 에포크 이전의 시각은 이미
+        // 지난 시각이므로 0으로 보낸다.
+        let millis = self
+            .at
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_millis() as i64)
+            .unwrap_or(0);
+
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("pexpireat".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(millis);
+        frame
+    }
+}
+
+impl Persist {
+    /// 'key'의 만료 시간을 제거하는 새로운 'Persist' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> Persist {
+        Persist {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'Persist' 인스턴스를 파싱한다.
+    ///
+    /// 'PERSIST' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// PERSIST key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Persist> {
+        let key = parse.next_string()?;
+
+        Ok(Persist { key })
+    }
+
+    /// 'Persist' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 만료 시간이 제거되면 1, 키가 없거나 만료 시간이 없던 키라면 0으로 응답한다.
+        let updated = db.expire(&self.key, None);
+
+        let response = Frame::Integer(updated as i64);
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Persist'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("persist".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
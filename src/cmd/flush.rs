@@ -0,0 +1,88 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 모든 키를 삭제한다. 'FLUSHDB'와 'FLUSHALL'.
+///
+/// 'FLUSHDB'는 커넥션이 선택한 데이터베이스의 키만을, 'FLUSHALL'은 모든 데이터베이스의 키를 삭제한다.
+/// pub/sub 채널의 구독은 유지된다.
+///
+/// 레디스와의 호환을 위해 'ASYNC', 'SYNC' 옵션을 받지만, 삭제는 항상 커맨드 안에서 수행된다.
+#[derive(Debug)]
+pub struct Flush {
+    /// 'true'이면 모든 데이터베이스의 키를 삭제한다 ('FLUSHALL').
+    all: bool,
+}
+
+impl Flush {
+    /// 새로운 'Flush' 커맨드를 생성한다. 'all'이 'true'이면 'FLUSHALL'로 요청한다.
+    pub fn new(all: bool) -> Flush {
+        Flush { all }
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.all {
+            "flushall"
+        } else {
+            "flushdb"
+        }
+    }
+
+    /// 수신한 프레임으로부터 'Flush' 인스턴스를 파싱한다.
+    ///
+    /// 'FLUSHDB' 혹은 'FLUSHALL' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 커맨드 이름과 선택적인 옵션을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// FLUSHDB [ASYNC|SYNC]
+    /// FLUSHALL [ASYNC|SYNC]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, all: bool) -> crate::Result<Flush> {
+        use ParseError::EndOfStream;
+
+        match parse.next_string() {
+            Ok(option) => match &option.to_uppercase()[..] {
+                "ASYNC" | "SYNC" => {}
+                _ => return Err("ERR syntax error".into()),
+            },
+            Err(EndOfStream) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        Ok(Flush { all })
+    }
+
+    /// 'Flush' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        if self.all {
+            db.flush_all();
+        } else {
+            db.flush();
+        }
+
+        let response = Frame::Simple("OK".to_string());
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Flush'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame
+    }
+}
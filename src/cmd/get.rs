@@ -54,20 +54,22 @@ impl Get {
     /// 'Get' 커맨드를 특정 'Db' 인스턴스에 수행한다. 
     /// 
     /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
     pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
         // 공유 데이터베이스 상태로부터 값을 가져온다.
-        let response = if let Some(value) = db.get(&self.key) {
+        let response = match db.get(&self.key) {
             // 값이 존재하면 "bulk" 형식으로 클라이언트에게 응답한다.
-            Frame::Bulk(value)
-        } else {
+            Ok(Some(value)) => Frame::Bulk(value),
             // 값이 없다면 'Null'으로 응답한다.
-            Frame::Null
+            Ok(None) => Frame::Null,
+            // 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 응답한다.
+            Err(err) => Frame::Error(err.to_string()),
         };
 
         debug!(?response);
 
         // 응답을 클라이언트에게 쓴다.
-        dst.write_frame(&response).await?;
+        dst.write_frame_buffered(&response).await?;
 
         Ok(())
     }
@@ -81,4 +83,19 @@ impl Get {
         frame.push_bulk(Bytes::from(self.key.into_bytes()));
         frame
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut parse = Parse::new(Get::new("foo").into_frame()).unwrap();
+        assert_eq!(parse.next_string().unwrap(), "get");
+        let get = Get::parse_frames(&mut parse).unwrap();
+        parse.finish().unwrap();
+
+        assert_eq!(get.key(), "foo");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 값의 'offset' 위치의 비트를 가져온다.
+///
+/// 비트 오프셋은 각 바이트의 최상위 비트부터 센다. 'offset'이 값을 벗어나거나 키가 존재하지 않으면
+/// 0을 반환한다.
+#[derive(Debug)]
+pub struct GetBit {
+    /// 비트를 가져올 키
+    key: String,
+
+    /// 가져올 비트의 오프셋
+    offset: u64,
+}
+
+impl GetBit {
+    /// 'key'에 저장된 값의 'offset' 위치의 비트를 가져오는 'GetBit' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, offset: u64) -> GetBit {
+        GetBit {
+            key: key.to_string(),
+            offset,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'GetBit' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'GETBIT' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'GetBit' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// GETBIT key offset
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<GetBit> {
+        let key = parse.next_string()?;
+        let offset = parse.next_int()?;
+
+        Ok(GetBit { key, offset })
+    }
+
+    /// 'GetBit' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.getbit(&self.key, self.offset) {
+            Ok(bit) => Frame::Integer(bit as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'GetBit'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("getbit".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.offset as i64);
+        frame
+    }
+}
@@ -0,0 +1,155 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use std::time::{Duration, UNIX_EPOCH};
+use tracing::{debug, instrument};
+
+pub use crate::db::Expiry;
+
+/// 키의 값을 가져오고, 옵션에 따라 키의 만료 시간을 함께 변경한다.
+///
+/// 값을 가져오는 것과 만료 시간을 변경하는 것은 원자적으로 이루어진다. 키가 존재하지 않을 경우,
+/// 특별한 값인 nil을 반환하며 아무것도 변경하지 않는다. 옵션이 없다면 'GET'과 같다.
+///
+/// # Options
+///
+/// 다음의 옵션 중 하나를 지정할 수 있다:
+///
+/// * EX 'seconds' -- 만료 시간을 초 단위로 지정한다.
+/// * PX 'milliseconds' -- 만료 시간을 밀리초 단위로 지정한다.
+/// * EXAT 'unix-time-seconds' -- 만료될 시각을 초 단위의 유닉스 시간으로 지정한다.
+/// * PXAT 'unix-time-milliseconds' -- 만료될 시각을 밀리초 단위의 유닉스 시간으로 지정한다.
+/// * PERSIST -- 만료 시간을 제거한다.
+#[derive(Debug)]
+pub struct GetEx {
+    /// 검색할 키
+    key: String,
+
+    /// 만료 시간의 변경. 'None'이면 만료 시간을 변경하지 않는다.
+    expiry: Option<Expiry>,
+}
+
+impl GetEx {
+    /// 'key'의 값을 가져오며 만료 시간을 'expiry'로 변경하는 새로운 'GetEx' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, expiry: Option<Expiry>) -> GetEx {
+        GetEx {
+            key: key.to_string(),
+            expiry,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 만료 시간의 변경을 가져온다.
+    pub fn expiry(&self) -> Option<Expiry> {
+        self.expiry
+    }
+
+    /// 수신한 프레임으로부터 'GetEx' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'GETEX' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'GetEx' 값을 반환한다. 프레임의 형태가 잘못되었거나 만료 시간이 0인 경우 'Err'을
+    /// 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// GETEX key [EX seconds|PX milliseconds|EXAT unix-time-seconds|PXAT unix-time-milliseconds|PERSIST]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<GetEx> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        // 옵션은 선택적이다. 하나만 지정할 수 있다.
+        let option = match parse.next_string() {
+            Ok(option) => option.to_uppercase(),
+            Err(EndOfStream) => return Ok(GetEx { key, expiry: None }),
+            Err(err) => return Err(err.into()),
+        };
+
+        let expiry = match &option[..] {
+            "PERSIST" => Expiry::Persist,
+            "EX" | "PX" | "EXAT" | "PXAT" => {
+                let value = parse.next_int()?;
+                if value == 0 {
+                    return Err("ERR invalid expire time in 'getex' command".into());
+                }
+
+                match &option[..] {
+                    "EX" => Expiry::In(Duration::from_secs(value)),
+                    "PX" => Expiry::In(Duration::from_millis(value)),
+                    "EXAT" => Expiry::At(UNIX_EPOCH + Duration::from_secs(value)),
+                    _ => Expiry::At(UNIX_EPOCH + Duration::from_millis(value)),
+                }
+            }
+            _ => return Err("ERR syntax error".into()),
+        };
+
+        // 옵션 뒤에 남은 아규먼트가 있다면 'Parse::finish'가 에러를 반환한다.
+        Ok(GetEx {
+            key,
+            expiry: Some(expiry),
+        })
+    }
+
+    /// 'GetEx' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.get_ex(&self.key, self.expiry) {
+            Ok(Some(value)) => Frame::Bulk(value),
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'GetEx'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("getex".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+
+        // 'SET'과 마찬가지로, 값을 더 정밀하게 표현할 수 있는 밀리초 단위의 옵션을 사용한다. 에포크
+        // 이전의 시각은 이미 지난 시각이므로 가장 이른 시각인 1로 보낸다.
+        match self.expiry {
+            Some(Expiry::In(duration)) => {
+                frame.push_bulk(Bytes::from("px".as_bytes()));
+                frame.push_int(duration.as_millis() as i64);
+            }
+            Some(Expiry::At(at)) => {
+                let millis = at
+                    .duration_since(UNIX_EPOCH)
+                    .map(|since| since.as_millis() as i64)
+                    .unwrap_or(0);
+
+                frame.push_bulk(Bytes::from("pxat".as_bytes()));
+                frame.push_int(millis.max(1));
+            }
+            Some(Expiry::Persist) => {
+                frame.push_bulk(Bytes::from("persist".as_bytes()));
+            }
+            None => {}
+        }
+        frame
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 값의 일부를 가져온다.
+///
+/// 'start'와 'end'는 양 끝을 포함하는 바이트 오프셋이다. 음수는 값의 끝에서부터의 위치를 나타내며,
+/// -1은 마지막 바이트가 된다. 범위가 값을 벗어나면 값의 범위로 제한되고, 범위가 비어있거나 키가
+/// 존재하지 않으면 빈 문자열을 반환한다.
+#[derive(Debug)]
+pub struct GetRange {
+    /// 값을 가져올 키
+    key: String,
+
+    /// 범위의 시작 오프셋
+    start: i64,
+
+    /// 범위의 끝 오프셋. 범위에 포함된다.
+    end: i64,
+}
+
+impl GetRange {
+    /// 'key'에 저장된 값의 'start'부터 'end'까지를 가져오는 'GetRange' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, start: i64, end: i64) -> GetRange {
+        GetRange {
+            key: key.to_string(),
+            start,
+            end,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'GetRange' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'GETRANGE' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'GetRange' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// GETRANGE key start end
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<GetRange> {
+        let key = parse.next_string()?;
+        let start = parse.next_signed_int()?;
+        let end = parse.next_signed_int()?;
+
+        Ok(GetRange { key, start, end })
+    }
+
+    /// 'GetRange' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 범위가 비어있더라도 'Null'이 아닌 빈 "bulk"로 응답한다. 키에 문자열이 아닌 값이 저장되어
+        // 있다면 에러 프레임으로 응답한다.
+        let response = match db.getrange(&self.key, self.start, self.end) {
+            Ok(value) => Frame::Bulk(value),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'GetRange'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("getrange".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.start);
+        frame.push_int(self.end);
+        frame
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 새로운 값을 저장하고, 이전 값을 반환한다.
+///
+/// 저장과 이전 값의 조회는 원자적으로 이루어진다. 키에 설정되어 있던 만료 시간은 폐기된다.
+/// 키가 존재하지 않았다면 특별한 값인 nil을 반환한다.
+#[derive(Debug)]
+pub struct GetSet {
+    /// 저장할 키
+    key: String,
+
+    /// 저장할 값
+    value: Bytes,
+}
+
+impl GetSet {
+    /// 'key'에 'value'를 저장하고 이전 값을 가져오는 'GetSet' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, value: Bytes) -> GetSet {
+        GetSet {
+            key: key.to_string(),
+            value,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 값을 가져온다.
+    pub fn value(&self) -> &Bytes {
+        &self.value
+    }
+
+    /// 수신한 프레임으로부터 'GetSet' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'GETSET' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'GetSet' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// GETSET key value
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<GetSet> {
+        let key = parse.next_string()?;
+        let value = parse.next_bytes()?;
+
+        Ok(GetSet { key, value })
+    }
+
+    /// 'GetSet' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 새로운 값을 저장하고, 이전 값을 돌려받는다.
+        let response = match db.get_set(self.key, self.value) {
+            Ok(Some(value)) => Frame::Bulk(value),
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'GetSet'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("getset".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(self.value);
+        frame
+    }
+}
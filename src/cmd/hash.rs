@@ -0,0 +1,769 @@
+//! 해시 커맨드.
+
+use crate::cmd::{Parse, ParseError};
+use crate::db::MAX_RANDOM_COUNT;
+use crate::{Connection, Db, Frame, ProtocolVersion};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 해시에 하나 이상의 필드-값 쌍을 저장한다.
+///
+/// 이미 존재하는 필드의 값은 덮어쓴다. 키가 존재하지 않으면 새로운 해시를 생성한다. 응답으로 새로
+/// 생성된 필드의 수를 반환한다.
+#[derive(Debug)]
+pub struct HSet {
+    /// 해시의 키
+    key: String,
+
+    /// 저장할 필드-값 쌍 목록
+    pairs: Vec<(String, Bytes)>,
+}
+
+/// 해시에서 필드의 값을 가져온다.
+///
+/// 키나 필드가 존재하지 않으면 nil을 반환한다.
+#[derive(Debug)]
+pub struct HGet {
+    /// 해시의 키
+    key: String,
+
+    /// 값을 가져올 필드
+    field: String,
+}
+
+/// 해시에서 하나 이상의 필드를 삭제한다.
+///
+/// 존재하지 않는 필드는 무시한다. 응답으로 실제로 삭제된 필드의 수를 반환한다. 해시의 마지막 필드가
+/// 삭제되면 키도 삭제된다.
+#[derive(Debug)]
+pub struct HDel {
+    /// 해시의 키
+    key: String,
+
+    /// 삭제할 필드 목록
+    fields: Vec<String>,
+}
+
+/// 해시의 모든 필드-값 쌍을 반환한다.
+///
+/// RESP2에서는 필드와 값이 번갈아 나오는 배열로, RESP3에서는 맵으로 응답한다. 필드의 순서는 정해져
+/// 있지 않다.
+#[derive(Debug)]
+pub struct HGetAll {
+    key: String,
+}
+
+/// 해시의 필드 수를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+#[derive(Debug)]
+pub struct HLen {
+    key: String,
+}
+
+/// 해시의 필드에 저장된 정수 값을 'increment'만큼 증가시킨다.
+///
+/// 필드가 존재하지 않으면 연산 전에 값을 0으로 간주하며, 키가 존재하지 않으면 새로운 해시를 생성한다.
+/// 필드의 값이 정수로 표현될 수 없는 문자열이거나 연산 결과가 64비트 부호 있는 정수의 범위를 벗어나면
+/// 에러를 반환한다. 응답으로 증가된 값을 반환한다.
+#[derive(Debug)]
+pub struct HIncrBy {
+    /// 해시의 키
+    key: String,
+
+    /// 증가시킬 필드
+    field: String,
+
+    /// 더할 값. 음수라면 값을 감소시킨다.
+    increment: i64,
+}
+
+/// 해시의 필드에 저장된 실수 값을 'increment'만큼 증가시킨다.
+///
+/// 'HIncrBy'와 같지만 값을 실수로 다루며, 응답으로 증가된 값을 벌크 문자열로 반환한다. 연산 결과가
+/// NaN이나 무한대라면 에러를 반환한다.
+#[derive(Debug)]
+pub struct HIncrByFloat {
+    /// 해시의 키
+    key: String,
+
+    /// 증가시킬 필드
+    field: String,
+
+    /// 더할 값
+    increment: f64,
+}
+
+/// 해시에서 무작위로 고른 필드를 반환한다.
+///
+/// 'count'가 없으면 필드 하나를 벌크 문자열로, 키가 존재하지 않으면 nil을 반환한다. 'count'가
+/// 양수라면 서로 다른 필드를 최대 'count'개, 음수라면 중복을 허용하여 정확히 '-count'개를 배열로
+/// 반환한다. 'WITHVALUES'가 지정되면 필드와 값을 함께 반환한다.
+#[derive(Debug)]
+pub struct HRandField {
+    /// 해시의 키
+    key: String,
+
+    /// 고를 필드의 수. 'None'이라면 필드 하나를 배열이 아닌 값으로 응답한다.
+    count: Option<i64>,
+
+    /// 필드의 값을 함께 응답할지 여부
+    with_values: bool,
+}
+
+impl HSet {
+    /// 'key'의 해시에 'pairs'를 저장하는 새로운 'HSet' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, pairs: &[(impl ToString, Bytes)]) -> HSet {
+        HSet {
+            key: key.to_string(),
+            pairs: pairs
+                .iter()
+                .map(|(field, value)| (field.to_string(), value.clone()))
+                .collect(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'HSet' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'HSET' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'HSet' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 네 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HSET key field value [field value ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HSet> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        // 최소 하나의 필드-값 쌍이 필요하다.
+        let mut pairs = vec![(parse.next_string()?, parse.next_bytes()?)];
+
+        loop {
+            let field = match parse.next_string() {
+                Ok(field) => field,
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            };
+
+            // 필드에 대응하는 값이 없다면 잘못된 프레임이다.
+            let value = match parse.next_bytes() {
+                Ok(value) => value,
+                Err(EndOfStream) => {
+                    return Err("ERR wrong number of arguments for 'hset' command".into())
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            pairs.push((field, value));
+        }
+
+        Ok(HSet { key, pairs })
+    }
+
+    /// 'HSet' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 새로 생성된 필드의 수를 응답한다. 키에 해시가 아닌 값이 저장되어 있다면 에러 프레임으로
+        // 응답한다.
+        let response = match db.hset(&self.key, self.pairs) {
+            Ok(created) => Frame::Integer(created as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HSet'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hset".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        for (field, value) in self.pairs {
+            frame.push_bulk(Bytes::from(field.into_bytes()));
+            frame.push_bulk(value);
+        }
+        frame
+    }
+}
+
+impl HGet {
+    /// 'key'의 해시에서 'field'의 값을 가져오는 새로운 'HGet' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, field: impl ToString) -> HGet {
+        HGet {
+            key: key.to_string(),
+            field: field.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 필드를 가져온다.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// 수신한 프레임으로부터 'HGet' 인스턴스를 파싱한다.
+    ///
+    /// 'HGET' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HGET key field
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HGet> {
+        let key = parse.next_string()?;
+        let field = parse.next_string()?;
+
+        Ok(HGet { key, field })
+    }
+
+    /// 'HGet' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.hget(&self.key, &self.field) {
+            Ok(Some(value)) => Frame::Bulk(value),
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HGet'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hget".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(Bytes::from(self.field.into_bytes()));
+        frame
+    }
+}
+
+impl HDel {
+    /// 'key'의 해시에서 'fields'를 삭제하는 새로운 'HDel' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, fields: &[impl ToString]) -> HDel {
+        HDel {
+            key: key.to_string(),
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'HDel' 인스턴스를 파싱한다.
+    ///
+    /// 'HDEL' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HDEL key field [field ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HDel> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        // 최소 하나의 필드가 필요하다.
+        let mut fields = vec![parse.next_string()?];
+
+        loop {
+            match parse.next_string() {
+                Ok(field) => fields.push(field),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(HDel { key, fields })
+    }
+
+    /// 'HDel' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.hdel(&self.key, &self.fields) {
+            Ok(deleted) => Frame::Integer(deleted as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HDel'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hdel".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        for field in self.fields {
+            frame.push_bulk(Bytes::from(field.into_bytes()));
+        }
+        frame
+    }
+}
+
+impl HGetAll {
+    /// 'key'의 해시의 모든 필드-값 쌍을 가져오는 새로운 'HGetAll' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> HGetAll {
+        HGetAll {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'HGetAll' 인스턴스를 파싱한다.
+    ///
+    /// 'HGETALL' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HGETALL key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HGetAll> {
+        let key = parse.next_string()?;
+
+        Ok(HGetAll { key })
+    }
+
+    /// 'HGetAll' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 맵 프레임으로 응답한다. RESP2 커넥션에서는 필드와 값이 번갈아 나오는 배열로 인코딩된다.
+        let response = match db.hgetall(&self.key) {
+            Ok(pairs) => Frame::Map(
+                pairs
+                    .into_iter()
+                    .map(|(field, value)| (Frame::Bulk(Bytes::from(field)), Frame::Bulk(value)))
+                    .collect(),
+            ),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HGetAll'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hgetall".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
+
+impl HLen {
+    /// 'key'의 해시의 필드 수를 가져오는 새로운 'HLen' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> HLen {
+        HLen {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'HLen' 인스턴스를 파싱한다.
+    ///
+    /// 'HLEN' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HLEN key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HLen> {
+        let key = parse.next_string()?;
+
+        Ok(HLen { key })
+    }
+
+    /// 'HLen' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.hlen(&self.key) {
+            Ok(len) => Frame::Integer(len as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HLen'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hlen".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
+
+impl HIncrBy {
+    /// 'key'의 해시에서 'field'의 값을 'increment'만큼 증가시키는 새로운 'HIncrBy' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, field: impl ToString, increment: i64) -> HIncrBy {
+        HIncrBy {
+            key: key.to_string(),
+            field: field.to_string(),
+            increment,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 필드를 가져온다.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// 수신한 프레임으로부터 'HIncrBy' 인스턴스를 파싱한다.
+    ///
+    /// 'HINCRBY' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HINCRBY key field increment
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HIncrBy> {
+        let key = parse.next_string()?;
+        let field = parse.next_string()?;
+        let increment = parse.next_signed_int()?;
+
+        Ok(HIncrBy {
+            key,
+            field,
+            increment,
+        })
+    }
+
+    /// 'HIncrBy' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.hincr_by(&self.key, &self.field, self.increment) {
+            Ok(value) => Frame::Integer(value),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HIncrBy'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hincrby".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(Bytes::from(self.field.into_bytes()));
+        frame.push_bulk(Bytes::from(self.increment.to_string().into_bytes()));
+        frame
+    }
+}
+
+impl HIncrByFloat {
+    /// 'key'의 해시에서 'field'의 값을 'increment'만큼 증가시키는 새로운 'HIncrByFloat' 커맨드를
+    /// 생성한다.
+    pub fn new(key: impl ToString, field: impl ToString, increment: f64) -> HIncrByFloat {
+        HIncrByFloat {
+            key: key.to_string(),
+            field: field.to_string(),
+            increment,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 필드를 가져온다.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// 수신한 프레임으로부터 'HIncrByFloat' 인스턴스를 파싱한다.
+    ///
+    /// 'HINCRBYFLOAT' 문자열은 이미 소비되었다. 'increment'는 레디스와 같이 'inf'를 받아들이지만,
+    /// 연산 결과가 무한대가 되므로 수행 시 에러가 된다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HINCRBYFLOAT key field increment
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HIncrByFloat> {
+        let key = parse.next_string()?;
+        let field = parse.next_string()?;
+        let increment = match parse.next_string()?.parse::<f64>() {
+            Ok(increment) if !increment.is_nan() => increment,
+            _ => return Err("ERR value is not a valid float".into()),
+        };
+
+        Ok(HIncrByFloat {
+            key,
+            field,
+            increment,
+        })
+    }
+
+    /// 'HIncrByFloat' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.hincr_by_float(&self.key, &self.field, self.increment) {
+            Ok(value) => Frame::Bulk(value),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HIncrByFloat'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hincrbyfloat".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(Bytes::from(self.field.into_bytes()));
+        frame.push_bulk(Bytes::from(self.increment.to_string().into_bytes()));
+        frame
+    }
+}
+
+impl HRandField {
+    /// 'key'의 해시에서 무작위로 필드를 고르는 새로운 'HRandField' 커맨드를 생성한다.
+    ///
+    /// 'with_values'는 'count'가 지정된 경우에만 사용된다.
+    pub fn new(key: impl ToString, count: Option<i64>, with_values: bool) -> HRandField {
+        HRandField {
+            key: key.to_string(),
+            count,
+            with_values: with_values && count.is_some(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'HRandField' 인스턴스를 파싱한다.
+    ///
+    /// 'HRANDFIELD' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 최소 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HRANDFIELD key [count [WITHVALUES]]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<HRandField> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        let count = match parse.next_signed_int() {
+            Ok(count) if count < 0 && count.unsigned_abs() > MAX_RANDOM_COUNT => {
+                return Err("ERR value is out of range".into())
+            }
+            Ok(count) => count,
+            Err(EndOfStream) => {
+                return Ok(HRandField {
+                    key,
+                    count: None,
+                    with_values: false,
+                })
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let with_values = match parse.next_string() {
+            Ok(option) if option.eq_ignore_ascii_case("withvalues") => true,
+            Ok(_) => return Err("ERR syntax error".into()),
+            Err(EndOfStream) => false,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(HRandField {
+            key,
+            count: Some(count),
+            with_values,
+        })
+    }
+
+    /// 'HRandField' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.hrandfield(&self.key, self.count.unwrap_or(1)) {
+            Ok(pairs) => {
+                let mut pairs = pairs
+                    .into_iter()
+                    .map(|(field, value)| (Frame::Bulk(Bytes::from(field)), Frame::Bulk(value)));
+
+                //  중복된 필드가 있을 수 있으므로 맵이 아닌 배열로 응답한다. RESP3에서는 레디스와 같이
+                //  각 쌍을 두 앤트리의 배열로 감싼다.
+                match (self.count, self.with_values) {
+                    (None, _) => pairs.next().map_or(Frame::Null, |(field, _)| field),
+                    (Some(_), false) => Frame::Array(pairs.map(|(field, _)| field).collect()),
+                    (Some(_), true) => match dst.version() {
+                        ProtocolVersion::Resp2 => {
+                            Frame::Array(pairs.flat_map(|(field, value)| [field, value]).collect())
+                        }
+                        ProtocolVersion::Resp3 => Frame::Array(
+                            pairs.map(|(field, value)| Frame::Array(vec![field, value])).collect(),
+                        ),
+                    },
+                }
+            }
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'HRandField'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("hrandfield".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        if let Some(count) = self.count {
+            frame.push_bulk(Bytes::from(count.to_string().into_bytes()));
+        }
+        if self.with_values {
+            frame.push_bulk(Bytes::from("withvalues".as_bytes()));
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 'args'로 이루어진 커맨드 프레임에서 커맨드 이름을 제외한 커서를 만든다.
+    fn parse(args: &[&str]) -> Parse {
+        let mut frame = Frame::array();
+        for arg in args {
+            frame.push_bulk(Bytes::from(arg.to_string()));
+        }
+
+        let mut parse = Parse::new(frame).unwrap();
+        parse.next_string().unwrap();
+        parse
+    }
+
+    #[test]
+    fn hrandfield_count_out_of_range() {
+        let min = (-(MAX_RANDOM_COUNT as i64)).to_string();
+        let count = HRandField::parse_frames(&mut parse(&["hrandfield", "h", &min]))
+            .unwrap()
+            .count;
+        assert_eq!(count, Some(-(MAX_RANDOM_COUNT as i64)));
+
+        let too_small = (-(MAX_RANDOM_COUNT as i64) - 1).to_string();
+        for count in [&too_small[..], "-9223372036854775807"] {
+            let err = HRandField::parse_frames(&mut parse(&["hrandfield", "h", count]))
+                .unwrap_err();
+            assert_eq!(err.to_string(), "ERR value is out of range");
+        }
+
+        // 양수 'count'는 해시의 크기로 제한되므로 거절하지 않는다.
+        let max = i64::MAX.to_string();
+        assert!(HRandField::parse_frames(&mut parse(&["hrandfield", "h", &max])).is_ok());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Frame, ProtocolVersion};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 커넥션의 프로토콜 버전을 변경하고, 서버 정보를 응답한다.
+///
+/// 'protover'로 2 혹은 3을 지정할 수 있다. 3을 지정하면 커넥션은 RESP3로 전환되어 이후의
+/// 응답에 맵, 셋 등의 RESP3 타입을 사용한다. 버전이 지정되지 않으면 현재 버전을 유지한다.
+///
+/// 응답은 서버 정보를 담은 맵이다. RESP2 커넥션에서는 키와 값을 번갈아 담은 배열이 된다.
+#[derive(Debug, Default)]
+pub struct Hello {
+    /// 전환할 프로토콜 버전
+    protover: Option<u64>,
+}
+
+impl Hello {
+    /// 'protover'로 전환하는 새로운 'Hello' 커맨드를 생성한다.
+    pub fn new(protover: Option<u64>) -> Hello {
+        Hello { protover }
+    }
+
+    /// 전환할 프로토콜 버전을 가져온다.
+    pub fn protover(&self) -> Option<u64> {
+        self.protover
+    }
+
+    /// 수신한 프레임으로부터 'Hello' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'HELLO' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Hello' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 'HELLO'와 선택적인 프로토콜 버전을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// HELLO [protover]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Hello> {
+        use ParseError::EndOfStream;
+
+        match parse.next_int() {
+            Ok(protover) => Ok(Hello::new(Some(protover))),
+            // 'EndOfStream'은 버전이 지정되지 않았음을 나타낸다.
+            Err(EndOfStream) => Ok(Hello::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// 'Hello' 커맨드를 수행한다.
+    ///
+    /// 커넥션의 프로토콜 버전을 변경한 뒤 응답을 'dst'에 쓴다. 응답은 변경된 버전으로 인코딩된다.
+    #[instrument(skip(self, dst))]
+    pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
+        let version = match self.protover {
+            None => dst.version(),
+            Some(2) => ProtocolVersion::Resp2,
+            Some(3) => ProtocolVersion::Resp3,
+            // 지원하지 않는 버전이라면 커넥션의 버전을 유지하고 에러를 응답한다.
+            Some(_) => {
+                let response = Frame::Error("NOPROTO unsupported protocol version".to_string());
+                debug!(?response);
+                dst.write_frame_buffered(&response).await?;
+                return Ok(());
+            }
+        };
+
+        dst.set_version(version);
+
+        let proto = match version {
+            ProtocolVersion::Resp2 => 2,
+            ProtocolVersion::Resp3 => 3,
+        };
+
+        let response = Frame::Map(vec![
+            (bulk("server"), bulk("mini-redis")),
+            (bulk("version"), bulk(env!("CARGO_PKG_VERSION"))),
+            (bulk("proto"), Frame::Integer(proto)),
+            (bulk("mode"), bulk("standalone")),
+            (bulk("role"), bulk("master")),
+            (bulk("modules"), Frame::array()),
+        ]);
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+}
+
+// 정적 문자열로 벌크 프레임을 생성한다.
+fn bulk(val: &'static str) -> Frame {
+    Frame::Bulk(Bytes::from_static(val.as_bytes()))
+}
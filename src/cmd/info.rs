@@ -0,0 +1,197 @@
+use crate::cmd::{Parse, ParseError};
+use crate::metrics::Metrics;
+use crate::snapshot::Persistence;
+use crate::stats::Stats;
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use std::fmt::Write;
+use tracing::{debug, instrument};
+
+/// 'INFO'가 응답하는 섹션의 이름. 응답에는 이 순서대로 포함된다.
+const SECTIONS: &[&str] =
+    &["server", "clients", "memory", "persistence", "stats", "commandstats", "keyspace"];
+
+/// 섹션이 주어지지 않았을 때 응답하는 섹션의 이름. 레디스와 같이 'commandstats'는 제외한다.
+const DEFAULT_SECTIONS: &[&str] =
+    &["server", "clients", "memory", "persistence", "stats", "keyspace"];
+
+/// 서버의 상태와 통계 정보를 응답한다.
+///
+/// 응답은 레디스와 같은 형식의 텍스트를 담은 bulk 프레임이다. 각 섹션은 '# Section' 헤더로 시작하며,
+/// 각 줄은 'key:value' 형식으로 '\r\n'으로 끝난다. 다음의 섹션을 지원한다:
+///
+/// * server -- 'uptime_in_seconds'
+/// * clients -- 'connected_clients'
+/// * memory -- 'used_memory'. 모든 데이터베이스의 키와 값의 바이트 길이의 합으로 어림한 값이다.
+/// * persistence -- 'rdb_changes_since_last_save', 'rdb_bgsave_in_progress'
+/// * stats -- 'total_connections_received', 'total_commands_processed', 'pubsub_dropped_messages'
+/// * commandstats -- 한 번 이상 호출된 각 커맨드의 호출 횟수. 'cmdstat_get:calls=3'과 같은 형식이다.
+///   'PEXPIRE'처럼 다른 커맨드와 같은 구현을 공유하는 커맨드는 그 커맨드('EXPIRE')로 집계된다.
+/// * keyspace -- 키가 저장된 각 데이터베이스의 키 수. 'db0'은 항상 포함된다.
+///
+/// 섹션이 주어지지 않았거나 'default'가 주어지면 'commandstats'를 제외한 섹션을, 'all' 혹은
+/// 'everything'이 주어지면 모든 섹션을 응답한다.
+/// 지원하지 않는 섹션이 주어지면 빈 문자열을 응답한다.
+#[derive(Debug, Default)]
+pub struct Info {
+    /// 응답할 섹션. 'None'이면 모든 섹션을 응답한다.
+    section: Option<String>,
+}
+
+impl Info {
+    /// 'section'을 조회하는 새로운 'Info' 커맨드를 생성한다.
+    pub fn new(section: Option<String>) -> Info {
+        Info { section }
+    }
+
+    /// 섹션을 가져온다.
+    pub fn section(&self) -> Option<&str> {
+        self.section.as_deref()
+    }
+
+    /// 수신한 프레임으로부터 'Info' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'INFO' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Info' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// INFO [section]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Info> {
+        use ParseError::EndOfStream;
+
+        match parse.next_string() {
+            Ok(section) => Ok(Info::new(Some(section.to_lowercase()))),
+            // 'EndOfStream'은 섹션이 주어지지 않았음을 나타낸다.
+            Err(EndOfStream) => Ok(Info::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// 'Info' 커맨드를 수행한다.
+    ///
+    /// 'INFO'는 서버의 통계 정보를 필요로 하므로, 'Command::apply'를 거치지 않고 핸들러가 직접 이
+    /// 함수를 호출한다. 'db'는 모든 데이터베이스의 키 수와 메모리 사용량을 구하는 데에 사용한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, db, stats, metrics, persistence, dst))]
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        stats: &Stats,
+        metrics: &Metrics,
+        persistence: Option<&Persistence>,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let sections: Vec<&str> = match self.section.as_deref() {
+            None | Some("default") => DEFAULT_SECTIONS.to_vec(),
+            Some("all") | Some("everything") => SECTIONS.to_vec(),
+            Some(section) => SECTIONS.iter().copied().filter(|name| *name == section).collect(),
+        };
+
+        let mut info = String::new();
+
+        for section in sections {
+            // 섹션 사이는 빈 줄로 구분한다.
+            if !info.is_empty() {
+                info.push_str("\r\n");
+            }
+
+            match section {
+                "server" => {
+                    info.push_str("# Server\r\n");
+                    push_field(&mut info, "uptime_in_seconds", stats.uptime().as_secs());
+                }
+                "clients" => {
+                    info.push_str("# Clients\r\n");
+                    push_field(&mut info, "connected_clients", stats.connected_clients());
+                }
+                "memory" => {
+                    info.push_str("# Memory\r\n");
+                    push_field(&mut info, "used_memory", db.used_memory());
+                }
+                "persistence" => {
+                    info.push_str("# Persistence\r\n");
+                    push_field(&mut info, "rdb_changes_since_last_save", db.changes_since_save());
+
+                    // 스냅샷 파일이 설정되지 않았다면 저장은 진행될 수 없다.
+                    let saving = persistence.map(Persistence::is_saving).unwrap_or(false);
+                    push_field(&mut info, "rdb_bgsave_in_progress", saving as u8);
+                }
+                "stats" => {
+                    info.push_str("# Stats\r\n");
+                    push_field(
+                        &mut info,
+                        "total_connections_received",
+                        stats.total_connections_received(),
+                    );
+                    push_field(
+                        &mut info,
+                        "total_commands_processed",
+                        stats.total_commands_processed(),
+                    );
+                    push_field(&mut info, "pubsub_dropped_messages", db.dropped_messages());
+                }
+                "commandstats" => {
+                    info.push_str("# Commandstats\r\n");
+
+                    for (name, calls) in metrics.calls() {
+                        let name = format!("cmdstat_{}", name);
+                        push_field(&mut info, &name, format!("calls={}", calls));
+                    }
+                }
+                "keyspace" => {
+                    info.push_str("# Keyspace\r\n");
+
+                    // 'select'는 존재하지 않는 번호에 대해 'None'을 반환하므로, 모든 데이터베이스를
+                    // 차례로 확인한다.
+                    let databases = (0..).map_while(|index| db.select(index));
+
+                    for (index, db) in databases.enumerate() {
+                        let keys = db.dbsize();
+
+                        if index == 0 || keys > 0 {
+                            let name = format!("db{}", index);
+                            push_field(&mut info, &name, format!("keys={}", keys));
+                        }
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        let response = Frame::Bulk(Bytes::from(info.into_bytes()));
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Info'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("info".as_bytes()));
+        if let Some(section) = self.section {
+            frame.push_bulk(Bytes::from(section.into_bytes()));
+        }
+        frame
+    }
+}
+
+/// 'key:value' 한 줄을 '\r\n'으로 끝나도록 'info'에 쓴다.
+fn push_field(info: &mut String, key: &str, value: impl std::fmt::Display) {
+    // 'String'에 쓰는 것은 실패하지 않는다.
+    let _ = write!(info, "{}:{}\r\n", key, value);
+}
@@ -0,0 +1,1210 @@
+//! 리스트 커맨드.
+//!
+//! 리스트의 양 끝을 다루는 커맨드들은 'L', 'R' 접두어만 다르다. 'LPUSH'와 'RPUSH'는 'Push'로,
+//! 'LPOP'과 'RPOP'은 'Pop'으로, 'BLPOP'과 'BRPOP'은 'BPop'으로 파싱되며, 'left' 필드가 어느 쪽 끝을
+//! 다루는지를 나타낸다.
+
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame, Shutdown};
+
+use bytes::Bytes;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+use tokio::time::{self, Instant};
+use tracing::{debug, instrument};
+
+/// 리스트의 앞('LPUSH') 혹은 뒤('RPUSH')에 하나 이상의 값을 삽입한다.
+///
+/// 키가 존재하지 않으면 새로운 리스트를 생성한다. 응답으로 삽입 후의 리스트의 길이를 반환한다.
+#[derive(Debug)]
+pub struct Push {
+    /// 리스트의 키
+    key: String,
+
+    /// 삽입할 값 목록. 주어진 순서대로 하나씩 삽입된다.
+    values: Vec<Bytes>,
+
+    /// 'true'이면 리스트의 앞에 삽입한다 ('LPUSH').
+    left: bool,
+}
+
+/// 리스트의 앞('LPOP') 혹은 뒤('RPOP')에서 원소를 꺼낸다.
+///
+/// 'count'가 없으면 하나의 원소를 꺼내 응답하고, 리스트가 없으면 nil을 응답한다. 'count'가 있으면
+/// 최대 'count'개의 원소를 배열로 응답한다. 리스트의 마지막 원소를 꺼내면 키가 삭제된다.
+#[derive(Debug)]
+pub struct Pop {
+    /// 리스트의 키
+    key: String,
+
+    /// 꺼낼 원소의 최대 수
+    count: Option<u64>,
+
+    /// 'true'이면 리스트의 앞에서 꺼낸다 ('LPOP').
+    left: bool,
+}
+
+/// 'Pop'의 블로킹 버전. 'BLPOP'과 'BRPOP'.
+///
+/// 주어진 키들의 리스트를 순서대로 확인하여, 처음으로 찾은 비어있지 않은 리스트에서 원소 하나를
+/// 꺼낸다. 모든 리스트가 비어있다면 원소가 삽입되거나 'timeout'이 지날 때까지 기다린다.
+///
+/// 원소를 꺼냈다면 '[key, element]' 배열을, 시간이 초과되었다면 nil을 응답한다.
+#[derive(Debug)]
+pub struct BPop {
+    /// 확인할 리스트의 키 목록
+    keys: Vec<String>,
+
+    /// 최대 대기 시간. 'None'이면 원소가 삽입될 때까지 무한히 기다린다.
+    timeout: Option<Duration>,
+
+    /// 'true'이면 리스트의 앞에서 꺼낸다 ('BLPOP').
+    left: bool,
+}
+
+/// 리스트의 길이를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+#[derive(Debug)]
+pub struct LLen {
+    key: String,
+}
+
+/// 리스트의 'start'부터 'stop'까지의 원소를 반환한다.
+///
+/// 'start'와 'stop'은 양 끝을 포함하며, 음수는 리스트의 끝에서부터의 위치를 나타낸다. -1은 마지막
+/// 원소가 된다.
+#[derive(Debug)]
+pub struct LRange {
+    /// 리스트의 키
+    key: String,
+
+    /// 범위의 시작 인덱스
+    start: i64,
+
+    /// 범위의 끝 인덱스. 범위에 포함된다.
+    stop: i64,
+}
+
+/// 한 리스트의 끝에서 원소를 꺼내 다른 리스트의 끝에 삽입한다. 'LMOVE'와 'RPOPLPUSH'.
+///
+/// 'RPOPLPUSH source destination'은 'LMOVE source destination RIGHT LEFT'와 같다. 원소를 꺼내고
+/// 삽입하는 일은 원자적으로 이루어지며, 두 리스트가 같다면 리스트를 회전시킨다. 옮긴 원소를 응답하고,
+/// 원본 리스트가 존재하지 않으면 nil을 응답한다.
+#[derive(Debug)]
+pub struct LMove {
+    /// 원소를 꺼낼 리스트의 키
+    source: String,
+
+    /// 원소를 삽입할 리스트의 키
+    destination: String,
+
+    /// 'true'이면 원본 리스트의 앞에서 꺼낸다.
+    from_left: bool,
+
+    /// 'true'이면 대상 리스트의 앞에 삽입한다.
+    to_left: bool,
+
+    /// 'true'이면 'RPOPLPUSH'로 수신한 커맨드이다.
+    rpoplpush: bool,
+}
+
+/// 리스트에서 'element'와 같은 원소의 인덱스를 반환한다.
+///
+/// 'rank'는 몇 번째로 일치하는 원소부터 찾을지를 나타낸다. 음수이면 리스트의 뒤에서부터 찾는다.
+/// 'count'가 없으면 일치하는 첫 원소의 인덱스를, 일치하는 원소가 없으면 nil을 응답한다. 'count'가
+/// 있으면 최대 'count'개의 인덱스를 배열로 응답하며, 0은 일치하는 모든 원소를 의미한다. 인덱스는 찾는
+/// 방향과 무관하게 항상 리스트의 앞에서부터의 위치이다.
+#[derive(Debug)]
+pub struct LPos {
+    /// 리스트의 키
+    key: String,
+
+    /// 찾을 원소
+    element: Bytes,
+
+    /// 몇 번째로 일치하는 원소부터 찾을지. 0이 될 수 없다.
+    rank: i64,
+
+    /// 찾을 인덱스의 최대 수. 0은 제한이 없음을 의미한다.
+    count: Option<u64>,
+}
+
+/// 리스트에서 'pivot'과 같은 첫 원소의 앞('BEFORE') 혹은 뒤('AFTER')에 값을 삽입한다.
+///
+/// 삽입 후의 리스트의 길이를 응답한다. 'pivot'을 찾지 못하면 -1을, 키가 존재하지 않으면 0을 응답한다.
+#[derive(Debug)]
+pub struct LInsert {
+    /// 리스트의 키
+    key: String,
+
+    /// 'true'이면 'pivot'의 앞에 삽입한다.
+    before: bool,
+
+    /// 삽입할 위치의 기준이 되는 원소
+    pivot: Bytes,
+
+    /// 삽입할 값
+    value: Bytes,
+}
+
+/// 리스트의 'index' 위치의 원소를 바꾼다.
+///
+/// 음수 인덱스는 리스트의 끝에서부터의 위치를 나타낸다. 인덱스가 리스트의 범위를 벗어나면 에러를
+/// 응답한다.
+#[derive(Debug)]
+pub struct LSet {
+    /// 리스트의 키
+    key: String,
+
+    /// 바꿀 원소의 인덱스
+    index: i64,
+
+    /// 새로운 값
+    value: Bytes,
+}
+
+/// 리스트에서 'element'와 같은 원소를 최대 'count'개 삭제한다.
+///
+/// 'count'가 양수이면 리스트의 앞에서부터, 음수이면 뒤에서부터 삭제하며, 0이면 같은 원소를 모두
+/// 삭제한다. 삭제한 원소의 수를 응답한다.
+#[derive(Debug)]
+pub struct LRem {
+    /// 리스트의 키
+    key: String,
+
+    /// 삭제할 원소의 최대 수와 방향
+    count: i64,
+
+    /// 삭제할 원소
+    element: Bytes,
+}
+
+/// 리스트를 'start'부터 'stop'까지의 원소만 남도록 자른다.
+///
+/// 'start'와 'stop'은 'LRANGE'와 같이 해석한다. 남는 원소가 없으면 키가 삭제된다.
+#[derive(Debug)]
+pub struct LTrim {
+    /// 리스트의 키
+    key: String,
+
+    /// 남길 범위의 시작 인덱스
+    start: i64,
+
+    /// 남길 범위의 끝 인덱스. 범위에 포함된다.
+    stop: i64,
+}
+
+impl Push {
+    /// 'key'의 리스트에 'values'를 삽입하는 새로운 'Push' 커맨드를 생성한다.
+    ///
+    /// 'left'가 'true'이면 리스트의 앞에 삽입한다.
+    pub fn new(key: impl ToString, values: Vec<Bytes>, left: bool) -> Push {
+        Push {
+            key: key.to_string(),
+            values,
+            left,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.left {
+            "lpush"
+        } else {
+            "rpush"
+        }
+    }
+
+    /// 수신한 프레임으로부터 'Push' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'LPUSH' 혹은 'RPUSH' 문자열은 이미 소비되었다. 'left'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Push' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LPUSH key value [value ...]
+    /// RPUSH key value [value ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, left: bool) -> crate::Result<Push> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        // 최소 하나의 값이 필요하다.
+        let mut values = vec![parse.next_bytes()?];
+
+        loop {
+            match parse.next_bytes() {
+                Ok(value) => values.push(value),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(Push { key, values, left })
+    }
+
+    /// 'Push' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 삽입 후의 리스트의 길이를 응답한다. 키에 리스트가 아닌 값이 저장되어 있다면 에러 프레임으로
+        // 응답한다.
+        let response = match db.push(&self.key, self.values, self.left) {
+            Ok(len) => Frame::Integer(len as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Push'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        for value in self.values {
+            frame.push_bulk(value);
+        }
+        frame
+    }
+}
+
+impl Pop {
+    /// 'key'의 리스트에서 원소를 꺼내는 새로운 'Pop' 커맨드를 생성한다.
+    ///
+    /// 'count'가 'Some'이면 최대 'count'개의 원소를 꺼낸다. 'left'가 'true'이면 리스트의 앞에서
+    /// 꺼낸다.
+    pub fn new(key: impl ToString, count: Option<u64>, left: bool) -> Pop {
+        Pop {
+            key: key.to_string(),
+            count,
+            left,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.left {
+            "lpop"
+        } else {
+            "rpop"
+        }
+    }
+
+    /// 수신한 프레임으로부터 'Pop' 인스턴스를 파싱한다.
+    ///
+    /// 'LPOP' 혹은 'RPOP' 문자열은 이미 소비되었다. 'left'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 혹은 세 개의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LPOP key [count]
+    /// RPOP key [count]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, left: bool) -> crate::Result<Pop> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        // 꺼낼 원소의 수는 선택적이다.
+        let count = match parse.next_int() {
+            Ok(count) => Some(count),
+            Err(EndOfStream) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Pop { key, count, left })
+    }
+
+    /// 'Pop' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let count = self.count.unwrap_or(1) as usize;
+
+        let response = match db.pop(&self.key, count, self.left) {
+            // 'count'가 지정되었다면 꺼낸 원소들을 배열로 응답한다.
+            Ok(Some(values)) if self.count.is_some() => {
+                Frame::Array(values.into_iter().map(Frame::Bulk).collect())
+            }
+            // 'count'가 지정되지 않았다면 꺼낸 하나의 원소를 응답한다.
+            Ok(Some(values)) => match values.into_iter().next() {
+                Some(value) => Frame::Bulk(value),
+                None => Frame::Null,
+            },
+            // 리스트가 존재하지 않는다.
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Pop'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        if let Some(count) = self.count {
+            frame.push_int(count as i64);
+        }
+        frame
+    }
+}
+
+impl BPop {
+    /// 'keys'의 리스트에서 원소를 꺼내는 새로운 'BPop' 커맨드를 생성한다.
+    ///
+    /// 'timeout'이 'None'이면 원소가 삽입될 때까지 무한히 기다린다. 'left'가 'true'이면 리스트의
+    /// 앞에서 꺼낸다.
+    pub fn new(keys: &[impl ToString], timeout: Option<Duration>, left: bool) -> BPop {
+        BPop {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            timeout,
+            left,
+        }
+    }
+
+    /// 키 목록을 가져온다.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.left {
+            "blpop"
+        } else {
+            "brpop"
+        }
+    }
+
+    /// 원소를 기다리지 않는 커맨드로 바꾼다. 모든 리스트가 비어있다면 곧바로 nil을 응답한다.
+    ///
+    /// 레디스와 같이, 트랜잭션 안의 블로킹 커맨드는 기다리지 않는다.
+    pub(crate) fn non_blocking(self) -> BPop {
+        BPop {
+            timeout: Some(Duration::ZERO),
+            ..self
+        }
+    }
+
+    /// 수신한 프레임으로부터 'BPop' 인스턴스를 파싱한다.
+    ///
+    /// 'BLPOP' 혹은 'BRPOP' 문자열은 이미 소비되었다. 'left'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다. 마지막 앤트리는 초 단위의 대기
+    /// 시간이며, 소수를 허용한다. 0은 무한히 기다림을 의미한다.
+    ///
+    /// ```text
+    /// BLPOP key [key ...] timeout
+    /// BRPOP key [key ...] timeout
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, left: bool) -> crate::Result<BPop> {
+        use ParseError::EndOfStream;
+
+        // 최소 하나의 키와 대기 시간이 필요하다.
+        let mut args = vec![parse.next_string()?, parse.next_string()?];
+
+        loop {
+            match parse.next_string() {
+                Ok(arg) => args.push(arg),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        // 마지막 값이 대기 시간이 된다.
+        let timeout = args.pop().unwrap();
+        let timeout = match timeout.parse::<f64>() {
+            Ok(secs) if secs < 0.0 => return Err("ERR timeout is negative".into()),
+            Ok(0.0) => None,
+            Ok(secs) if secs.is_finite() => Some(Duration::from_secs_f64(secs)),
+            _ => return Err("ERR timeout is not a float or out of range".into()),
+        };
+
+        Ok(BPop {
+            keys: args,
+            timeout,
+            left,
+        })
+    }
+
+    /// 'BPop' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    ///
+    /// 원소를 기다리는 동안 셧다운 시그널을 수신하면 응답 없이 반환한다.
+    #[instrument(skip(self, db, dst, shutdown))]
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        dst: &mut Connection,
+        shutdown: &mut Shutdown,
+    ) -> crate::Result<()> {
+        let response = self.wait(db, dst, shutdown).await;
+
+        // 기다림을 마쳤다. 이 커넥션만 기다리던 키의 'Notify'를 정리한다.
+        db.release_list_waiters(&self.keys);
+
+        let response = match response? {
+            Some(response) => response,
+            // 셧다운 시그널을 수신했다.
+            None => return Ok(()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 원소를 꺼내거나 대기 시간이 초과될 때까지 기다리고, 응답할 프레임을 반환한다.
+    ///
+    /// 셧다운 시그널을 수신하면 'None'을 반환한다.
+    async fn wait(
+        &self,
+        db: &Db,
+        dst: &mut Connection,
+        shutdown: &mut Shutdown,
+    ) -> crate::Result<Option<Frame>> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut flushed = false;
+
+        loop {
+            // 리스트를 확인하기 전에 알림을 받을 future를 먼저 생성한다. 생성된 'Notified'는 poll되기
+            // 전이라도 'notify_waiters'의 알림을 받으므로, 확인과 대기 사이에 삽입된 원소를 놓치지 않는다.
+            let waiters = db.list_waiters(&self.keys);
+            let mut notified: Vec<_> = waiters
+                .iter()
+                .map(|notify| Box::pin(notify.notified()))
+                .collect();
+
+            match db.pop_first(&self.keys, self.left) {
+                Ok(Some((key, value))) => {
+                    let mut response = Frame::array();
+                    response.push_bulk(Bytes::from(key.into_bytes()));
+                    response.push_bulk(value);
+                    return Ok(Some(response));
+                }
+                // 기다리지 않는 커맨드다.
+                Ok(None) if self.timeout == Some(Duration::ZERO) => return Ok(Some(Frame::Null)),
+                Ok(None) => {}
+                Err(err) => return Ok(Some(Frame::Error(err.to_string()))),
+            }
+
+            // 기다리기 전에, 파이프라인으로 앞서 처리된 커맨드의 응답을 클라이언트에게 보낸다.
+            if !flushed {
+                dst.flush().await?;
+                flushed = true;
+            }
+
+            // 어느 한 키에 원소가 삽입되면 완료되는 future.
+            let inserted = future::poll_fn(|cx| {
+                for notified in notified.iter_mut() {
+                    if Pin::new(notified).poll(cx).is_ready() {
+                        return Poll::Ready(());
+                    }
+                }
+                Poll::Pending
+            });
+
+            let timeout = async {
+                match deadline {
+                    Some(deadline) => time::sleep_until(deadline).await,
+                    None => future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                // 다른 커넥션이 먼저 원소를 꺼냈을 수 있으므로, 깨어나면 락을 잡고 다시 확인한다.
+                _ = inserted => {}
+                _ = timeout => return Ok(Some(Frame::Null)),
+                _ = shutdown.recv() => return Ok(None),
+            }
+        }
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'BPop'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        for key in self.keys {
+            frame.push_bulk(Bytes::from(key.into_bytes()));
+        }
+        // 대기 시간은 초 단위로 전송한다. 0은 무한히 기다림을 의미한다.
+        let timeout = self.timeout.map(|timeout| timeout.as_secs_f64()).unwrap_or(0.0);
+        frame.push_bulk(Bytes::from(timeout.to_string()));
+        frame
+    }
+}
+
+impl LLen {
+    /// 'key'의 리스트의 길이를 가져오는 새로운 'LLen' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> LLen {
+        LLen {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LLen' 인스턴스를 파싱한다.
+    ///
+    /// 'LLEN' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LLEN key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LLen> {
+        let key = parse.next_string()?;
+
+        Ok(LLen { key })
+    }
+
+    /// 'LLen' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.llen(&self.key) {
+            Ok(len) => Frame::Integer(len as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LLen'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("llen".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
+
+impl LRange {
+    /// 'key'의 리스트의 'start'부터 'stop'까지의 원소를 가져오는 새로운 'LRange' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, start: i64, stop: i64) -> LRange {
+        LRange {
+            key: key.to_string(),
+            start,
+            stop,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LRange' 인스턴스를 파싱한다.
+    ///
+    /// 'LRANGE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LRANGE key start stop
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LRange> {
+        let key = parse.next_string()?;
+        let start = parse.next_signed_int()?;
+        let stop = parse.next_signed_int()?;
+
+        Ok(LRange { key, start, stop })
+    }
+
+    /// 'LRange' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 범위의 원소들을 배열로 응답한다. 범위가 비어있다면 빈 배열이 된다.
+        let response = match db.lrange(&self.key, self.start, self.stop) {
+            Ok(values) => Frame::Array(values.into_iter().map(Frame::Bulk).collect()),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LRange'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("lrange".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.start);
+        frame.push_int(self.stop);
+        frame
+    }
+}
+
+impl LPos {
+    /// 'key'의 리스트에서 'element'의 인덱스를 찾는 새로운 'LPos' 커맨드를 생성한다.
+    ///
+    /// 'rank'는 0이 될 수 없다. 'count'가 'Some'이면 최대 'count'개의 인덱스를 찾는다.
+    pub fn new(key: impl ToString, element: Bytes, rank: i64, count: Option<u64>) -> LPos {
+        LPos {
+            key: key.to_string(),
+            element,
+            rank,
+            count,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LPos' 인스턴스를 파싱한다.
+    ///
+    /// 'LPOS' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다. 옵션은 순서와 무관하다.
+    ///
+    /// ```text
+    /// LPOS key element [RANK rank] [COUNT count]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LPos> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+        let element = parse.next_bytes()?;
+
+        let mut rank = 1;
+        let mut count = None;
+
+        loop {
+            let option = match parse.next_string() {
+                Ok(option) => option.to_uppercase(),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            };
+
+            match &option[..] {
+                "RANK" => {
+                    rank = parse.next_signed_int()?;
+                    if rank == 0 {
+                        return Err("ERR RANK can't be zero: use 1 to start from the first match, \
+                                    2 from the second ... or use negative to start from the end \
+                                    of the list"
+                            .into());
+                    }
+                }
+                "COUNT" => match parse.next_signed_int()? {
+                    value if value < 0 => return Err("ERR COUNT can't be negative".into()),
+                    value => count = Some(value as u64),
+                },
+                _ => return Err("ERR syntax error".into()),
+            }
+        }
+
+        Ok(LPos {
+            key,
+            element,
+            rank,
+            count,
+        })
+    }
+
+    /// 'LPos' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 'count'가 없다면 일치하는 첫 원소 하나만 찾는다.
+        let count = self.count.unwrap_or(1) as usize;
+
+        let response = match db.lpos(&self.key, &self.element, self.rank, count) {
+            // 'count'가 지정되었다면 찾은 인덱스들을 배열로 응답한다.
+            Ok(positions) if self.count.is_some() => Frame::Array(
+                positions
+                    .into_iter()
+                    .map(|pos| Frame::Integer(pos as i64))
+                    .collect(),
+            ),
+            Ok(positions) => match positions.first() {
+                Some(&pos) => Frame::Integer(pos as i64),
+                None => Frame::Null,
+            },
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LPos'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("lpos".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(self.element);
+        if self.rank != 1 {
+            frame.push_bulk(Bytes::from("rank".as_bytes()));
+            frame.push_int(self.rank);
+        }
+        if let Some(count) = self.count {
+            frame.push_bulk(Bytes::from("count".as_bytes()));
+            frame.push_int(count as i64);
+        }
+        frame
+    }
+}
+
+impl LMove {
+    /// 'source' 리스트에서 원소를 꺼내 'destination' 리스트에 삽입하는 새로운 'LMove' 커맨드를 생성한다.
+    ///
+    /// 'from_left'가 'true'이면 'source'의 앞에서 꺼내고, 'to_left'가 'true'이면 'destination'의
+    /// 앞에 삽입한다.
+    pub fn new(
+        source: impl ToString,
+        destination: impl ToString,
+        from_left: bool,
+        to_left: bool,
+    ) -> LMove {
+        LMove {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            from_left,
+            to_left,
+            rpoplpush: false,
+        }
+    }
+
+    /// 'RPOPLPUSH source destination'에 해당하는 새로운 'LMove' 커맨드를 생성한다.
+    pub fn rpoplpush(source: impl ToString, destination: impl ToString) -> LMove {
+        LMove {
+            rpoplpush: true,
+            ..LMove::new(source, destination, false, true)
+        }
+    }
+
+    /// 원본 리스트의 키를 가져온다.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// 대상 리스트의 키를 가져온다.
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.rpoplpush {
+            "rpoplpush"
+        } else {
+            "lmove"
+        }
+    }
+
+    /// 수신한 프레임으로부터 'LMove' 인스턴스를 파싱한다.
+    ///
+    /// 'LMOVE' 혹은 'RPOPLPUSH' 문자열은 이미 소비되었다. 'rpoplpush'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// LMOVE source destination LEFT|RIGHT LEFT|RIGHT
+    /// RPOPLPUSH source destination
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, rpoplpush: bool) -> crate::Result<LMove> {
+        let source = parse.next_string()?;
+        let destination = parse.next_string()?;
+
+        if rpoplpush {
+            return Ok(LMove::rpoplpush(source, destination));
+        }
+
+        let from_left = parse_direction(parse)?;
+        let to_left = parse_direction(parse)?;
+
+        Ok(LMove::new(source, destination, from_left, to_left))
+    }
+
+    /// 'LMove' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.lmove(&self.source, &self.destination, self.from_left, self.to_left)
+        {
+            Ok(Some(value)) => Frame::Bulk(value),
+            // 원본 리스트가 존재하지 않는다.
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LMove'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame.push_bulk(Bytes::from(self.source.into_bytes()));
+        frame.push_bulk(Bytes::from(self.destination.into_bytes()));
+        if !self.rpoplpush {
+            for left in [self.from_left, self.to_left] {
+                let direction = if left { "left" } else { "right" };
+                frame.push_bulk(Bytes::from(direction.as_bytes()));
+            }
+        }
+        frame
+    }
+}
+
+/// 'LEFT' 혹은 'RIGHT' 아규먼트를 파싱한다. 'LEFT'이면 'true'를 반환한다.
+fn parse_direction(parse: &mut Parse) -> crate::Result<bool> {
+    match &parse.next_string()?.to_uppercase()[..] {
+        "LEFT" => Ok(true),
+        "RIGHT" => Ok(false),
+        _ => Err("ERR syntax error".into()),
+    }
+}
+
+impl LInsert {
+    /// 'key'의 리스트의 'pivot' 앞 혹은 뒤에 'value'를 삽입하는 새로운 'LInsert' 커맨드를 생성한다.
+    ///
+    /// 'before'가 'true'이면 'pivot'의 앞에 삽입한다.
+    pub fn new(key: impl ToString, before: bool, pivot: Bytes, value: Bytes) -> LInsert {
+        LInsert {
+            key: key.to_string(),
+            before,
+            pivot,
+            value,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LInsert' 인스턴스를 파싱한다.
+    ///
+    /// 'LINSERT' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 다섯 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LINSERT key BEFORE|AFTER pivot element
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LInsert> {
+        let key = parse.next_string()?;
+
+        let before = match &parse.next_string()?.to_uppercase()[..] {
+            "BEFORE" => true,
+            "AFTER" => false,
+            _ => return Err("ERR syntax error".into()),
+        };
+
+        let pivot = parse.next_bytes()?;
+        let value = parse.next_bytes()?;
+
+        Ok(LInsert {
+            key,
+            before,
+            pivot,
+            value,
+        })
+    }
+
+    /// 'LInsert' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.linsert(&self.key, self.before, &self.pivot, self.value) {
+            Ok(len) => Frame::Integer(len),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LInsert'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("linsert".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        let position = if self.before { "before" } else { "after" };
+        frame.push_bulk(Bytes::from(position.as_bytes()));
+        frame.push_bulk(self.pivot);
+        frame.push_bulk(self.value);
+        frame
+    }
+}
+
+impl LSet {
+    /// 'key'의 리스트의 'index' 위치의 원소를 'value'로 바꾸는 새로운 'LSet' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, index: i64, value: Bytes) -> LSet {
+        LSet {
+            key: key.to_string(),
+            index,
+            value,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LSet' 인스턴스를 파싱한다.
+    ///
+    /// 'LSET' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LSET key index element
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LSet> {
+        let key = parse.next_string()?;
+        let index = parse.next_signed_int()?;
+        let value = parse.next_bytes()?;
+
+        Ok(LSet { key, index, value })
+    }
+
+    /// 'LSet' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.lset(&self.key, self.index, self.value) {
+            Ok(()) => Frame::Simple("OK".to_string()),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LSet'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("lset".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.index);
+        frame.push_bulk(self.value);
+        frame
+    }
+}
+
+impl LRem {
+    /// 'key'의 리스트에서 'element'를 최대 'count'개 삭제하는 새로운 'LRem' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, count: i64, element: Bytes) -> LRem {
+        LRem {
+            key: key.to_string(),
+            count,
+            element,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LRem' 인스턴스를 파싱한다.
+    ///
+    /// 'LREM' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LREM key count element
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LRem> {
+        let key = parse.next_string()?;
+        let count = parse.next_signed_int()?;
+        let element = parse.next_bytes()?;
+
+        Ok(LRem {
+            key,
+            count,
+            element,
+        })
+    }
+
+    /// 'LRem' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.lrem(&self.key, self.count, &self.element) {
+            Ok(removed) => Frame::Integer(removed as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LRem'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("lrem".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.count);
+        frame.push_bulk(self.element);
+        frame
+    }
+}
+
+impl LTrim {
+    /// 'key'의 리스트를 'start'부터 'stop'까지의 원소만 남도록 자르는 새로운 'LTrim' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, start: i64, stop: i64) -> LTrim {
+        LTrim {
+            key: key.to_string(),
+            start,
+            stop,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'LTrim' 인스턴스를 파싱한다.
+    ///
+    /// 'LTRIM' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// LTRIM key start stop
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<LTrim> {
+        let key = parse.next_string()?;
+        let start = parse.next_signed_int()?;
+        let stop = parse.next_signed_int()?;
+
+        Ok(LTrim { key, start, stop })
+    }
+
+    /// 'LTrim' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.ltrim(&self.key, self.start, self.stop) {
+            Ok(()) => Frame::Simple("OK".to_string()),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'LTrim'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("ltrim".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.start);
+        frame.push_int(self.stop);
+        frame
+    }
+}
@@ -1,31 +1,494 @@
+mod append;
+pub use append::Append;
+
+mod auth;
+pub use auth::Auth;
+
+mod bgsave;
+pub use bgsave::BgSave;
+
+mod bitcount;
+pub use bitcount::BitCount;
+
+mod client;
+pub use client::ClientCommand;
+
+mod command;
+pub use command::CommandInfo;
+
+mod config;
+pub use config::Config;
+
+mod copy;
+pub use copy::Copy;
+
+mod dbsize;
+pub use dbsize::DbSize;
+
+mod debug;
+pub use debug::Debug;
+
+mod decr;
+pub use decr::Decr;
+
+mod del;
+pub use del::Del;
+
+mod exists;
+pub use exists::Exists;
+
+mod expire;
+pub use expire::{Expire, ExpireAt, Persist};
+
+mod flush;
+pub use flush::Flush;
+
 mod get;
 pub use get::Get;
 
+mod getbit;
+pub use getbit::GetBit;
+
+mod getdel;
+pub use getdel::GetDel;
+
+mod getex;
+pub use getex::{Expiry, GetEx};
+
+mod getrange;
+pub use getrange::GetRange;
+
+mod getset;
+pub use getset::GetSet;
+
+mod hash;
+pub use hash::{HDel, HGet, HGetAll, HIncrBy, HIncrByFloat, HLen, HRandField, HSet};
+
+mod hello;
+pub use hello::Hello;
+
+mod incr;
+pub use incr::Incr;
+
+mod info;
+pub use info::Info;
+
+mod keys;
+pub use keys::Keys;
+
+mod list;
+pub use list::{BPop, LInsert, LLen, LMove, LPos, LRange, LRem, LSet, LTrim, Pop, Push};
+
+mod mget;
+pub use mget::MGet;
+
+mod mset;
+pub use mset::MSet;
+
+mod multi;
+pub(crate) use multi::Transaction;
+pub use multi::{Discard, Exec, Multi};
+
+mod monitor;
+pub(crate) use monitor::monitor_line;
+pub use monitor::Monitor;
+
+mod object;
+pub use object::Object;
+
+mod ping;
+pub use ping::Ping;
+
 mod publish;
-pub use publish::Publish;
+pub use publish::{Publish, SPublish};
+
+mod pubsub;
+pub use pubsub::PubSub;
+
+mod quit;
+pub use quit::Quit;
+
+mod randomkey;
+pub use randomkey::RandomKey;
+
+mod rename;
+pub use rename::Rename;
+
+mod reset;
+pub use reset::Reset;
+
+mod save;
+pub use save::Save;
+
+mod scan;
+pub use scan::Scan;
+
+mod select;
+pub use select::Select;
 
 mod set;
 pub use set::Set;
 
+mod set_type;
+pub use set_type::{
+    SAdd, SCard, SetAlgebra, SetAlgebraStore, SIsMember, SMembers, SRandMember, SRem,
+};
+
+mod setbit;
+pub use setbit::SetBit;
+
+mod setex;
+pub use setex::SetEx;
+
+mod setnx;
+pub use setnx::SetNx;
+
+mod setrange;
+pub use setrange::SetRange;
+
+mod slowlog;
+pub use slowlog::SlowLog;
+
+mod strlen;
+pub use strlen::Strlen;
+
 mod subscribe;
-pub use subscribe::{Subscribe, Unsubscribe};
+pub(crate) use subscribe::Leave;
+pub use subscribe::{
+    PSubscribe, PUnsubscribe, SSubscribe, SUnsubscribe, Subscribe, Unsubscribe,
+};
+
+mod touch;
+pub use touch::Touch;
+
+mod ttl;
+pub use ttl::Ttl;
+
+// 'type'은 예약어이므로 raw identifier로 모듈을 선언한다.
+mod r#type;
+pub use r#type::Type;
 
 mod unknown;
 pub use unknown::Unknown;
 
+mod unlink;
+pub use unlink::Unlink;
+
+mod wait;
+pub use wait::Wait;
+
+mod watch;
+pub(crate) use watch::WatchedKeys;
+pub use watch::{Unwatch, Watch};
+
+mod zset;
+pub use zset::{ZAdd, ZRange, ZRangeByScore, ZRank, ZScore};
+
+use crate::db::SetOp;
 use crate::{Connection, Db, Frame, Parse, ParseError, Shutdown};
 
+use std::time::Duration;
+
+/// 지원하는 커맨드의 이름과 파서의 목록. 이름의 알파벳 순서로 정렬한다.
+/// 
+/// 'Command::from_frame'은 이 목록에서 커맨드 이름을 찾아 파싱을 위임하며, 'COMMAND'는 이 목록으로
+/// 지원하는 커맨드를 응답한다. 새로운 커맨드는 'Command'에 변형을 추가하고 이 목록에 등록한다.
+/// 
+/// 'LPUSH'와 'RPUSH'처럼 하나의 'Command' 변형이 여러 이름으로 등록될 수 있다.
+pub(crate) static COMMANDS: &[CommandSpec] = &[
+    CommandSpec::new("append", 3, |parse| Append::parse_frames(parse).map(Command::Append)),
+    CommandSpec::new("auth", -2, |parse| Auth::parse_frames(parse).map(Command::Auth)),
+    CommandSpec::new("bgsave", 1, |parse| BgSave::parse_frames(parse).map(Command::BgSave)),
+    CommandSpec::new("bitcount", -2, |parse| BitCount::parse_frames(parse).map(Command::BitCount)),
+    CommandSpec::new("blpop", -3, |parse| BPop::parse_frames(parse, true).map(Command::BPop)),
+    CommandSpec::new("brpop", -3, |parse| BPop::parse_frames(parse, false).map(Command::BPop)),
+    CommandSpec::new("client", -2, |parse| {
+        ClientCommand::parse_frames(parse).map(Command::ClientCommand)
+    }),
+    CommandSpec::new("command", -1, |parse| {
+        CommandInfo::parse_frames(parse).map(Command::CommandInfo)
+    }),
+    CommandSpec::new("config", -2, |parse| Config::parse_frames(parse).map(Command::Config)),
+    CommandSpec::new("copy", -3, |parse| Copy::parse_frames(parse).map(Command::Copy)),
+    CommandSpec::new("dbsize", 1, |parse| DbSize::parse_frames(parse).map(Command::DbSize)),
+    CommandSpec::new("debug", -2, |parse| Debug::parse_frames(parse).map(Command::Debug)),
+    CommandSpec::new("decr", 2, |parse| Decr::parse_frames(parse).map(Command::Decr)),
+    CommandSpec::new("del", -2, |parse| Del::parse_frames(parse).map(Command::Del)),
+    CommandSpec::new("discard", 1, |parse| Discard::parse_frames(parse).map(Command::Discard)),
+    CommandSpec::new("exec", 1, |parse| Exec::parse_frames(parse).map(Command::Exec)),
+    CommandSpec::new("exists", -2, |parse| Exists::parse_frames(parse).map(Command::Exists)),
+    CommandSpec::new("expire", 3, |parse| {
+        Expire::parse_frames(parse, Duration::from_secs).map(Command::Expire)
+    }),
+    CommandSpec::new("expireat", 3, |parse| {
+        ExpireAt::parse_frames(parse, Duration::from_secs).map(Command::ExpireAt)
+    }),
+    CommandSpec::new("flushall", -1, |parse| Flush::parse_frames(parse, true).map(Command::Flush)),
+    CommandSpec::new("flushdb", -1, |parse| Flush::parse_frames(parse, false).map(Command::Flush)),
+    CommandSpec::new("get", 2, |parse| Get::parse_frames(parse).map(Command::Get)),
+    CommandSpec::new("getbit", 3, |parse| GetBit::parse_frames(parse).map(Command::GetBit)),
+    CommandSpec::new("getdel", 2, |parse| GetDel::parse_frames(parse).map(Command::GetDel)),
+    CommandSpec::new("getex", -2, |parse| GetEx::parse_frames(parse).map(Command::GetEx)),
+    CommandSpec::new("getrange", 4, |parse| GetRange::parse_frames(parse).map(Command::GetRange)),
+    CommandSpec::new("getset", 3, |parse| GetSet::parse_frames(parse).map(Command::GetSet)),
+    CommandSpec::new("hdel", -3, |parse| HDel::parse_frames(parse).map(Command::HDel)),
+    CommandSpec::new("hello", -1, |parse| Hello::parse_frames(parse).map(Command::Hello)),
+    CommandSpec::new("hget", 3, |parse| HGet::parse_frames(parse).map(Command::HGet)),
+    CommandSpec::new("hgetall", 2, |parse| HGetAll::parse_frames(parse).map(Command::HGetAll)),
+    CommandSpec::new("hincrby", 4, |parse| HIncrBy::parse_frames(parse).map(Command::HIncrBy)),
+    CommandSpec::new("hincrbyfloat", 4, |parse| {
+        HIncrByFloat::parse_frames(parse).map(Command::HIncrByFloat)
+    }),
+    CommandSpec::new("hlen", 2, |parse| HLen::parse_frames(parse).map(Command::HLen)),
+    CommandSpec::new("hrandfield", -2, |parse| {
+        HRandField::parse_frames(parse).map(Command::HRandField)
+    }),
+    CommandSpec::new("hset", -4, |parse| HSet::parse_frames(parse).map(Command::HSet)),
+    CommandSpec::new("incr", 2, |parse| Incr::parse_frames(parse).map(Command::Incr)),
+    CommandSpec::new("info", -1, |parse| Info::parse_frames(parse).map(Command::Info)),
+    CommandSpec::new("keys", 2, |parse| Keys::parse_frames(parse).map(Command::Keys)),
+    CommandSpec::new("linsert", 5, |parse| LInsert::parse_frames(parse).map(Command::LInsert)),
+    CommandSpec::new("llen", 2, |parse| LLen::parse_frames(parse).map(Command::LLen)),
+    CommandSpec::new("lmove", 5, |parse| LMove::parse_frames(parse, false).map(Command::LMove)),
+    CommandSpec::new("lpop", -2, |parse| Pop::parse_frames(parse, true).map(Command::Pop)),
+    CommandSpec::new("lpos", -3, |parse| LPos::parse_frames(parse).map(Command::LPos)),
+    CommandSpec::new("lpush", -3, |parse| Push::parse_frames(parse, true).map(Command::Push)),
+    CommandSpec::new("lrange", 4, |parse| LRange::parse_frames(parse).map(Command::LRange)),
+    CommandSpec::new("lrem", 4, |parse| LRem::parse_frames(parse).map(Command::LRem)),
+    CommandSpec::new("lset", 4, |parse| LSet::parse_frames(parse).map(Command::LSet)),
+    CommandSpec::new("ltrim", 4, |parse| LTrim::parse_frames(parse).map(Command::LTrim)),
+    CommandSpec::new("mget", -2, |parse| MGet::parse_frames(parse).map(Command::MGet)),
+    CommandSpec::new("monitor", 1, |parse| Monitor::parse_frames(parse).map(Command::Monitor)),
+    CommandSpec::new("mset", -3, |parse| MSet::parse_frames(parse).map(Command::MSet)),
+    CommandSpec::new("multi", 1, |parse| Multi::parse_frames(parse).map(Command::Multi)),
+    CommandSpec::new("object", -2, |parse| Object::parse_frames(parse).map(Command::Object)),
+    CommandSpec::new("persist", 2, |parse| Persist::parse_frames(parse).map(Command::Persist)),
+    CommandSpec::new("pexpire", 3, |parse| {
+        Expire::parse_frames(parse, Duration::from_millis).map(Command::Expire)
+    }),
+    CommandSpec::new("pexpireat", 3, |parse| {
+        ExpireAt::parse_frames(parse, Duration::from_millis).map(Command::ExpireAt)
+    }),
+    CommandSpec::new("ping", -1, |parse| Ping::parse_frames(parse).map(Command::Ping)),
+    CommandSpec::new("psetex", 4, |parse| SetEx::parse_frames(parse, true).map(Command::SetEx)),
+    CommandSpec::new("psubscribe", -2, |parse| {
+        PSubscribe::parse_frames(parse).map(Command::PSubscribe)
+    }),
+    CommandSpec::new("pttl", 2, |parse| Ttl::parse_frames(parse, true).map(Command::Ttl)),
+    CommandSpec::new("publish", 3, |parse| Publish::parse_frames(parse).map(Command::Publish)),
+    CommandSpec::new("pubsub", -2, |parse| PubSub::parse_frames(parse).map(Command::PubSub)),
+    CommandSpec::new("punsubscribe", -1, |parse| {
+        Ok(Command::PUnsubscribe(PUnsubscribe::parse_frames(parse)?))
+    }),
+    CommandSpec::new("quit", 1, |parse| Quit::parse_frames(parse).map(Command::Quit)),
+    CommandSpec::new("randomkey", 1, |parse| {
+        RandomKey::parse_frames(parse).map(Command::RandomKey)
+    }),
+    CommandSpec::new("rename", 3, |parse| Rename::parse_frames(parse, false).map(Command::Rename)),
+    CommandSpec::new("renamenx", 3, |parse| {
+        Rename::parse_frames(parse, true).map(Command::Rename)
+    }),
+    CommandSpec::new("reset", 1, |parse| Reset::parse_frames(parse).map(Command::Reset)),
+    CommandSpec::new("rpop", -2, |parse| Pop::parse_frames(parse, false).map(Command::Pop)),
+    CommandSpec::new("rpoplpush", 3, |parse| {
+        LMove::parse_frames(parse, true).map(Command::LMove)
+    }),
+    CommandSpec::new("rpush", -3, |parse| Push::parse_frames(parse, false).map(Command::Push)),
+    CommandSpec::new("sadd", -3, |parse| SAdd::parse_frames(parse).map(Command::SAdd)),
+    CommandSpec::new("save", 1, |parse| Save::parse_frames(parse).map(Command::Save)),
+    CommandSpec::new("scan", -2, |parse| Scan::parse_frames(parse).map(Command::Scan)),
+    CommandSpec::new("scard", 2, |parse| SCard::parse_frames(parse).map(Command::SCard)),
+    CommandSpec::new("sdiff", -2, |parse| {
+        SetAlgebra::parse_frames(parse, SetOp::Diff).map(Command::SetAlgebra)
+    }),
+    CommandSpec::new("sdiffstore", -3, |parse| {
+        SetAlgebraStore::parse_frames(parse, SetOp::Diff).map(Command::SetAlgebraStore)
+    }),
+    CommandSpec::new("select", 2, |parse| Select::parse_frames(parse).map(Command::Select)),
+    CommandSpec::new("set", -3, |parse| Set::parse_frames(parse).map(Command::Set)),
+    CommandSpec::new("setbit", 4, |parse| SetBit::parse_frames(parse).map(Command::SetBit)),
+    CommandSpec::new("setex", 4, |parse| SetEx::parse_frames(parse, false).map(Command::SetEx)),
+    CommandSpec::new("setnx", 3, |parse| SetNx::parse_frames(parse).map(Command::SetNx)),
+    CommandSpec::new("setrange", 4, |parse| SetRange::parse_frames(parse).map(Command::SetRange)),
+    CommandSpec::new("sinter", -2, |parse| {
+        SetAlgebra::parse_frames(parse, SetOp::Inter).map(Command::SetAlgebra)
+    }),
+    CommandSpec::new("sinterstore", -3, |parse| {
+        SetAlgebraStore::parse_frames(parse, SetOp::Inter).map(Command::SetAlgebraStore)
+    }),
+    CommandSpec::new("sismember", 3, |parse| {
+        SIsMember::parse_frames(parse).map(Command::SIsMember)
+    }),
+    CommandSpec::new("slowlog", -2, |parse| SlowLog::parse_frames(parse).map(Command::SlowLog)),
+    CommandSpec::new("smembers", 2, |parse| SMembers::parse_frames(parse).map(Command::SMembers)),
+    CommandSpec::new("spublish", 3, |parse| {
+        SPublish::parse_frames(parse).map(Command::SPublish)
+    }),
+    CommandSpec::new("srandmember", -2, |parse| {
+        SRandMember::parse_frames(parse).map(Command::SRandMember)
+    }),
+    CommandSpec::new("srem", -3, |parse| SRem::parse_frames(parse).map(Command::SRem)),
+    CommandSpec::new("ssubscribe", -2, |parse| {
+        SSubscribe::parse_frames(parse).map(Command::SSubscribe)
+    }),
+    CommandSpec::new("strlen", 2, |parse| Strlen::parse_frames(parse).map(Command::Strlen)),
+    CommandSpec::new("subscribe", -2, |parse| {
+        Subscribe::parse_frames(parse).map(Command::Subscribe)
+    }),
+    CommandSpec::new("sunion", -2, |parse| {
+        SetAlgebra::parse_frames(parse, SetOp::Union).map(Command::SetAlgebra)
+    }),
+    CommandSpec::new("sunionstore", -3, |parse| {
+        SetAlgebraStore::parse_frames(parse, SetOp::Union).map(Command::SetAlgebraStore)
+    }),
+    CommandSpec::new("sunsubscribe", -1, |parse| {
+        Ok(Command::SUnsubscribe(SUnsubscribe::parse_frames(parse)?))
+    }),
+    CommandSpec::new("touch", -2, |parse| Touch::parse_frames(parse).map(Command::Touch)),
+    CommandSpec::new("ttl", 2, |parse| Ttl::parse_frames(parse, false).map(Command::Ttl)),
+    CommandSpec::new("type", 2, |parse| Type::parse_frames(parse).map(Command::Type)),
+    CommandSpec::new("unlink", -2, |parse| Unlink::parse_frames(parse).map(Command::Unlink)),
+    CommandSpec::new("unsubscribe", -1, |parse| {
+        Ok(Command::Unsubscribe(Unsubscribe::parse_frames(parse)?))
+    }),
+    CommandSpec::new("unwatch", 1, |parse| Unwatch::parse_frames(parse).map(Command::Unwatch)),
+    CommandSpec::new("wait", 3, |parse| Wait::parse_frames(parse).map(Command::Wait)),
+    CommandSpec::new("watch", -2, |parse| Watch::parse_frames(parse).map(Command::Watch)),
+    CommandSpec::new("zadd", -4, |parse| ZAdd::parse_frames(parse).map(Command::ZAdd)),
+    CommandSpec::new("zrange", -4, |parse| ZRange::parse_frames(parse).map(Command::ZRange)),
+    CommandSpec::new("zrangebyscore", -4, |parse| {
+        ZRangeByScore::parse_frames(parse).map(Command::ZRangeByScore)
+    }),
+    CommandSpec::new("zrank", 3, |parse| ZRank::parse_frames(parse).map(Command::ZRank)),
+    CommandSpec::new("zscore", 3, |parse| ZScore::parse_frames(parse).map(Command::ZScore)),
+];
+
+/// 'COMMANDS'에 등록되는 커맨드 하나의 정보
+pub(crate) struct CommandSpec {
+    /// 소문자로 된 커맨드 이름
+    pub(crate) name: &'static str,
+
+    /// 커맨드 이름을 포함한 아규먼트의 수. 레디스와 같이, 음수는 최소 '-arity'개의 아규먼트를 받는
+    /// 가변 길이 커맨드를 나타낸다.
+    pub(crate) arity: i64,
+
+    /// 커맨드 이름이 소비된 'Parse'로부터 커맨드를 파싱한다.
+    parse: fn(&mut Parse) -> crate::Result<Command>,
+}
+
+impl CommandSpec {
+    const fn new(
+        name: &'static str,
+        arity: i64,
+        parse: fn(&mut Parse) -> crate::Result<Command>,
+    ) -> CommandSpec {
+        CommandSpec { name, arity, parse }
+    }
+}
+
 /// 지원하는 Redis 커맨드 목록
 /// 
 /// 'Command'에 호출되는 메서드는 커맨드 구현체로 위임된다.
 /// 
 #[derive(Debug)]
 pub enum Command {
+    Append(Append),
+    Auth(Auth),
+    BgSave(BgSave),
+    BitCount(BitCount),
+    BPop(BPop),
+    ClientCommand(ClientCommand),
+    CommandInfo(CommandInfo),
+    Config(Config),
+    Copy(Copy),
+    DbSize(DbSize),
+    Debug(Debug),
+    Decr(Decr),
+    Del(Del),
+    Discard(Discard),
+    Exec(Exec),
+    Exists(Exists),
+    Expire(Expire),
+    ExpireAt(ExpireAt),
+    Flush(Flush),
     Get(Get),
+    GetBit(GetBit),
+    GetDel(GetDel),
+    GetEx(GetEx),
+    GetRange(GetRange),
+    GetSet(GetSet),
+    HDel(HDel),
+    Hello(Hello),
+    HGet(HGet),
+    HGetAll(HGetAll),
+    HIncrBy(HIncrBy),
+    HIncrByFloat(HIncrByFloat),
+    HLen(HLen),
+    HRandField(HRandField),
+    HSet(HSet),
+    Incr(Incr),
+    Info(Info),
+    Keys(Keys),
+    LLen(LLen),
+    LInsert(LInsert),
+    LMove(LMove),
+    LPos(LPos),
+    LRange(LRange),
+    LRem(LRem),
+    LSet(LSet),
+    LTrim(LTrim),
+    MGet(MGet),
+    MSet(MSet),
+    Monitor(Monitor),
+    Multi(Multi),
+    Object(Object),
+    Persist(Persist),
+    Ping(Ping),
+    Pop(Pop),
+    PSubscribe(PSubscribe),
     Publish(Publish),
+    PubSub(PubSub),
+    PUnsubscribe(PUnsubscribe),
+    Push(Push),
+    Quit(Quit),
+    RandomKey(RandomKey),
+    Rename(Rename),
+    Reset(Reset),
+    SAdd(SAdd),
+    Save(Save),
+    SCard(SCard),
+    Scan(Scan),
+    Select(Select),
     Set(Set),
+    SetAlgebra(SetAlgebra),
+    SetAlgebraStore(SetAlgebraStore),
+    SetBit(SetBit),
+    SetEx(SetEx),
+    SetNx(SetNx),
+    SetRange(SetRange),
+    SIsMember(SIsMember),
+    SlowLog(SlowLog),
+    SMembers(SMembers),
+    SPublish(SPublish),
+    SRandMember(SRandMember),
+    SRem(SRem),
+    SSubscribe(SSubscribe),
+    Strlen(Strlen),
     Subscribe(Subscribe),
+    SUnsubscribe(SUnsubscribe),
+    Touch(Touch),
+    Ttl(Ttl),
+    Type(Type),
+    Unlink(Unlink),
     Unsubscribe(Unsubscribe),
+    Unwatch(Unwatch),
+    Wait(Wait),
+    Watch(Watch),
+    ZAdd(ZAdd),
+    ZRange(ZRange),
+    ZRangeByScore(ZRangeByScore),
+    ZRank(ZRank),
+    ZScore(ZScore),
     Unknwon(Unknown),
 }
 
@@ -50,14 +513,10 @@ impl Command {
         // 이 이름을 읽어서 소문자로 변환한다.
         let command_name = parse.next_string()?.to_lowercase();
 
-        // 커맨드 이름을 매칭하고 나머지 값들은 해당 커맨드에 위임한다.
-        let command = match &command_name[..] {
-            "get" => Command::Get(Get::parse_frames(&mut parse)?),
-            "publish" => Command::Publish(Publish::parse_frames(&mut parse)?),
-            "set" => Command::Set(Set::parse_frames(&mut parse)?),
-            "subscribe" => Command::Subscribe(Subscribe::parse_frames(&mut parse)?),
-            "unsubscribe" => Command::Unsubscribe(Unsubscribe::parse_frames(&mut parse)?),
-            _ => {
+        // 커맨드 이름으로 커맨드를 찾고, 나머지 값들의 파싱은 해당 커맨드에 위임한다.
+        let command = match COMMANDS.iter().find(|spec| spec.name == command_name) {
+            Some(spec) => (spec.parse)(&mut parse)?,
+            None => {
                 // 지원하지 않는 커맨드는 Unknwon 커맨드로 반환한다.
                 // 
                 // 여기의 'return'은 아래의 'finish()' 호출을 생략하기 위한 것이다.
@@ -78,36 +537,340 @@ impl Command {
     // 커맨드를 특정 'Db' 인스턴스에 수행한다.
     // 
     // 응답은 'dst'에 쓴다. 수신한 커맨드를 실행하기 위해, 이 함수는 서버가 호출한다.
+    // 
+    // 'db'는 커넥션이 사용하는 핸들이다. 'SELECT'는 이를 다른 데이터베이스의 핸들로 교체한다.
     pub(crate) async fn apply(
         self,
-        db: &Db,
+        db: &mut Db,
         dst: &mut Connection,
         shutdown: &mut Shutdown,
     ) -> crate::Result<()> {
         use Command::*;
 
         match self {
+            Append(cmd) => cmd.apply(db, dst).await,
+            // 'Auth'는 커넥션의 인증 상태를 변경하므로 핸들러가 직접 수행한다.
+            Auth(_) => Err("'Auth' is unsupported in this context".into()),
+            // 'BgSave'는 'Save'와 마찬가지로 핸들러가 직접 수행한다.
+            BgSave(_) => Err("'BgSave' is unsupported in this context".into()),
+            BitCount(cmd) => cmd.apply(db, dst).await,
+            BPop(cmd) => cmd.apply(db, dst, shutdown).await,
+            // 'ClientCommand'는 커넥션별 상태를 다루므로 핸들러가 직접 수행한다.
+            ClientCommand(_) => Err("'ClientCommand' is unsupported in this context".into()),
+            CommandInfo(cmd) => cmd.apply(dst).await,
+            // 'Config'는 서버의 설정이 필요하므로 핸들러가 직접 수행한다.
+            Config(_) => Err("'Config' is unsupported in this context".into()),
+            Copy(cmd) => cmd.apply(db, dst).await,
+            DbSize(cmd) => cmd.apply(db, dst).await,
+            Debug(cmd) => cmd.apply(dst, shutdown).await,
+            Decr(cmd) => cmd.apply(db, dst).await,
+            Del(cmd) => cmd.apply(db, dst).await,
+            // 트랜잭션은 커넥션별 상태이므로 'Discard', 'Exec', 'Multi'는 핸들러가 직접 수행한다.
+            Discard(_) => Err("'Discard' is unsupported in this context".into()),
+            Exec(_) => Err("'Exec' is unsupported in this context".into()),
+            Exists(cmd) => cmd.apply(db, dst).await,
+            Expire(cmd) => cmd.apply(db, dst).await,
+            ExpireAt(cmd) => cmd.apply(db, dst).await,
+            Flush(cmd) => cmd.apply(db, dst).await,
             Get(cmd) => cmd.apply(db, dst).await,
+            GetBit(cmd) => cmd.apply(db, dst).await,
+            GetDel(cmd) => cmd.apply(db, dst).await,
+            GetEx(cmd) => cmd.apply(db, dst).await,
+            GetRange(cmd) => cmd.apply(db, dst).await,
+            GetSet(cmd) => cmd.apply(db, dst).await,
+            HDel(cmd) => cmd.apply(db, dst).await,
+            Hello(cmd) => cmd.apply(dst).await,
+            HGet(cmd) => cmd.apply(db, dst).await,
+            HGetAll(cmd) => cmd.apply(db, dst).await,
+            HIncrBy(cmd) => cmd.apply(db, dst).await,
+            HIncrByFloat(cmd) => cmd.apply(db, dst).await,
+            HLen(cmd) => cmd.apply(db, dst).await,
+            HRandField(cmd) => cmd.apply(db, dst).await,
+            HSet(cmd) => cmd.apply(db, dst).await,
+            Incr(cmd) => cmd.apply(db, dst).await,
+            // 'Info'는 서버의 통계 정보가 필요하므로 핸들러가 직접 수행한다.
+            Info(_) => Err("'Info' is unsupported in this context".into()),
+            Keys(cmd) => cmd.apply(db, dst).await,
+            LLen(cmd) => cmd.apply(db, dst).await,
+            LInsert(cmd) => cmd.apply(db, dst).await,
+            LMove(cmd) => cmd.apply(db, dst).await,
+            LPos(cmd) => cmd.apply(db, dst).await,
+            LRange(cmd) => cmd.apply(db, dst).await,
+            LRem(cmd) => cmd.apply(db, dst).await,
+            LSet(cmd) => cmd.apply(db, dst).await,
+            LTrim(cmd) => cmd.apply(db, dst).await,
+            MGet(cmd) => cmd.apply(db, dst).await,
+            MSet(cmd) => cmd.apply(db, dst).await,
+            // 'Monitor'는 서버가 수신하는 커맨드를 발행하는 채널이 필요하므로 핸들러가 직접 수행한다.
+            Monitor(_) => Err("'Monitor' is unsupported in this context".into()),
+            Multi(_) => Err("'Multi' is unsupported in this context".into()),
+            Object(cmd) => cmd.apply(db, dst).await,
+            Persist(cmd) => cmd.apply(db, dst).await,
+            Ping(cmd) => cmd.apply(dst).await,
+            Pop(cmd) => cmd.apply(db, dst).await,
+            // 구독 상태에서 수신한 'Reset'을 처리해야 하므로 'PSubscribe'와 'Subscribe'는 핸들러가
+            // 직접 수행한다.
+            PSubscribe(_) => Err("'PSubscribe' is unsupported in this context".into()),
             Publish(cmd) => cmd.apply(db, dst).await,
+            Push(cmd) => cmd.apply(db, dst).await,
+            PubSub(cmd) => cmd.apply(db, dst).await,
+            // 'Quit'은 커넥션을 닫아야 하므로 핸들러가 직접 수행한다.
+            Quit(_) => Err("'Quit' is unsupported in this context".into()),
+            RandomKey(cmd) => cmd.apply(db, dst).await,
+            Rename(cmd) => cmd.apply(db, dst).await,
+            // 'Reset'은 커넥션별 상태를 초기화하므로 핸들러가 직접 수행한다.
+            Reset(_) => Err("'Reset' is unsupported in this context".into()),
+            SAdd(cmd) => cmd.apply(db, dst).await,
+            // 'Save'는 서버에 설정된 스냅샷 경로가 필요하므로 핸들러가 직접 수행한다.
+            Save(_) => Err("'Save' is unsupported in this context".into()),
+            SCard(cmd) => cmd.apply(db, dst).await,
+            Scan(cmd) => cmd.apply(db, dst).await,
+            Select(cmd) => cmd.apply(db, dst).await,
             Set(cmd) => cmd.apply(db, dst).await,
-            Subscribe(cmd) => cmd.apply(db, dst, shutdown).await,
+            SetAlgebra(cmd) => cmd.apply(db, dst).await,
+            SetAlgebraStore(cmd) => cmd.apply(db, dst).await,
+            SetBit(cmd) => cmd.apply(db, dst).await,
+            SetEx(cmd) => cmd.apply(db, dst).await,
+            SetNx(cmd) => cmd.apply(db, dst).await,
+            SetRange(cmd) => cmd.apply(db, dst).await,
+            SIsMember(cmd) => cmd.apply(db, dst).await,
+            // 'SlowLog'는 모든 커넥션이 공유하는 슬로우 로그가 필요하므로 핸들러가 직접 수행한다.
+            SlowLog(_) => Err("'SlowLog' is unsupported in this context".into()),
+            SMembers(cmd) => cmd.apply(db, dst).await,
+            SPublish(cmd) => cmd.apply(db, dst).await,
+            SRandMember(cmd) => cmd.apply(db, dst).await,
+            SRem(cmd) => cmd.apply(db, dst).await,
+            // 'SSubscribe'도 'Subscribe'와 마찬가지로 핸들러가 직접 수행한다.
+            SSubscribe(_) => Err("'SSubscribe' is unsupported in this context".into()),
+            Strlen(cmd) => cmd.apply(db, dst).await,
+            Subscribe(_) => Err("'Subscribe' is unsupported in this context".into()),
+            // 'SUnsubscribe'도 구독 상태에서만 수신한다.
+            SUnsubscribe(_) => Err("'SUnsubscribe' is unsupported in this context".into()),
+            Touch(cmd) => cmd.apply(db, dst).await,
+            Ttl(cmd) => cmd.apply(db, dst).await,
+            Type(cmd) => cmd.apply(db, dst).await,
+            Unlink(cmd) => cmd.apply(db, dst).await,
             Unknwon(cmd) => cmd.apply(dst).await,
             // 'Unsubscribe'는 수행할 수 없다. 이 커맨드는 'Subscribe' 커맨드로부터만 
             // 수신한다.
             Unsubscribe(_) => Err("'Unsubscribe' is unsupported in this context".into()),
+            // 'PUnsubscribe'도 마찬가지로 구독 상태에서만 수신한다.
+            PUnsubscribe(_) => Err("'PUnsubscribe' is unsupported in this context".into()),
+            // 감시하는 키는 커넥션별 상태이므로 'Unwatch'와 'Watch'는 핸들러가 직접 수행한다.
+            Unwatch(_) => Err("'Unwatch' is unsupported in this context".into()),
+            Wait(cmd) => cmd.apply(dst).await,
+            Watch(_) => Err("'Watch' is unsupported in this context".into()),
+            ZAdd(cmd) => cmd.apply(db, dst).await,
+            ZRange(cmd) => cmd.apply(db, dst).await,
+            ZRangeByScore(cmd) => cmd.apply(db, dst).await,
+            ZRank(cmd) => cmd.apply(db, dst).await,
+            ZScore(cmd) => cmd.apply(db, dst).await,
         }
     }
 
+    /// 키 공간을 변경할 수 있는 커맨드라면 'true'를 반환한다.
+    /// 
+    /// 핸들러는 이 값으로 마지막 스냅샷 이후의 변경 횟수를 센다. 새로운 쓰기 커맨드는 여기에 추가한다.
+    pub(crate) fn is_write(&self) -> bool {
+        use Command::*;
+
+        matches!(
+            self,
+            Append(_)
+                | BPop(_)
+                | Copy(_)
+                | Decr(_)
+                | Del(_)
+                | Expire(_)
+                | ExpireAt(_)
+                | Flush(_)
+                | GetDel(_)
+                | GetEx(_)
+                | GetSet(_)
+                | HDel(_)
+                | HIncrBy(_)
+                | HIncrByFloat(_)
+                | HSet(_)
+                | Incr(_)
+                | LInsert(_)
+                | LMove(_)
+                | LRem(_)
+                | LSet(_)
+                | LTrim(_)
+                | MSet(_)
+                | Persist(_)
+                | Pop(_)
+                | Push(_)
+                | Rename(_)
+                | SAdd(_)
+                | Set(_)
+                | SetAlgebraStore(_)
+                | SetBit(_)
+                | SetEx(_)
+                | SetNx(_)
+                | SetRange(_)
+                | SRem(_)
+                | Unlink(_)
+                | ZAdd(_)
+        )
+    }
+
+    /// 키 공간의 메모리 사용량을 늘릴 수 있는 커맨드라면 'true'를 반환한다.
+    /// 
+    /// 핸들러는 이 커맨드들을 수행하기 전에 'maxmemory'를 넘지 않도록 메모리를 확보하고, 확보하지 못하면
+    /// 'OOM' 에러를 응답한다. 키를 삭제하기만 하는 쓰기 커맨드는 메모리가 부족해도 수행할 수 있다.
+    pub(crate) fn may_grow(&self) -> bool {
+        use Command::*;
+
+        matches!(
+            self,
+            Append(_)
+                | Copy(_)
+                | Decr(_)
+                | GetSet(_)
+                | HIncrBy(_)
+                | HIncrByFloat(_)
+                | HSet(_)
+                | Incr(_)
+                | LInsert(_)
+                | LMove(_)
+                | LSet(_)
+                | MSet(_)
+                | Push(_)
+                | SAdd(_)
+                | Set(_)
+                | SetAlgebraStore(_)
+                | SetBit(_)
+                | SetEx(_)
+                | SetNx(_)
+                | SetRange(_)
+                | ZAdd(_)
+        )
+    }
+
     /// 커맨드 이름을 반환한다.
     pub(crate) fn get_name(&self) -> &str {
         match self {
+            Command::Append(_) => "append",
+            Command::Auth(_) => "auth",
+            Command::BgSave(_) => "bgsave",
+            Command::BitCount(_) => "bitcount",
+            Command::BPop(cmd) => cmd.get_name(),
+            Command::ClientCommand(_) => "client",
+            Command::CommandInfo(_) => "command",
+            Command::Config(_) => "config",
+            Command::Copy(_) => "copy",
+            Command::DbSize(_) => "dbsize",
+            Command::Debug(_) => "debug",
+            Command::Decr(_) => "decr",
+            Command::Del(_) => "del",
+            Command::Discard(_) => "discard",
+            Command::Exec(_) => "exec",
+            Command::Exists(_) => "exists",
+            Command::Expire(_) => "expire",
+            Command::ExpireAt(_) => "expireat",
+            Command::Flush(cmd) => cmd.get_name(),
             Command::Get(_) => "get",
-            Command::Publish(_) => "pub",
+            Command::GetBit(_) => "getbit",
+            Command::GetDel(_) => "getdel",
+            Command::GetEx(_) => "getex",
+            Command::GetRange(_) => "getrange",
+            Command::GetSet(_) => "getset",
+            Command::HDel(_) => "hdel",
+            Command::Hello(_) => "hello",
+            Command::HGet(_) => "hget",
+            Command::HGetAll(_) => "hgetall",
+            Command::HIncrBy(_) => "hincrby",
+            Command::HIncrByFloat(_) => "hincrbyfloat",
+            Command::HLen(_) => "hlen",
+            Command::HRandField(_) => "hrandfield",
+            Command::HSet(_) => "hset",
+            Command::Incr(_) => "incr",
+            Command::Info(_) => "info",
+            Command::Keys(_) => "keys",
+            Command::LInsert(_) => "linsert",
+            Command::LLen(_) => "llen",
+            Command::LMove(cmd) => cmd.get_name(),
+            Command::LPos(_) => "lpos",
+            Command::LRange(_) => "lrange",
+            Command::LRem(_) => "lrem",
+            Command::LSet(_) => "lset",
+            Command::LTrim(_) => "ltrim",
+            Command::MGet(_) => "mget",
+            Command::Monitor(_) => "monitor",
+            Command::MSet(_) => "mset",
+            Command::Multi(_) => "multi",
+            Command::Object(_) => "object",
+            Command::Persist(_) => "persist",
+            Command::Ping(_) => "ping",
+            Command::Pop(cmd) => cmd.get_name(),
+            Command::PSubscribe(_) => "psubscribe",
+            Command::Publish(_) => "publish",
+            Command::PubSub(_) => "pubsub",
+            Command::PUnsubscribe(_) => "punsubscribe",
+            Command::Push(cmd) => cmd.get_name(),
+            Command::Quit(_) => "quit",
+            Command::RandomKey(_) => "randomkey",
+            Command::Rename(cmd) => cmd.get_name(),
+            Command::Reset(_) => "reset",
+            Command::SAdd(_) => "sadd",
+            Command::Save(_) => "save",
+            Command::Scan(_) => "scan",
+            Command::SCard(_) => "scard",
+            Command::Select(_) => "select",
             Command::Set(_) => "set",
+            Command::SetAlgebra(cmd) => cmd.get_name(),
+            Command::SetAlgebraStore(cmd) => cmd.get_name(),
+            Command::SetBit(_) => "setbit",
+            Command::SetEx(_) => "setex",
+            Command::SetNx(_) => "setnx",
+            Command::SetRange(_) => "setrange",
+            Command::SIsMember(_) => "sismember",
+            Command::SlowLog(_) => "slowlog",
+            Command::SMembers(_) => "smembers",
+            Command::SPublish(_) => "spublish",
+            Command::SRandMember(_) => "srandmember",
+            Command::SRem(_) => "srem",
+            Command::SSubscribe(_) => "ssubscribe",
+            Command::Strlen(_) => "strlen",
             Command::Subscribe(_) => "subscribe",
+            Command::SUnsubscribe(_) => "sunsubscribe",
+            Command::Touch(_) => "touch",
+            Command::Ttl(cmd) => cmd.get_name(),
+            Command::Type(_) => "type",
+            Command::Unlink(_) => "unlink",
             Command::Unsubscribe(_) => "unsubscribe",
+            Command::Unwatch(_) => "unwatch",
+            Command::Wait(_) => "wait",
+            Command::Watch(_) => "watch",
+            Command::ZAdd(_) => "zadd",
+            Command::ZRange(_) => "zrange",
+            Command::ZRangeByScore(_) => "zrangebyscore",
+            Command::ZRank(_) => "zrank",
+            Command::ZScore(_) => "zscore",
             Command::Unknwon(cmd) => cmd.get_name(),
         }
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    #[test]
+    fn parse_inline_command() {
+        let mut src = Cursor::new(&b"GET foo\r\n"[..]);
+        Frame::check(&mut src, usize::MAX).unwrap();
+
+        src.set_position(0);
+        let frame = Frame::parse(&mut src).unwrap();
+
+        match Command::from_frame(frame).unwrap() {
+            Command::Get(cmd) => assert_eq!(cmd.key(), "foo"),
+            cmd => panic!("unexpected command: {:?}", cmd),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::cmd::{Leave, Unknown};
+use crate::{Command, Connection, Frame, Parse, Shutdown};
+
+use bytes::Bytes;
+use std::fmt::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::select;
+use tokio::sync::broadcast;
+use tracing::{debug, instrument};
+
+/// 커넥션을 모니터 상태로 전환한다.
+///
+/// 'OK'를 응답한 뒤, 서버가 수신하는 모든 커맨드를 한 줄씩 스트리밍한다. 각 줄은
+/// '1339518083.107412 [0 127.0.0.1:60866] "SET" "key" "value"'와 같이 커맨드를 수신한 시각과
+/// 데이터베이스, 클라이언트의 주소, 커맨드의 아규먼트로 이루어진다.
+///
+/// 모니터 상태의 커넥션은 'RESET'과 'QUIT' 외의 커맨드를 수행하지 못한다. 'AUTH'와 'HELLO'의
+/// 아규먼트는 비밀번호를 노출하지 않도록 가려진다.
+#[derive(Debug, Default)]
+pub struct Monitor {}
+
+impl Monitor {
+    /// 새로운 'Monitor' 커맨드를 생성한다.
+    pub fn new() -> Monitor {
+        Monitor {}
+    }
+
+    /// 수신한 프레임으로부터 'Monitor' 인스턴스를 파싱한다.
+    ///
+    /// 'MONITOR' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// MONITOR
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Monitor> {
+        Ok(Monitor {})
+    }
+
+    /// 커넥션을 모니터 상태로 전환하고, 'monitor' 채널로 발행되는 커맨드를 'dst'에 쓴다.
+    ///
+    /// 커넥션이 끊어지거나 서버가 셧다운될 때까지 반환하지 않는다. 'RESET'이나 'QUIT'을 수신하면
+    /// 구독 상태와 마찬가지로 이를 반환하여 핸들러가 커넥션의 나머지 상태를 다루도록 한다.
+    #[instrument(skip(self, monitor, dst, shutdown))]
+    pub(crate) async fn apply(
+        self,
+        monitor: &broadcast::Sender<String>,
+        dst: &mut Connection,
+        shutdown: &mut Shutdown,
+    ) -> crate::Result<Option<Leave>> {
+        //  응답하기 전에 채널을 구독하여, 'OK' 이후에 수신한 커맨드가 누락되지 않도록 한다.
+        let mut rx = monitor.subscribe();
+
+        let response = Frame::Simple("OK".to_string());
+        debug!(?response);
+        dst.write_frame(&response).await?;
+
+        loop {
+            select! {
+                res = rx.recv() => match res {
+                    Ok(line) => dst.write_frame(&Frame::Simple(line)).await?,
+                    //  소비가 지연되어 놓친 커맨드는 건너뛴다.
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => return Ok(None),
+                },
+                res = dst.read_frame() => {
+                    let frame = match res? {
+                        Some(frame) => frame,
+                        //  원격 클라이언트의 연결이 끊어지면 발생한다.
+                        None => return Ok(None),
+                    };
+
+                    match Command::from_frame(frame)? {
+                        //  응답은 커넥션의 상태를 초기화한 핸들러가 쓴다.
+                        Command::Reset(reset) => return Ok(Some(Leave::Reset(reset))),
+                        Command::Quit(quit) => {
+                            quit.apply(dst).await?;
+                            dst.flush().await?;
+
+                            return Ok(Some(Leave::Quit));
+                        }
+                        command => {
+                            Unknown::new(command.get_name()).apply(dst).await?;
+                            dst.flush().await?;
+                        }
+                    }
+                }
+                _ = shutdown.recv() => return Ok(None),
+            }
+        }
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Monitor'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("monitor".as_bytes()));
+        frame
+    }
+}
+
+/// 'db' 데이터베이스를 선택한 'addr' 클라이언트로부터 수신한 'frame'을 'MONITOR'가 스트리밍하는
+/// 한 줄로 변환한다.
+///
+/// 아규먼트는 큰따옴표로 감싸며, 출력할 수 없는 바이트는 레디스와 같이 이스케이프한다.
+pub(crate) fn monitor_line(frame: &Frame, db: usize, addr: &str) -> String {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let mut line = format!("{}.{:06} [{} {}]", now.as_secs(), now.subsec_micros(), db, addr);
+
+    let parts = match frame {
+        Frame::Array(parts) => &parts[..],
+        frame => std::slice::from_ref(frame),
+    };
+
+    //  'AUTH'와 'HELLO'는 비밀번호를 담을 수 있으므로 커맨드 이름만 남긴다.
+    let redacted = match parts.first() {
+        Some(Frame::Bulk(name)) => {
+            name.eq_ignore_ascii_case(b"auth") || name.eq_ignore_ascii_case(b"hello")
+        }
+        Some(Frame::Simple(name)) => {
+            name.eq_ignore_ascii_case("auth") || name.eq_ignore_ascii_case("hello")
+        }
+        _ => false,
+    };
+
+    for (i, part) in parts.iter().enumerate() {
+        line.push(' ');
+
+        if redacted && i > 0 {
+            line.push_str("\"(redacted)\"");
+            continue;
+        }
+
+        match part {
+            Frame::Bulk(bytes) => push_quoted(&mut line, bytes),
+            Frame::Simple(s) => push_quoted(&mut line, s.as_bytes()),
+            Frame::Integer(n) => push_quoted(&mut line, n.to_string().as_bytes()),
+            _ => push_quoted(&mut line, b""),
+        }
+    }
+
+    line
+}
+
+/// 'bytes'를 큰따옴표로 감싸 'dst'에 쓴다. 레디스의 'sdscatrepr'과 같은 방식으로 이스케이프한다.
+fn push_quoted(dst: &mut String, bytes: &[u8]) {
+    dst.push('"');
+
+    for &byte in bytes {
+        match byte {
+            b'\\' => dst.push_str("\\\\"),
+            b'"' => dst.push_str("\\\""),
+            b'\n' => dst.push_str("\\n"),
+            b'\r' => dst.push_str("\\r"),
+            b'\t' => dst.push_str("\\t"),
+            0x07 => dst.push_str("\\a"),
+            0x08 => dst.push_str("\\b"),
+            byte if byte.is_ascii_graphic() || byte == b' ' => dst.push(byte as char),
+            // 'String'에 쓰는 것은 실패하지 않는다.
+            byte => {
+                let _ = write!(dst, "\\x{:02x}", byte);
+            }
+        }
+    }
+
+    dst.push('"');
+}
@@ -0,0 +1,111 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 여러 키-값 쌍을 한 번에 저장한다.
+///
+/// 각 키에 이미 연결된 값이 있다면 값을 덮어쓴다. 'SET'과 마찬가지로 이전 값의 남은 만료
+/// 시간은 폐기되며, 저장된 키-값 쌍은 만료 시간을 가지지 않는다.
+#[derive(Debug)]
+pub struct MSet {
+    /// 저장할 키-값 쌍 목록
+    pairs: Vec<(String, Bytes)>,
+}
+
+impl MSet {
+    /// 'pairs'의 키-값 쌍을 저장하는 새로운 'MSet' 커맨드를 생성한다.
+    pub fn new(pairs: &[(impl ToString, Bytes)]) -> MSet {
+        MSet {
+            pairs: pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        }
+    }
+
+    /// 저장할 키-값 쌍 목록을 가져온다.
+    pub fn pairs(&self) -> &[(String, Bytes)] {
+        &self.pairs
+    }
+
+    /// 수신한 프레임으로부터 'MSet' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'MSET' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'MSet' 값을 반환한다. 프레임의 형태가 잘못되었거나, 키에 대응하는 값이
+    /// 없는 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 홀수 개 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// MSET key value [key value ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<MSet> {
+        use ParseError::EndOfStream;
+
+        // 최소 하나의 키-값 쌍이 필요하다.
+        let mut pairs = vec![(parse.next_string()?, parse.next_bytes()?)];
+
+        // 프레임의 나머지 값들은 키와 값이 번갈아 나타난다.
+        loop {
+            let key = match parse.next_string() {
+                Ok(key) => key,
+                // 'EndOfStream'은 더이상 파싱할 데이터가 남아있지 않음을 나타낸다.
+                Err(EndOfStream) => break,
+                // 이 외의 다른 모든 에러는 커넥션을 중단한다.
+                Err(err) => return Err(err.into()),
+            };
+
+            // 키 뒤에는 반드시 값이 와야 한다. 아규먼트의 수가 맞지 않으면 에러를 반환한다.
+            let value = match parse.next_bytes() {
+                Ok(value) => value,
+                Err(EndOfStream) => {
+                    return Err("ERR wrong number of arguments for 'mset' command".into())
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            pairs.push((key, value));
+        }
+
+        Ok(MSet { pairs })
+    }
+
+    /// 'MSet' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 하나의 락 안에서 모든 키-값 쌍을 저장한다.
+        db.mset(self.pairs);
+
+        // 성공 응답을 생성하여 'dst'에 쓴다.
+        let response = Frame::Simple("OK".to_string());
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'MSet'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("mset".as_bytes()));
+        for (key, value) in self.pairs {
+            frame.push_bulk(Bytes::from(key.into_bytes()));
+            frame.push_bulk(value);
+        }
+        frame
+    }
+}
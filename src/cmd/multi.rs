@@ -0,0 +1,292 @@
+use crate::cmd::WatchedKeys;
+use crate::{Command, Connection, Frame, MiniRedisError, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 트랜잭션을 시작한다.
+///
+/// 이후 'EXEC' 혹은 'DISCARD'를 수신할 때까지 커넥션이 수신한 커맨드는 수행되지 않고 큐에 쌓이며,
+/// 각 커맨드에는 'QUEUED'를 응답한다. 트랜잭션은 중첩할 수 없다.
+#[derive(Debug, Default)]
+pub struct Multi {}
+
+/// 'MULTI' 이후 큐에 쌓인 커맨드를 순서대로 수행한다.
+///
+/// 각 커맨드의 응답을 담은 배열을 응답한다. 큐에 쌓는 도중 잘못된 커맨드를 수신했다면 어떤
+/// 커맨드도 수행하지 않고 'EXECABORT' 에러를 응답한다. 'WATCH'로 감시하는 키가 변경되었다면 어떤
+/// 커맨드도 수행하지 않고 nil을 응답한다.
+///
+/// 레디스와 달리, 다른 커넥션의 커맨드가 트랜잭션의 커맨드 사이에 수행될 수 있다.
+#[derive(Debug, Default)]
+pub struct Exec {}
+
+/// 'MULTI' 이후 큐에 쌓인 커맨드를 수행하지 않고 버리고, 트랜잭션을 종료한다.
+#[derive(Debug, Default)]
+pub struct Discard {}
+
+/// 'MULTI' 이후 'EXEC'까지 큐에 쌓인 커맨드
+///
+/// 트랜잭션은 커넥션별 상태이므로 핸들러가 보관한다.
+#[derive(Debug, Default)]
+pub(crate) struct Transaction {
+    /// 수신한 순서대로 쌓인 커맨드
+    commands: Vec<Command>,
+
+    /// 큐에 쌓는 도중 잘못된 커맨드를 수신했는지 여부. 'true'라면 'EXEC'는 트랜잭션을 중단한다.
+    aborted: bool,
+}
+
+impl Multi {
+    /// 새로운 'Multi' 커맨드를 생성한다.
+    pub fn new() -> Multi {
+        Multi {}
+    }
+
+    /// 수신한 프레임으로부터 'Multi' 인스턴스를 파싱한다.
+    ///
+    /// 'MULTI' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// MULTI
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Multi> {
+        Ok(Multi {})
+    }
+
+    /// 'Multi' 커맨드를 수행한다.
+    ///
+    /// 트랜잭션은 커넥션별 상태이므로, 'Command::apply'를 거치지 않고 핸들러가 직접 이 함수를
+    /// 호출한다. 'transaction'은 커넥션의 트랜잭션 상태이다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, transaction, dst))]
+    pub(crate) async fn apply(
+        self,
+        transaction: &mut Option<Transaction>,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match transaction {
+            Some(_) => Frame::Error("ERR MULTI calls can not be nested".to_string()),
+            None => {
+                *transaction = Some(Transaction::default());
+                Frame::Simple("OK".to_string())
+            }
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Multi'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("multi".as_bytes()));
+        frame
+    }
+}
+
+impl Exec {
+    /// 새로운 'Exec' 커맨드를 생성한다.
+    pub fn new() -> Exec {
+        Exec {}
+    }
+
+    /// 수신한 프레임으로부터 'Exec' 인스턴스를 파싱한다.
+    ///
+    /// 'EXEC' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// EXEC
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Exec> {
+        Ok(Exec {})
+    }
+
+    /// 'Exec' 커맨드를 수행하고, 수행할 커맨드를 반환한다.
+    ///
+    /// 큐에 쌓인 커맨드는 핸들러를 통해 수행되어야 하므로, 이 함수는 커맨드를 직접 수행하지 않는다.
+    /// 대신 응답 배열의 헤더만을 'dst'에 쓰고 커맨드를 반환한다. 핸들러는 반환된 커맨드를 순서대로
+    /// 수행하여 각 응답을 배열의 앤트리로 잇는다. 따라서 각 커맨드는 정확히 하나의 응답 프레임을
+    /// 써야 한다.
+    ///
+    /// 'transaction'은 커넥션의 트랜잭션 상태이며, 이 호출로 트랜잭션은 종료된다. 트랜잭션이
+    /// 시작되지 않았거나 중단되었다면 에러를 응답하고 빈 목록을 반환한다. 'watched'의 키가
+    /// 변경되었다면 nil을 응답하고 빈 목록을 반환한다. 트랜잭션이 종료되면 키의 감시도 해제된다.
+    #[instrument(skip(self, transaction, watched, dst))]
+    pub(crate) async fn apply(
+        self,
+        transaction: Option<Transaction>,
+        watched: &mut WatchedKeys,
+        dst: &mut Connection,
+    ) -> crate::Result<Vec<Command>> {
+        //  트랜잭션 밖의 'EXEC'는 키의 감시를 해제하지 않는다.
+        let modified = transaction.is_some() && watched.is_modified();
+        if transaction.is_some() {
+            watched.clear();
+        }
+
+        let response = match transaction {
+            Some(transaction) if transaction.aborted => Frame::Error(
+                "EXECABORT Transaction discarded because of previous errors.".to_string(),
+            ),
+            //  감시하는 키가 변경되었다.
+            Some(_) if modified => Frame::Null,
+            Some(transaction) => {
+                debug!(queued = transaction.commands.len());
+                dst.write_array_header(transaction.commands.len()).await?;
+
+                return Ok(transaction.commands);
+            }
+            None => Frame::Error("ERR EXEC without MULTI".to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(vec![])
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Exec'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("exec".as_bytes()));
+        frame
+    }
+}
+
+impl Discard {
+    /// 새로운 'Discard' 커맨드를 생성한다.
+    pub fn new() -> Discard {
+        Discard {}
+    }
+
+    /// 수신한 프레임으로부터 'Discard' 인스턴스를 파싱한다.
+    ///
+    /// 'DISCARD' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// DISCARD
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Discard> {
+        Ok(Discard {})
+    }
+
+    /// 'Discard' 커맨드를 수행한다.
+    ///
+    /// 'MULTI'와 마찬가지로 핸들러가 직접 이 함수를 호출한다. 트랜잭션이 시작되지 않았다면 에러를
+    /// 응답한다. 트랜잭션을 종료하면 'watched'의 키의 감시도 해제된다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, transaction, watched, dst))]
+    pub(crate) async fn apply(
+        self,
+        transaction: &mut Option<Transaction>,
+        watched: &mut WatchedKeys,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match transaction.take() {
+            Some(_) => {
+                watched.clear();
+                Frame::Simple("OK".to_string())
+            }
+            None => Frame::Error("ERR DISCARD without MULTI".to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Discard'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("discard".as_bytes()));
+        frame
+    }
+}
+
+impl Transaction {
+    /// 'cmd'를 큐에 쌓고 'QUEUED'를 응답한다.
+    ///
+    /// 'EXEC'의 응답 배열에 여러 프레임을 쓰는 구독 커맨드와 'WATCH'는 트랜잭션 안에서 허용하지 않는다.
+    /// 레디스와 같이, 블로킹 커맨드는 기다리지 않는 커맨드로 바꾸어 쌓는다. 지원하지 않거나 허용하지
+    /// 않는 커맨드는 에러를 응답하고 트랜잭션을 중단시킨다.
+    pub(crate) async fn queue(&mut self, cmd: Command, dst: &mut Connection) -> crate::Result<()> {
+        let cmd = match cmd {
+            Command::Subscribe(_)
+            | Command::PSubscribe(_)
+            | Command::Unsubscribe(_)
+            | Command::PUnsubscribe(_)
+            | Command::SSubscribe(_)
+            | Command::SUnsubscribe(_)
+            | Command::Monitor(_) => {
+                let msg =
+                    format!("ERR Command '{}' not allowed inside a transaction", cmd.get_name());
+                return self.abort(MiniRedisError::Server(msg), dst).await;
+            }
+            Command::Watch(_) => {
+                let msg = "ERR WATCH inside MULTI is not allowed".to_string();
+                return self.abort(MiniRedisError::Server(msg), dst).await;
+            }
+            Command::Unknwon(cmd) => {
+                self.aborted = true;
+                return cmd.apply(dst).await;
+            }
+            Command::BPop(cmd) => Command::BPop(cmd.non_blocking()),
+            cmd => cmd,
+        };
+
+        self.commands.push(cmd);
+
+        let response = Frame::Simple("QUEUED".to_string());
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 'err'를 응답하고 트랜잭션을 중단시킨다.
+    ///
+    /// 커맨드를 파싱하지 못했거나 허용하지 않는 커맨드를 수신했을 때 호출한다. 트랜잭션 밖에서와
+    /// 달리 커넥션을 종료하지 않으며, 트랜잭션은 이어지는 'EXEC'가 종료한다.
+    pub(crate) async fn abort(
+        &mut self,
+        err: MiniRedisError,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        self.aborted = true;
+
+        let response = match err {
+            MiniRedisError::Server(msg) => Frame::Error(msg),
+            err => Frame::Error(format!("ERR {}", err)),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+}
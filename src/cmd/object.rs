@@ -0,0 +1,90 @@
+use crate::db::Value;
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 값의 내부 상태를 확인한다.
+///
+/// 다음의 서브커맨드를 지원한다:
+///
+/// * ENCODING 'key' -- 값의 인코딩 이름을 응답한다. 'raw', 'int', 'listpack', 'hashtable' 등이
+///   있다.
+/// * IDLETIME 'key' -- 키에 마지막으로 접근한 뒤 지난 시간(초)을 응답한다.
+///
+/// 키가 존재하지 않으면 에러를 응답한다. 'OBJECT'로 확인하는 것은 키에 접근한 것으로 보지 않는다.
+#[derive(Debug)]
+pub enum Object {
+    /// 'OBJECT ENCODING key'
+    Encoding(String),
+
+    /// 'OBJECT IDLETIME key'
+    IdleTime(String),
+}
+
+impl Object {
+    /// 수신한 프레임으로부터 'Object' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'OBJECT' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Object' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// OBJECT ENCODING key
+    /// OBJECT IDLETIME key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Object> {
+        let subcommand = parse.next_string()?.to_lowercase();
+
+        match &subcommand[..] {
+            "encoding" => Ok(Object::Encoding(parse.next_string()?)),
+            "idletime" => Ok(Object::IdleTime(parse.next_string()?)),
+            _ => Err(format!("ERR unknown subcommand '{}' for 'object'", subcommand).into()),
+        }
+    }
+
+    /// 'Object' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match self {
+            Object::Encoding(key) => db
+                .get_raw(&key, Value::encoding)
+                .map(|encoding| Frame::Bulk(Bytes::from(encoding.as_bytes()))),
+            Object::IdleTime(key) => db
+                .idle_time(&key)
+                .map(|idle| Frame::Integer(idle.as_secs() as i64)),
+        };
+
+        let response = response.unwrap_or_else(|| Frame::Error("ERR no such key".to_string()));
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Object'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("object".as_bytes()));
+        let (subcommand, key) = match self {
+            Object::Encoding(key) => ("encoding", key),
+            Object::IdleTime(key) => ("idletime", key),
+        };
+        frame.push_bulk(Bytes::from(subcommand.as_bytes()));
+        frame.push_bulk(Bytes::from(key.into_bytes()));
+        frame
+    }
+}
@@ -0,0 +1,84 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 아규먼트가 주어지지 않으면 PONG을, 주어지면 아규먼트를 그대로 응답한다.
+///
+/// 이 커맨드는 주로 커넥션이 살아있는지 확인하거나 지연 시간을 측정하기 위해 사용한다.
+/// 구독 모드의 커넥션에서도 사용할 수 있다.
+#[derive(Debug, Default)]
+pub struct Ping {
+    /// 응답으로 돌려받을 메시지
+    msg: Option<Bytes>,
+}
+
+impl Ping {
+    /// 'msg'를 담은 새로운 'Ping' 커맨드를 생성한다.
+    pub fn new(msg: Option<Bytes>) -> Ping {
+        Ping { msg }
+    }
+
+    /// 메시지를 가져온다.
+    pub fn msg(&self) -> Option<&Bytes> {
+        self.msg.as_ref()
+    }
+
+    /// 수신한 프레임으로부터 'Ping' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'PING' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Ping' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 'PING'과 선택적인 메시지를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// PING [message]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Ping> {
+        use ParseError::EndOfStream;
+
+        match parse.next_bytes() {
+            Ok(msg) => Ok(Ping::new(Some(msg))),
+            // 'EndOfStream'은 메시지가 주어지지 않았음을 나타낸다.
+            Err(EndOfStream) => Ok(Ping::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// 'Ping' 커맨드를 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, dst))]
+    pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
+        let response = match self.msg {
+            None => Frame::Simple("PONG".to_string()),
+            Some(msg) => Frame::Bulk(msg),
+        };
+
+        debug!(?response);
+
+        dst.write_frame_buffered(&response).await?;
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Ping'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("ping".as_bytes()));
+        if let Some(msg) = self.msg {
+            frame.push_bulk(msg);
+        }
+        frame
+    }
+}
@@ -70,10 +70,10 @@ impl Publish {
         let num_subscribers = db.publish(&self.channel, self.message);
 
         // 구독자 수를 반환한다.
-        let response = Frame::Integer(num_subscribers as u64);
+        let response = Frame::Integer(num_subscribers as i64);
 
         // 클라이이언트에 프레임을 쓴다.
-        dst.write_frame(&response).await?;
+        dst.write_frame_buffered(&response).await?;
 
         Ok(())
     }
@@ -89,4 +89,84 @@ impl Publish {
 
         frame
     }
-}
\ No newline at end of file
+}
+/// 주어진 샤드 채널에 메시지를 전송한다.
+/// 
+/// 'SSUBSCRIBE'로 샤드 채널을 구독한 구독자만이 메시지를 수신한다. 같은 이름의 일반 채널을
+/// 구독하거나 매칭되는 패턴을 구독한 구독자는 메시지를 수신하지 않는다.
+#[derive(Debug)]
+pub struct SPublish {
+    /// 메시지가 전송되는 샤드 채널의 이름
+    channel: String,
+
+    /// 전송되는 메시지
+    message: Bytes,
+}
+
+impl SPublish {
+    /// 'channel'에 'message'를 전송하는 새로운 'SPublish'를 생성한다.
+    pub(crate) fn new(channel: impl ToString, message: Bytes) -> SPublish {
+        SPublish {
+            channel: channel.to_string(),
+            message,
+        }
+    }
+
+    /// 수신한 프레임으로부터 'SPublish' 인스턴스를 파싱한다.
+    /// 
+    /// 'SPUBLISH' 문자열은 이미 소비되었다.
+    /// 
+    /// # Format
+    /// 
+    /// ```text
+    /// SPUBLISH shardchannel message
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SPublish> {
+        let channel = parse.next_string()?;
+        let message = parse.next_bytes()?;
+
+        Ok(SPublish { channel, message })
+    }
+
+    /// 'SPublish' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    /// 
+    /// 'PUBLISH'와 마찬가지로 샤드 채널의 구독자 수를 응답한다. 응답은 'dst'에 쓰여진다.
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let num_subscribers = db.spublish(&self.channel, self.message);
+
+        let response = Frame::Integer(num_subscribers as i64);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    /// 
+    /// 이 함수는 'SPublish'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("spublish".as_bytes()));
+        frame.push_bulk(Bytes::from(self.channel.into_bytes()));
+        frame.push_bulk(self.message);
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let frame = Publish::new("news", Bytes::from("hello")).into_frame();
+
+        let mut parse = Parse::new(frame).unwrap();
+        assert_eq!(parse.next_string().unwrap(), "publish");
+        let publish = Publish::parse_frames(&mut parse).unwrap();
+        parse.finish().unwrap();
+
+        assert_eq!(publish.channel, "news");
+        assert_eq!(publish.message, "hello");
+    }
+}
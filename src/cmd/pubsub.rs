@@ -0,0 +1,139 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{glob, Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// pub/sub 시스템의 상태를 조회한다.
+///
+/// 모니터링 도구를 위한 커맨드로, 다음의 서브커맨드를 지원한다:
+///
+/// * CHANNELS ['pattern'] -- 구독자가 있는 채널 목록. 패턴이 주어지면 매칭되는 채널만 포함한다.
+/// * NUMSUB ['channel' ...] -- 각 채널의 구독자 수
+/// * NUMPAT -- 구독자가 있는 패턴의 수
+#[derive(Debug)]
+pub enum PubSub {
+    /// 'PUBSUB CHANNELS [pattern]'
+    Channels(Option<String>),
+
+    /// 'PUBSUB NUMSUB [channel ...]'
+    NumSub(Vec<String>),
+
+    /// 'PUBSUB NUMPAT'
+    NumPat,
+}
+
+impl PubSub {
+    /// 수신한 프레임으로부터 'PubSub' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'PUBSUB' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'PubSub' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// PUBSUB CHANNELS [pattern]
+    /// PUBSUB NUMSUB [channel ...]
+    /// PUBSUB NUMPAT
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<PubSub> {
+        use ParseError::EndOfStream;
+
+        let subcommand = parse.next_string()?.to_lowercase();
+
+        match &subcommand[..] {
+            "channels" => match parse.next_string() {
+                Ok(pattern) => Ok(PubSub::Channels(Some(pattern))),
+                Err(EndOfStream) => Ok(PubSub::Channels(None)),
+                Err(err) => Err(err.into()),
+            },
+            "numsub" => {
+                // 채널 목록은 비어있을 수 있다.
+                let mut channels = vec![];
+
+                loop {
+                    match parse.next_string() {
+                        Ok(channel) => channels.push(channel),
+                        Err(EndOfStream) => break,
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+
+                Ok(PubSub::NumSub(channels))
+            }
+            "numpat" => Ok(PubSub::NumPat),
+            _ => Err(format!("ERR unknown subcommand '{}' for 'pubsub'", subcommand).into()),
+        }
+    }
+
+    /// 'PubSub' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match self {
+            PubSub::Channels(pattern) => {
+                // 구독자가 있는 채널 중, 패턴이 주어졌다면 매칭되는 채널만 응답한다.
+                let mut response = Frame::array();
+                for channel in db.active_channels() {
+                    let matched = pattern
+                        .as_ref()
+                        .map(|pattern| glob::matches(pattern.as_bytes(), channel.as_bytes()))
+                        .unwrap_or(true);
+
+                    if matched {
+                        response.push_bulk(Bytes::from(channel.into_bytes()));
+                    }
+                }
+                response
+            }
+            PubSub::NumSub(channels) => {
+                // 채널 이름과 구독자 수를 번갈아 담은 배열을 응답한다.
+                let mut response = Frame::array();
+                for channel in channels {
+                    let num_subs = db.subscriber_count(&channel);
+                    response.push_bulk(Bytes::from(channel.into_bytes()));
+                    response.push_int(num_subs as i64);
+                }
+                response
+            }
+            PubSub::NumPat => Frame::Integer(db.pattern_count() as i64),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'PubSub'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("pubsub".as_bytes()));
+        match self {
+            PubSub::Channels(pattern) => {
+                frame.push_bulk(Bytes::from("channels".as_bytes()));
+                if let Some(pattern) = pattern {
+                    frame.push_bulk(Bytes::from(pattern.into_bytes()));
+                }
+            }
+            PubSub::NumSub(channels) => {
+                frame.push_bulk(Bytes::from("numsub".as_bytes()));
+                for channel in channels {
+                    frame.push_bulk(Bytes::from(channel.into_bytes()));
+                }
+            }
+            PubSub::NumPat => frame.push_bulk(Bytes::from("numpat".as_bytes())),
+        }
+        frame
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{Connection, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 서버에게 커넥션을 닫도록 요청한다.
+///
+/// 'OK'를 응답한 뒤 커넥션을 닫는다. 파이프라인으로 'QUIT' 뒤에 이어서 전송된 커맨드는 수행되지
+/// 않는다. 인증되지 않은 커넥션도 수행할 수 있으며, 트랜잭션 중에도 큐에 쌓이지 않고 바로 수행된다.
+/// 구독 상태의 커넥션에서도 사용할 수 있다.
+#[derive(Debug, Default)]
+pub struct Quit {}
+
+impl Quit {
+    /// 새로운 'Quit' 커맨드를 생성한다.
+    pub fn new() -> Quit {
+        Quit {}
+    }
+
+    /// 수신한 프레임으로부터 'Quit' 인스턴스를 파싱한다.
+    ///
+    /// 'QUIT' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// QUIT
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Quit> {
+        Ok(Quit {})
+    }
+
+    /// 'Quit' 커맨드에 응답한다.
+    ///
+    /// 커넥션을 닫는 것은 핸들러의 몫이다. 핸들러는 이 함수를 호출한 뒤 응답을 소켓에 쓰고 커넥션을
+    /// 닫는다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, dst))]
+    pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
+        let response = Frame::Simple("OK".to_string());
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Quit'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("quit".as_bytes()));
+        frame
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 데이터베이스에 저장된 키 중 하나를 무작위로 반환한다.
+///
+/// 모든 키는 같은 확률로 선택되며, 만료된 키는 포함하지 않는다. 데이터베이스가 비어 있다면 'Null'을
+/// 응답한다.
+#[derive(Debug, Default)]
+pub struct RandomKey {}
+
+impl RandomKey {
+    /// 새로운 'RandomKey' 커맨드를 생성한다.
+    pub fn new() -> RandomKey {
+        RandomKey {}
+    }
+
+    /// 수신한 프레임으로부터 'RandomKey' 인스턴스를 파싱한다.
+    ///
+    /// 'RANDOMKEY' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// RANDOMKEY
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<RandomKey> {
+        Ok(RandomKey {})
+    }
+
+    /// 'RandomKey' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.random_key() {
+            Some(key) => Frame::Bulk(Bytes::from(key.into_bytes())),
+            None => Frame::Null,
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'RandomKey'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("randomkey".as_bytes()));
+        frame
+    }
+}
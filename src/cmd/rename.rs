@@ -0,0 +1,104 @@
+use crate::db::RenameResult;
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키의 이름을 변경한다.
+///
+/// 값과 남은 만료 시간은 그대로 유지된다. 'RENAME'은 대상 키에 이미 값이 있다면 덮어쓰고 'OK'를
+/// 응답한다. 'RENAMENX'는 대상 키가 존재하지 않을 때만 변경하며, 변경했다면 1을, 그렇지 않다면 0을
+/// 응답한다. 두 커맨드는 모두 'Rename'으로 파싱된다.
+///
+/// 원본 키가 존재하지 않으면 에러를 응답한다.
+#[derive(Debug)]
+pub struct Rename {
+    /// 이름을 변경할 키
+    key: String,
+
+    /// 새로운 이름
+    newkey: String,
+
+    /// 'true'이면 대상 키가 존재하지 않을 때만 변경한다 ('RENAMENX').
+    nx: bool,
+}
+
+impl Rename {
+    /// 'key'의 이름을 'newkey'로 변경하는 새로운 'Rename' 커맨드를 생성한다.
+    ///
+    /// 'nx'가 'true'이면 'newkey'가 존재하지 않을 때만 변경한다.
+    pub fn new(key: impl ToString, newkey: impl ToString, nx: bool) -> Rename {
+        Rename {
+            key: key.to_string(),
+            newkey: newkey.to_string(),
+            nx,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 새로운 이름을 가져온다.
+    pub fn newkey(&self) -> &str {
+        &self.newkey
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.nx {
+            "renamenx"
+        } else {
+            "rename"
+        }
+    }
+
+    /// 수신한 프레임으로부터 'Rename' 인스턴스를 파싱한다.
+    ///
+    /// 'RENAME' 혹은 'RENAMENX' 문자열은 이미 소비되었다. 'nx'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// RENAME key newkey
+    /// RENAMENX key newkey
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, nx: bool) -> crate::Result<Rename> {
+        let key = parse.next_string()?;
+        let newkey = parse.next_string()?;
+
+        Ok(Rename { key, newkey, nx })
+    }
+
+    /// 'Rename' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.rename(&self.key, &self.newkey, self.nx) {
+            RenameResult::Renamed if self.nx => Frame::Integer(1),
+            RenameResult::Renamed => Frame::Simple("OK".to_string()),
+            RenameResult::DestinationExists => Frame::Integer(0),
+            RenameResult::NoSuchKey => Frame::Error("ERR no such key".to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Rename'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(Bytes::from(self.newkey.into_bytes()));
+        frame
+    }
+}
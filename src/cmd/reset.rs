@@ -0,0 +1,62 @@
+use crate::{Connection, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 커넥션을 처음 수립되었을 때의 상태로 되돌린다.
+///
+/// 구독 상태를 벗어나고, 'MULTI'로 시작한 트랜잭션을 버리고, 'WATCH'로 감시하는 키를 해제한다.
+/// 서버에 비밀번호가 설정되어 있다면 인증 상태도 해제되며, 선택한 데이터베이스는 0번으로,
+/// 프로토콜은 RESP2로 돌아간다. 'RESET'을 응답한다.
+///
+/// 레디스와 같이 인증되지 않은 커넥션도 수행할 수 있으며, 트랜잭션 중에도 큐에 쌓이지 않고 바로
+/// 수행된다.
+#[derive(Debug, Default)]
+pub struct Reset {}
+
+impl Reset {
+    /// 새로운 'Reset' 커맨드를 생성한다.
+    pub fn new() -> Reset {
+        Reset {}
+    }
+
+    /// 수신한 프레임으로부터 'Reset' 인스턴스를 파싱한다.
+    ///
+    /// 'RESET' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// RESET
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Reset> {
+        Ok(Reset {})
+    }
+
+    /// 'Reset' 커맨드에 응답한다.
+    ///
+    /// 초기화할 상태는 모두 커넥션별 상태이므로, 핸들러가 상태를 초기화한 뒤에 이 함수를 호출한다.
+    /// 구독 상태에서 수신한 'RESET'은 구독 루프가 핸들러에게 돌려준다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, dst))]
+    pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
+        let response = Frame::Simple("RESET".to_string());
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Reset'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("reset".as_bytes()));
+        frame
+    }
+}
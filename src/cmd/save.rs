@@ -0,0 +1,79 @@
+use crate::snapshot::Persistence;
+use crate::{Connection, Db, Frame, MiniRedisError, Parse};
+
+use bytes::Bytes;
+use std::sync::Arc;
+use tracing::{debug, error, instrument};
+
+/// 모든 데이터베이스의 키 공간을 스냅샷 파일에 저장한다.
+///
+/// 스냅샷 파일의 경로는 'server::Config::dbfilename'으로 설정한다. 저장이 끝날 때까지 응답하지 않으며,
+/// 서버는 가동 시 이 파일로부터 키 공간을 복원한다. 파일의 형식은 'snapshot' 모듈을 본다.
+///
+/// 'BGSAVE'로 시작한 저장이 진행 중이라면 에러를 응답한다.
+#[derive(Debug, Default)]
+pub struct Save {}
+
+impl Save {
+    /// 새로운 'Save' 커맨드를 생성한다.
+    pub fn new() -> Save {
+        Save {}
+    }
+
+    /// 수신한 프레임으로부터 'Save' 인스턴스를 파싱한다.
+    ///
+    /// 'SAVE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SAVE
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Save> {
+        Ok(Save {})
+    }
+
+    /// 'Save' 커맨드를 수행한다.
+    ///
+    /// 'SAVE'는 서버에 설정된 스냅샷 경로를 필요로 하므로, 'Command::apply'를 거치지 않고 핸들러가 직접
+    /// 이 함수를 호출한다. 경로가 설정되지 않았거나, 다른 저장이 진행 중이거나, 저장에 실패하면 에러를
+    /// 응답한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, db, persistence, dst))]
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        persistence: Option<&Arc<Persistence>>,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match persistence {
+            Some(persistence) => match persistence.save(db).await {
+                Ok(()) => Frame::Simple("OK".to_string()),
+                // 다른 저장이 진행 중이다.
+                Err(MiniRedisError::Server(msg)) => Frame::Error(msg),
+                Err(err) => {
+                    error!(cause = %err, "failed to save snapshot");
+                    Frame::Error(format!("ERR {}", err))
+                }
+            },
+            None => Frame::Error("ERR no snapshot file configured".to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Save'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("save".as_bytes()));
+        frame
+    }
+}
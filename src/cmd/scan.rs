@@ -0,0 +1,138 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 'COUNT'가 지정되지 않았을 때 한 번에 확인하는 키의 수. 레디스와 같다.
+const DEFAULT_COUNT: u64 = 10;
+
+/// 커서를 사용해 키 공간을 조금씩 순회한다.
+///
+/// 'KEYS'와 달리 한 번의 호출에서 일부의 키만을 확인하므로, 키가 많은 서버에서도 다른 커맨드의 처리를
+/// 오래 지연시키지 않는다. 클라이언트는 커서 0으로 순회를 시작하고, 응답받은 커서로 다음 호출을 이어간다.
+/// 응답받은 커서가 0이면 순회가 끝난 것이다.
+///
+/// 응답은 '[cursor, [key ...]]' 형태의 배열이다. 순회 중에 추가되거나 삭제된 키는 반환되지 않을 수
+/// 있지만, 순회 내내 존재한 키는 반드시 반환된다.
+#[derive(Debug)]
+pub struct Scan {
+    /// 순회를 이어갈 커서
+    cursor: u64,
+
+    /// 반환할 키의 glob 패턴
+    pattern: Option<String>,
+
+    /// 한 번에 확인할 키의 수
+    count: Option<u64>,
+}
+
+impl Scan {
+    /// 'cursor'부터 키 공간을 순회하는 'Scan' 커맨드를 생성한다.
+    pub fn new(cursor: u64, pattern: Option<String>, count: Option<u64>) -> Scan {
+        Scan {
+            cursor,
+            pattern,
+            count,
+        }
+    }
+
+    /// 커서를 가져온다.
+    pub fn cursor(&self) -> u64 {
+        self.cursor
+    }
+
+    /// 수신한 프레임으로부터 'Scan' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SCAN' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Scan' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SCAN cursor [MATCH pattern] [COUNT count]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Scan> {
+        use ParseError::EndOfStream;
+
+        let cursor = parse.next_int()?;
+
+        let mut pattern = None;
+        let mut count = None;
+
+        // 옵션은 순서에 상관없이 지정될 수 있다.
+        loop {
+            let option = match parse.next_string() {
+                Ok(s) => s.to_uppercase(),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            };
+
+            match &option[..] {
+                "MATCH" if pattern.is_none() => pattern = Some(parse.next_string()?),
+                "COUNT" if count.is_none() => match parse.next_int()? {
+                    // 레디스와 마찬가지로 0은 허용하지 않는다.
+                    0 => return Err("ERR syntax error".into()),
+                    n => count = Some(n),
+                },
+                _ => return Err("ERR syntax error".into()),
+            }
+        }
+
+        Ok(Scan {
+            cursor,
+            pattern,
+            count,
+        })
+    }
+
+    /// 'Scan' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let count = self.count.unwrap_or(DEFAULT_COUNT) as usize;
+        let (cursor, keys) = db.scan(self.cursor, count, self.pattern.as_deref());
+
+        // 레디스와 마찬가지로 커서는 정수가 아닌 bulk 문자열로 응답한다.
+        let response = Frame::Array(vec![
+            Frame::Bulk(Bytes::from(cursor.to_string())),
+            Frame::Array(
+                keys.into_iter()
+                    .map(|key| Frame::Bulk(Bytes::from(key)))
+                    .collect(),
+            ),
+        ]);
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Scan'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("scan".as_bytes()));
+        frame.push_bulk(Bytes::from(self.cursor.to_string()));
+        if let Some(pattern) = self.pattern {
+            frame.push_bulk(Bytes::from("match".as_bytes()));
+            frame.push_bulk(Bytes::from(pattern.into_bytes()));
+        }
+        if let Some(count) = self.count {
+            frame.push_bulk(Bytes::from("count".as_bytes()));
+            frame.push_bulk(Bytes::from(count.to_string()));
+        }
+        frame
+    }
+}
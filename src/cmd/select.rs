@@ -0,0 +1,84 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use std::convert::TryFrom;
+use tracing::{debug, instrument};
+
+/// 커넥션이 사용할 논리 데이터베이스를 선택한다.
+///
+/// 이후 이 커넥션의 커맨드는 선택된 데이터베이스의 키 공간에 수행된다. 데이터베이스의 번호는 0부터
+/// 시작하며, 서버에 설정된 데이터베이스 수보다 작아야 한다. 새 커넥션은 0번 데이터베이스를 사용한다.
+#[derive(Debug)]
+pub struct Select {
+    /// 선택할 데이터베이스의 번호. 범위를 벗어난 번호는 에러로 응답하기 위해 음수도 그대로 파싱한다.
+    index: i64,
+}
+
+impl Select {
+    /// 'index'번 데이터베이스를 선택하는 'Select' 커맨드를 생성한다.
+    pub fn new(index: i64) -> Select {
+        Select { index }
+    }
+
+    /// 선택할 데이터베이스의 번호를 가져온다.
+    pub fn index(&self) -> i64 {
+        self.index
+    }
+
+    /// 수신한 프레임으로부터 'Select' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SELECT' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Select' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SELECT index
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Select> {
+        let index = parse.next_signed_int()?;
+
+        Ok(Select { index })
+    }
+
+    /// 'Select' 커맨드를 수행한다.
+    ///
+    /// 'db'를 선택한 데이터베이스의 핸들로 교체한다. 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &mut Db, dst: &mut Connection) -> crate::Result<()> {
+        let selected = usize::try_from(self.index)
+            .ok()
+            .and_then(|index| db.select(index));
+
+        let response = match selected {
+            Some(selected) => {
+                *db = selected;
+                Frame::Simple("OK".to_string())
+            }
+            None => Frame::Error("ERR DB index is out of range".to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Select'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("select".as_bytes()));
+        frame.push_bulk(Bytes::from(self.index.to_string()));
+        frame
+    }
+}
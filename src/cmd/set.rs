@@ -1,4 +1,5 @@
 use crate::cmd::{Parse, ParseError};
+use crate::db::Condition;
 use crate::{Connection, Db, Frame};
 
 use bytes::Bytes;
@@ -15,6 +16,10 @@ use tracing::{debug, instrument};
 /// 
 /// * EX 'seconds' -- 특정 만료 시간을 초 단위로 지정한다.
 /// * PX 'milliseconds' -- 특정 만료 시간을 밀리초 단위로 지정한다.
+/// * NX -- 키가 존재하지 않을 때만 값을 저장한다.
+/// * XX -- 키가 이미 존재할 때만 값을 저장한다.
+/// 
+/// NX 혹은 XX 옵션의 조건을 만족하지 못해 값이 저장되지 않으면 nil로 응답한다.
 #[derive(Debug)]
 pub struct Set {
     /// 검색할 키
@@ -25,6 +30,9 @@ pub struct Set {
 
     /// 만료 시간
     expire: Option<Duration>,
+
+    /// 쓰기 조건
+    condition: Condition,
 }
 
 impl Set {
@@ -36,6 +44,7 @@ impl Set {
             key: key.to_string(),
             value,
             expire,
+            condition: Condition::Always,
         }
     }
 
@@ -70,7 +79,7 @@ impl Set {
     /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
     /// 
     /// ```text
-    /// SET key value [EX seconds|PX milliseconds]
+    /// SET key value [EX seconds|PX milliseconds] [NX|XX]
     /// ```
     pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Set> {
         use ParseError::EndOfStream;
@@ -84,42 +93,63 @@ impl Set {
         // 만료 지정은 선택적이다. 뒤에 아무것도 없다면 'None'이 된다.
         let mut expire = None;
 
-        // 다음 문자열 파싱을 시도한다.
-        match parse.next_string() {
-            Ok(s) if s.to_uppercase() == "EX" => {
-                // 만료 시간이 초로 지정된 경우. 다음 값은 integer가 된다.
-                let secs = parse.next_int()?;
-                expire = Some(Duration::from_secs(secs));
-            }
-            Ok(s) if s.to_uppercase() == "PX" => {
-                // 만료 시간이 ms로 지정된 경우. 다음 값은 integer가 된다.
-                let ms = parse.next_int()?;
-                expire = Some(Duration::from_millis(ms));
+        // 쓰기 조건도 선택적이다. 지정되지 않으면 항상 값을 저장한다.
+        let mut condition = Condition::Always;
+
+        // 옵션은 순서에 상관없이 지정될 수 있다. 남은 문자열을 모두 옵션으로 파싱한다.
+        loop {
+            let option = match parse.next_string() {
+                Ok(s) => s.to_uppercase(),
+                // 'EndOfStream'에러는 앞으로 파싱을 위한 데이터가 존재하지 않음을 나타낸다. 이 경우는 런타임에
+                // 일반적으로 있을 수 있는 상황이며, 요청된 'SET'커맨드에 더이상 옵션이 없음을 나타낸다.
+                Err(EndOfStream) => break,
+                // 이 외의 에러는 결과적으로 커넥션을 중단시킨다.
+                Err(err) => return Err(err.into()),
+            };
+
+            match &option[..] {
+                "EX" if expire.is_none() => {
+                    // 만료 시간이 초로 지정된 경우. 다음 값은 integer가 된다.
+                    let secs = parse.next_int()?;
+                    expire = Some(Duration::from_secs(secs));
+                }
+                "PX" if expire.is_none() => {
+                    // 만료 시간이 ms로 지정된 경우. 다음 값은 integer가 된다.
+                    let ms = parse.next_int()?;
+                    expire = Some(Duration::from_millis(ms));
+                }
+                "NX" if condition == Condition::Always => condition = Condition::IfNotExists,
+                "XX" if condition == Condition::Always => condition = Condition::IfExists,
+                // 지원하지 않는 옵션이거나, 'EX'와 'PX' 혹은 'NX'와 'XX'가 함께 지정되었다. 여기서 반환하는
+                // 에러는 커넥션을 중단시킨다. 다른 커넥션들은 영향을 받지 않는다.
+                _ => return Err("ERR syntax error".into()),
             }
-            // 현재 mini-redis는 SET 커맨드에 다른 옵션을 지원하지 않는다. 여기서 반환하는 에러는
-            // 커넥션을 중단시킨다. 다른 커넥션들은 영향을 받지 않는다.
-            Ok(_) => return Err("currently 'SET' only supports the expiration option".into()),
-            // 'EndOfStream'에러는 앞으로 파싱을 위한 데이터가 존재하지 않음을 나타낸다. 이 경우는 런타임에
-            // 일반적으로 있을 수 있는 상황이며, 요청된 'SET'커맨드에 다른 옵션이 없음을 나타낸다.
-            Err(EndOfStream) => {}
-            // 이 외의 에러는 결과적으로 커넥션을 중단시킨다.
-            Err(err) => return Err(err.into()),
         }
 
-        Ok(Set { key, value, expire })
+        Ok(Set {
+            key,
+            value,
+            expire,
+            condition,
+        })
     }
 
     /// 'Set' 커맨드를 특정 'Db' 인스턴스에 수행한다. 
     /// 
     /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
     pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
         // 공유 데이터베이스 상태로부터 값을 세팅한다.
-        db.set(self.key, self.value, self.expire);
-
-        //  성공 응답을 생성하여 'dst'에 쓴다.
-        let response = Frame::Simple("OK".to_string());
+        let written = db.set_options(self.key, self.value, self.expire, self.condition);
+
+        //  값이 저장되었다면 성공 응답을, 'NX' 혹은 'XX' 조건을 만족하지 못했다면 'Null'을 'dst'에 쓴다.
+        let response = if written {
+            Frame::Simple("OK".to_string())
+        } else {
+            Frame::Null
+        };
         debug!(?response);
-        dst.write_frame(&response).await?;
+        dst.write_frame_buffered(&response).await?;
 
         Ok(())
     }
@@ -131,7 +161,7 @@ impl Set {
         let mut frame = Frame::array();
         frame.push_bulk(Bytes::from("set".as_bytes()));
         frame.push_bulk(Bytes::from(self.key.into_bytes()));
-        frame.push_bulk(Bytes::from(self.value));
+        frame.push_bulk(self.value);
         if let Some(ms) = self.expire {
             // 레디스 프로토콜에서 만료를 지정하는 방법에는 두 가지가 있다.
             // 1. SET key value EX seconds
@@ -139,8 +169,57 @@ impl Set {
             // 여기서는 두 번째 옵션을 사용한다. 왜냐하면 이 옵션이 값을 표현하기에 더 정밀하기 때문이다.
             // 그리고 src/bin/cli.rs 는 duration_from_ms_str() 함수에서 만료 아규먼트를 ms로 파싱한다.
             frame.push_bulk(Bytes::from("px".as_bytes()));
-            frame.push_int(ms.as_millis() as u64);
+            frame.push_int(ms.as_millis() as i64);
+        }
+        match self.condition {
+            Condition::Always => {}
+            Condition::IfNotExists => frame.push_bulk(Bytes::from("nx".as_bytes())),
+            Condition::IfExists => frame.push_bulk(Bytes::from("xx".as_bytes())),
         }
         frame
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 'args'로 이루어진 'SET' 커맨드 프레임을 파싱한다.
+    fn parse(args: &[&str]) -> Set {
+        let mut frame = Frame::array();
+        for arg in args {
+            frame.push_bulk(Bytes::from(arg.to_string()));
+        }
+
+        let mut parse = Parse::new(frame).unwrap();
+        assert_eq!(parse.next_string().unwrap(), "set");
+        let set = Set::parse_frames(&mut parse).unwrap();
+        parse.finish().unwrap();
+        set
+    }
+
+    #[test]
+    fn parse_ex() {
+        let set = parse(&["set", "k", "v", "EX", "10"]);
+
+        assert_eq!(set.key(), "k");
+        assert_eq!(set.value(), "v");
+        assert_eq!(set.expire(), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn parse_px() {
+        let set = parse(&["set", "k", "v", "PX", "5000"]);
+
+        assert_eq!(set.expire(), Some(Duration::from_millis(5000)));
+    }
+
+    #[test]
+    fn expire_accessor() {
+        let set = Set::new("k", Bytes::from("v"), Some(Duration::from_secs(3)));
+        assert_eq!(set.expire(), Some(Duration::from_secs(3)));
+
+        let set = Set::new("k", Bytes::from("v"), None);
+        assert_eq!(set.expire(), None);
+    }
+}
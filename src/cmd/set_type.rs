@@ -0,0 +1,728 @@
+//! 셋 커맨드.
+//!
+//! 'set.rs'는 문자열 값을 저장하는 'SET' 커맨드가 이미 사용하고 있으므로, 셋 타입을 다루는 커맨드들은
+//! 이 모듈에 둔다.
+//!
+//! 'SINTER', 'SUNION', 'SDIFF'는 'SetAlgebra'로, 결과를 저장하는 '...STORE' 변형은 'SetAlgebraStore'로
+//! 파싱되며, 'op' 필드가 어느 연산인지를 나타낸다.
+
+use crate::cmd::{Parse, ParseError};
+use crate::db::{SetOp, SetOpResult, MAX_RANDOM_COUNT};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 셋에 하나 이상의 멤버를 추가한다.
+///
+/// 이미 존재하는 멤버는 무시한다. 키가 존재하지 않으면 새로운 셋을 생성한다. 응답으로 새로 추가된
+/// 멤버의 수를 반환한다.
+#[derive(Debug)]
+pub struct SAdd {
+    /// 셋의 키
+    key: String,
+
+    /// 추가할 멤버 목록
+    members: Vec<Bytes>,
+}
+
+/// 셋에서 하나 이상의 멤버를 삭제한다.
+///
+/// 존재하지 않는 멤버는 무시한다. 응답으로 실제로 삭제된 멤버의 수를 반환한다. 셋의 마지막 멤버가
+/// 삭제되면 키도 삭제된다.
+#[derive(Debug)]
+pub struct SRem {
+    /// 셋의 키
+    key: String,
+
+    /// 삭제할 멤버 목록
+    members: Vec<Bytes>,
+}
+
+/// 셋의 모든 멤버를 반환한다.
+///
+/// RESP2에서는 배열로, RESP3에서는 셋으로 응답한다. 멤버의 순서는 정해져 있지 않다.
+#[derive(Debug)]
+pub struct SMembers {
+    key: String,
+}
+
+/// 셋에 멤버가 포함되어 있다면 1을, 그렇지 않다면 0을 반환한다.
+#[derive(Debug)]
+pub struct SIsMember {
+    /// 셋의 키
+    key: String,
+
+    /// 확인할 멤버
+    member: Bytes,
+}
+
+/// 셋의 멤버 수를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+#[derive(Debug)]
+pub struct SCard {
+    key: String,
+}
+
+/// 셋에서 무작위로 고른 멤버를 반환한다.
+///
+/// 'count'가 없으면 멤버 하나를 벌크 문자열로, 키가 존재하지 않으면 nil을 반환한다. 'count'가
+/// 양수라면 서로 다른 멤버를 최대 'count'개, 음수라면 중복을 허용하여 정확히 '-count'개를 배열로
+/// 반환한다.
+#[derive(Debug)]
+pub struct SRandMember {
+    /// 셋의 키
+    key: String,
+
+    /// 고를 멤버의 수. 'None'이라면 멤버 하나를 배열이 아닌 값으로 응답한다.
+    count: Option<i64>,
+}
+
+/// 여러 셋의 교집합('SINTER'), 합집합('SUNION'), 차집합('SDIFF')을 반환한다.
+///
+/// 존재하지 않는 키는 빈 셋으로 취급한다. 차집합은 첫 번째 셋에서 나머지 셋들의 멤버를 뺀 것이다.
+#[derive(Debug)]
+pub struct SetAlgebra {
+    /// 수행할 셋 연산
+    op: SetOp,
+
+    /// 연산할 셋의 키 목록
+    keys: Vec<String>,
+}
+
+/// 'SetAlgebra'와 같은 연산을 수행하고, 결과를 'destination' 키에 저장한다. 'SINTERSTORE',
+/// 'SUNIONSTORE', 'SDIFFSTORE'.
+///
+/// 'destination'에 저장되어 있던 값은 대체된다. 결과가 빈 셋이라면 'destination'은 삭제된다. 응답으로
+/// 결과 셋의 멤버 수를 반환한다.
+#[derive(Debug)]
+pub struct SetAlgebraStore {
+    /// 수행할 셋 연산
+    op: SetOp,
+
+    /// 결과를 저장할 키
+    destination: String,
+
+    /// 연산할 셋의 키 목록
+    keys: Vec<String>,
+}
+
+/// 'key' 뒤에 오는 하나 이상의 멤버를 파싱한다.
+///
+/// 'SADD'와 'SREM'은 같은 형태의 아규먼트를 받는다.
+fn parse_members(parse: &mut Parse) -> crate::Result<Vec<Bytes>> {
+    use ParseError::EndOfStream;
+
+    // 최소 하나의 멤버가 필요하다.
+    let mut members = vec![parse.next_bytes()?];
+
+    loop {
+        match parse.next_bytes() {
+            Ok(member) => members.push(member),
+            Err(EndOfStream) => break,
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(members)
+}
+
+/// 하나 이상의 키를 파싱한다.
+fn parse_keys(parse: &mut Parse) -> crate::Result<Vec<String>> {
+    use ParseError::EndOfStream;
+
+    // 최소 하나의 키가 필요하다.
+    let mut keys = vec![parse.next_string()?];
+
+    loop {
+        match parse.next_string() {
+            Ok(key) => keys.push(key),
+            Err(EndOfStream) => break,
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(keys)
+}
+
+impl SAdd {
+    /// 'key'의 셋에 'members'를 추가하는 새로운 'SAdd' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, members: Vec<Bytes>) -> SAdd {
+        SAdd {
+            key: key.to_string(),
+            members,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SAdd' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SADD' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'SAdd' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SADD key member [member ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SAdd> {
+        let key = parse.next_string()?;
+        let members = parse_members(parse)?;
+
+        Ok(SAdd { key, members })
+    }
+
+    /// 'SAdd' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 새로 추가된 멤버의 수를 응답한다. 키에 셋이 아닌 값이 저장되어 있다면 에러 프레임으로
+        // 응답한다.
+        let response = match db.sadd(&self.key, self.members) {
+            Ok(added) => Frame::Integer(added as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SAdd'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("sadd".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        for member in self.members {
+            frame.push_bulk(member);
+        }
+        frame
+    }
+}
+
+impl SRem {
+    /// 'key'의 셋에서 'members'를 삭제하는 새로운 'SRem' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, members: Vec<Bytes>) -> SRem {
+        SRem {
+            key: key.to_string(),
+            members,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SRem' 인스턴스를 파싱한다.
+    ///
+    /// 'SREM' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SREM key member [member ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SRem> {
+        let key = parse.next_string()?;
+        let members = parse_members(parse)?;
+
+        Ok(SRem { key, members })
+    }
+
+    /// 'SRem' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.srem(&self.key, &self.members) {
+            Ok(removed) => Frame::Integer(removed as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SRem'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("srem".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        for member in self.members {
+            frame.push_bulk(member);
+        }
+        frame
+    }
+}
+
+impl SMembers {
+    /// 'key'의 셋의 모든 멤버를 가져오는 새로운 'SMembers' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> SMembers {
+        SMembers {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SMembers' 인스턴스를 파싱한다.
+    ///
+    /// 'SMEMBERS' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SMEMBERS key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SMembers> {
+        let key = parse.next_string()?;
+
+        Ok(SMembers { key })
+    }
+
+    /// 'SMembers' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.smembers(&self.key) {
+            // 셋 프레임으로 응답한다. RESP2 커넥션에서는 배열로 인코딩된다.
+            Ok(members) => Frame::Set(members.into_iter().map(Frame::Bulk).collect()),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SMembers'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("smembers".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
+
+impl SIsMember {
+    /// 'key'의 셋에 'member'가 포함되어 있는지 확인하는 새로운 'SIsMember' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, member: Bytes) -> SIsMember {
+        SIsMember {
+            key: key.to_string(),
+            member,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 멤버를 가져온다.
+    pub fn member(&self) -> &Bytes {
+        &self.member
+    }
+
+    /// 수신한 프레임으로부터 'SIsMember' 인스턴스를 파싱한다.
+    ///
+    /// 'SISMEMBER' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SISMEMBER key member
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SIsMember> {
+        let key = parse.next_string()?;
+        let member = parse.next_bytes()?;
+
+        Ok(SIsMember { key, member })
+    }
+
+    /// 'SIsMember' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.sismember(&self.key, &self.member) {
+            Ok(is_member) => Frame::Integer(is_member as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SIsMember'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("sismember".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(self.member);
+        frame
+    }
+}
+
+impl SCard {
+    /// 'key'의 셋의 멤버 수를 가져오는 새로운 'SCard' 커맨드를 생성한다.
+    pub fn new(key: impl ToString) -> SCard {
+        SCard {
+            key: key.to_string(),
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SCard' 인스턴스를 파싱한다.
+    ///
+    /// 'SCARD' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SCARD key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SCard> {
+        let key = parse.next_string()?;
+
+        Ok(SCard { key })
+    }
+
+    /// 'SCard' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.scard(&self.key) {
+            Ok(len) => Frame::Integer(len as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SCard'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("scard".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
+
+impl SRandMember {
+    /// 'key'의 셋에서 무작위로 멤버를 고르는 새로운 'SRandMember' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, count: Option<i64>) -> SRandMember {
+        SRandMember {
+            key: key.to_string(),
+            count,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SRandMember' 인스턴스를 파싱한다.
+    ///
+    /// 'SRANDMEMBER' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 혹은 세 개의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SRANDMEMBER key [count]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SRandMember> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        let count = match parse.next_signed_int() {
+            Ok(count) if count < 0 && count.unsigned_abs() > MAX_RANDOM_COUNT => {
+                return Err("ERR value is out of range".into())
+            }
+            Ok(count) => Some(count),
+            Err(EndOfStream) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(SRandMember { key, count })
+    }
+
+    /// 'SRandMember' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.srandmember(&self.key, self.count.unwrap_or(1)) {
+            Ok(members) => match self.count {
+                None => members.into_iter().next().map_or(Frame::Null, Frame::Bulk),
+                Some(_) => Frame::Array(members.into_iter().map(Frame::Bulk).collect()),
+            },
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SRandMember'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("srandmember".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        if let Some(count) = self.count {
+            frame.push_bulk(Bytes::from(count.to_string().into_bytes()));
+        }
+        frame
+    }
+}
+
+impl SetAlgebra {
+    /// 'keys'의 셋들에 'op' 연산을 수행하는 새로운 'SetAlgebra' 커맨드를 생성한다.
+    pub(crate) fn new(op: SetOp, keys: Vec<String>) -> SetAlgebra {
+        SetAlgebra { op, keys }
+    }
+
+    /// 키 목록을 가져온다.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        match self.op {
+            SetOp::Inter => "sinter",
+            SetOp::Union => "sunion",
+            SetOp::Diff => "sdiff",
+        }
+    }
+
+    /// 수신한 프레임으로부터 'SetAlgebra' 인스턴스를 파싱한다.
+    ///
+    /// 'SINTER', 'SUNION' 혹은 'SDIFF' 문자열은 이미 소비되었다. 'op'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SINTER key [key ...]
+    /// SUNION key [key ...]
+    /// SDIFF key [key ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, op: SetOp) -> crate::Result<SetAlgebra> {
+        let keys = parse_keys(parse)?;
+
+        Ok(SetAlgebra { op, keys })
+    }
+
+    /// 'SetAlgebra' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.set_op(self.op, &self.keys, None) {
+            // 'SMEMBERS'와 같이 셋 프레임으로 응답한다.
+            Ok(SetOpResult::Members(members)) => {
+                Frame::Set(members.into_iter().map(Frame::Bulk).collect())
+            }
+            Ok(SetOpResult::Stored(_)) => unreachable!(),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SetAlgebra'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        for key in self.keys {
+            frame.push_bulk(Bytes::from(key.into_bytes()));
+        }
+        frame
+    }
+}
+
+impl SetAlgebraStore {
+    /// 'keys'의 셋들에 'op' 연산을 수행하고 결과를 'destination'에 저장하는 새로운 'SetAlgebraStore'
+    /// 커맨드를 생성한다.
+    pub(crate) fn new(
+        op: SetOp,
+        destination: impl ToString,
+        keys: Vec<String>,
+    ) -> SetAlgebraStore {
+        SetAlgebraStore {
+            op,
+            destination: destination.to_string(),
+            keys,
+        }
+    }
+
+    /// 결과를 저장할 키를 가져온다.
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// 키 목록을 가져온다.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        match self.op {
+            SetOp::Inter => "sinterstore",
+            SetOp::Union => "sunionstore",
+            SetOp::Diff => "sdiffstore",
+        }
+    }
+
+    /// 수신한 프레임으로부터 'SetAlgebraStore' 인스턴스를 파싱한다.
+    ///
+    /// 'SINTERSTORE', 'SUNIONSTORE' 혹은 'SDIFFSTORE' 문자열은 이미 소비되었다. 'op'는 어느
+    /// 커맨드인지를 나타낸다.
+    ///
+    /// # Format
+    ///
+    /// 세 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SINTERSTORE destination key [key ...]
+    /// SUNIONSTORE destination key [key ...]
+    /// SDIFFSTORE destination key [key ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, op: SetOp) -> crate::Result<SetAlgebraStore> {
+        let destination = parse.next_string()?;
+        let keys = parse_keys(parse)?;
+
+        Ok(SetAlgebraStore {
+            op,
+            destination,
+            keys,
+        })
+    }
+
+    /// 'SetAlgebraStore' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.set_op(self.op, &self.keys, Some(&self.destination)) {
+            Ok(SetOpResult::Stored(len)) => Frame::Integer(len as i64),
+            Ok(SetOpResult::Members(_)) => unreachable!(),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SetAlgebraStore'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame.push_bulk(Bytes::from(self.destination.into_bytes()));
+        for key in self.keys {
+            frame.push_bulk(Bytes::from(key.into_bytes()));
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 'args'로 이루어진 커맨드 프레임에서 커맨드 이름을 제외한 커서를 만든다.
+    fn parse(args: &[&str]) -> Parse {
+        let mut frame = Frame::array();
+        for arg in args {
+            frame.push_bulk(Bytes::from(arg.to_string()));
+        }
+
+        let mut parse = Parse::new(frame).unwrap();
+        parse.next_string().unwrap();
+        parse
+    }
+
+    #[test]
+    fn srandmember_count_out_of_range() {
+        let min = (-(MAX_RANDOM_COUNT as i64)).to_string();
+        let count = SRandMember::parse_frames(&mut parse(&["srandmember", "s", &min]))
+            .unwrap()
+            .count;
+        assert_eq!(count, Some(-(MAX_RANDOM_COUNT as i64)));
+
+        let too_small = (-(MAX_RANDOM_COUNT as i64) - 1).to_string();
+        for count in [&too_small[..], "-9223372036854775807"] {
+            let err = SRandMember::parse_frames(&mut parse(&["srandmember", "s", count]))
+                .unwrap_err();
+            assert_eq!(err.to_string(), "ERR value is out of range");
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 값의 'offset' 위치의 비트를 0 혹은 1로 설정한다.
+///
+/// 비트 오프셋은 각 바이트의 최상위 비트부터 센다. 'offset'이 값을 벗어나면 필요한 만큼 0 바이트로
+/// 채운다. 키가 존재하지 않으면 빈 값으로 간주한다. 키의 만료 시간은 유지된다. 응답으로 설정하기
+/// 전의 비트를 반환한다.
+#[derive(Debug)]
+pub struct SetBit {
+    /// 비트를 설정할 키
+    key: String,
+
+    /// 설정할 비트의 오프셋
+    offset: u64,
+
+    /// 설정할 비트. 'true'는 1이다.
+    value: bool,
+}
+
+impl SetBit {
+    /// 'key'에 저장된 값의 'offset' 위치의 비트를 'value'로 설정하는 'SetBit' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, offset: u64, value: bool) -> SetBit {
+        SetBit {
+            key: key.to_string(),
+            offset,
+            value,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SetBit' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SETBIT' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'SetBit' 값을 반환한다. 프레임의 형태가 잘못되었거나 'value'가 0 혹은 1이 아닌 경우
+    /// 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SETBIT key offset value
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SetBit> {
+        let key = parse.next_string()?;
+        let offset = parse.next_int()?;
+        let value = match parse.next_int()? {
+            0 => false,
+            1 => true,
+            _ => return Err("ERR bit is not an integer or out of range".into()),
+        };
+
+        Ok(SetBit { key, offset, value })
+    }
+
+    /// 'SetBit' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 오프셋이 허용된 범위를 넘는 경우 에러 프레임으로 응답한다. 이 에러는 커넥션을 중단시키지
+        // 않는다.
+        let response = match db.setbit(&self.key, self.offset, self.value) {
+            Ok(prev) => Frame::Integer(prev as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SetBit'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("setbit".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.offset as i64);
+        frame.push_int(self.value as i64);
+        frame
+    }
+}
@@ -0,0 +1,115 @@
+use crate::db::Condition;
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use std::time::Duration;
+use tracing::{debug, instrument};
+
+/// 'key'와 'value'를 묶어 저장하고, 키가 주어진 시간 후에 만료되도록 설정한다.
+///
+/// 'SET key value EX seconds'와 같다. 값의 저장과 만료 시간의 설정은 원자적으로 이루어진다.
+///
+/// 'SETEX'는 만료 시간을 초 단위로, 'PSETEX'는 밀리초 단위로 지정한다. 두 커맨드는 모두 'SetEx'로
+/// 파싱된다.
+#[derive(Debug)]
+pub struct SetEx {
+    /// 저장할 키
+    key: String,
+
+    /// 저장할 값
+    value: Bytes,
+
+    /// 만료 시간
+    expire: Duration,
+}
+
+impl SetEx {
+    /// 'key'에 'value'를 저장하고 'expire' 이후에 만료되도록 하는 새로운 'SetEx' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, value: Bytes, expire: Duration) -> SetEx {
+        SetEx {
+            key: key.to_string(),
+            value,
+            expire,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 값을 가져온다.
+    pub fn value(&self) -> &Bytes {
+        &self.value
+    }
+
+    /// 만료 시간을 가져온다.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// 수신한 프레임으로부터 'SetEx' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SETEX' 혹은 'PSETEX' 문자열은 이미 소비되었다. 'millis'가 'true'이면 'PSETEX'로, 만료 시간을
+    /// 밀리초 단위로 파싱한다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'SetEx' 값을 반환한다. 프레임의 형태가 잘못되었거나 만료 시간이 0 이하인 경우
+    /// 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SETEX key seconds value
+    /// PSETEX key milliseconds value
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, millis: bool) -> crate::Result<SetEx> {
+        let key = parse.next_string()?;
+
+        let expire = match parse.next_signed_int()? {
+            expire if expire > 0 && millis => Duration::from_millis(expire as u64),
+            expire if expire > 0 => Duration::from_secs(expire as u64),
+            _ => {
+                let name = if millis { "psetex" } else { "setex" };
+                return Err(format!("ERR invalid expire time in '{}' command", name).into());
+            }
+        };
+
+        let value = parse.next_bytes()?;
+
+        Ok(SetEx { key, value, expire })
+    }
+
+    /// 'SetEx' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        db.set_options(self.key, self.value, Some(self.expire), Condition::Always);
+
+        let response = Frame::Simple("OK".to_string());
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SetEx'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        // 'SET'과 마찬가지로, 값을 더 정밀하게 표현할 수 있는 밀리초 단위의 'PSETEX'를 사용한다.
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("psetex".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.expire.as_millis() as i64);
+        frame.push_bulk(self.value);
+        frame
+    }
+}
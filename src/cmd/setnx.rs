@@ -0,0 +1,88 @@
+use crate::db::Condition;
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키가 존재하지 않을 때만 'key'와 'value'를 묶어 저장한다.
+///
+/// 'SET key value NX'와 같지만, 값이 저장되었다면 1을, 그렇지 않다면 0을 응답한다.
+#[derive(Debug)]
+pub struct SetNx {
+    /// 저장할 키
+    key: String,
+
+    /// 저장할 값
+    value: Bytes,
+}
+
+impl SetNx {
+    /// 'key'가 존재하지 않을 때만 'value'를 저장하는 'SetNx' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, value: Bytes) -> SetNx {
+        SetNx {
+            key: key.to_string(),
+            value,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 값을 가져온다.
+    pub fn value(&self) -> &Bytes {
+        &self.value
+    }
+
+    /// 수신한 프레임으로부터 'SetNx' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SETNX' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'SetNx' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SETNX key value
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SetNx> {
+        let key = parse.next_string()?;
+        let value = parse.next_bytes()?;
+
+        Ok(SetNx { key, value })
+    }
+
+    /// 'SetNx' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let written = db.set_options(self.key, self.value, None, Condition::IfNotExists);
+
+        // 값이 저장되었는지를 1 혹은 0으로 응답한다.
+        let response = Frame::Integer(written as i64);
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SetNx'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("setnx".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(self.value);
+        frame
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키에 저장된 값의 'offset' 위치부터를 'value'로 덮어쓴다.
+///
+/// 'offset'이 값의 길이보다 크면 그 사이를 0 바이트로 채운다. 키가 존재하지 않으면 빈 값으로
+/// 간주한다. 키의 만료 시간은 유지된다. 응답으로 덮어쓴 뒤의 값의 길이를 반환한다.
+#[derive(Debug)]
+pub struct SetRange {
+    /// 값을 덮어쓸 키
+    key: String,
+
+    /// 덮어쓰기를 시작할 오프셋
+    offset: u64,
+
+    /// 덮어쓸 값
+    value: Bytes,
+}
+
+impl SetRange {
+    /// 'key'에 저장된 값의 'offset' 위치부터를 'value'로 덮어쓰는 'SetRange' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, offset: u64, value: Bytes) -> SetRange {
+        SetRange {
+            key: key.to_string(),
+            offset,
+            value,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'SetRange' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SETRANGE' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'SetRange' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 네 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// SETRANGE key offset value
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SetRange> {
+        let key = parse.next_string()?;
+        let offset = parse.next_int()?;
+        let value = parse.next_bytes()?;
+
+        Ok(SetRange { key, offset, value })
+    }
+
+    /// 'SetRange' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 결과 값이 허용된 크기를 넘는 경우 에러 프레임으로 응답한다. 이 에러는 커넥션을 중단시키지
+        // 않는다.
+        let response = match db.setrange(&self.key, self.offset, self.value) {
+            Ok(len) => Frame::Integer(len as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SetRange'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("setrange".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.offset as i64);
+        frame.push_bulk(self.value);
+        frame
+    }
+}
@@ -0,0 +1,137 @@
+use crate::cmd::{Parse, ParseError};
+use crate::slowlog::Entry;
+use crate::{Connection, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 'SLOWLOG GET'이 'count' 없이 호출되었을 때 응답하는 기록의 수. 레디스와 같다.
+const DEFAULT_COUNT: usize = 10;
+
+/// 처리에 오래 걸린 커맨드의 기록을 조회하거나 지운다.
+///
+/// 다음의 서브커맨드를 지원한다:
+///
+/// * GET ['count'] -- 최근의 기록부터 'count'개를 응답한다. 기본값은 10이며, -1은 모든 기록을
+///   응답한다. 각 기록은 식별자, 기록한 시각(유닉스 시간, 초), 처리 시간(마이크로초), 커맨드의
+///   아규먼트 배열, 클라이언트의 주소, 클라이언트의 이름으로 이루어진 배열이다.
+/// * LEN -- 기록의 수를 응답한다.
+/// * RESET -- 모든 기록을 지우고 OK를 응답한다.
+///
+/// 기록의 기준과 최대 개수는 'slowlog-log-slower-than'과 'slowlog-max-len' 설정으로 정한다.
+#[derive(Debug)]
+pub enum SlowLog {
+    /// 'SLOWLOG GET [count]'. 'None'은 모든 기록이다.
+    Get(Option<usize>),
+
+    /// 'SLOWLOG LEN'
+    Len,
+
+    /// 'SLOWLOG RESET'
+    Reset,
+}
+
+impl SlowLog {
+    /// 수신한 프레임으로부터 'SlowLog' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'SLOWLOG' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'SlowLog' 값을 반환한다. 프레임의 형태가 잘못되었거나 지원하지 않는
+    /// 서브커맨드인 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// SLOWLOG GET [count]
+    /// SLOWLOG LEN
+    /// SLOWLOG RESET
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SlowLog> {
+        use ParseError::EndOfStream;
+
+        let subcommand = parse.next_string()?.to_lowercase();
+
+        match &subcommand[..] {
+            "get" => match parse.next_string() {
+                Ok(count) => match count.parse::<i64>() {
+                    Ok(-1) => Ok(SlowLog::Get(None)),
+                    Ok(count) if count >= 0 => Ok(SlowLog::Get(Some(count as usize))),
+                    _ => Err("ERR count should be greater than or equal to -1".into()),
+                },
+                Err(EndOfStream) => Ok(SlowLog::Get(Some(DEFAULT_COUNT))),
+                Err(err) => Err(err.into()),
+            },
+            "len" => Ok(SlowLog::Len),
+            "reset" => Ok(SlowLog::Reset),
+            _ => Err(format!("ERR unknown subcommand '{}' for 'slowlog'", subcommand).into()),
+        }
+    }
+
+    /// 'SlowLog' 커맨드를 수행한다.
+    ///
+    /// 슬로우 로그는 모든 커넥션이 공유하는 서버 상태이므로, 'Command::apply'를 거치지 않고 핸들러가
+    /// 직접 이 함수를 호출한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, log, dst))]
+    pub(crate) async fn apply(
+        self,
+        log: &crate::slowlog::SlowLog,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        let response = match self {
+            SlowLog::Get(count) => {
+                Frame::Array(log.get(count).into_iter().map(describe).collect())
+            }
+            SlowLog::Len => Frame::Integer(log.len() as i64),
+            SlowLog::Reset => {
+                log.reset();
+                Frame::Simple("OK".to_string())
+            }
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'SlowLog'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("slowlog".as_bytes()));
+        match self {
+            SlowLog::Get(count) => {
+                frame.push_bulk(Bytes::from("get".as_bytes()));
+                let count = count.map_or(-1, |count| count as i64);
+                frame.push_bulk(Bytes::from(count.to_string().into_bytes()));
+            }
+            SlowLog::Len => {
+                frame.push_bulk(Bytes::from("len".as_bytes()));
+            }
+            SlowLog::Reset => {
+                frame.push_bulk(Bytes::from("reset".as_bytes()));
+            }
+        }
+        frame
+    }
+}
+
+/// 기록 하나를 '[id, timestamp, duration, [arg ...], addr, name]' 배열로 만든다.
+fn describe(entry: Entry) -> Frame {
+    Frame::Array(vec![
+        Frame::Integer(entry.id as i64),
+        Frame::Integer(entry.timestamp as i64),
+        Frame::Integer(entry.duration.as_micros() as i64),
+        Frame::Array(entry.args.into_iter().map(Frame::Bulk).collect()),
+        Frame::Bulk(Bytes::from(entry.addr.into_bytes())),
+        Frame::Bulk(Bytes::from(entry.name.into_bytes())),
+    ])
+}
@@ -1,4 +1,4 @@
-use crate::cmd::{Parse, ParseError, Unknown};
+use crate::cmd::{Parse, ParseError, Reset, Unknown};
 use crate::{Command, Connection, Db, Frame, Shutdown};
 
 use bytes::Bytes;
@@ -6,6 +6,7 @@ use std::pin::Pin;
 use tokio::select;
 use tokio::sync::broadcast;
 use tokio_stream::{Stream, StreamExt, StreamMap};
+use tracing::warn;
 
 /// 클라이언트를 하나 혹은 둘 이상의 채널에 구독자로 등록한다.
 /// 
@@ -26,12 +27,132 @@ pub struct Unsubscribe {
     channels: Vec<String>,
 }
 
+/// 클라이언트를 하나 혹은 둘 이상의 glob 패턴에 구독자로 등록한다.
+/// 
+/// 패턴과 매칭되는 이름의 채널에 발행된 메시지를 수신한다. 패턴은 '*', '?', '[...]'를 지원한다.
+/// 'SUBSCRIBE'와 마찬가지로 클라이언트는 구독 상태가 된다.
+#[derive(Debug)]
+pub struct PSubscribe {
+    patterns: Vec<String>,
+}
+
+/// 클라이언트를 하나 혹은 둘 이상의 glob 패턴으로부터 구독 해지한다.
+/// 
+/// 구독 해지 패턴이 지정되지 않으면, 이전까지 구독되었던 모든 패턴으로부터 클라이언트를
+/// 구독 해지한다.
+#[derive(Debug)]
+pub struct PUnsubscribe {
+    patterns: Vec<String>,
+}
+
+/// 클라이언트를 하나 혹은 둘 이상의 샤드 채널에 구독자로 등록한다.
+/// 
+/// 샤드 채널은 일반 채널과 별도의 키 공간을 사용하며, 'SPUBLISH'로 발행된 메시지만을 수신한다.
+/// 'SUBSCRIBE'와 마찬가지로 클라이언트는 구독 상태가 된다.
+#[derive(Debug)]
+pub struct SSubscribe {
+    channels: Vec<String>,
+}
+
+/// 클라이언트를 하나 혹은 둘 이상의 샤드 채널로부터 구독 해지한다.
+/// 
+/// 구독 해지 채널이 지정되지 않으면, 이전까지 구독되었던 모든 샤드 채널로부터 클라이언트를
+/// 구독 해지한다.
+#[derive(Debug)]
+pub struct SUnsubscribe {
+    channels: Vec<String>,
+}
+
 /// 메시지의 스트림
 /// 스트림은 'broadcast::Receiver'로부터 메시지를 수신한다. 'stream!'을 사용하여 메시지를
 /// 소비하는 'Stream'을 생성한다. 'stream!'에는 이름을 지정할 수 없기 때문에, 여기서는 trait object를
 /// 사용하여 스트림을 박싱한다.
 type Messages = Pin<Box<dyn Stream<Item = Bytes> + Send>>;
 
+/// 패턴 구독으로 수신하는 메시지의 스트림. 각 메시지는 발행된 채널의 이름과 함께 전달된다.
+type PatternMessages = Pin<Box<dyn Stream<Item = (String, Bytes)> + Send>>;
+
+/// 구독 상태를 벗어나게 한 커맨드
+/// 
+/// 구독 루프는 이 커맨드들을 수신하면 모든 구독을 해지하고 종료한 뒤, 핸들러가 커넥션의 나머지
+/// 상태를 다룰 수 있도록 이를 반환한다.
+#[derive(Debug)]
+pub(crate) enum Leave {
+    /// 'RESET'. 응답은 커넥션의 상태를 초기화한 핸들러가 쓴다.
+    Reset(Reset),
+
+    /// 'QUIT'. 구독 루프가 이미 응답을 썼다.
+    Quit,
+}
+
+/// 구독 상태인 클라이언트의 활성화된 구독 목록
+/// 
+/// 채널 구독, 패턴 구독, 샤드 채널 구독을 별도의 'StreamMap'으로 추적한다. 레디스와 같이
+/// 'SUBSCRIBE', 'PSUBSCRIBE'에 응답하는 구독 수는 채널 구독과 패턴 구독 수의 합이며, 'SSUBSCRIBE'에
+/// 응답하는 구독 수는 샤드 채널 구독의 수이다.
+/// 
+/// 구독을 해지하거나 목록이 drop되면, 구독자가 남지 않은 채널과 패턴을 'db'의 pub/sub 상태에서
+/// 삭제한다.
+struct Subscriptions {
+    channels: StreamMap<String, Messages>,
+    patterns: StreamMap<String, PatternMessages>,
+    shard_channels: StreamMap<String, Messages>,
+    db: Db,
+}
+
+impl Subscriptions {
+    /// 채널 구독과 패턴 구독을 모두 합한 구독의 수를 반환한다.
+    fn len(&self) -> usize {
+        self.channels.len() + self.patterns.len()
+    }
+
+    /// 샤드 채널 구독의 수를 반환한다.
+    fn shard_len(&self) -> usize {
+        self.shard_channels.len()
+    }
+
+    /// 채널 구독을 해지한다. 수신자를 drop한 뒤, 채널에 구독자가 남지 않았다면 채널을 삭제한다.
+    fn unsubscribe_channel(&mut self, channel_name: &str) {
+        if self.channels.remove(channel_name).is_some() {
+            self.db.release_channel(channel_name);
+        }
+    }
+
+    /// 패턴 구독을 해지한다. 채널과 마찬가지로 구독자가 남지 않은 패턴을 삭제한다.
+    fn unsubscribe_pattern(&mut self, pattern: &str) {
+        if self.patterns.remove(pattern).is_some() {
+            self.db.release_pattern(pattern);
+        }
+    }
+
+    /// 샤드 채널 구독을 해지한다. 채널과 마찬가지로 구독자가 남지 않은 샤드 채널을 삭제한다.
+    fn unsubscribe_shard_channel(&mut self, channel_name: &str) {
+        if self.shard_channels.remove(channel_name).is_some() {
+            self.db.release_shard_channel(channel_name);
+        }
+    }
+}
+
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        // 커넥션이 끊어지거나 구독 상태를 벗어나면 남은 모든 구독을 해지한다.
+        let channels: Vec<String> = self.channels.keys().cloned().collect();
+        for channel_name in channels {
+            self.unsubscribe_channel(&channel_name);
+        }
+
+        let patterns: Vec<String> = self.patterns.keys().cloned().collect();
+        for pattern in patterns {
+            self.unsubscribe_pattern(&pattern);
+        }
+
+        let shard_channels: Vec<String> = self.shard_channels.keys().cloned().collect();
+        for channel_name in shard_channels {
+            self.unsubscribe_shard_channel(&channel_name);
+        }
+    }
+}
+
 impl Subscribe {
     // 특정 채널을 수신하기 위한 새로운 'Subscribe'를 생성한다.
     pub(crate) fn new(channels: &[String]) -> Subscribe {
@@ -91,59 +212,17 @@ impl Subscribe {
     // 이 함수 호출 이후에도 클라이언트로부터 'subscribe', 'unsubscribe' 커맨드를
     // 수신할 수 있으며, 이에 따라서 구독 목록을 갱신한다.
     // 
+    // 구독 상태에서 'RESET'이나 'QUIT'을 수신하면 구독을 마치고 이를 반환한다. 핸들러는 반환된
+    // 'Leave'로 커넥션의 나머지 상태를 다룬다.
+    // 
     // [here]: https://redis.io/topics/pubsub
     pub(crate) async fn apply(
-        mut self,
+        self,
         db: &Db,
         dst: &mut Connection,
         shutdown: &mut Shutdown,
-    ) -> crate::Result<()> {
-        // 독립적인 각 채널 구독은 'sync::broadcast' 채널을 사용하여 핸들링한다.
-        // 메시지들은 현재 채널을 구독 중인 모든 클라이언트에게 퍼지며 전송된다.
-        // 
-        // 독립적인 하나의 클라이언트는 여러 개의 채널을 구독할 수 있고, 자신의 구독
-        // 목록에서 채널을 동적으로 추가하고 삭제할 수 있다. 이 기능을 위해, 'StreamMap'
-        // 을 사용하여 활성화된 구독을 추적한다. 메시지를 수신할 때와 같이, 'SteramMap'은
-        // 각 브로드캐스트 채널로부터의 메시지를 병합한다.
-        let mut subscriptions = StreamMap::new();
-
-        loop {
-            // 'self.channels'를 사용하여 추가적인 구독 대상 채널을 추적한다.
-            // 'apply'를 실행하는 동안 새로운 'SUBSCRIBE' 커맨드를 수신하면 새 채널을
-            // 여기의 vec에 추가한다.
-            for channel_name in self.channels.drain(..) {
-                subscribe_to_channel(channel_name, &mut subscriptions, db, dst).await?;
-            }
-
-            // 다음 중 하나를 기다린다.
-            // 
-            // - 구독 채널 중 하나에서 메시지를 수신
-            // - 클라이언트로부터 구독 혹은 구독 해지 커맨드를 수신
-            // - 서버 셧다운 시그널
-            select! {
-                // 구독 채널로부터 메시지를 수신한다.
-                Some((channel_name, msg)) = subscriptions.next() => {
-                    dst.write_frame(&make_message_frame(channel_name, msg)).await?;
-                }
-                res = dst.read_frame() => {
-                    let frame = match res? {
-                        Some(frame) => frame,
-                        // 원격 클라이언트의 연결이 끊어지면 발생한다.
-                        None => return Ok(())
-                    };
-
-                    handle_command(
-                        frame,
-                        &mut self.channels,
-                        &mut subscriptions,
-                        dst,
-                    ).await?;
-                }
-                _ = shutdown.recv() => {
-                    return Ok(());
-                }
-            };
-        }
+    ) -> crate::Result<Option<Leave>> {
+        run_subscriber(self.channels, vec![], vec![], db, dst, shutdown).await
     }
 
     /// 커맨드를 'Frame'으로 변환한다.
@@ -160,53 +239,248 @@ impl Subscribe {
     }
 }
 
+/// 구독 상태의 커넥션을 처리한다. 'SUBSCRIBE', 'PSUBSCRIBE', 'SSUBSCRIBE' 커맨드가 공유한다.
+/// 
+/// 'channels', 'patterns', 'shard_channels'는 구독 대상 채널, 패턴, 샤드 채널의 초기 목록이다. 커넥션이 끊어지거나
+/// 서버가 셧다운될 때까지 반환하지 않는다. 'RESET'이나 'QUIT'을 수신하면 모든 구독을 해지하고
+/// 이를 반환한다.
+async fn run_subscriber(
+    mut channels: Vec<String>,
+    mut patterns: Vec<String>,
+    mut shard_channels: Vec<String>,
+    db: &Db,
+    dst: &mut Connection,
+    shutdown: &mut Shutdown,
+) -> crate::Result<Option<Leave>> {
+    // 독립적인 각 채널 구독은 'sync::broadcast' 채널을 사용하여 핸들링한다.
+    // 메시지들은 현재 채널을 구독 중인 모든 클라이언트에게 퍼지며 전송된다.
+    // 
+    // 독립적인 하나의 클라이언트는 여러 개의 채널을 구독할 수 있고, 자신의 구독
+    // 목록에서 채널을 동적으로 추가하고 삭제할 수 있다. 이 기능을 위해, 'StreamMap'
+    // 을 사용하여 활성화된 구독을 추적한다. 메시지를 수신할 때와 같이, 'SteramMap'은
+    // 각 브로드캐스트 채널로부터의 메시지를 병합한다.
+    let mut subscriptions = Subscriptions {
+        channels: StreamMap::new(),
+        patterns: StreamMap::new(),
+        shard_channels: StreamMap::new(),
+        db: db.clone(),
+    };
+
+    loop {
+        // 'channels', 'patterns'를 사용하여 추가적인 구독 대상을 추적한다.
+        // 구독 상태에서 새로운 'SUBSCRIBE', 'PSUBSCRIBE' 커맨드를 수신하면 새 채널
+        // 혹은 패턴을 여기의 vec에 추가한다.
+        for channel_name in channels.drain(..) {
+            subscribe_to_channel(channel_name, &mut subscriptions, db, dst).await?;
+        }
+
+        for pattern in patterns.drain(..) {
+            subscribe_to_pattern(pattern, &mut subscriptions, db, dst).await?;
+        }
+
+        for channel_name in shard_channels.drain(..) {
+            subscribe_to_shard_channel(channel_name, &mut subscriptions, db, dst).await?;
+        }
+
+        // 다음 중 하나를 기다린다.
+        // 
+        // - 구독 채널 중 하나에서 메시지를 수신
+        // - 구독 패턴 중 하나에서 메시지를 수신
+        // - 구독 샤드 채널 중 하나에서 메시지를 수신
+        // - 클라이언트로부터 구독 혹은 구독 해지 커맨드를 수신
+        // - 서버 셧다운 시그널
+        select! {
+            // 구독 채널로부터 메시지를 수신한다.
+            Some((channel_name, msg)) = subscriptions.channels.next() => {
+                dst.write_frame(&make_message_frame(channel_name, msg)).await?;
+            }
+            // 구독 패턴으로부터 메시지를 수신한다.
+            Some((pattern, (channel_name, msg))) = subscriptions.patterns.next() => {
+                dst.write_frame(&make_pmessage_frame(pattern, channel_name, msg)).await?;
+            }
+            // 구독 샤드 채널로부터 메시지를 수신한다.
+            Some((channel_name, msg)) = subscriptions.shard_channels.next() => {
+                dst.write_frame(&make_smessage_frame(channel_name, msg)).await?;
+            }
+            res = dst.read_frame() => {
+                let frame = match res? {
+                    Some(frame) => frame,
+                    // 원격 클라이언트의 연결이 끊어지면 발생한다.
+                    None => return Ok(None)
+                };
+
+                let leave = handle_command(
+                    frame,
+                    &mut channels,
+                    &mut patterns,
+                    &mut shard_channels,
+                    &mut subscriptions,
+                    dst,
+                ).await?;
+
+                // 'subscriptions'가 drop되며 모든 구독이 해지된다.
+                if leave.is_some() {
+                    return Ok(leave);
+                }
+            }
+            _ = shutdown.recv() => {
+                return Ok(None);
+            }
+        };
+    }
+}
+
 async fn subscribe_to_channel(
     channel_name: String,
-    subscriptions: &mut StreamMap<String, Messages>,
+    subscriptions: &mut Subscriptions,
     db: &Db,
     dst: &mut Connection,
 ) -> crate::Result<()> {
-    let mut rx = db.subscribe(channel_name.clone());
+    // 이미 구독 중인 채널이라면 레디스와 같이 다시 구독하지 않고, 현재의 구독 수로 응답만 한다. 수신자를
+    // 새로 만들어 교체하면 기존 수신자가 받아둔 메시지를 잃는다.
+    if subscriptions.channels.contains_key(&channel_name) {
+        let response = make_subscribe_frame(channel_name, subscriptions.len());
+        dst.write_frame(&response).await?;
+
+        return Ok(());
+    }
 
     // 채널을 구독한다.
+    let rx = db.subscribe(channel_name.clone());
+    let rx = channel_messages(rx, db, channel_name.clone());
+
+    // 클라이언트의 구독 목록 안의 구독을 추적한다.
+    subscriptions.channels.insert(channel_name.clone(), rx);
+
+    // 성공적으로 구독을 마쳤음을 응답한다.
+    let response = make_subscribe_frame(channel_name, subscriptions.len());
+    dst.write_frame(&response).await?;
+
+    Ok(())
+}
+
+async fn subscribe_to_shard_channel(
+    channel_name: String,
+    subscriptions: &mut Subscriptions,
+    db: &Db,
+    dst: &mut Connection,
+) -> crate::Result<()> {
+    // 채널과 마찬가지로, 이미 구독 중인 샤드 채널은 다시 구독하지 않는다.
+    if subscriptions.shard_channels.contains_key(&channel_name) {
+        let response = make_ssubscribe_frame(channel_name, subscriptions.shard_len());
+        dst.write_frame(&response).await?;
+
+        return Ok(());
+    }
+
+    // 샤드 채널을 구독한다. 일반 채널과는 다른 키 공간의 브로드캐스트 채널을 사용한다.
+    let rx = db.subscribe_shard(channel_name.clone());
+    let rx = channel_messages(rx, db, channel_name.clone());
+
+    subscriptions.shard_channels.insert(channel_name.clone(), rx);
+
+    let response = make_ssubscribe_frame(channel_name, subscriptions.shard_len());
+    dst.write_frame(&response).await?;
+
+    Ok(())
+}
+
+/// 채널의 리시버로부터 메시지를 소비하는 스트림을 생성한다. 채널 구독과 샤드 채널 구독이 공유한다.
+fn channel_messages(mut rx: broadcast::Receiver<Bytes>, db: &Db, channel: String) -> Messages {
+    let db = db.clone();
+
+    Box::pin(async_stream::stream! {
+        loop {
+            match rx.recv().await {
+                Ok(msg) => yield msg,
+                // 메시지 소비에서 지연이 발생하면 놓친 메시지는 건너뛰고 다시 시도한다. 메시지가
+                // 조용히 사라지지 않도록 버려진 수를 로깅하고 'INFO'에 집계한다.
+                Err(broadcast::error::RecvError::Lagged(dropped)) => {
+                    warn!(%channel, dropped, "subscriber lagged behind; messages dropped");
+                    db.record_dropped_messages(dropped);
+                }
+                Err(_) => break,
+            }
+        }
+    })
+}
+
+async fn subscribe_to_pattern(
+    pattern: String,
+    subscriptions: &mut Subscriptions,
+    db: &Db,
+    dst: &mut Connection,
+) -> crate::Result<()> {
+    // 채널과 마찬가지로, 이미 구독 중인 패턴은 다시 구독하지 않는다.
+    if subscriptions.patterns.contains_key(&pattern) {
+        let response = make_psubscribe_frame(pattern, subscriptions.len());
+        dst.write_frame(&response).await?;
+
+        return Ok(());
+    }
+
+    let mut rx = db.subscribe_pattern(pattern.clone());
+    let db = db.clone();
+    let subscribed = pattern.clone();
+
+    // 패턴을 구독한다.
     let rx = Box::pin(async_stream::stream! {
         loop {
             match rx.recv().await {
                 Ok(msg) => yield msg,
-                // 메시지 소비에서 지연이 발생하면 그냥 다시 시도한다.
-                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                // 채널 구독과 마찬가지로, 버려진 메시지의 수를 로깅하고 집계한 뒤 다시 시도한다.
+                Err(broadcast::error::RecvError::Lagged(dropped)) => {
+                    warn!(
+                        pattern = %subscribed,
+                        dropped,
+                        "subscriber lagged behind; messages dropped"
+                    );
+                    db.record_dropped_messages(dropped);
+                }
                 Err(_) => break,
             }
         }
     });
 
     // 클라이언트의 구독 목록 안의 구독을 추적한다.
-    subscriptions.insert(channel_name.clone(), rx);
+    subscriptions.patterns.insert(pattern.clone(), rx);
 
     // 성공적으로 구독을 마쳤음을 응답한다.
-    let response = make_subscribe_frame(channel_name, subscriptions.len());
+    let response = make_psubscribe_frame(pattern, subscriptions.len());
     dst.write_frame(&response).await?;
 
     Ok(())
 }
 
-/// 'Subscribe::apply'에 있는 동안 수신한 커맨드를 핸들링한다. 이 시점에는 구독과 해지
-/// 커맨드만이 허용된다.
+/// 구독 상태에 있는 동안 수신한 커맨드를 핸들링한다. 이 시점에는 구독과 해지
+/// 커맨드, 'PING', 'RESET', 'QUIT'만이 허용된다.
 /// 
-/// 다른 새로운 구독은 'subscriptions'를 변경하는 대신 'subscribe_to'에 추가된다.
+/// 다른 새로운 구독은 'subscriptions'를 변경하는 대신 'subscribe_to', 'psubscribe_to',
+/// 'ssubscribe_to'에 추가된다.
+/// 'RESET'을 수신하면 응답하지 않고, 'QUIT'을 수신하면 'OK'를 응답한 뒤 이를 반환하여, 구독 루프가
+/// 종료되도록 한다.
 async fn handle_command(
     frame: Frame,
     subscribe_to: &mut Vec<String>,
-    subscriptions: &mut StreamMap<String, Messages>,
+    psubscribe_to: &mut Vec<String>,
+    ssubscribe_to: &mut Vec<String>,
+    subscriptions: &mut Subscriptions,
     dst: &mut Connection,
-) -> crate::Result<()> {
+) -> crate::Result<Option<Leave>> {
     // 클라이언트로부터 수신한 커맨드
     // 
-    // 여기서는 'SUBSCRIBE', 'UNSUBSCRIBE' 커맨드만이 허용된다.
+    // 여기서는 'SUBSCRIBE', 'UNSUBSCRIBE', 'PSUBSCRIBE', 'PUNSUBSCRIBE', 'SSUBSCRIBE',
+    // 'SUNSUBSCRIBE', 'PING', 'RESET', 'QUIT' 커맨드만이 허용된다.
     match Command::from_frame(frame)? {
         Command::Subscribe(subscribe) => {
-            // 여기서 vector에 추가한 채널을 'apply' 메서드에서 구독한다.
-            subscribe_to.extend(subscribe.channels.into_iter());
+            // 여기서 vector에 추가한 채널을 'run_subscriber'에서 구독한다.
+            subscribe_to.extend(subscribe.channels);
+        }
+        Command::PSubscribe(psubscribe) => {
+            psubscribe_to.extend(psubscribe.patterns);
+        }
+        Command::SSubscribe(ssubscribe) => {
+            ssubscribe_to.extend(ssubscribe.channels);
         }
         Command::Unsubscribe(mut unsubscribe) => {
             // 채널이 지정되지 않았다면 이 요청은 모든 채널을 구독 해지한다.
@@ -214,24 +488,79 @@ async fn handle_command(
             // vector에 위치시킨다.
             if unsubscribe.channels.is_empty() {
                 unsubscribe.channels = subscriptions
+                    .channels
                     .keys()
                     .map(|channel_name| channel_name.to_string())
                     .collect();
             }
 
             for channel_name in unsubscribe.channels {
-                subscriptions.remove(&channel_name);
+                subscriptions.unsubscribe_channel(&channel_name);
 
                 let response = make_unsubscribe_frame(channel_name, subscriptions.len());
                 dst.write_frame(&response).await?;
             }
         }
+        Command::PUnsubscribe(mut punsubscribe) => {
+            // 'UNSUBSCRIBE'와 마찬가지로, 패턴이 지정되지 않았다면 모든 패턴을 구독 해지한다.
+            if punsubscribe.patterns.is_empty() {
+                punsubscribe.patterns = subscriptions
+                    .patterns
+                    .keys()
+                    .map(|pattern| pattern.to_string())
+                    .collect();
+            }
+
+            for pattern in punsubscribe.patterns {
+                subscriptions.unsubscribe_pattern(&pattern);
+
+                let response = make_punsubscribe_frame(pattern, subscriptions.len());
+                dst.write_frame(&response).await?;
+            }
+        }
+        Command::SUnsubscribe(mut sunsubscribe) => {
+            // 샤드 채널이 지정되지 않았다면 모든 샤드 채널을 구독 해지한다. 일반 채널과 패턴의
+            // 구독은 유지한다.
+            if sunsubscribe.channels.is_empty() {
+                sunsubscribe.channels = subscriptions
+                    .shard_channels
+                    .keys()
+                    .map(|channel_name| channel_name.to_string())
+                    .collect();
+            }
+
+            for channel_name in sunsubscribe.channels {
+                subscriptions.unsubscribe_shard_channel(&channel_name);
+
+                let response = make_sunsubscribe_frame(channel_name, subscriptions.shard_len());
+                dst.write_frame(&response).await?;
+            }
+        }
+        Command::Ping(ping) => {
+            // 레디스는 구독 모드에서도 'PING'을 허용한다.
+            ping.apply(dst).await?;
+        }
+        Command::Reset(reset) => {
+            // 응답은 커넥션의 상태를 초기화한 핸들러가 쓴다.
+            return Ok(Some(Leave::Reset(reset)));
+        }
+        Command::Quit(quit) => {
+            quit.apply(dst).await?;
+            dst.flush().await?;
+
+            return Ok(Some(Leave::Quit));
+        }
         command => {
             let cmd = Unknown::new(command.get_name());
             cmd.apply(dst).await?;
         }
     }
-    Ok(())
+
+    // 'PING'과 같은 커맨드는 응답을 쓰기 버퍼에만 쓴다. 구독 상태에서는 핸들러가 flush하지
+    // 않으므로, 여기서 응답을 소켓에 쓴다.
+    dst.flush().await?;
+
+    Ok(None)
 }
 
 // 구독 요청에 대한 응답을 생성한다.
@@ -243,7 +572,7 @@ fn make_subscribe_frame(channel_name: String, num_subs: usize) -> Frame {
     let mut response = Frame::array();
     response.push_bulk(Bytes::from_static(b"subscribe"));
     response.push_bulk(Bytes::from(channel_name));
-    response.push_int(num_subs as u64);
+    response.push_int(num_subs as i64);
     response
 }
 
@@ -252,7 +581,7 @@ fn make_unsubscribe_frame(channel_name: String, num_subs: usize) -> Frame {
     let mut response = Frame::array();
     response.push_bulk(Bytes::from_static(b"unsubscribe"));
     response.push_bulk(Bytes::from(channel_name));
-    response.push_int(num_subs as u64);
+    response.push_int(num_subs as i64);
     response
 }
 
@@ -265,6 +594,62 @@ fn make_message_frame(channel_name: String, msg: Bytes) -> Frame {
     response
 }
 
+// 패턴 구독 요청에 대한 응답을 생성한다.
+fn make_psubscribe_frame(pattern: String, num_subs: usize) -> Frame {
+    let mut response = Frame::array();
+    response.push_bulk(Bytes::from_static(b"psubscribe"));
+    response.push_bulk(Bytes::from(pattern));
+    response.push_int(num_subs as i64);
+    response
+}
+
+// 패턴 구독 해지 요청에 대한 응답을 생성한다.
+fn make_punsubscribe_frame(pattern: String, num_subs: usize) -> Frame {
+    let mut response = Frame::array();
+    response.push_bulk(Bytes::from_static(b"punsubscribe"));
+    response.push_bulk(Bytes::from(pattern));
+    response.push_int(num_subs as i64);
+    response
+}
+
+// 클라이언트에게, 구독 중인 패턴과 매칭되는 채널에서 메시지가 수신되었음을 알리는 메시지를
+// 생성한다. 어느 패턴으로 수신되었는지와, 메시지가 발행된 채널을 함께 전달한다.
+fn make_pmessage_frame(pattern: String, channel_name: String, msg: Bytes) -> Frame {
+    let mut response = Frame::array();
+    response.push_bulk(Bytes::from_static(b"pmessage"));
+    response.push_bulk(Bytes::from(pattern));
+    response.push_bulk(Bytes::from(channel_name));
+    response.push_bulk(msg);
+    response
+}
+
+// 샤드 채널 구독 요청에 대한 응답을 생성한다.
+fn make_ssubscribe_frame(channel_name: String, num_subs: usize) -> Frame {
+    let mut response = Frame::array();
+    response.push_bulk(Bytes::from_static(b"ssubscribe"));
+    response.push_bulk(Bytes::from(channel_name));
+    response.push_int(num_subs as i64);
+    response
+}
+
+// 샤드 채널 구독 해지 요청에 대한 응답을 생성한다.
+fn make_sunsubscribe_frame(channel_name: String, num_subs: usize) -> Frame {
+    let mut response = Frame::array();
+    response.push_bulk(Bytes::from_static(b"sunsubscribe"));
+    response.push_bulk(Bytes::from(channel_name));
+    response.push_int(num_subs as i64);
+    response
+}
+
+// 클라이언트에게, 구독 중인 샤드 채널에서 메시지가 수신되었음을 알리는 메시지를 생성한다.
+fn make_smessage_frame(channel_name: String, msg: Bytes) -> Frame {
+    let mut response = Frame::array();
+    response.push_bulk(Bytes::from_static(b"smessage"));
+    response.push_bulk(Bytes::from(channel_name));
+    response.push_bulk(msg);
+    response
+}
+
 impl Unsubscribe {
     // 주어진 'channels'로 새로운 'Unsubscribe'를 생성한다.
     pub(crate) fn new(channels: &[String]) -> Unsubscribe {
@@ -327,4 +712,237 @@ impl Unsubscribe {
 
         frame
     }
-}
\ No newline at end of file
+}
+impl PSubscribe {
+    // 특정 패턴을 수신하기 위한 새로운 'PSubscribe'를 생성한다.
+    pub(crate) fn new(patterns: &[String]) -> PSubscribe {
+        PSubscribe {
+            patterns: patterns.to_vec(),
+        }
+    }
+
+    /// 수신한 프레임으로부터 'PSubscribe' 인스턴스를 파싱한다.
+    /// 
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    /// 
+    /// 'PSUBSCRIBE' 문자열은 이미 소비되었다.
+    /// 
+    /// # Returns
+    /// 
+    /// 성공의 경우 'PSubscribe' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    /// 
+    /// # Format
+    /// 
+    /// 두 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    /// 
+    /// ```text
+    /// PSUBSCRIBE pattern [pattern ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<PSubscribe> {
+        use ParseError::EndOfStream;
+
+        // 최소 하나의 패턴이 필요하다. 패턴이 없다면 잘못된 프레임이며, 에러를 반환한다.
+        let mut patterns = vec![parse.next_string()?];
+
+        loop {
+            match parse.next_string() {
+                Ok(s) => patterns.push(s),
+                // 'EndOfStream'은 더이상 파싱할 데이터가 남아있지 않음을 나타낸다.
+                Err(EndOfStream) => break,
+                // 이 외의 다른 모든 값은 에러가 되고, 커넥션을 중단한다.
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(PSubscribe { patterns })
+    }
+
+    // 'PSubscribe' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    // 
+    // 'Subscribe::apply'와 마찬가지로 커넥션은 구독 상태가 되며, 'RESET'이나 'QUIT'을 수신하면 이를
+    // 반환한다.
+    // 구독 상태에서는 채널 구독과 패턴 구독을 함께 사용할 수 있다.
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        dst: &mut Connection,
+        shutdown: &mut Shutdown,
+    ) -> crate::Result<Option<Leave>> {
+        run_subscriber(vec![], self.patterns, vec![], db, dst, shutdown).await
+    }
+
+    /// 커맨드를 'Frame'으로 변환한다.
+    /// 
+    /// 이 함수는 'PSubscribe' 커맨드를 인코딩하여 서버로 전송하는 시점에 클라이언트로부터 
+    /// 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("psubscribe".as_bytes()));
+        for pattern in self.patterns {
+            frame.push_bulk(Bytes::from(pattern.into_bytes()));
+        }
+        frame
+    }
+}
+
+impl PUnsubscribe {
+    // 주어진 'patterns'로 새로운 'PUnsubscribe'를 생성한다.
+    pub(crate) fn new(patterns: &[String]) -> PUnsubscribe {
+        PUnsubscribe {
+            patterns: patterns.to_vec(),
+        }
+    }
+
+    /// 수신한 프레임으로부터 'PUnsubscribe' 인스턴스를 파싱한다.
+    /// 
+    /// 'PUNSUBSCRIBE' 문자열은 이미 소비되었다.
+    /// 
+    /// # Format
+    /// 
+    /// ```text
+    /// PUNSUBSCRIBE [pattern [pattern ...]]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> Result<PUnsubscribe, ParseError> {
+        use ParseError::EndOfStream;
+
+        // 패턴 목록이 비어있을 수 있기에, 빈 vec로 시작한다.
+        let mut patterns = vec![];
+
+        loop {
+            match parse.next_string() {
+                Ok(s) => patterns.push(s),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(PUnsubscribe { patterns })
+    }
+
+    /// 커맨드를 'Frame'으로 변환한다.
+    /// 
+    /// 이 함수는 'PUnsubscribe' 커맨드를 인코딩하여 서버로 전송하는 시점에 클라이언트로부터
+    /// 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("punsubscribe".as_bytes()));
+
+        for pattern in self.patterns {
+            frame.push_bulk(Bytes::from(pattern.into_bytes()));
+        }
+
+        frame
+    }
+}
+
+impl SSubscribe {
+    // 특정 샤드 채널을 수신하기 위한 새로운 'SSubscribe'를 생성한다.
+    pub(crate) fn new(channels: &[String]) -> SSubscribe {
+        SSubscribe {
+            channels: channels.to_vec(),
+        }
+    }
+
+    /// 수신한 프레임으로부터 'SSubscribe' 인스턴스를 파싱한다.
+    /// 
+    /// 'SSUBSCRIBE' 문자열은 이미 소비되었다.
+    /// 
+    /// # Format
+    /// 
+    /// ```text
+    /// SSUBSCRIBE shardchannel [shardchannel ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<SSubscribe> {
+        use ParseError::EndOfStream;
+
+        // 최소 하나의 채널이 필요하다.
+        let mut channels = vec![parse.next_string()?];
+
+        loop {
+            match parse.next_string() {
+                Ok(s) => channels.push(s),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(SSubscribe { channels })
+    }
+
+    // 'SSubscribe' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    // 
+    // 'Subscribe::apply'와 마찬가지로 커넥션은 구독 상태가 되며, 'RESET'이나 'QUIT'을 수신하면 이를
+    // 반환한다. 구독 상태에서는 일반 채널, 패턴, 샤드 채널 구독을 함께 사용할 수 있다.
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        dst: &mut Connection,
+        shutdown: &mut Shutdown,
+    ) -> crate::Result<Option<Leave>> {
+        run_subscriber(vec![], vec![], self.channels, db, dst, shutdown).await
+    }
+
+    /// 커맨드를 'Frame'으로 변환한다.
+    /// 
+    /// 이 함수는 'SSubscribe' 커맨드를 인코딩하여 서버로 전송하는 시점에 클라이언트로부터
+    /// 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("ssubscribe".as_bytes()));
+        for channel in self.channels {
+            frame.push_bulk(Bytes::from(channel.into_bytes()));
+        }
+        frame
+    }
+}
+
+impl SUnsubscribe {
+    // 주어진 'channels'로 새로운 'SUnsubscribe'를 생성한다.
+    pub(crate) fn new(channels: &[String]) -> SUnsubscribe {
+        SUnsubscribe {
+            channels: channels.to_vec(),
+        }
+    }
+
+    /// 수신한 프레임으로부터 'SUnsubscribe' 인스턴스를 파싱한다.
+    /// 
+    /// 'SUNSUBSCRIBE' 문자열은 이미 소비되었다.
+    /// 
+    /// # Format
+    /// 
+    /// ```text
+    /// SUNSUBSCRIBE [shardchannel [shardchannel ...]]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> Result<SUnsubscribe, ParseError> {
+        use ParseError::EndOfStream;
+
+        // 채널 목록이 비어있을 수 있기에, 빈 vec로 시작한다.
+        let mut channels = vec![];
+
+        loop {
+            match parse.next_string() {
+                Ok(s) => channels.push(s),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(SUnsubscribe { channels })
+    }
+
+    /// 커맨드를 'Frame'으로 변환한다.
+    /// 
+    /// 이 함수는 'SUnsubscribe' 커맨드를 인코딩하여 서버로 전송하는 시점에 클라이언트로부터
+    /// 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("sunsubscribe".as_bytes()));
+
+        for channel in self.channels {
+            frame.push_bulk(Bytes::from(channel.into_bytes()));
+        }
+
+        frame
+    }
+}
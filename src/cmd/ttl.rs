@@ -0,0 +1,102 @@
+use crate::{Connection, Db, Frame, Parse};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 키의 남은 만료 시간을 가져온다.
+///
+/// 'TTL'은 남은 시간을 초 단위로, 'PTTL'은 밀리초 단위로 응답한다. 두 커맨드는 모두 'Ttl'로
+/// 파싱된다.
+///
+/// 레디스의 관례에 따라, 키가 존재하지 않으면 -2를, 키가 존재하지만 만료 시간이 없다면 -1을
+/// 응답한다.
+#[derive(Debug)]
+pub struct Ttl {
+    /// 만료 시간을 가져올 키
+    key: String,
+
+    /// 'true'이면 남은 시간을 밀리초 단위로 응답한다 ('PTTL').
+    millis: bool,
+}
+
+impl Ttl {
+    /// 'key'의 남은 만료 시간을 가져오는 새로운 'Ttl' 커맨드를 생성한다.
+    ///
+    /// 'millis'가 'true'이면 남은 시간을 밀리초 단위로 가져온다.
+    pub fn new(key: impl ToString, millis: bool) -> Ttl {
+        Ttl {
+            key: key.to_string(),
+            millis,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 커맨드 이름을 반환한다.
+    pub(crate) fn get_name(&self) -> &str {
+        if self.millis {
+            "pttl"
+        } else {
+            "ttl"
+        }
+    }
+
+    /// 수신한 프레임으로부터 'Ttl' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'TTL' 혹은 'PTTL' 문자열은 이미 소비되었다. 'millis'는 어느 커맨드인지를 나타낸다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Ttl' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// TTL key
+    /// PTTL key
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse, millis: bool) -> crate::Result<Ttl> {
+        let key = parse.next_string()?;
+
+        Ok(Ttl { key, millis })
+    }
+
+    /// 'Ttl' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.ttl(&self.key) {
+            // 키가 존재하지 않는다.
+            None => Frame::Integer(-2),
+            // 키가 존재하지만 만료 시간이 없다.
+            Some(None) => Frame::Integer(-1),
+            // 남은 시간을 요청된 단위로 응답한다. 초 단위의 경우 가장 가까운 초로 반올림한다.
+            Some(Some(remaining)) if self.millis => Frame::Integer(remaining.as_millis() as i64),
+            Some(Some(remaining)) => Frame::Integer(((remaining.as_millis() + 500) / 1000) as i64),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Ttl'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from(self.get_name().as_bytes().to_vec()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame
+    }
+}
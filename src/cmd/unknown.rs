@@ -24,12 +24,36 @@ impl Unknown {
     /// 클라이언트에게 '이 커맨드는 알 수 없음' 을 알린다.
     /// 
     /// 이것은 주로 커맨드가 'mini-redis'에서 아직 구현되지 않았음을 의미한다.
+    #[instrument(skip(self, dst))]
     pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
         let response = Frame::Error(format!("ERR unknown command '{}'", self.command_name));
 
         debug!(?response);
 
-        dst.write_frame(&response).await?;
+        dst.write_frame_buffered(&response).await?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn replies_with_error() {
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut connection = Connection::boxed(client);
+
+        let cmd = Unknown::new("foo");
+        assert_eq!(cmd.get_name(), "foo");
+        cmd.apply(&mut connection).await.unwrap();
+        connection.flush().await.unwrap();
+        drop(connection);
+
+        let mut buf = vec![];
+        server.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, b"-ERR unknown command 'foo'\r\n");
+    }
+}
@@ -0,0 +1,95 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 하나 혹은 둘 이상의 키를 삭제한다.
+///
+/// 'DEL'과 같이 동작하지만, 삭제된 값의 메모리는 키 공간의 락을 해제한 뒤에 회수한다. 존재하지 않는
+/// 키는 무시한다. 응답으로 실제로 삭제된 키의 수를 반환한다.
+#[derive(Debug)]
+pub struct Unlink {
+    /// 삭제할 키 목록
+    keys: Vec<String>,
+}
+
+impl Unlink {
+    /// 'keys'를 삭제하는 새로운 'Unlink' 커맨드를 생성한다.
+    pub fn new(keys: &[impl ToString]) -> Unlink {
+        Unlink {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+
+    /// 삭제할 키 목록을 가져온다.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// 수신한 프레임으로부터 'Unlink' 인스턴스를 파싱한다.
+    ///
+    /// 'Parse' 아규먼트는 'Frame'의 필드를 읽기 위한 커서 방식의 API를 제공한다.
+    /// 이 함수의 호출 시점에는 프레임은 소켓으로부터 수신한 하나의 완전한 프레임이다.
+    ///
+    /// 'UNLINK' 문자열은 이미 소비되었다.
+    ///
+    /// # Returns
+    ///
+    /// 성공의 경우 'Unlink' 값을 반환한다. 프레임의 형태가 잘못된 경우 'Err'을 반환한다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// UNLINK key [key ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Unlink> {
+        use ParseError::EndOfStream;
+
+        // 최소 하나의 키가 필요하다. 키가 없다면 잘못된 프레임이며, 에러를 반환한다.
+        let mut keys = vec![parse.next_string()?];
+
+        // 프레임의 나머지 값들은 모두 삭제할 키가 된다.
+        loop {
+            match parse.next_string() {
+                Ok(key) => keys.push(key),
+                // 'EndOfStream'은 더이상 파싱할 데이터가 남아있지 않음을 나타낸다.
+                Err(EndOfStream) => break,
+                // 이 외의 다른 모든 에러는 커넥션을 중단한다.
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(Unlink { keys })
+    }
+
+    /// 'Unlink' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        // 공유 데이터베이스 상태에서 키를 삭제한다. 실제로 삭제된 키의 수를 반환받는다.
+        let num_deleted = db.unlink(&self.keys);
+
+        // 삭제된 키의 수를 응답한다.
+        let response = Frame::Integer(num_deleted as i64);
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Unlink'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("unlink".as_bytes()));
+        for key in self.keys {
+            frame.push_bulk(Bytes::from(key.into_bytes()));
+        }
+        frame
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Connection, Frame, Parse};
+
+use bytes::Bytes;
+use std::time::Duration;
+use tracing::{debug, instrument};
+
+/// 이전의 쓰기 커맨드들이 'numreplicas'개의 복제본에 전달될 때까지 기다리고, 전달을 확인한 복제본의
+/// 수를 응답한다.
+///
+/// 'mini-redis'는 복제를 지원하지 않으므로 확인할 복제본이 없다. 기다리지 않고 바로 0을 응답하며,
+/// 따라서 'timeout'보다 오래 커넥션을 붙잡아두지 않는다. 'WAIT'을 보내는 클라이언트가 에러 없이
+/// 동작할 수 있도록 커맨드를 받아들인다.
+#[derive(Debug)]
+pub struct Wait {
+    /// 전달을 확인해야 하는 복제본의 수
+    numreplicas: u64,
+
+    /// 최대로 기다리는 시간. 0이면 무한히 기다린다.
+    timeout: Duration,
+}
+
+impl Wait {
+    /// 'numreplicas'개의 복제본을 최대 'timeout' 동안 기다리는 새로운 'Wait' 커맨드를 생성한다.
+    pub fn new(numreplicas: u64, timeout: Duration) -> Wait {
+        Wait {
+            numreplicas,
+            timeout,
+        }
+    }
+
+    /// 전달을 확인해야 하는 복제본의 수를 가져온다.
+    pub fn numreplicas(&self) -> u64 {
+        self.numreplicas
+    }
+
+    /// 최대로 기다리는 시간을 가져온다.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// 수신한 프레임으로부터 'Wait' 인스턴스를 파싱한다.
+    ///
+    /// 'WAIT' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다. 'timeout'은 밀리초 단위이다.
+    ///
+    /// ```text
+    /// WAIT numreplicas timeout
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Wait> {
+        let numreplicas = parse.next_int()?;
+
+        let timeout = parse.next_signed_int()?;
+        if timeout < 0 {
+            return Err("ERR timeout is negative".into());
+        }
+
+        Ok(Wait {
+            numreplicas,
+            timeout: Duration::from_millis(timeout as u64),
+        })
+    }
+
+    /// 'Wait' 커맨드를 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, dst))]
+    pub(crate) async fn apply(self, dst: &mut Connection) -> crate::Result<()> {
+        // 복제본이 없으므로 전달을 확인한 복제본도 없다.
+        let response = Frame::Integer(0);
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Wait'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("wait".as_bytes()));
+        frame.push_int(self.numreplicas as i64);
+        frame.push_int(self.timeout.as_millis() as i64);
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn replies_with_zero() {
+        let mut parse = Parse::new(Wait::new(1, Duration::from_millis(500)).into_frame()).unwrap();
+        assert_eq!(parse.next_string().unwrap(), "wait");
+        let wait = Wait::parse_frames(&mut parse).unwrap();
+        parse.finish().unwrap();
+
+        assert_eq!(wait.numreplicas(), 1);
+        assert_eq!(wait.timeout(), Duration::from_millis(500));
+
+        let (client, server) = tokio::io::duplex(64);
+        let mut connection = Connection::boxed(client);
+        let mut peer = Connection::new(server);
+
+        wait.apply(&mut connection).await.unwrap();
+        connection.flush().await.unwrap();
+        assert!(matches!(peer.read_frame().await.unwrap(), Some(Frame::Integer(0))));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use tracing::{debug, instrument};
+
+/// 하나 혹은 둘 이상의 키의 변경을 감시한다.
+///
+/// 감시를 시작한 뒤 'EXEC' 전에 감시하는 키가 변경되면, 'EXEC'는 트랜잭션을 수행하지 않고 nil을
+/// 응답한다. 키의 삭제와 만료도 변경으로 간주한다. 감시는 'EXEC', 'DISCARD', 'UNWATCH'로 해제된다.
+///
+/// 트랜잭션 안에서는 사용할 수 없다.
+#[derive(Debug)]
+pub struct Watch {
+    /// 감시할 키 목록
+    keys: Vec<String>,
+}
+
+/// 커넥션이 감시하는 모든 키의 감시를 해제한다.
+#[derive(Debug, Default)]
+pub struct Unwatch {}
+
+/// 커넥션이 'WATCH'로 감시하는 키들
+///
+/// 각 키를 감시를 시작한 시점의 데이터베이스와 버전과 함께 보관한다. 트랜잭션과 마찬가지로 커넥션별
+/// 상태이므로 핸들러가 보관한다.
+#[derive(Default)]
+pub(crate) struct WatchedKeys {
+    /// 키가 속한 데이터베이스, 키, 감시를 시작한 시점의 버전
+    keys: Vec<(Db, String, Option<u64>)>,
+}
+
+impl Watch {
+    /// 'keys'를 감시하는 새로운 'Watch' 커맨드를 생성한다.
+    pub fn new(keys: &[impl ToString]) -> Watch {
+        Watch {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+
+    /// 감시할 키 목록을 가져온다.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// 수신한 프레임으로부터 'Watch' 인스턴스를 파싱한다.
+    ///
+    /// 'WATCH' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 두 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// WATCH key [key ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<Watch> {
+        use ParseError::EndOfStream;
+
+        // 최소 하나의 키가 필요하다.
+        let mut keys = vec![parse.next_string()?];
+
+        loop {
+            match parse.next_string() {
+                Ok(key) => keys.push(key),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(Watch { keys })
+    }
+
+    /// 'Watch' 커맨드를 수행한다.
+    ///
+    /// 감시하는 키는 커넥션별 상태이므로, 'Command::apply'를 거치지 않고 핸들러가 직접 이 함수를
+    /// 호출한다. 'db'는 커넥션이 선택한 데이터베이스이며, 키의 현재 버전이 'watched'에 기록된다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, db, watched, dst))]
+    pub(crate) async fn apply(
+        self,
+        db: &Db,
+        watched: &mut WatchedKeys,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        for key in self.keys {
+            let version = db.version(&key);
+            watched.keys.push((db.clone(), key, version));
+        }
+
+        let response = Frame::Simple("OK".to_string());
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Watch'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("watch".as_bytes()));
+        for key in self.keys {
+            frame.push_bulk(Bytes::from(key.into_bytes()));
+        }
+        frame
+    }
+}
+
+impl Unwatch {
+    /// 새로운 'Unwatch' 커맨드를 생성한다.
+    pub fn new() -> Unwatch {
+        Unwatch {}
+    }
+
+    /// 수신한 프레임으로부터 'Unwatch' 인스턴스를 파싱한다.
+    ///
+    /// 'UNWATCH' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 한 앤트리만을 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// UNWATCH
+    /// ```
+    pub(crate) fn parse_frames(_parse: &mut Parse) -> crate::Result<Unwatch> {
+        Ok(Unwatch {})
+    }
+
+    /// 'Unwatch' 커맨드를 수행한다.
+    ///
+    /// 'WATCH'와 마찬가지로 핸들러가 직접 이 함수를 호출한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다.
+    #[instrument(skip(self, watched, dst))]
+    pub(crate) async fn apply(
+        self,
+        watched: &mut WatchedKeys,
+        dst: &mut Connection,
+    ) -> crate::Result<()> {
+        watched.clear();
+
+        let response = Frame::Simple("OK".to_string());
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'Unwatch'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("unwatch".as_bytes()));
+        frame
+    }
+}
+
+impl WatchedKeys {
+    /// 감시를 시작한 뒤 변경된 키가 있다면 'true'를 반환한다.
+    pub(crate) fn is_modified(&self) -> bool {
+        self.keys
+            .iter()
+            .any(|(db, key, version)| db.version(key) != *version)
+    }
+
+    /// 모든 키의 감시를 해제한다.
+    pub(crate) fn clear(&mut self) {
+        self.keys.clear();
+    }
+}
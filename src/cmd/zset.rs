@@ -0,0 +1,557 @@
+//! 정렬된 셋 커맨드.
+//!
+//! 정렬된 셋의 멤버는 점수의 오름차순으로, 점수가 같다면 멤버의 사전순으로 정렬된다. 위치를 다루는
+//! 커맨드는 이 순서를 따른다.
+
+use crate::cmd::{Parse, ParseError};
+use crate::{Connection, Db, Frame};
+
+use bytes::Bytes;
+use std::ops::Bound;
+use tracing::{debug, instrument};
+
+/// 정렬된 셋에 하나 이상의 멤버를 점수와 함께 추가한다.
+///
+/// 이미 존재하는 멤버는 점수를 갱신하며, 갱신된 점수에 맞는 위치로 옮겨진다. 키가 존재하지 않으면
+/// 새로운 정렬된 셋을 생성한다. 응답으로 새로 추가된 멤버의 수를 반환한다.
+#[derive(Debug)]
+pub struct ZAdd {
+    /// 정렬된 셋의 키
+    key: String,
+
+    /// 추가할 점수와 멤버의 쌍 목록
+    pairs: Vec<(f64, Bytes)>,
+}
+
+/// 정렬된 셋에서 멤버의 점수를 반환한다. 키나 멤버가 존재하지 않으면 nil을 반환한다.
+#[derive(Debug)]
+pub struct ZScore {
+    /// 정렬된 셋의 키
+    key: String,
+
+    /// 점수를 가져올 멤버
+    member: Bytes,
+}
+
+/// 정렬된 셋의 'start'부터 'stop'까지 위치의 멤버를 반환한다.
+///
+/// 'start'와 'stop'은 양 끝을 포함하며, 'LRANGE'와 같이 음수는 끝에서부터의 위치를 나타낸다.
+/// 'WITHSCORES'가 주어지면 각 멤버 뒤에 점수를 함께 응답한다.
+#[derive(Debug)]
+pub struct ZRange {
+    /// 정렬된 셋의 키
+    key: String,
+
+    /// 범위의 시작 위치
+    start: i64,
+
+    /// 범위의 끝 위치. 범위에 포함된다.
+    stop: i64,
+
+    /// 'true'이면 멤버와 함께 점수를 응답한다.
+    withscores: bool,
+}
+
+/// 정렬된 셋에서 점수가 'min'과 'max' 사이인 멤버를 정렬된 순서로 반환한다.
+///
+/// 범위는 기본적으로 양 끝을 포함하며, '(5'와 같이 '('로 시작하는 끝은 포함하지 않는다. '-inf'와
+/// '+inf'로 범위의 한쪽을 열어둘 수 있다. 'LIMIT offset count'가 주어지면 범위 안의 멤버 중
+/// 'offset'개를 건너뛰고 최대 'count'개를 반환한다.
+#[derive(Debug)]
+pub struct ZRangeByScore {
+    /// 정렬된 셋의 키
+    key: String,
+
+    /// 점수의 하한
+    min: Bound<f64>,
+
+    /// 점수의 상한
+    max: Bound<f64>,
+
+    /// 'true'이면 멤버와 함께 점수를 응답한다.
+    withscores: bool,
+
+    /// 건너뛸 멤버의 수와 반환할 멤버의 최대 수
+    limit: Option<(i64, i64)>,
+}
+
+/// 정렬된 셋에서 멤버의 위치(0부터 시작)를 반환한다. 키나 멤버가 존재하지 않으면 nil을 반환한다.
+#[derive(Debug)]
+pub struct ZRank {
+    /// 정렬된 셋의 키
+    key: String,
+
+    /// 위치를 가져올 멤버
+    member: Bytes,
+}
+
+/// 점수를 파싱한다. 레디스와 같이 'inf', '+inf', '-inf'를 받아들이며, NaN은 받아들이지 않는다.
+fn parse_score(score: &str) -> crate::Result<f64> {
+    match score.parse::<f64>() {
+        Ok(score) if !score.is_nan() => Ok(score),
+        _ => Err("ERR value is not a valid float".into()),
+    }
+}
+
+/// 'ZRANGEBYSCORE'의 점수 범위의 한쪽 끝을 파싱한다.
+///
+/// '('로 시작하면 끝을 포함하지 않는다. '-inf'와 '+inf'는 무한대로 파싱되므로 모든 점수를 포함한다.
+fn parse_bound(bound: &str) -> crate::Result<Bound<f64>> {
+    const MSG: &str = "ERR min or max is not a float";
+
+    let (score, exclusive) = match bound.strip_prefix('(') {
+        Some(score) => (score, true),
+        None => (bound, false),
+    };
+
+    match score.parse::<f64>() {
+        Ok(score) if score.is_nan() => Err(MSG.into()),
+        Ok(score) if exclusive => Ok(Bound::Excluded(score)),
+        Ok(score) => Ok(Bound::Included(score)),
+        Err(_) => Err(MSG.into()),
+    }
+}
+
+/// 점수 범위의 한쪽 끝을 'ZRANGEBYSCORE'의 아규먼트로 변환한다. 'parse_bound'의 반대이다.
+///
+/// 열린 끝은 하한이라면 '-inf'로, 상한이라면 '+inf'로 변환한다.
+fn bound_to_bytes(bound: Bound<f64>, lower: bool) -> Bytes {
+    let bound = match bound {
+        Bound::Included(score) => score.to_string(),
+        Bound::Excluded(score) => format!("({}", score),
+        Bound::Unbounded if lower => "-inf".to_string(),
+        Bound::Unbounded => "+inf".to_string(),
+    };
+
+    Bytes::from(bound.into_bytes())
+}
+
+impl ZAdd {
+    /// 'key'의 정렬된 셋에 점수와 멤버의 쌍 'pairs'를 추가하는 새로운 'ZAdd' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, pairs: Vec<(f64, Bytes)>) -> ZAdd {
+        ZAdd {
+            key: key.to_string(),
+            pairs,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'ZAdd' 인스턴스를 파싱한다.
+    ///
+    /// 'ZADD' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// ZADD key score member [score member ...]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<ZAdd> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+
+        // 최소 하나의 쌍이 필요하다.
+        let score = parse_score(&parse.next_string()?)?;
+        let mut pairs = vec![(score, parse.next_bytes()?)];
+
+        loop {
+            let score = match parse.next_string() {
+                Ok(score) => parse_score(&score)?,
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            };
+
+            pairs.push((score, parse.next_bytes()?));
+        }
+
+        Ok(ZAdd { key, pairs })
+    }
+
+    /// 'ZAdd' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.zadd(&self.key, self.pairs) {
+            Ok(added) => Frame::Integer(added as i64),
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ZAdd'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("zadd".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        for (score, member) in self.pairs {
+            frame.push_bulk(Bytes::from(score.to_string().into_bytes()));
+            frame.push_bulk(member);
+        }
+        frame
+    }
+}
+
+impl ZScore {
+    /// 'key'의 정렬된 셋에서 'member'의 점수를 가져오는 새로운 'ZScore' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, member: Bytes) -> ZScore {
+        ZScore {
+            key: key.to_string(),
+            member,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 멤버를 가져온다.
+    pub fn member(&self) -> &Bytes {
+        &self.member
+    }
+
+    /// 수신한 프레임으로부터 'ZScore' 인스턴스를 파싱한다.
+    ///
+    /// 'ZSCORE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// ZSCORE key member
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<ZScore> {
+        let key = parse.next_string()?;
+        let member = parse.next_bytes()?;
+
+        Ok(ZScore { key, member })
+    }
+
+    /// 'ZScore' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.zscore(&self.key, &self.member) {
+            // RESP2 커넥션에서는 벌크 문자열로 인코딩된다.
+            Ok(Some(score)) => Frame::Double(score),
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ZScore'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("zscore".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(self.member);
+        frame
+    }
+}
+
+impl ZRange {
+    /// 'key'의 정렬된 셋에서 'start'부터 'stop'까지 위치의 멤버를 가져오는 새로운 'ZRange' 커맨드를
+    /// 생성한다. 'withscores'가 'true'이면 점수를 함께 가져온다.
+    pub fn new(key: impl ToString, start: i64, stop: i64, withscores: bool) -> ZRange {
+        ZRange {
+            key: key.to_string(),
+            start,
+            stop,
+            withscores,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'ZRange' 인스턴스를 파싱한다.
+    ///
+    /// 'ZRANGE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 개 혹은 다섯 개의 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// ZRANGE key start stop [WITHSCORES]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<ZRange> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+        let start = parse.next_signed_int()?;
+        let stop = parse.next_signed_int()?;
+
+        let withscores = match parse.next_string() {
+            Ok(option) if option.to_uppercase() == "WITHSCORES" => true,
+            Ok(_) => return Err("ERR syntax error".into()),
+            Err(EndOfStream) => false,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(ZRange {
+            key,
+            start,
+            stop,
+            withscores,
+        })
+    }
+
+    /// 'ZRange' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.zrange(&self.key, self.start, self.stop) {
+            Ok(members) => {
+                let mut response = vec![];
+
+                // 'WITHSCORES'가 주어지면 멤버와 점수를 번갈아 응답한다.
+                for (member, score) in members {
+                    response.push(Frame::Bulk(member));
+                    if self.withscores {
+                        response.push(Frame::Double(score));
+                    }
+                }
+
+                Frame::Array(response)
+            }
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ZRange'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("zrange".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_int(self.start);
+        frame.push_int(self.stop);
+        if self.withscores {
+            frame.push_bulk(Bytes::from("withscores".as_bytes()));
+        }
+        frame
+    }
+}
+
+impl ZRangeByScore {
+    /// 'key'의 정렬된 셋에서 점수가 'min'과 'max' 사이인 멤버를 가져오는 새로운 'ZRangeByScore'
+    /// 커맨드를 생성한다.
+    ///
+    /// 'withscores'가 'true'이면 점수를 함께 가져온다. 'limit'은 건너뛸 멤버의 수와 가져올 멤버의
+    /// 최대 수이다.
+    pub fn new(
+        key: impl ToString,
+        min: Bound<f64>,
+        max: Bound<f64>,
+        withscores: bool,
+        limit: Option<(i64, i64)>,
+    ) -> ZRangeByScore {
+        ZRangeByScore {
+            key: key.to_string(),
+            min,
+            max,
+            withscores,
+            limit,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 수신한 프레임으로부터 'ZRangeByScore' 인스턴스를 파싱한다.
+    ///
+    /// 'ZRANGEBYSCORE' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 네 개 이상의 앤트리를 포함하는 배열 프레임이 되어야 한다. 옵션은 순서에 상관없이 지정될 수
+    /// 있다.
+    ///
+    /// ```text
+    /// ZRANGEBYSCORE key min max [WITHSCORES] [LIMIT offset count]
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<ZRangeByScore> {
+        use ParseError::EndOfStream;
+
+        let key = parse.next_string()?;
+        let min = parse_bound(&parse.next_string()?)?;
+        let max = parse_bound(&parse.next_string()?)?;
+
+        let mut withscores = false;
+        let mut limit = None;
+
+        loop {
+            let option = match parse.next_string() {
+                Ok(option) => option.to_uppercase(),
+                Err(EndOfStream) => break,
+                Err(err) => return Err(err.into()),
+            };
+
+            match &option[..] {
+                "WITHSCORES" => withscores = true,
+                "LIMIT" => {
+                    let offset = parse.next_signed_int()?;
+                    let count = parse.next_signed_int()?;
+                    limit = Some((offset, count));
+                }
+                _ => return Err("ERR syntax error".into()),
+            }
+        }
+
+        Ok(ZRangeByScore {
+            key,
+            min,
+            max,
+            withscores,
+            limit,
+        })
+    }
+
+    /// 'ZRangeByScore' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.zrange_by_score(&self.key, self.min, self.max, self.limit) {
+            Ok(members) => {
+                let mut response = vec![];
+
+                // 'ZRANGE'와 같이 'WITHSCORES'가 주어지면 멤버와 점수를 번갈아 응답한다.
+                for (member, score) in members {
+                    response.push(Frame::Bulk(member));
+                    if self.withscores {
+                        response.push(Frame::Double(score));
+                    }
+                }
+
+                Frame::Array(response)
+            }
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ZRangeByScore'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("zrangebyscore".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(bound_to_bytes(self.min, true));
+        frame.push_bulk(bound_to_bytes(self.max, false));
+        if self.withscores {
+            frame.push_bulk(Bytes::from("withscores".as_bytes()));
+        }
+        if let Some((offset, count)) = self.limit {
+            frame.push_bulk(Bytes::from("limit".as_bytes()));
+            frame.push_int(offset);
+            frame.push_int(count);
+        }
+        frame
+    }
+}
+
+impl ZRank {
+    /// 'key'의 정렬된 셋에서 'member'의 위치를 가져오는 새로운 'ZRank' 커맨드를 생성한다.
+    pub fn new(key: impl ToString, member: Bytes) -> ZRank {
+        ZRank {
+            key: key.to_string(),
+            member,
+        }
+    }
+
+    /// 키를 가져온다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 멤버를 가져온다.
+    pub fn member(&self) -> &Bytes {
+        &self.member
+    }
+
+    /// 수신한 프레임으로부터 'ZRank' 인스턴스를 파싱한다.
+    ///
+    /// 'ZRANK' 문자열은 이미 소비되었다.
+    ///
+    /// # Format
+    ///
+    /// 세 앤트리를 포함하는 배열 프레임이 되어야 한다.
+    ///
+    /// ```text
+    /// ZRANK key member
+    /// ```
+    pub(crate) fn parse_frames(parse: &mut Parse) -> crate::Result<ZRank> {
+        let key = parse.next_string()?;
+        let member = parse.next_bytes()?;
+
+        Ok(ZRank { key, member })
+    }
+
+    /// 'ZRank' 커맨드를 특정 'Db' 인스턴스에 수행한다.
+    ///
+    /// 응답은 'dst'에 쓰여진다. 수신한 커맨드를 실행하기 위해, 서버가 이 함수를 호출한다.
+    #[instrument(skip(self, db, dst))]
+    pub(crate) async fn apply(self, db: &Db, dst: &mut Connection) -> crate::Result<()> {
+        let response = match db.zrank(&self.key, &self.member) {
+            Ok(Some(rank)) => Frame::Integer(rank as i64),
+            Ok(None) => Frame::Null,
+            Err(err) => Frame::Error(err.to_string()),
+        };
+
+        debug!(?response);
+        dst.write_frame_buffered(&response).await?;
+
+        Ok(())
+    }
+
+    /// 커맨드를 자신에 대응하는 'Frame'으로 변환한다.
+    ///
+    /// 이 함수는 'ZRank'커맨드를 서버로 전송하기 위한 인코딩 시 클라이언트에 의해 호출된다.
+    pub(crate) fn into_frame(self) -> Frame {
+        let mut frame = Frame::array();
+        frame.push_bulk(Bytes::from("zrank".as_bytes()));
+        frame.push_bulk(Bytes::from(self.key.into_bytes()));
+        frame.push_bulk(self.member);
+        frame
+    }
+}
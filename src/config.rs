@@ -0,0 +1,343 @@
+use crate::glob;
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// 'CONFIG GET'으로 조회하고 'CONFIG SET'으로 변경할 수 있는 서버 설정.
+///
+/// 리스너와 모든 커넥션 핸들러가 'Arc'로 공유한다. 서버 가동 시의 설정('server::Config') 중 가동
+/// 중에 변경할 수 있는 값만을 담는다.
+#[derive(Debug)]
+pub(crate) struct RuntimeConfig {
+    /// 최대 커넥션 수를 제한하는 세마포어. 'Listener'의 값을 공유한다. 'maxclients'를 변경하면
+    /// permit을 추가하거나 회수한다.
+    limit_connections: Arc<Semaphore>,
+
+    /// 설정 값. 'maxclients'의 변경과 permit 반환이 서로 엇갈리지 않도록 하나의 락으로 보호한다.
+    values: Mutex<Values>,
+}
+
+#[derive(Debug)]
+struct Values {
+    /// 동시에 수용하는 최대 커넥션 수
+    maxclients: usize,
+
+    /// 사용할 수 있는 최대 메모리(바이트). 0이면 제한이 없다.
+    maxmemory: u64,
+
+    /// 메모리 사용량이 'maxmemory'를 넘었을 때 메모리를 확보하는 방식
+    maxmemory_policy: MaxMemoryPolicy,
+
+    /// 유휴 커넥션을 종료하기까지의 시간. 0이면 종료하지 않는다. 'CONFIG'로는 초 단위로 다룬다.
+    timeout: Duration,
+
+    /// 슬로우 로그에 기록할 커맨드의 처리 시간(마이크로초). 음수이면 기록하지 않고, 0이면 모든
+    /// 커맨드를 기록한다.
+    slowlog_log_slower_than: i64,
+
+    /// 슬로우 로그가 유지하는 기록의 최대 개수
+    slowlog_max_len: u64,
+
+    /// 'maxclients'를 줄였지만 아직 회수하지 못한 permit의 수
+    ///
+    /// 사용 중인 permit은 바로 회수할 수 없으므로, 커넥션이 종료되어 permit이 반환될 때 세마포어에
+    /// 돌려주지 않는 방식으로 회수한다.
+    unreclaimed_permits: usize,
+}
+
+/// 메모리 사용량이 'maxmemory'를 넘었을 때 메모리를 확보하는 방식. 'maxmemory-policy' 파라미터의
+/// 값이다.
+///
+/// 정책은 키 공간의 메모리 사용량을 늘릴 수 있는 커맨드를 수행하기 전에 적용된다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MaxMemoryPolicy {
+    /// 키를 제거하지 않는다. 메모리 사용량을 늘릴 수 있는 커맨드에는 'OOM' 에러를 응답한다. 레디스와
+    /// 같이 기본값이다.
+    #[default]
+    NoEviction,
+
+    /// 모든 데이터베이스의 키 중 가장 오래전에 접근한 키부터 제거한다.
+    AllKeysLru,
+}
+
+/// 'CONFIG'로 조회하고 변경할 수 있는 파라미터의 이름
+const PARAMETERS: &[&str] = &[
+    "maxclients",
+    "maxmemory",
+    "maxmemory-policy",
+    "slowlog-log-slower-than",
+    "slowlog-max-len",
+    "timeout",
+];
+
+impl RuntimeConfig {
+    /// 새로운 'RuntimeConfig'를 생성한다. 'limit_connections'는 'maxclients'개의 permit으로
+    /// 생성된 세마포어여야 한다.
+    ///
+    /// 'slowlog_log_slower_than'이 'None'이면 슬로우 로그에 기록하지 않는다.
+    pub(crate) fn new(
+        maxclients: usize,
+        maxmemory: u64,
+        maxmemory_policy: MaxMemoryPolicy,
+        timeout: Option<Duration>,
+        slowlog_log_slower_than: Option<Duration>,
+        slowlog_max_len: usize,
+        limit_connections: Arc<Semaphore>,
+    ) -> RuntimeConfig {
+        let slowlog_log_slower_than = slowlog_log_slower_than
+            .map(|threshold| i64::try_from(threshold.as_micros()).unwrap_or(i64::MAX))
+            .unwrap_or(-1);
+
+        RuntimeConfig {
+            limit_connections,
+            values: Mutex::new(Values {
+                maxclients,
+                maxmemory,
+                maxmemory_policy,
+                timeout: timeout.unwrap_or(Duration::ZERO),
+                slowlog_log_slower_than,
+                slowlog_max_len: slowlog_max_len as u64,
+                unreclaimed_permits: 0,
+            }),
+        }
+    }
+
+    /// 'pattern'과 매칭되는 파라미터의 이름과 값을 반환한다.
+    pub(crate) fn get(&self, pattern: &str) -> Vec<(&'static str, String)> {
+        let pattern = pattern.to_lowercase();
+        let values = self.values.lock().unwrap();
+
+        PARAMETERS
+            .iter()
+            .filter(|name| glob::matches(pattern.as_bytes(), name.as_bytes()))
+            .map(|&name| {
+                let value = match name {
+                    "maxclients" => values.maxclients.to_string(),
+                    "maxmemory" => values.maxmemory.to_string(),
+                    "maxmemory-policy" => values.maxmemory_policy.as_str().to_string(),
+                    "slowlog-log-slower-than" => values.slowlog_log_slower_than.to_string(),
+                    "slowlog-max-len" => values.slowlog_max_len.to_string(),
+                    "timeout" => values.timeout.as_secs().to_string(),
+                    _ => unreachable!(),
+                };
+
+                (name, value)
+            })
+            .collect()
+    }
+
+    /// 'name' 파라미터의 값을 'value'로 변경한다.
+    ///
+    /// 지원하지 않는 파라미터이거나 값이 유효하지 않다면 클라이언트에게 응답할 에러 메시지를 반환한다.
+    pub(crate) fn set(&self, name: &str, value: &str) -> Result<(), String> {
+        let name = name.to_lowercase();
+        if !PARAMETERS.contains(&&name[..]) {
+            return Err(format!(
+                "ERR Unknown option or number of arguments for CONFIG SET - '{}'",
+                name
+            ));
+        }
+
+        //  정수가 아닌 값을 받는 파라미터는 먼저 처리한다.
+        if name == "maxmemory-policy" {
+            let policy = value.parse().map_err(|_| {
+                format!(
+                    "ERR CONFIG SET failed (possibly related to argument '{}') - argument(s) must \
+                     be one of the following: noeviction, allkeys-lru",
+                    name
+                )
+            })?;
+
+            self.values.lock().unwrap().maxmemory_policy = policy;
+            return Ok(());
+        }
+
+        //  'slowlog-log-slower-than'은 기록을 끄는 음수를 받는다.
+        if name == "slowlog-log-slower-than" {
+            let value = value.parse().map_err(|_| {
+                format!(
+                    "ERR CONFIG SET failed (possibly related to argument '{}') - argument \
+                     couldn't be parsed into an integer",
+                    name
+                )
+            })?;
+
+            self.values.lock().unwrap().slowlog_log_slower_than = value;
+            return Ok(());
+        }
+
+        let value: u64 = value.parse().map_err(|_| {
+            format!(
+                "ERR CONFIG SET failed (possibly related to argument '{}') - argument couldn't be \
+                 parsed into an integer",
+                name
+            )
+        })?;
+
+        //  세마포어는 'Semaphore::MAX_PERMITS'보다 많은 permit을 가질 수 없다. 범위를 넘는 값은 락을
+        //  잡기 전에 거절한다.
+        if name == "maxclients" {
+            let maxclients = match usize::try_from(value) {
+                Ok(maxclients) if (1..=Semaphore::MAX_PERMITS).contains(&maxclients) => maxclients,
+                _ => {
+                    return Err(format!(
+                        "ERR CONFIG SET failed (possibly related to argument '{}') - argument \
+                         must be between 1 and {}",
+                        name,
+                        Semaphore::MAX_PERMITS
+                    ))
+                }
+            };
+
+            let mut values = self.values.lock().unwrap();
+            self.resize_connections(&mut values, maxclients);
+            return Ok(());
+        }
+
+        let mut values = self.values.lock().unwrap();
+
+        match &name[..] {
+            "maxmemory" => values.maxmemory = value,
+            "slowlog-max-len" => values.slowlog_max_len = value,
+            "timeout" => values.timeout = Duration::from_secs(value),
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /// 사용할 수 있는 최대 메모리와 이를 넘었을 때의 정책을 반환한다. 'None'이면 제한이 없다.
+    pub(crate) fn maxmemory(&self) -> Option<(u64, MaxMemoryPolicy)> {
+        let values = self.values.lock().unwrap();
+
+        if values.maxmemory == 0 {
+            None
+        } else {
+            Some((values.maxmemory, values.maxmemory_policy))
+        }
+    }
+
+    /// 유휴 커넥션을 종료하기까지의 시간을 반환한다. 'None'이면 종료하지 않는다.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        let timeout = self.values.lock().unwrap().timeout;
+
+        if timeout.is_zero() {
+            None
+        } else {
+            Some(timeout)
+        }
+    }
+
+    /// 슬로우 로그에 기록할 커맨드의 처리 시간을 반환한다. 'None'이면 기록하지 않는다.
+    pub(crate) fn slowlog_log_slower_than(&self) -> Option<Duration> {
+        let threshold = self.values.lock().unwrap().slowlog_log_slower_than;
+
+        u64::try_from(threshold).ok().map(Duration::from_micros)
+    }
+
+    /// 슬로우 로그가 유지하는 기록의 최대 개수를 반환한다.
+    pub(crate) fn slowlog_max_len(&self) -> usize {
+        let max_len = self.values.lock().unwrap().slowlog_max_len;
+
+        usize::try_from(max_len).unwrap_or(usize::MAX)
+    }
+
+    /// 커넥션 하나가 사용하던 permit을 반환한다. 핸들러가 drop될 때 호출한다.
+    ///
+    /// 'maxclients'를 줄여 회수해야 할 permit이 남아있다면, 세마포어에 돌려주지 않고 회수한다.
+    pub(crate) fn release_connection(&self) {
+        let mut values = self.values.lock().unwrap();
+
+        if values.unreclaimed_permits > 0 {
+            values.unreclaimed_permits -= 1;
+        } else {
+            self.limit_connections.add_permits(1);
+        }
+    }
+
+    /// 세마포어의 permit 수를 'maxclients'에 맞춘다.
+    ///
+    /// 늘린 경우에는 회수하지 못한 permit을 먼저 상쇄한 뒤 나머지를 추가한다. 줄인 경우에는 사용되지
+    /// 않는 permit을 바로 회수하고, 나머지는 커넥션이 종료될 때 회수한다. 이미 수립된 커넥션은
+    /// 종료하지 않는다.
+    fn resize_connections(&self, values: &mut Values, maxclients: usize) {
+        if maxclients > values.maxclients {
+            let added = maxclients - values.maxclients;
+            let offset = added.min(values.unreclaimed_permits);
+
+            values.unreclaimed_permits -= offset;
+            self.limit_connections.add_permits(added - offset);
+        } else {
+            let removed = values.maxclients - maxclients;
+            let forgotten = self.limit_connections.forget_permits(removed);
+
+            values.unreclaimed_permits += removed - forgotten;
+        }
+
+        values.maxclients = maxclients;
+    }
+}
+
+impl MaxMemoryPolicy {
+    /// 'CONFIG GET'이 응답하는 정책의 이름을 반환한다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MaxMemoryPolicy::NoEviction => "noeviction",
+            MaxMemoryPolicy::AllKeysLru => "allkeys-lru",
+        }
+    }
+}
+
+impl FromStr for MaxMemoryPolicy {
+    type Err = String;
+
+    /// 대소문자를 구분하지 않고 정책의 이름을 파싱한다.
+    fn from_str(s: &str) -> Result<MaxMemoryPolicy, String> {
+        match &s.to_lowercase()[..] {
+            "noeviction" => Ok(MaxMemoryPolicy::NoEviction),
+            "allkeys-lru" => Ok(MaxMemoryPolicy::AllKeysLru),
+            _ => Err(format!("unknown maxmemory policy '{}'", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runtime_config(maxclients: usize) -> RuntimeConfig {
+        let limit_connections = Arc::new(Semaphore::new(maxclients));
+
+        RuntimeConfig::new(
+            maxclients,
+            0,
+            MaxMemoryPolicy::NoEviction,
+            None,
+            None,
+            128,
+            limit_connections,
+        )
+    }
+
+    #[test]
+    fn maxclients_out_of_range() {
+        let config = runtime_config(10);
+
+        assert!(config.set("maxclients", "0").is_err());
+        assert!(config.set("maxclients", "18446744073709551615").is_err());
+
+        let too_many = (Semaphore::MAX_PERMITS + 1).to_string();
+        assert!(config.set("maxclients", &too_many).is_err());
+
+        // 거절된 값은 설정과 세마포어에 반영되지 않는다.
+        assert_eq!(config.get("maxclients"), [("maxclients", "10".to_string())]);
+        assert_eq!(config.limit_connections.available_permits(), 10);
+
+        let max = Semaphore::MAX_PERMITS.to_string();
+        config.set("maxclients", &max).unwrap();
+        assert_eq!(config.get("maxclients"), [("maxclients", max)]);
+    }
+}
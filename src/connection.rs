@@ -1,43 +1,138 @@
 use crate::frame::{self, Frame};
+use crate::MiniRedisError;
 
 use bytes::{Buf, BytesMut};
 use std::io::{self, Cursor};
-use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
-use tokio::net::TcpStream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufWriter};
 /// 원격 피어로부터 'Frame' 값을 송신/수신한다.
 ///
 /// 네트워크 프로토콜을 구현할 때, 프로토콜 상의 하나의 메시지는 주로 프레임이라고 하는
 /// 작은 메시지들로 구성된다. 'Connection'은 'TcpStream' 위에서 프레임들을 읽고 쓰는데에 목적이 있다.
+/// 'AsyncRead + AsyncWrite'를 구현하는 스트림이라면 'TcpStream'이 아니어도 된다(e.g. TLS 스트림,
+/// 'tokio::io::duplex').
+///
+/// 스트림 타입 'S'의 기본값은 박싱된 스트림인 'BoxStream'이다. 서버와 클라이언트는 TCP와 TLS 스트림을
+/// 같은 타입으로 다루기 위해 기본값을 사용한다.
 ///
 /// 'Connection'은 프레임을 읽기 위해 내부 버퍼를 사용한다. 완전한 하나의 프레임을 생성하기
 /// 위한 충분한 수의 바이트가 모일 때까지 버퍼를 채우다가, 버퍼가 가득 차면 'Conneciton'은 프레임을 생성하고
 /// 이를 호출자에게 반환한다.
 ///
 /// 프레임을 쓸(writing) 때는 먼저 프레임을 인코딩하여 버퍼에 쓴 뒤, 버퍼의 내용을 소켓에 쓴다.
-pub struct Connection {
+pub struct Connection<S = BoxStream> {
     /// 'TcpStream'을 'BufWriter'로 감싸 쓰기 레벨의 버퍼링을 지원한다.
     /// Tokio의 'BufWriter' 구현체는 이 프로그램의 요구사항을 만족시키기에 충분하다.
-    stream: BufWriter<TcpStream>,
+    stream: BufWriter<S>,
 
     /// 프레임 읽기에 사용될 버퍼.
     buffer: BytesMut,
+
+    /// 커넥션이 사용하는 프로토콜 버전. 'HELLO' 커맨드로 변경할 수 있다.
+    version: ProtocolVersion,
+
+    /// 읽기/쓰기 중 에러가 발생했거나 스트림이 닫혔는지 여부. 한 번 'true'가 되면 스트림의
+    /// 상태를 신뢰할 수 없으므로, 커넥션을 재사용해서는 안 된다.
+    broken: bool,
+
+    /// 읽어들일 수 있는 프레임의 최대 크기(바이트). 이보다 큰 프레임을 수신하면 에러를 반환한다.
+    max_frame_size: usize,
+}
+
+/// 'BoxStream'으로 박싱할 수 있는 스트림
+/// 
+/// 'AsyncRead + AsyncWrite + Unpin + Send'를 구현하는 모든 타입에 구현된다. 트레잇 객체로 저장하기
+/// 위해 정의한다.
+pub trait AsyncStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> AsyncStream for T {}
+
+/// 박싱된 스트림. 'Connection'의 기본 스트림 타입이다.
+pub type BoxStream = Box<dyn AsyncStream>;
+
+/// 'Connection::new'가 사용하는 읽기 버퍼의 기본 크기
+pub(crate) const DEFAULT_READ_BUFFER_CAPACITY: usize = 4 * 1024;
+
+/// 읽어들일 수 있는 프레임의 최대 크기의 기본값. 레디스의 'proto-max-bulk-len' 기본값과 같다.
+pub(crate) const DEFAULT_MAX_FRAME_SIZE: usize = 512 * 1024 * 1024;
+
+/// 레디스 프로토콜(RESP)의 버전
+/// 
+/// 커넥션은 RESP2로 시작한다. RESP3는 맵, 셋, 불리언 등 더 다양한 타입을 지원한다.
+/// RESP2 커넥션에 쓰여지는 RESP3 프레임은 대응하는 RESP2 타입으로 변환된다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolVersion {
+    Resp2,
+    Resp3,
 }
 
 impl Connection {
+    /// 'socket'을 박싱하여 기본 스트림 타입의 'Connection'을 생성한다.
+    /// 
+    /// 스트림의 실제 타입과 관계 없이 같은 'Connection' 타입을 얻는다. 'TcpStream'과 TLS 스트림을 같은
+    /// 타입으로 다뤄야 할 때 사용한다.
+    pub fn boxed<S: AsyncStream + 'static>(socket: S) -> Connection {
+        Connection::new(Box::new(socket))
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
 
     /// 'socket' 기반의 새 'Connnection'을 생성한다.
     /// 읽기/쓰기 버퍼를 초기화한다.
-    pub fn new(socket: TcpStream) -> Connection {
+    pub fn new(socket: S) -> Connection<S> {
+        // 읽기 버퍼의 기본 크기는 4KB가 된다. mini redis의 사용에 있어 이 크기는
+        // 충분하다. 하지만 실제 어플리케이션의 경우 이 값을 특정한 사용처에 맞게 조정해야 한다.
+        // 이보다 큰 사이즈의 버퍼가 더 잘 작동할 가능성이 높다.
+        Connection::with_capacity(socket, DEFAULT_READ_BUFFER_CAPACITY)
+    }
+
+    /// 'socket' 기반의 새 'Connnection'을 읽기 버퍼의 초기 크기를 지정하여 생성한다.
+    /// 
+    /// 큰 벌크 값을 주로 다루는 경우 버퍼를 크게 지정하여 'read_buf' 호출 횟수를 줄일 수 있다.
+    /// 버퍼는 필요에 따라 커지므로, 'capacity'보다 큰 프레임도 읽을 수 있다.
+    pub fn with_capacity(socket: S, capacity: usize) -> Connection<S> {
         Connection {
             stream: BufWriter::new(socket),
-
-            // 읽기 버퍼의 기본 크기는 4KB가 된다. mini redis의 사용에 있어 이 크기는
-            // 충분하다. 하지만 실제 어플리케이션의 경우 이 값을 특정한 사용처에 맞게 조정해야 한다.
-            // 이보다 큰 사이즈의 버퍼가 더 잘 작동할 가능성이 높다.
-            buffer: BytesMut::with_capacity(4 * 1024),
+            buffer: BytesMut::with_capacity(capacity),
+            version: ProtocolVersion::Resp2,
+            broken: false,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
         }
     }
 
+    /// 커넥션이 사용하는 프로토콜 버전을 반환한다.
+    pub fn version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// 커넥션이 사용할 프로토콜 버전을 지정한다. 이후에 쓰여지는 프레임부터 적용된다.
+    pub fn set_version(&mut self, version: ProtocolVersion) {
+        self.version = version;
+    }
+
+    /// 읽어들일 수 있는 프레임의 최대 크기(바이트)를 지정한다.
+    ///
+    /// 이보다 긴 벌크 문자열이나 많은 앤트리를 담은 배열은 데이터를 모두 수신하기 전에 거부되며,
+    /// 프레임을 완성하지 못한 채 읽기 버퍼가 이 크기를 넘어도 에러를 반환한다. 악의적인 피어가 큰
+    /// 길이를 주장하여 메모리를 고갈시키는 것을 막는다.
+    pub fn set_max_frame_size(&mut self, max_frame_size: usize) {
+        self.max_frame_size = max_frame_size;
+    }
+
+    /// 읽기/쓰기 중 에러가 발생했거나 스트림이 닫혀 커넥션을 더 이상 사용할 수 없다면 'true'를
+    /// 반환한다.
+    pub(crate) fn is_broken(&self) -> bool {
+        self.broken
+    }
+
+    /// 커넥션을 더 이상 사용할 수 없다고 표시한다.
+    /// 
+    /// 프레임을 읽거나 쓰는 도중에 작업이 취소되었다면(e.g. 타임아웃) 스트림이 프레임 경계에 맞춰져
+    /// 있지 않을 수 있다. 이런 커넥션을 재사용하면 이후의 응답이 뒤섞이므로, 호출자가 직접 표시한다.
+    pub(crate) fn poison(&mut self) {
+        self.broken = true;
+    }
+
     /// 기반 스트림으로부터 'Frame' 하나를 읽어들인다.
     /// 
     /// 이 함수는 한 프레임을 만들기 위한 충분한 데이터가 모일 때까지 기다린다.
@@ -49,6 +144,18 @@ impl Connection {
     /// 성공할 경우 frame을 반환한다. 'TcpStream'이 프레임을 반으로 나누지 않는 방식으로 닫히면
     /// 'None'을 반환한다. 그렇지 않으면 에러를 반환한다.
     pub async fn read_frame(&mut self) -> crate::Result<Option<Frame>> {
+        let res = self.read_frame_inner().await;
+
+        // 에러가 발생했거나 스트림이 닫혔다면 읽기 버퍼가 프레임 경계에 맞춰져 있음을 보장할 수
+        // 없다. 이후 이 커넥션을 재사용하지 않도록 표시한다.
+        if !matches!(res, Ok(Some(_))) {
+            self.broken = true;
+        }
+
+        res
+    }
+
+    async fn read_frame_inner(&mut self) -> crate::Result<Option<Frame>> {
         loop {
             // 버퍼 데이터로부터 프레임을 파싱한다.
             // 데이터가 프레임을 만들기에 충분하다면 프레임을 반환한다.
@@ -63,12 +170,20 @@ impl Connection {
                 if self.buffer.is_empty() {
                     return Ok(None);
                 } else {
-                    return Err("connection reset by peer".into());
+                    return Err(MiniRedisError::ConnectionReset);
                 }
             }
         }
     }
 
+    /// 소켓으로부터 읽지 않고, 이미 읽기 버퍼에 있는 데이터로부터 'Frame' 하나를 읽어들인다.
+    /// 
+    /// 버퍼에 완전한 프레임이 없다면 'Ok(None)'을 반환한다. 클라이언트가 여러 요청을 연달아
+    /// 전송한 경우(파이프라이닝), 이미 수신한 요청들을 대기 없이 읽어들이기 위해 사용한다.
+    pub fn read_buffered_frame(&mut self) -> crate::Result<Option<Frame>> {
+        self.parse_frame()
+    }
+
     /// 버퍼로부터 프레임 파싱을 시도한다. 버퍼의 데이터가 충분하다면 프레임을 반환하고
     /// 버퍼의 데이터를 제거한다. 데이터가 아직 충분하지 않다면 'Ok(None)'을 반환한다.
     /// 버퍼 데이터가 유효한 프레임을 나타내지 않는다면 'Err'를 반환한다.
@@ -84,7 +199,7 @@ impl Connection {
         // 확인하는 일이다. 보통 이 단계는 프레임 전체 파싱보다 훨씬 빠르게 동작하며, 프레임
         // 전체를 수신했음을 아직 알지 못하는 상황에서 프레임 데이터를 보유하기 위한 데이터 구조 할당을
         // 생략할 수 있도록 해준다.
-        match Frame::check(&mut buf) {
+        match Frame::check(&mut buf, self.max_frame_size) {
             Ok(_) => {
                 // 'check'함수는 커서를 프레임의 끝까지 전진시킬 것이다.
                 // 'Frame::check'를 호출하기 전까지 커서의 포지션을 0 으로 세팅되기 때문에,
@@ -116,6 +231,10 @@ impl Connection {
             // 수신을 위해 대기해야 한다. 소켓 읽기는 이 'match' 후에 완료된다.
             // 
             // 여기에서 'Err'을 반환하지 않는 이유는 이 "에러"는 런타임에 예상할 수 있는 결과이기 때문이다.
+            // 단, 프레임을 완성하지 못한 데이터가 최대 크기를 넘었다면 더 이상 수신하지 않는다.
+            Err(Incomplete) if self.buffer.len() > self.max_frame_size => {
+                Err("ERR Protocol error: too big frame".into())
+            }
             Err(Incomplete) => Ok(None),
             // 프레임 파싱 중 발생한 에러. 커넥션은 이제 무효한 상태가 된다.
             // 여기서 반환하는 'Err'는 커넥션을 닫을 것이다.
@@ -130,32 +249,113 @@ impl Connection {
     // syscalls를 발생시키기 때문이다. 하지만 버퍼링된 쓰기 스트림에 대해서는 이런 방식도 괜찮다.
     // 데이터가 소켓이 아닌 버퍼에 쓰여지기 때문이다. 버퍼가 가득 차면 기반 소켓에 flush된다.
     pub async fn write_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.write_frame_buffered(frame).await?;
+
+        // 인코딩된 프레임을 소켓에 쓴다. 위 호출은 버퍼 스트림에 이루어지고, 쓰여진다.
+        // 'flush' 호출은 버퍼에 남아있는 내용을 소켓에 쓴다.
+        self.flush().await
+    }
+
+    /// 'write_frame'과 같지만, 프레임을 쓰기 버퍼에만 쓰고 소켓으로 flush하지 않는다.
+    /// 
+    /// 파이프라이닝으로 여러 요청을 처리할 때, 각 응답마다 flush하는 대신 모든 응답을 버퍼에 쓴
+    /// 뒤 'flush'를 한 번만 호출하기 위해 사용한다. 버퍼가 가득 차면 버퍼의 내용은 자동으로
+    /// 소켓에 쓰여지므로, 응답의 순서는 유지된다.
+    pub async fn write_frame_buffered(&mut self, frame: &Frame) -> io::Result<()> {
+        let res = self.write_frame_inner(frame).await;
+
+        // 프레임의 일부만 쓰여졌을 수 있으므로, 이후 이 커넥션을 재사용하지 않도록 표시한다.
+        if res.is_err() {
+            self.broken = true;
+        }
+
+        res
+    }
+
+    async fn write_frame_inner(&mut self, frame: &Frame) -> io::Result<()> {
+        let resp3 = self.version == ProtocolVersion::Resp3;
+
         // 배열은 배열 안의 각 앤트리를 인코딩하는 방식으로 인코딩된다. 다른 모든 프레임 타입은
-        // 리터럴로 취급된다. 지금의 mini-redis는 재귀적 프레임 구조로 인코딩할 수 없다.
-        // 자세한 내용은 아래에서 다룬다.
-        match frame {
-            Frame::Array(val) => {
-                // 프레임 타입 접두어를 인코딩한다. 배열의 경우, 이는 '*'가 된다.
-                self.stream.write_u8(b'*').await?;
-                
-                // 배열의 길이를 인코딩한다.
-                self.write_decimal(val.len() as u64).await?;
+        // 리터럴로 취급된다. 맵과 셋도 배열과 같은 방식으로 인코딩된다.
+        //
+        // async 함수는 재귀 호출을 지원하지 않기 때문에, 중첩 배열은 명시적인 스택을 사용하여
+        // 인코딩한다. 스택의 각 원소는 인코딩 중인 배열의 남은 앤트리를 순회하는 이터레이터다.
+        // 최상위 프레임은 원소가 하나인 배열처럼 취급한다.
+        let mut stack: Vec<Box<dyn Iterator<Item = &Frame> + Send + '_>> =
+            vec![Box::new(std::iter::once(frame))];
 
-                // 배열 안의 각 앤트리를 순회하며 인코딩한다.
-                for entry in &**val {
-                    self.write_value(entry).await?;
+        while let Some(entries) = stack.last_mut() {
+            match entries.next() {
+                Some(Frame::Array(val)) => {
+                    // 프레임 타입 접두어와 배열의 길이를 인코딩한다. 배열의 경우, 접두어는 '*'가 된다.
+                    self.write_aggregate_header(b'*', val.len()).await?;
+
+                    // 배열 안의 앤트리들은 다음 반복부터 인코딩된다.
+                    stack.push(Box::new(val.iter()));
+                }
+                Some(Frame::Set(val)) => {
+                    // RESP2에서 셋은 배열로 인코딩된다.
+                    let prefix = if resp3 { b'~' } else { b'*' };
+                    self.write_aggregate_header(prefix, val.len()).await?;
+
+                    stack.push(Box::new(val.iter()));
+                }
+                Some(Frame::Map(val)) => {
+                    // RESP2에서 맵은 키와 값을 번갈아 담은 배열로 인코딩된다.
+                    if resp3 {
+                        self.write_aggregate_header(b'%', val.len()).await?;
+                    } else {
+                        self.write_aggregate_header(b'*', val.len() * 2).await?;
+                    }
+
+                    stack.push(Box::new(val.iter().flat_map(|(key, value)| [key, value])));
+                }
+                // 프레임 타입이 리터럴이다. 값을 직접 인코딩한다.
+                Some(frame) => self.write_value(frame, resp3).await?,
+                // 현재 배열의 모든 앤트리를 인코딩했다. 바깥 배열로 돌아간다.
+                None => {
+                    stack.pop();
                 }
             }
-            // 프레임 타입이 리터럴이다. 값을 직접 인코딩한다.
-            _ => self.write_value(frame).await?,
         }
-        // 인코딩된 프레임을 소켓에 쓴다. 위 코드의 각 write 호출들은 버퍼 스트림에 이루어지고, 쓰여진다.
-        // 'flush' 호출은 버퍼에 남아있는 내용을 소켓에 쓴다.
-        self.stream.flush().await
+
+        Ok(())
+    }
+
+    /// 배열 프레임의 헤더만을 쓰기 버퍼에 쓴다. 'len'개의 앤트리는 이어지는 쓰기로 채워야 한다.
+    /// 
+    /// 'EXEC'처럼 각 앤트리를 서로 다른 커맨드가 쓰는 응답을 위해 사용한다.
+    pub(crate) async fn write_array_header(&mut self, len: usize) -> io::Result<()> {
+        let res = self.write_aggregate_header(b'*', len).await;
+
+        if res.is_err() {
+            self.broken = true;
+        }
+
+        res
+    }
+
+    /// 쓰기 버퍼에 남아있는 내용을 소켓에 쓴다.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        let res = self.stream.flush().await;
+
+        if res.is_err() {
+            self.broken = true;
+        }
+
+        res
+    }
+
+    /// 배열, 셋, 맵의 접두어와 길이를 스트림에 쓴다.
+    async fn write_aggregate_header(&mut self, prefix: u8, len: usize) -> io::Result<()> {
+        self.stream.write_u8(prefix).await?;
+        self.write_decimal(len as i64).await
     }
 
     /// 프레임 리터럴을 스트림에 쓴다.
-    async fn write_value(&mut self, frame: &Frame) -> io::Result<()> {
+    /// 
+    /// 'resp3'가 'false'라면 RESP3 타입은 대응하는 RESP2 타입으로 변환하여 쓴다.
+    async fn write_value(&mut self, frame: &Frame, resp3: bool) -> io::Result<()> {
         match frame {
             Frame::Simple(val) => {
                 self.stream.write_u8(b'+').await?;
@@ -174,26 +374,64 @@ impl Connection {
             Frame::Null => {
                 self.stream.write_all(b"$-1\r\n").await?;
             }
-            Frame::Bulk(val) => {
-                let len = val.len();
+            Frame::Bulk(val) => self.write_bulk(val).await?,
+            Frame::Double(val) => {
+                let val = format_double(*val);
 
-                self.stream.write_u8(b'$').await?;
-                self.write_decimal(len as u64).await?;
-                self.stream.write_all(val).await?;
-                self.stream.write_all(b"\r\n").await?;
+                if resp3 {
+                    self.stream.write_u8(b',').await?;
+                    self.stream.write_all(val.as_bytes()).await?;
+                    self.stream.write_all(b"\r\n").await?;
+                } else {
+                    // RESP2에서는 벌크 문자열로 변환한다.
+                    self.write_bulk(val.as_bytes()).await?;
+                }
+            }
+            Frame::Boolean(val) => {
+                if resp3 {
+                    self.stream.write_all(if *val { b"#t\r\n" } else { b"#f\r\n" }).await?;
+                } else {
+                    // RESP2에서는 정수 1, 0으로 변환한다.
+                    self.stream.write_u8(b':').await?;
+                    self.write_decimal(*val as i64).await?;
+                }
+            }
+            Frame::BigNumber(val) => {
+                if resp3 {
+                    self.stream.write_u8(b'(').await?;
+                    self.stream.write_all(val.as_bytes()).await?;
+                    self.stream.write_all(b"\r\n").await?;
+                } else {
+                    // RESP2에서는 벌크 문자열로 변환한다.
+                    self.write_bulk(val.as_bytes()).await?;
+                }
+            }
+            Frame::Null3 => {
+                if resp3 {
+                    self.stream.write_all(b"_\r\n").await?;
+                } else {
+                    self.stream.write_all(b"$-1\r\n").await?;
+                }
             }
 
-            // 프레임 값 안의 'Array'는 재귀적으로 인코딩할 수 없다. 일반적으로, async 함수들은
-            // 재귀를 지원하지 않는다. mini-redis는 아직 중첩 배열 인코딩이 필요하지 않다.
-            // 때문에 당장은 이 경우는 생략한다.
-            Frame::Array(_val) => unreachable!(),
+            // 'Array', 'Set', 'Map'은 'write_frame'에서 스택을 사용하여 인코딩한다. 이 함수로
+            // 전달되지 않는다.
+            Frame::Array(_) | Frame::Set(_) | Frame::Map(_) => unreachable!(),
         }
 
         Ok(())
     }
 
+    /// 벌크 문자열을 스트림에 쓴다.
+    async fn write_bulk(&mut self, val: &[u8]) -> io::Result<()> {
+        self.stream.write_u8(b'$').await?;
+        self.write_decimal(val.len() as i64).await?;
+        self.stream.write_all(val).await?;
+        self.stream.write_all(b"\r\n").await
+    }
+
     /// 십진수 프레임을 스트림에 쓴다.
-    async fn write_decimal(&mut self, val: u64) -> io::Result<()> {
+    async fn write_decimal(&mut self, val: i64) -> io::Result<()> {
         use std::io::Write;
 
         // 값을 문자열로 변환한다.
@@ -207,4 +445,112 @@ impl Connection {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// RESP3의 double 표기에 맞추어 실수를 문자열로 변환한다. 무한대와 NaN은 'inf', '-inf',
+/// 'nan'으로 표기한다.
+fn format_double(val: f64) -> String {
+    if val.is_nan() {
+        "nan".to_string()
+    } else if val.is_infinite() {
+        if val > 0.0 { "inf" } else { "-inf" }.to_string()
+    } else {
+        val.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bytes::Bytes;
+
+    /// 'frame'을 커넥션에 쓰고, 반대편 스트림에서 읽은 바이트를 반환한다.
+    async fn encode(frame: Frame) -> Vec<u8> {
+        let (client, mut server) = tokio::io::duplex(64);
+
+        let mut connection = Connection::new(client);
+        connection.write_frame(&frame).await.unwrap();
+        drop(connection);
+
+        let mut buf = vec![];
+        server.read_to_end(&mut buf).await.unwrap();
+        buf
+    }
+
+    #[tokio::test]
+    async fn write_simple() {
+        assert_eq!(encode(Frame::Simple("OK".to_string())).await, b"+OK\r\n");
+    }
+
+    #[tokio::test]
+    async fn write_error() {
+        assert_eq!(encode(Frame::Error("ERR x".to_string())).await, b"-ERR x\r\n");
+    }
+
+    #[tokio::test]
+    async fn write_integer() {
+        assert_eq!(encode(Frame::Integer(42)).await, b":42\r\n");
+        assert_eq!(encode(Frame::Integer(-7)).await, b":-7\r\n");
+    }
+
+    #[tokio::test]
+    async fn write_null() {
+        assert_eq!(encode(Frame::Null).await, b"$-1\r\n");
+    }
+
+    #[tokio::test]
+    async fn write_bulk() {
+        assert_eq!(encode(Frame::Bulk(Bytes::from("hi"))).await, b"$2\r\nhi\r\n");
+        assert_eq!(encode(Frame::Bulk(Bytes::new())).await, b"$0\r\n\r\n");
+    }
+
+    #[tokio::test]
+    async fn write_nested_array() {
+        let frame = Frame::Array(vec![
+            Frame::Array(vec![Frame::Integer(1)]),
+            Frame::Bulk(Bytes::from("x")),
+        ]);
+        let buf = encode(frame.clone()).await;
+        assert_eq!(buf, b"*2\r\n*1\r\n:1\r\n$1\r\nx\r\n");
+
+        let parsed = Frame::parse(&mut Cursor::new(&buf[..])).unwrap();
+        assert_eq!(format!("{:?}", parsed), format!("{:?}", frame));
+    }
+
+    #[tokio::test]
+    async fn read_with_small_capacity() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let value = vec![b'x'; 8 * 1024];
+
+        let mut encoded = format!("${}\r\n", value.len()).into_bytes();
+        encoded.extend_from_slice(&value);
+        encoded.extend_from_slice(b"\r\n");
+        tokio::spawn(async move { client.write_all(&encoded).await.unwrap() });
+
+        // 버퍼가 여러 번 채워지고 늘어나야 프레임 전체를 읽을 수 있다.
+        let mut connection = Connection::with_capacity(server, 16);
+        match connection.read_frame().await.unwrap() {
+            Some(Frame::Bulk(data)) => assert_eq!(data, value),
+            frame => panic!("unexpected frame: {:?}", frame),
+        }
+    }
+
+    #[tokio::test]
+    async fn read_from_duplex() {
+        let (mut client, server) = tokio::io::duplex(64);
+        client
+            .write_all(b"*2\r\n$3\r\nget\r\n$1\r\nk\r\n:5\r\n")
+            .await
+            .unwrap();
+        drop(client);
+
+        let mut connection = Connection::new(server);
+        match connection.read_frame().await.unwrap() {
+            Some(frame @ Frame::Array(_)) => assert_eq!(frame.to_string(), "get k"),
+            frame => panic!("unexpected frame: {:?}", frame),
+        }
+        assert!(matches!(connection.read_frame().await.unwrap(), Some(Frame::Integer(5))));
+        assert!(connection.read_frame().await.unwrap().is_none());
+    }
+}
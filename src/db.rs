@@ -1,15 +1,59 @@
+use crate::glob;
+use crate::zset::SortedSet;
+use crate::random::Rng;
+
 use tokio::sync::{broadcast, Notify};
 use tokio::time::{self, Duration, Instant};
 
-use bytes::Bytes;
-use std::collections::{BTreeMap, HashMap};
-use std::sync::{Arc, Mutex};
+use bytes::{Bytes, BytesMut};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::{Bound, Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+///  'Db::new'가 사용하는 데이터베이스별 키 공간 샤드의 기본 수
+const DEFAULT_SHARDS: usize = 16;
+
+///  문자열 값의 최대 크기. 레디스와 같은 512MB이다.
+///  
+///  'SETRANGE'처럼 값을 늘리는 연산이 클라이언트가 지정한 크기만큼 메모리를 할당하지 않도록 제한한다.
+const MAX_STRING_SIZE: u64 = 512 * 1024 * 1024;
+
+///  'Db::evict'가 한 번에 고르는 제거 후보의 최대 수
+///  
+///  후보를 고르려면 모든 샤드를 순회해야 하므로, 한 번의 순회로 여러 키를 제거할 수 있도록 한다.
+const EVICTION_POOL_SIZE: usize = 16;
+
+///  키에 저장된 값의 타입이 연산이 기대하는 타입과 다를 때 반환하는 에러
+const WRONGTYPE: &str = "WRONGTYPE Operation against a key holding the wrong kind of value";
+
+///  'SETBIT', 'GETBIT'의 오프셋이 'MAX_STRING_SIZE'에 해당하는 비트 수를 넘을 때 반환하는 에러
+const BIT_OFFSET_OUT_OF_RANGE: &str = "ERR bit offset is not an integer or out of range";
+
+///  'HRANDFIELD', 'SRANDMEMBER'에 음수로 지정할 수 있는 'count'의 최대 절댓값
+///  
+///  음수 'count'는 중복을 허용하므로 응답의 크기가 컬렉션의 크기로 제한되지 않는다. 샤드의 락을 잡은
+///  채로 클라이언트가 지정한 크기만큼 메모리를 할당하지 않도록, 커맨드를 파싱할 때 이 값을 넘는
+///  'count'를 거절한다.
+pub(crate) const MAX_RANDOM_COUNT: u64 = 1024 * 1024;
 
 ///  모든 커넥션이 공유하는 서버 상태
 /// 
 ///  'Db'는 키/값 데이터와, 활동중인 pub/sub 체널에 대한 모든 'broadcast::Sender' 값들을 'HashMap'에 저장한다.
 /// 
+///  키 공간은 여러 샤드로 나뉜다. 각 키는 키의 해시 값으로 정해지는 하나의 샤드에 속하며, 각 샤드는 독립된
+///  뮤택스로 보호된다. 이를 통해 서로 다른 샤드의 키에 접근하는 커넥션들은 락을 두고 경합하지 않는다.
+/// 
+///  레디스와 마찬가지로 번호로 구분되는 여러 논리 데이터베이스를 가지며, 각 데이터베이스는 독립된 키 공간을
+///  갖는다. pub/sub 채널은 데이터베이스와 무관하게 공유된다.
+/// 
 ///  한 'Db' 인스턴스는 공유 상태에 대한 핸들이다. 'Db'의 cloning은 shallow이며, atomic 레퍼런스 카운드를 증가시키기만 한다.
+///  각 핸들은 연산의 대상이 되는 데이터베이스의 번호를 가지며, 'select'로 다른 데이터베이스를 가리키는 핸들을
+///  얻는다.
 /// 
 ///  'Db' 값이 하나 생성되면 백그라운드 작업 하나가 시작된다. 이 작업은 요청된 만료 시간이 도래했을 때 값을 expiring 한다.
 ///  작업은 모든 'Db' 인스턴스의 dropped 까지 계속된다.
@@ -18,29 +62,51 @@ pub(crate) struct Db {
      ///  공유 상태의 핸들.
      ///  백그라운드 작업 또한 'Arc<Shared>'를 갖는다.
     shared: Arc<Shared>,
+
+    ///  이 핸들이 가리키는 데이터베이스의 번호. 'Shared::databases'의 인덱스이다.
+    index: usize,
 }
 struct Shared {
-    ///  공유 상태는 mutex로 보호된다. mutex는 'std::sync::Mutex' 이다. Tokio의 mutex가 아니다.
+    ///  논리 데이터베이스들의 키 공간. 인덱스가 데이터베이스의 번호가 된다.
+    databases: Vec<Keyspace>,
+
+    ///  pub/sub 상태. 키 공간과 무관하므로 샤드와 별도의 뮤택스로 보호한다.
+    pub_sub: Mutex<PubSubState>,
+
+    ///  앤트리 만료를 핸들링하는 백그라운드 작업에게 신호를 보낸다. 백그라운드 작업은 대기하다가 이 신호가 오면 신호가 만료값을 체크인지, 셧다운 시그널인지 확인한다.
+    background_task: Notify,
+
+    ///  키가 변경될 때 키 공간 알림을 pub/sub으로 발행할지 여부. 'Config::notify_keyspace_events'로 설정한다.
+    notify_keyspace_events: bool,
+
+    ///  마지막으로 스냅샷을 저장한 뒤 수행된 쓰기 커맨드의 수. 'BGSAVE'는 이 값이 0이면 저장을 생략한다.
+    changes_since_save: AtomicU64,
+
+    ///  구독자의 소비가 지연되어 전달되지 못하고 버려진 pub/sub 메시지의 수. 'INFO'가 응답한다.
+    dropped_messages: AtomicU64,
+
+    ///  Db 인스턴스가 셧다운되면 true가 된다. 'Db'인스턴스는 내부의 모든 값이 drop될 때 셧다운된다.
+    ///  이 값을 true로 세팅하면 백그라운드 태스크에게도 종료를 알린다. 
+    shutdown: AtomicBool,
+}
+
+///  논리 데이터베이스 하나의 키 공간
+struct Keyspace {
+    ///  키 공간의 샤드. 각 샤드의 상태는 mutex로 보호된다. mutex는 'std::sync::Mutex' 이다. Tokio의 mutex가 아니다.
     ///  이는 mutex를 획득한 상태에서 취하는 비동기 연산이 없기 때문이다. 그리고, 크리티컬 섹션이 아주 작다.
     ///  
     ///  Tokio mutex는 주로 '.await' 이 값을 반환하는 시점에 락이 유지되어야 할 때 사용된다. 이를 제외한 대부분의 상황에서는 
     ///  std mutex가 최선의 선택이다. 만일 크리티컬 섹션에 비동기 연산이 존재하지 않지만 동작 시간이 긴 경우 (CPU 인텐시브한 작업 or 블로킹 연산),
     ///  mutex 대기 연산을 포함한 전체 연산은 'blocking' 연산으로 간주되며, 'tokio::task:spawn_blocking'이 사용되어야 한다.
-    state: Mutex<State>,
-
-    ///  앤트리 만료를 핸들링하는 백그라운드 작업에게 신호를 보낸다. 백그라운드 작업은 대기하다가 이 신호가 오면 신호가 만료값을 체크인지, 셧다운 시그널인지 확인한다.
-    background_task: Notify,
+    shards: Vec<Mutex<State>>,
 }
 
+///  키 공간 샤드 하나의 상태
 struct State {
     ///  key-value 데이터
     ///  값을 저장할 뿐이기에, std HashMap으로 충분하다.
     entries: HashMap<String, Entry>,
 
-    ///  pub/sub key-space. 레디스는 pub/sub과 key-value의 키 공간을 분리하여 사용한다.
-    ///  'mini-redis'는 이를 별도의 'HashMap'을 두어 구현한다.
-    pub_sub: HashMap<String, broadcast::Sender<Bytes>>,
-
     ///  키 TTL을 추적한다.
     ///  
     ///  키 만료 정보를 졍렬하여 보관하기 위해 'BTreeMap'을 사용한다.
@@ -54,11 +120,182 @@ struct State {
     ///  다음 만료를 위한 식별자
     ///  각 만료는 유니크 식별자와 연결되어 있다.
     ///  여기서의 '식별자'는 위에서 언급된 '식별자'와 같은 것을 칭한다.
+    ///  
+    ///  만료 정보는 샤드마다 따로 관리되므로, 식별자는 샤드 안에서만 유니크하면 된다. 항목의 버전도 같은
+    ///  카운터로부터 받는다.
     next_id: u64,
 
-    ///  Db 인스턴스가 셧다운되면 true가 된다. 'Db'인스턴스는 내부의 모든 값이 drop될 때 셧다운된다.
-    ///  이 값을 true로 세팅하면 백그라운드 태스크에게도 종료를 알린다. 
-    shutdown: bool,
+    ///  'BLPOP', 'BRPOP'으로 리스트를 기다리는 커넥션들에게 원소의 삽입을 알리기 위한 키별 'Notify'.
+    ///  
+    ///  기다리는 커넥션이 있는 키에만 존재한다. 기다리던 커넥션들이 모두 떠나면 삭제된다.
+    list_waiters: HashMap<String, Arc<Notify>>,
+
+    ///  샤드에 저장된 항목들의 'size'의 합
+    ///  
+    ///  항목을 저장, 변경, 삭제할 때마다 갱신한다. 모든 샤드의 값을 합하면 서버의 메모리 사용량의
+    ///  근사값이 된다.
+    used_memory: usize,
+
+    ///  연산 전에 만료 시간이 지난 것을 발견하여 삭제한 키
+    ///  
+    ///  'expired' 키 공간 알림은 pub/sub 뮤택스를 획득하므로, 샤드 락을 해제한 뒤에 발행할 수 있도록
+    ///  모아둔다. 'ShardGuard'와 'LockedShards'가 락을 해제할 때 비운다.
+    lazily_expired: Vec<String>,
+}
+
+///  pub/sub 상태
+struct PubSubState {
+    ///  pub/sub key-space. 레디스는 pub/sub과 key-value의 키 공간을 분리하여 사용한다.
+    ///  'mini-redis'는 이를 별도의 'HashMap'을 두어 구현한다.
+    channels: HashMap<String, broadcast::Sender<Bytes>>,
+
+    ///  패턴 구독. 키는 glob 패턴이며, 메시지는 발행된 채널 이름과 함께 전송된다.
+    ///  
+    ///  메시지 발행 시 모든 패턴을 채널 이름과 대조해야 하므로, 패턴의 수에 비례하는 비용이 든다.
+    patterns: HashMap<String, broadcast::Sender<(String, Bytes)>>,
+
+    ///  샤드 채널. 'SPUBLISH'로 발행된 메시지는 여기의 구독자에게만 전송된다.
+    ///  
+    ///  일반 채널과 키 공간을 분리하므로, 같은 이름의 일반 채널이나 매칭되는 패턴의 구독자는 샤드
+    ///  채널의 메시지를 수신하지 않는다.
+    shard_channels: HashMap<String, broadcast::Sender<Bytes>>,
+}
+
+///  하나의 키를 다루는 연산을 위해 획득한 샤드 락
+///  
+///  'State'로 deref된다. 락을 잡은 동안 지연 만료로 삭제된 키의 'expired' 알림은 락을 해제한 뒤에
+///  발행한다.
+struct ShardGuard<'a> {
+    ///  샤드의 락. 'drop'에서 알림을 발행하기 전에 해제하기 위해 'Option'으로 감싼다.
+    guard: Option<MutexGuard<'a, State>>,
+
+    ///  락을 획득한 데이터베이스의 핸들
+    db: &'a Db,
+}
+
+///  여러 키를 다루는 연산을 위해 획득한 샤드 락의 모음
+///  
+///  락은 항상 샤드 인덱스의 오름차순으로 획득한다. 모든 연산이 같은 순서로 락을 획득하므로
+///  데드락이 발생하지 않는다. 'ShardGuard'와 같이, 지연 만료의 알림은 모든 락을 해제한 뒤에 발행한다.
+struct LockedShards<'a> {
+    guards: Vec<(usize, MutexGuard<'a, State>)>,
+
+    ///  락을 획득한 데이터베이스의 핸들
+    db: &'a Db,
+}
+
+///  'Db::set_options'의 쓰기 조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Condition {
+    ///  항상 값을 저장한다.
+    Always,
+
+    ///  키가 존재하지 않을 때만 값을 저장한다.
+    IfNotExists,
+
+    ///  키가 이미 존재할 때만 값을 저장한다.
+    IfExists,
+}
+
+///  'GETEX'가 값을 조회하며 함께 적용하는 만료 시간의 변경. 'Db::get_ex'가 사용한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiry {
+    ///  지금으로부터 주어진 시간이 지난 뒤 만료되도록 설정한다.
+    In(Duration),
+
+    ///  주어진 시각에 만료되도록 설정한다. 이미 지난 시각이라면 키를 삭제한다.
+    At(SystemTime),
+
+    ///  만료 시간을 제거한다.
+    Persist,
+}
+
+///  'Db::rename'의 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameResult {
+    ///  키의 이름이 변경되었다.
+    Renamed,
+
+    ///  원본 키가 존재하지 않는다.
+    NoSuchKey,
+
+    ///  'nx'가 주어졌고, 대상 키가 이미 존재한다.
+    DestinationExists,
+}
+
+///  'Db::set_op'가 수행하는 셋 연산
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetOp {
+    ///  모든 셋에 포함된 멤버. 'SINTER'
+    Inter,
+
+    ///  하나 이상의 셋에 포함된 멤버. 'SUNION'
+    Union,
+
+    ///  첫 번째 셋에만 포함된 멤버. 'SDIFF'
+    Diff,
+}
+
+///  'Db::set_op'의 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SetOpResult {
+    ///  대상 키가 주어지지 않았다. 연산 결과의 멤버 목록이다.
+    Members(Vec<Bytes>),
+
+    ///  연산 결과를 대상 키에 저장했다. 저장한 셋의 멤버 수이다.
+    Stored(usize),
+}
+
+///  키에 저장된 값의 타입. 'TYPE' 커맨드의 응답이 된다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyType {
+    ///  키가 존재하지 않는다.
+    None,
+
+    ///  문자열
+    String,
+
+    ///  리스트
+    List,
+
+    ///  해시
+    Hash,
+
+    ///  셋
+    Set,
+
+    ///  정렬된 셋
+    ZSet,
+}
+
+///  키에 저장되는 값
+///  
+///  레디스는 키마다 서로 다른 타입의 값을 저장할 수 있다. 새로운 타입은 이 열거형에 추가된다.
+///  특정 타입의 값을 다루는 연산은 다른 타입의 값을 만나면 'WRONGTYPE' 에러를 반환한다.
+#[derive(Clone)]
+pub(crate) enum Value {
+    ///  문자열. 레디스의 문자열은 임의의 바이트열이다.
+    String(Bytes),
+
+    ///  리스트. 양 끝에서의 삽입과 삭제가 빈번하므로 'VecDeque'를 사용한다.
+    ///  
+    ///  빈 리스트는 저장하지 않는다. 마지막 원소가 삭제되면 키도 함께 삭제된다.
+    List(VecDeque<Bytes>),
+
+    ///  해시. 필드 이름과 값의 맵이다.
+    ///  
+    ///  리스트와 마찬가지로 빈 해시는 저장하지 않는다.
+    Hash(HashMap<String, Bytes>),
+
+    ///  셋. 중복되지 않는 멤버의 집합이다.
+    ///  
+    ///  빈 셋은 저장하지 않는다.
+    Set(HashSet<Bytes>),
+
+    ///  정렬된 셋. 멤버마다 점수를 가지며, 점수의 순서로 정렬된다.
+    ///  
+    ///  빈 정렬된 셋은 저장하지 않는다.
+    SortedSet(SortedSet),
 }
 
 /// key-value 저장소에 저장될 항목
@@ -66,50 +303,459 @@ struct Entry {
     /// 항목을 찾기 위한 유니크한 값
     id: u64,
 
-    /// 저장되는 실제 데이터
-    data: Bytes,
+    /// 저장되는 실제 값
+    value: Value,
 
     /// 항목이 만료되어 데이터베이스에서 삭제되어야 하는 시간
     expires_at: Option<Instant>,
+
+    /// 항목이 마지막으로 변경된 시점의 버전. 'WATCH'는 이 값으로 키의 변경 여부를 확인한다.
+    ///
+    /// 항목이 생성될 때는 'id'와 같고, 값이나 만료 시간이 변경될 때마다 새로운 값을 받는다.
+    version: u64,
+
+    /// 키와 값의 바이트 길이의 합. 항목이 사용하는 메모리의 근사값으로, 'maxmemory'와 비교된다.
+    size: usize,
+
+    /// 항목에 마지막으로 접근한 시각. 'allkeys-lru' 정책은 이 값이 가장 오래된 키부터 제거하며,
+    /// 'OBJECT IDLETIME'은 이 시각으로부터 지난 시간을 응답한다.
+    accessed_at: Instant,
 }
 
 impl Db {
-    /// 비어있는 새로운 'Db' 인스턴스를 생성한다. 공유 상태를 할당하고, 백그라운드 작업이 키 만료를 관리하도록 한다.
-    pub(crate) fn new() -> Db {
+    /// 'databases'개의 비어있는 데이터베이스를 갖는 새로운 'Db' 인스턴스를 생성한다. 공유 상태를 할당하고,
+    /// 백그라운드 작업이 키 만료를 관리하도록 한다.
+    /// 
+    /// 반환된 핸들은 0번 데이터베이스를 가리킨다. 'notify_keyspace_events'가 'true'이면 키가 변경될 때
+    /// 키 공간 알림을 발행한다.
+    pub(crate) fn new(databases: usize, notify_keyspace_events: bool) -> Db {
+        Db::with_shards(databases, DEFAULT_SHARDS, notify_keyspace_events)
+    }
+
+    /// 각 데이터베이스의 키 공간을 'n'개의 샤드로 나눈 비어있는 새로운 'Db' 인스턴스를 생성한다.
+    /// 
+    /// 샤드의 수는 키가 속할 샤드를 결정할 뿐, 'Db'의 동작에는 영향을 주지 않는다.
+    /// 
+    /// # Panics
+    /// 
+    /// 'databases' 혹은 'n'이 0인 경우 패닉.
+    pub(crate) fn with_shards(databases: usize, n: usize, notify_keyspace_events: bool) -> Db {
+        assert!(databases > 0, "the number of databases must be positive");
+        assert!(n > 0, "the number of shards must be positive");
+
+        let databases = (0..databases)
+            .map(|_| Keyspace {
+                shards: (0..n)
+                    .map(|_| {
+                        Mutex::new(State {
+                            entries: HashMap::new(),
+                            expirations: BTreeMap::new(),
+                            next_id: 0,
+                            list_waiters: HashMap::new(),
+                            used_memory: 0,
+                            lazily_expired: vec![],
+                        })
+                    })
+                    .collect(),
+            })
+            .collect();
+
         let shared = Arc::new(Shared {
-            state: Mutex::new(State {
-                entries: HashMap::new(),
-                pub_sub: HashMap::new(),
-                expirations: BTreeMap::new(),
-                next_id: 0,
-                shutdown: false,
+            databases,
+            pub_sub: Mutex::new(PubSubState {
+                channels: HashMap::new(),
+                patterns: HashMap::new(),
+                shard_channels: HashMap::new(),
             }),
             background_task: Notify::new(),
+            notify_keyspace_events,
+            changes_since_save: AtomicU64::new(0),
+            dropped_messages: AtomicU64::new(0),
+            shutdown: AtomicBool::new(false),
         });
 
         // 백그라운드 작업 시작
         tokio::spawn(purge_expired_tasks(shared.clone()));
 
-        Db {shared}
+        Db { shared, index: 0 }
+    }
+
+    ///  이 핸들이 가리키는 데이터베이스의 번호를 반환한다.
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    ///  'index'번 데이터베이스를 가리키는 새로운 핸들을 반환한다. 'SELECT' 커맨드가 사용한다.
+    ///  
+    ///  'index'에 해당하는 데이터베이스가 없다면 'None'을 반환한다.
+    pub(crate) fn select(&self, index: usize) -> Option<Db> {
+        if index >= self.shared.databases.len() {
+            return None;
+        }
+
+        Some(Db {
+            shared: self.shared.clone(),
+            index,
+        })
+    }
+
+    ///  이 핸들이 가리키는 데이터베이스의 키 공간을 반환한다.
+    fn keyspace(&self) -> &Keyspace {
+        &self.shared.databases[self.index]
+    }
+
+    ///  키가 속한 샤드의 락을 획득한다.
+    fn lock_shard(&self, key: &str) -> ShardGuard<'_> {
+        let keyspace = self.keyspace();
+        let guard = keyspace.shards[keyspace.shard_index(key)].lock().unwrap();
+
+        ShardGuard {
+            guard: Some(guard),
+            db: self,
+        }
+    }
+
+    ///  키들이 속한 모든 샤드의 락을 샤드 인덱스의 오름차순으로 획득한다.
+    fn lock_shards<'a>(&self, keys: impl Iterator<Item = &'a str>) -> LockedShards<'_> {
+        let keyspace = self.keyspace();
+
+        let mut indices: Vec<usize> = keys.map(|key| keyspace.shard_index(key)).collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let guards = indices
+            .into_iter()
+            .map(|index| (index, keyspace.shards[index].lock().unwrap()))
+            .collect();
+
+        LockedShards { guards, db: self }
+    }
+
+    ///  지연 만료로 삭제된 키들의 'expired' 알림을 발행한다. 샤드 락을 해제한 뒤에 호출한다.
+    fn notify_lazily_expired(&self, keys: Vec<String>) {
+        for key in keys {
+            self.shared.notify_keyspace_event(self.index, "expired", &key);
+        }
     }
 
     ///  키에 해당하는 값을 꺼낸다.
     ///  
     ///  해당하는 값이 없으면 None을 반환한다. None을 반환하는 경우는 키에 대한 값이 할당되지 않았거나,
     ///  할당되었던 값이 만료된 경우이다.
-    pub(crate) fn get(&self, key: &str) -> Option<Bytes> {
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다. 값이 없는 키와 구분하기
+    ///  위함이다.
+    pub(crate) fn get(&self, key: &str) -> crate::Result<Option<Bytes>> {
         //  락을 획득하고, 값을 꺼내고, 꺼낸 값을 clone한다.
         //  
         //  데이터는 Bytes로 저장되기 때문에, shallow clone이 된다. 실제 데이터는 복사되지 않는다.
-        let state = self.shared.state.lock().unwrap();
-        state.entries.get(key).map(|entry| entry.data.clone())
+        //  'allkeys-lru' 정책을 위해 접근 시각을 갱신한다.
+        //  만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 키로 취급한다.
+        let mut state = self.lock_shard(key);
+        state.remove_if_expired(key);
+
+        let entry = match state.entries.get_mut(key) {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        entry.accessed_at = Instant::now();
+
+        match entry.value.as_string() {
+            Some(value) => Ok(Some(value.clone())),
+            None => Err(WRONGTYPE.into()),
+        }
+    }
+
+    ///  키에 해당하는 값을 꺼내고, 키를 삭제한다.
+    ///  
+    ///  조회와 삭제는 같은 락 안에서 이루어지므로, 다른 커넥션이 같은 값을 가져갈 수 없다. 키가 존재하지
+    ///  않거나 만료되었다면 'None'을 반환한다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 키를 삭제하지 않고 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn get_del(&self, key: &str) -> crate::Result<Option<Bytes>> {
+        let mut state = self.lock_shard(key);
+
+        //  만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 키로 취급한다.
+        state.remove_if_expired(key);
+        state.check_string(key)?;
+
+        //  'remove_entry'는 만료 정보도 함께 삭제한다. 백그라운드 태스크에게 알릴 필요는 없다.
+        Ok(state.remove_entry(key).and_then(|entry| entry.value.into_string()))
+    }
+
+    ///  키에 해당하는 문자열 값을 조회하고, 'expiry'가 주어지면 키의 만료 시간을 함께 변경한다.
+    ///  
+    ///  조회와 만료 시간의 변경은 같은 락 안에서 이루어진다. 'Expiry::At'의 시각이 이미 지났다면 값을
+    ///  반환하고 키를 삭제한다. 키가 존재하지 않거나 만료되었다면 만료 시간을 변경하지 않고 'None'을
+    ///  반환한다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn get_ex(
+        &self,
+        key: &str,
+        expiry: Option<Expiry>,
+    ) -> crate::Result<Option<Bytes>> {
+        let mut state = self.lock_shard(key);
+
+        //  만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 키로 취급한다.
+        state.remove_if_expired(key);
+        state.check_string(key)?;
+
+        let entry = match state.entries.get_mut(key) {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        entry.accessed_at = Instant::now();
+
+        let value = entry.value.as_string().cloned();
+        let prev = entry.expires_at;
+
+        //  'Expiry::At'의 시각은 지금으로부터 남은 시간으로 바꾼다. 이미 지난 시각이라면 키를 삭제한다.
+        let expires_at = match expiry {
+            None => return Ok(value),
+            Some(Expiry::Persist) if prev.is_none() => return Ok(value),
+            Some(Expiry::Persist) => None,
+            Some(Expiry::In(duration)) => Some(Instant::now() + duration),
+            Some(Expiry::At(at)) => match at.duration_since(SystemTime::now()) {
+                Ok(remaining) if !remaining.is_zero() => Some(Instant::now() + remaining),
+                _ => {
+                    state.remove_entry(key);
+                    drop(state);
+
+                    self.shared.notify_keyspace_event(self.index, "del", key);
+                    return Ok(value);
+                }
+            },
+        };
+
+        let notify = state.set_expiration(key, expires_at);
+        state.touch(key);
+
+        //  백그라운드 태스크에게 알리기 전에 뮤택스를 해제한다.
+        drop(state);
+
+        if notify {
+            self.shared.background_task.notify_one();
+        }
+
+        let event = if expires_at.is_some() { "expire" } else { "persist" };
+        self.shared.notify_keyspace_event(self.index, event, key);
+
+        Ok(value)
+    }
+
+    ///  키에 저장된 값을 그대로 조회한다.
+    ///  
+    ///  값을 복제하지 않도록, 락을 잡은 상태에서 'f'에 값의 레퍼런스를 전달하고 그 결과를 반환한다. 'TYPE'처럼
+    ///  값의 타입과 상관없이 동작하는 커맨드가 사용한다. 키가 존재하지 않거나 만료되었다면 'None'을 반환한다.
+    pub(crate) fn get_raw<T>(&self, key: &str, f: impl FnOnce(&Value) -> T) -> Option<T> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+            .map(|entry| f(&entry.value))
+    }
+
+    ///  키에 마지막으로 접근한 뒤 지난 시간을 반환한다. 키가 존재하지 않으면 'None'을 반환한다.
+    ///  
+    ///  확인하는 것은 접근으로 보지 않으므로, 접근 시각을 갱신하지 않는다.
+    pub(crate) fn idle_time(&self, key: &str) -> Option<Duration> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+            .map(|entry| now.saturating_duration_since(entry.accessed_at))
+    }
+
+    ///  키에 새로운 값을 저장하고, 이전 값을 반환한다.
+    ///  
+    ///  저장된 값은 만료 시간을 가지지 않는다. 이전 값에 만료 시간이 있었다면 만료 정보도 함께 삭제한다.
+    ///  키가 존재하지 않았거나 만료되었다면 'None'을 반환한다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 값을 저장하지 않고 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn get_set(&self, key: String, value: Bytes) -> crate::Result<Option<Bytes>> {
+        let mut state = self.lock_shard(&key);
+
+        //  만료된 값을 이전 값으로 반환하지 않도록 먼저 삭제한다.
+        state.remove_if_expired(&key);
+        state.check_string(&key)?;
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        let prev = match state.insert_entry(key, id, Value::String(value), None) {
+            Some(prev) => prev,
+            None => return Ok(None),
+        };
+
+        //  'mset'과 마찬가지로, 새로운 만료가 추가되지 않으므로 백그라운드 태스크에게 알릴 필요는 없다.
+        if let Some(when) = prev.expires_at {
+            state.expirations.remove(&(when, prev.id));
+        }
+
+        Ok(prev.value.into_string())
+    }
+
+    ///  여러 키의 값을 한 번에 가져온다.
+    ///  
+    ///  키들이 속한 모든 샤드의 락을 잡은 상태에서 조회하므로, 반환된 값들은 같은 시점의 상태를 나타낸다.
+    ///  반환 값의 순서는 'keys'의 순서와 같다. 존재하지 않거나 만료된 키는 'None'이 된다.
+    pub(crate) fn mget(&self, keys: &[String]) -> Vec<Option<Bytes>> {
+        let mut shards = self.lock_shards(keys.iter().map(String::as_str));
+        let now = Instant::now();
+
+        keys.iter()
+            .map(|key| {
+                shards
+                    .get_mut(self.keyspace().shard_index(key))
+                    .entries
+                    .get(key)
+                    .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+                    .and_then(|entry| entry.value.as_string())
+                    .cloned()
+            })
+            .collect()
+    }
+
+    ///  키가 존재하는지 확인한다.
+    ///  
+    ///  만료 시간이 지났지만 아직 백그라운드 태스크에 의해 퍼지되지 않은 키는 존재하지 않는 것으로 간주한다.
+    pub(crate) fn exists(&self, key: &str) -> bool {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        //  백그라운드 태스크의 퍼지 시점에 의존하지 않도록, 락을 잡은 상태에서 만료 시간을 직접 확인한다.
+        state
+            .entries
+            .get(key)
+            .map(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+            .unwrap_or(false)
     }
 
-    ///  키-값을 저장한다. 선택적으로 만료시간도 설정한다.
+    ///  키 목록 중 존재하는 키의 접근 시각을 갱신하고, 존재하는 키의 수를 반환한다.
     ///  
-    ///  이미 키에 해당하는 값이 있다면 삭제한다.
-    pub(crate) fn set(&self, key: String, value: Bytes, expire: Option<Duration>) {
-        let mut state = self.shared.state.lock().unwrap();
+    ///  값은 바뀌지 않으므로 버전을 갱신하지 않으며, 'WATCH'로 감시 중인 트랜잭션을 실패시키지 않는다.
+    ///  같은 키가 여러 번 지정되면 그 횟수만큼 중복하여 센다.
+    pub(crate) fn touch(&self, keys: &[String]) -> u64 {
+        let mut shards = self.lock_shards(keys.iter().map(String::as_str));
+        let now = Instant::now();
+        let mut touched = 0;
+
+        for key in keys {
+            let state = shards.get_mut(self.keyspace().shard_index(key));
+
+            let entry = state
+                .entries
+                .get_mut(key)
+                .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true));
+
+            if let Some(entry) = entry {
+                entry.accessed_at = now;
+                touched += 1;
+            }
+        }
+
+        touched
+    }
+
+    ///  여러 키-값 쌍을 한 번에 저장한다. 저장된 키는 만료 시간을 가지지 않는다.
+    ///  
+    ///  키들이 속한 모든 샤드의 락을 잡은 상태에서 저장하므로, 다른 커넥션은 일부만 저장된 상태를 관찰할 수 없다.
+    ///  'set'과 같이 저장한 키마다 'set' 키 공간 알림을 발행한다.
+    pub(crate) fn mset(&self, pairs: Vec<(String, Bytes)>) {
+        let mut shards = self
+            .lock_shards(pairs.iter().map(|(key, _)| key.as_str()));
+        let mut written = vec![];
+
+        for (key, value) in pairs {
+            let state = shards.get_mut(self.keyspace().shard_index(&key));
+
+            if self.shared.notify_keyspace_events {
+                written.push(key.clone());
+            }
+
+            //  'set'과 마찬가지로 각 항목에 유니크 식별자를 부여한다.
+            let id = state.next_id;
+            state.next_id += 1;
+
+            let prev = state.insert_entry(key, id, Value::String(value), None);
+
+            //  기존 항목의 만료 정보는 삭제한다. 새로운 만료가 추가되지 않으므로 백그라운드 태스크에게
+            //  알릴 필요는 없다.
+            if let Some(prev) = prev {
+                if let Some(when) = prev.expires_at {
+                    state.expirations.remove(&(when, prev.id));
+                }
+            }
+        }
+
+        //  키 공간 알림은 샤드 락을 해제한 뒤에 발행한다.
+        drop(shards);
+
+        for key in &written {
+            self.shared.notify_keyspace_event(self.index, "set", key);
+        }
+    }
+
+    ///  키의 남은 만료 시간을 반환한다.
+    ///  
+    ///  키가 존재하지 않으면 'None'을 반환한다. 키가 존재하지만 만료 시간이 없다면 'Some(None)'을 반환한다.
+    ///  만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 것으로 간주한다.
+    pub(crate) fn ttl(&self, key: &str) -> Option<Option<Duration>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let entry = state.entries.get(key)?;
+
+        match entry.expires_at {
+            Some(when) if when <= now => None,
+            Some(when) => Some(Some(when - now)),
+            None => Some(None),
+        }
+    }
+
+    ///  키의 현재 버전을 반환한다. 키가 존재하지 않거나 만료되었다면 'None'을 반환한다.
+    ///  
+    ///  키가 변경될 때마다 버전이 바뀌므로, 두 시점의 버전을 비교하여 그 사이에 키가 변경되었는지 알 수
+    ///  있다. 키가 삭제되거나 만료된 경우도 변경으로 간주된다.
+    pub(crate) fn version(&self, key: &str) -> Option<u64> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+            .map(|entry| entry.version)
+    }
+
+    ///  'condition'을 만족하는 경우에만 키-값을 저장한다. 선택적으로 만료시간도 설정한다.
+    ///  
+    ///  이미 키에 해당하는 값이 있다면 삭제한다. 조건의 확인과 저장은 같은 락 안에서 이루어진다. 만료 시간이 지났지만 아직 퍼지되지 않은 키는
+    ///  존재하지 않는 키로 취급한다. 값이 저장되었다면 'true'를 반환한다.
+    pub(crate) fn set_options(
+        &self,
+        key: String,
+        value: Bytes,
+        expire: Option<Duration>,
+        condition: Condition,
+    ) -> bool {
+        let mut state = self.lock_shard(&key);
+
+        state.remove_if_expired(&key);
+
+        let exists = state.entries.contains_key(&key);
+        match condition {
+            Condition::IfNotExists if exists => return false,
+            Condition::IfExists if !exists => return false,
+            _ => {}
+        }
 
         //  다음 저장 ID를 증가시킨다.
         //  락으로 보호함으로써, 이 과정은 각 'set' 연산에 대해 한 유니크 식별자가 생성됨을 보장한다.
@@ -126,6 +772,9 @@ impl Db {
 
             //  오직 새로운 입력 항목의 만료가 다음 만료 항목일 때만 백그라운드 워커(태스크)에게 알린다.
             //  이 경우, 워커는 깨어나서(woken up) 이 상태를 업데이트해야한다.
+            //  
+            //  샤드 안에서의 다음 만료 항목인지만 확인한다. 다른 샤드에 더 이른 만료가 있다면 워커는
+            //  불필요하게 깨어나지만, 이는 정확성에 영향을 주지 않는다.
             notify = state
                 .next_expiration()
                 .map(|expiration| expiration > when)
@@ -136,15 +785,9 @@ impl Db {
             when
         });
 
-        // 새 항목을 'HashMap'에 넣는다.
-        let prev = state.entries.insert(
-            key,
-            Entry {
-                id,
-                data: value,
-                expires_at,
-            }
-        );
+        // 새 항목을 'HashMap'에 넣는다. 키 공간 알림을 위해 키 이름을 남겨둔다.
+        let key_name = self.shared.notify_keyspace_events.then(|| key.clone());
+        let prev = state.insert_entry(key, id, Value::String(value), expires_at);
 
         // 이 키로 저장된 기존 항목에 만료 시간이 있을 경우, 이 만료 정보는 삭제되어야 한다.
         if let Some(prev) = prev {
@@ -161,93 +804,2595 @@ impl Db {
             // 마지막으로, 새로운 만료 정보를 업데이트해야 하는 경우에 한하여 백그라운드 태스크에게 알림을 보낸다.
             self.shared.background_task.notify_one();
         }
+
+        if let Some(key) = key_name {
+            self.shared.notify_keyspace_event(self.index, "set", &key);
+        }
+
+        true
     }
 
-    ///  요청된 채널에 대한 'Receiver'를 반환한다.
-    /// 
-    /// 반환되는 'Receiver'는 'PUBLISH' 커맨드로 값을 수신하는 경우에 사용된다.
-    pub(crate) fn subscribe(&self, key: String) -> broadcast::Receiver<Bytes> {
-        use std::collections::hash_map::Entry;
+    ///  키에 저장된 값을 십진수 정수로 해석하여 'delta'만큼 더하고, 그 결과를 반환한다.
+    ///
+    ///  키가 존재하지 않으면 0에서 시작한다. 값의 해석과 저장은 모두 락을 잡은 상태에서 이루어지기 때문에
+    ///  이 연산은 원자적이다. 키에 설정된 만료 시간은 그대로 유지된다.
+    ///
+    ///  저장된 값이 정수가 아니거나 결과가 'i64'의 범위를 벗어나면 에러를 반환한다. 키에 문자열이 아닌 값이
+    ///  저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn incr_by(&self, key: &str, delta: i64) -> crate::Result<i64> {
+        const MSG: &str = "ERR value is not an integer or out of range";
 
-        // 뮤택스를 획득한다.
-        let mut state = self.shared.state.lock().unwrap();
+        let mut state = self.lock_shard(key);
 
-        //  요청된 채널에 대한 앤트리가 없을 경우, 새로운 브로드캐스트 채널을 생성하여 키와 연결한다.
-        //  앤트리가 있다면 연결된 리시버를 반환한다.
-        match state.pub_sub.entry(key) {
-            Entry::Occupied(e) => e.get().subscribe(),
-            Entry::Vacant(e) => {
-                //  브로드캐스트가 없으면 새로 만든다.
-                //  
-                //  채널은 '1024'개의 메시지를 담을 수 있도록 생성한다.
-                //  한 메시지는 모든 수신자들에게 전송될 때까지 보유된다. 
-                //  이는 한 구독자의 수신 속도가 늦는다면 메시지가 사라지지 않고 계속 남아있을 수 있음을 의미한다.
-                //  
-                //  채널이 가득차면 메시지 발행은 오래된 메시지를 우선으로 drop한다. 
-                //  이렇게 함으로써 느린 메시지 수신자로 인해 전체 시스템이 정지되는 경우를 방지한다.
+        //  만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 키로 취급한다.
+        state.remove_if_expired(key);
+
+        if let Some(entry) = state.entries.get_mut(key) {
+            //  기존 값을 정수로 해석한다. 정수로 해석할 수 없는 값이라면 에러를 반환한다.
+            let data = entry.value.as_string().ok_or(WRONGTYPE)?;
+            let value = std::str::from_utf8(data)
+                .ok()
+                .and_then(|s| s.parse::<i64>().ok())
+                .ok_or(MSG)?;
+            let value = value.checked_add(delta).ok_or(MSG)?;
+
+            //  만료 정보를 건드리지 않도록 데이터만 교체한다.
+            let prev_len = data.len();
+            let data = Bytes::from(value.to_string());
+            let len = data.len();
+            entry.value = Value::String(data);
+            state.resize(key, len, prev_len);
+            state.touch(key);
+            return Ok(value);
+        }
+
+        //  키가 존재하지 않는다. 0 에서 시작하는 새로운 항목을 만료 시간 없이 저장한다.
+        let id = state.next_id;
+        state.next_id += 1;
+
+        let value = Value::String(Bytes::from(delta.to_string()));
+        state.insert_entry(key.to_string(), id, value, None);
+
+        Ok(delta)
+    }
+
+    ///  키에 저장된 값의 끝에 'value'를 덧붙이고, 덧붙인 뒤의 값의 길이를 반환한다.
+    ///  
+    ///  키가 존재하지 않으면 'value'를 값으로 하는 새로운 항목을 만료 시간 없이 저장한다. 키에 설정된 만료
+    ///  시간은 그대로 유지된다.
+    ///  
+    ///  저장된 'Bytes'는 다른 커넥션에게 반환된 값과 버퍼를 공유할 수 있으므로 제자리에서 늘릴 수 없다.
+    ///  대신 두 값의 길이를 합한 크기의 'BytesMut'를 한 번에 할당하여 복사한다. 덧붙일 때마다 기존 값
+    ///  전체가 한 번 복사되지만, 여러 번의 재할당은 일어나지 않는다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn append(&self, key: &str, value: Bytes) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        //  만료된 값에 덧붙이지 않도록 먼저 삭제한다.
+        state.remove_if_expired(key);
+
+        if let Some(entry) = state.entries.get_mut(key) {
+            let prev = entry.value.as_string().ok_or(WRONGTYPE)?;
+
+            let mut data = BytesMut::with_capacity(prev.len() + value.len());
+            data.extend_from_slice(prev);
+            data.extend_from_slice(&value);
+
+            //  'incr_by'와 마찬가지로 만료 정보를 건드리지 않도록 데이터만 교체한다.
+            let len = data.len();
+            entry.value = Value::String(data.freeze());
+            state.resize(key, value.len(), 0);
+            state.touch(key);
+            return Ok(len);
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        let len = value.len();
+        state.insert_entry(key.to_string(), id, Value::String(value), None);
+
+        Ok(len)
+    }
+
+    ///  키에 저장된 값의 길이를 반환한다. 키가 존재하지 않거나 만료되었다면 0을 반환한다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn strlen(&self, key: &str) -> crate::Result<usize> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_string().ok_or(WRONGTYPE)?.len()),
+            None => Ok(0),
+        }
+    }
+
+    ///  키에 저장된 값의 'start'부터 'end'까지를 반환한다.
+    ///  
+    ///  'start'와 'end'는 양 끝을 포함하며, 음수는 값의 끝에서부터의 위치를 나타낸다. 범위는 레디스와 같은
+    ///  방식으로 값의 범위로 제한된다. 제한된 범위가 비어있거나 키가 존재하지 않으면 빈 값을 반환한다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn getrange(&self, key: &str, start: i64, end: i64) -> crate::Result<Bytes> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let data = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_string().ok_or(WRONGTYPE)?,
+            None => return Ok(Bytes::new()),
+        };
+
+        let len = data.len() as i64;
+
+        //  양쪽 모두 음수이면서 범위가 뒤집혀 있다면, 제한 후에도 빈 범위가 되어야 한다.
+        if start < 0 && end < 0 && start > end {
+            return Ok(Bytes::new());
+        }
+
+        //  음수 오프셋을 값의 끝에서부터의 위치로 바꾸고, 값의 범위로 제한한다.
+        let start = if start < 0 { (len + start).max(0) } else { start };
+        let end = if end < 0 { (len + end).max(0) } else { end.min(len - 1) };
+
+        if len == 0 || start > end {
+            return Ok(Bytes::new());
+        }
+
+        //  'Bytes'의 slice는 버퍼를 공유하므로 데이터는 복사되지 않는다.
+        Ok(data.slice(start as usize..=end as usize))
+    }
+
+    ///  키에 저장된 값에서 1로 설정된 비트의 수를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+    ///  
+    ///  'range'가 'None'이라면 값 전체를 센다. 그렇지 않다면 양 끝을 포함하는 범위만을 세며, 'bit'가
+    ///  'true'라면 범위를 바이트가 아닌 비트 오프셋으로 해석한다. 비트 오프셋은 각 바이트의 최상위
+    ///  비트부터 센다. 음수와 값을 벗어나는 범위는 'getrange'와 같이 다룬다.
+    ///  
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn bitcount(
+        &self,
+        key: &str,
+        range: Option<(i64, i64)>,
+        bit: bool,
+    ) -> crate::Result<usize> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let data = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_string().ok_or(WRONGTYPE)?,
+            None => return Ok(0),
+        };
+
+        let count_ones = |bytes: &[u8]| bytes.iter().map(|byte| byte.count_ones() as usize).sum();
+
+        let (start, end) = match range {
+            Some(range) => range,
+            None => return Ok(count_ones(data)),
+        };
+
+        //  음수 오프셋을 값의 끝에서부터의 위치로 바꾸고, 값의 범위로 제한한다.
+        let len = if bit { data.len() as i64 * 8 } else { data.len() as i64 };
+        let start = if start < 0 { (len + start).max(0) } else { start };
+        let end = if end < 0 { (len + end).max(0) } else { end.min(len - 1) };
+
+        if len == 0 || start > end {
+            return Ok(0);
+        }
+
+        let (start, end) = (start as usize, end as usize);
+
+        if !bit {
+            return Ok(count_ones(&data[start..=end]));
+        }
+
+        //  범위에 걸친 바이트를 모두 센 뒤, 첫 바이트에서 범위 앞의 비트와 마지막 바이트에서 범위
+        //  뒤의 비트를 뺀다.
+        let (first, last) = (start / 8, end / 8);
+        let before = data[first] & !(0xff >> (start % 8));
+        let after = data[last] & 0xffu8.checked_shr(end as u32 % 8 + 1).unwrap_or(0);
+
+        Ok(count_ones(&data[first..=last])
+            - before.count_ones() as usize
+            - after.count_ones() as usize)
+    }
+
+    ///  키에 저장된 값의 'offset' 위치부터를 'value'로 덮어쓰고, 덮어쓴 뒤의 값의 길이를 반환한다.
+    ///  
+    ///  'offset'이 값의 길이보다 크면 그 사이를 0 바이트로 채운다. 키가 존재하지 않으면 빈 값에 덮어쓴
+    ///  결과를 만료 시간 없이 저장한다. 키에 설정된 만료 시간은 그대로 유지된다. 'value'가 비어있다면 값을
+    ///  변경하지 않으며, 키를 생성하지도 않는다.
+    ///  
+    ///  결과 값의 크기가 'MAX_STRING_SIZE'를 넘거나, 키에 문자열이 아닌 값이 저장되어 있다면 에러를 반환한다.
+    pub(crate) fn setrange(&self, key: &str, offset: u64, value: Bytes) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        //  만료된 값을 덮어쓰지 않도록 먼저 삭제한다.
+        state.remove_if_expired(key);
+
+        let prev = match state.entries.get(key) {
+            Some(entry) => entry.value.as_string().ok_or(WRONGTYPE)?.clone(),
+            None => Bytes::new(),
+        };
+        let current = prev.len();
+
+        if value.is_empty() {
+            return Ok(current);
+        }
+
+        if offset.saturating_add(value.len() as u64) > MAX_STRING_SIZE {
+            return Err("ERR string exceeds maximum allowed size (512MB)".into());
+        }
+
+        let offset = offset as usize;
+        let end = offset + value.len();
+
+        //  'append'와 마찬가지로, 저장된 'Bytes'를 제자리에서 변경할 수 없으므로 새로운 버퍼에 복사한다.
+        let mut data = BytesMut::with_capacity(current.max(end));
+        data.extend_from_slice(&prev);
+        if data.len() < end {
+            data.resize(end, 0);
+        }
+        data[offset..end].copy_from_slice(&value);
+
+        let data = data.freeze();
+        let len = data.len();
+
+        if let Some(entry) = state.entries.get_mut(key) {
+            //  만료 정보를 건드리지 않도록 데이터만 교체한다.
+            entry.value = Value::String(data);
+            state.resize(key, len, current);
+            state.touch(key);
+            return Ok(len);
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        state.insert_entry(key.to_string(), id, Value::String(data), None);
+
+        Ok(len)
+    }
+
+    ///  키에 저장된 값의 'offset' 위치의 비트를 'value'로 설정하고, 설정하기 전의 비트를 반환한다.
+    ///  
+    ///  비트 오프셋은 각 바이트의 최상위 비트부터 센다. 'offset'이 값을 벗어나면 필요한 만큼 0 바이트로
+    ///  채운다. 키가 존재하지 않으면 빈 값으로 간주하여 만료 시간 없이 저장한다. 키에 설정된 만료 시간은
+    ///  그대로 유지된다.
+    ///  
+    ///  'offset'이 'MAX_STRING_SIZE'에 해당하는 비트 수 이상이거나, 키에 문자열이 아닌 값이 저장되어
+    ///  있다면 에러를 반환한다.
+    pub(crate) fn setbit(&self, key: &str, offset: u64, value: bool) -> crate::Result<bool> {
+        if offset >= MAX_STRING_SIZE * 8 {
+            return Err(BIT_OFFSET_OUT_OF_RANGE.into());
+        }
+
+        let mut state = self.lock_shard(key);
+
+        //  만료된 값을 덮어쓰지 않도록 먼저 삭제한다.
+        state.remove_if_expired(key);
+
+        let prev = match state.entries.get(key) {
+            Some(entry) => entry.value.as_string().ok_or(WRONGTYPE)?.clone(),
+            None => Bytes::new(),
+        };
+        let current = prev.len();
+
+        let byte = (offset / 8) as usize;
+        let mask = 0x80 >> (offset % 8);
+
+        //  'setrange'와 마찬가지로, 저장된 'Bytes'를 제자리에서 변경할 수 없으므로 새로운 버퍼에 복사한다.
+        let mut data = BytesMut::with_capacity(current.max(byte + 1));
+        data.extend_from_slice(&prev);
+        if data.len() <= byte {
+            data.resize(byte + 1, 0);
+        }
+
+        let prev_bit = data[byte] & mask != 0;
+        if value {
+            data[byte] |= mask;
+        } else {
+            data[byte] &= !mask;
+        }
+
+        let data = data.freeze();
+        let len = data.len();
+
+        if let Some(entry) = state.entries.get_mut(key) {
+            //  만료 정보를 건드리지 않도록 데이터만 교체한다.
+            entry.value = Value::String(data);
+            state.resize(key, len, current);
+            state.touch(key);
+            return Ok(prev_bit);
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        state.insert_entry(key.to_string(), id, Value::String(data), None);
+
+        Ok(prev_bit)
+    }
+
+    ///  키에 저장된 값의 'offset' 위치의 비트를 반환한다.
+    ///  
+    ///  'offset'이 값을 벗어나거나 키가 존재하지 않으면 'false'를 반환한다. 'offset'의 범위와 값의 타입은
+    ///  'setbit'와 같이 검사한다.
+    pub(crate) fn getbit(&self, key: &str, offset: u64) -> crate::Result<bool> {
+        if offset >= MAX_STRING_SIZE * 8 {
+            return Err(BIT_OFFSET_OUT_OF_RANGE.into());
+        }
+
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let data = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_string().ok_or(WRONGTYPE)?,
+            None => return Ok(false),
+        };
+
+        Ok(data
+            .get((offset / 8) as usize)
+            .is_some_and(|byte| byte & (0x80 >> (offset % 8)) != 0))
+    }
+
+    ///  리스트의 앞('left'가 'true'인 경우) 혹은 뒤에 'values'를 차례로 삽입하고, 삽입 후의 리스트의 길이를
+    ///  반환한다.
+    ///  
+    ///  값들은 하나씩 차례로 삽입되므로, 앞에 삽입하는 경우 리스트에서의 순서는 'values'의 역순이 된다. 키가
+    ///  존재하지 않으면 새로운 리스트를 만료 시간 없이 저장한다. 키에 리스트가 아닌 값이 저장되어 있다면
+    ///  'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn push(&self, key: &str, values: Vec<Bytes>, left: bool) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        //  만료된 리스트에 삽입하지 않도록 먼저 삭제한다.
+        state.remove_if_expired(key);
+
+        if !state.entries.contains_key(key) {
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.insert_entry(key.to_string(), id, Value::List(VecDeque::new()), None);
+        }
+
+        let list = state
+            .entries
+            .get_mut(key)
+            .and_then(|entry| entry.value.as_list_mut())
+            .ok_or(WRONGTYPE)?;
+
+        let added = values.iter().map(Bytes::len).sum();
+        for value in values {
+            if left {
+                list.push_front(value);
+            } else {
+                list.push_back(value);
+            }
+        }
+
+        let len = list.len();
+        state.resize(key, added, 0);
+        state.touch(key);
+
+        //  리스트를 기다리는 커넥션들을 깨운다. 깨어난 커넥션들은 락을 다시 잡고 원소를 꺼내려 시도하므로,
+        //  원소보다 많은 커넥션을 깨우더라도 각 원소는 한 커넥션에게만 전달된다.
+        if let Some(notify) = state.list_waiters.get(key) {
+            notify.notify_waiters();
+        }
+
+        Ok(len)
+    }
+
+    ///  'keys'의 리스트들을 순서대로 확인하여, 처음으로 찾은 비어있지 않은 리스트에서 원소 하나를 꺼낸다.
+    ///  
+    ///  원소를 꺼낸 키와 원소를 반환한다. 모든 리스트가 비어있다면 'None'을 반환한다. 리스트가 아닌 값이
+    ///  저장된 키를 만나면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn pop_first(
+        &self,
+        keys: &[String],
+        left: bool,
+    ) -> crate::Result<Option<(String, Bytes)>> {
+        for key in keys {
+            let value = self.pop(key, 1, left)?.and_then(|values| values.into_iter().next());
+
+            if let Some(value) = value {
+                return Ok(Some((key.clone(), value)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    ///  'keys'의 리스트에 원소가 삽입될 때 알림을 받기 위한 'Notify'들을 반환한다.
+    ///  
+    ///  알림을 놓치지 않으려면, 리스트를 확인하기 전에 'Notify::notified'로 future를 먼저 생성해야 한다.
+    ///  기다림을 마친 뒤에는 반환된 'Notify'들을 drop하고 'release_list_waiters'를 호출해야 한다.
+    pub(crate) fn list_waiters(&self, keys: &[String]) -> Vec<Arc<Notify>> {
+        keys.iter()
+            .map(|key| {
+                let mut state = self.lock_shard(key);
+                state.list_waiters.entry(key.clone()).or_default().clone()
+            })
+            .collect()
+    }
+
+    ///  더 이상 기다리는 커넥션이 없는 키의 'Notify'를 삭제한다.
+    pub(crate) fn release_list_waiters(&self, keys: &[String]) {
+        for key in keys {
+            let mut state = self.lock_shard(key);
+
+            //  'State'가 가진 레퍼런스만 남아있다면 기다리는 커넥션이 없는 것이다.
+            let unused = state
+                .list_waiters
+                .get(key)
+                .map(|notify| Arc::strong_count(notify) == 1)
+                .unwrap_or(false);
+
+            if unused {
+                state.list_waiters.remove(key);
+            }
+        }
+    }
+
+    ///  리스트의 앞('left'가 'true'인 경우) 혹은 뒤에서 최대 'count'개의 원소를 꺼낸다.
+    ///  
+    ///  꺼낸 원소는 꺼낸 순서대로 반환된다. 리스트의 마지막 원소를 꺼내면 키를 삭제한다. 키가 존재하지
+    ///  않으면 'None'을, 키에 리스트가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn pop(
+        &self,
+        key: &str,
+        count: usize,
+        left: bool,
+    ) -> crate::Result<Option<Vec<Bytes>>> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let list = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_list_mut().ok_or(WRONGTYPE)?,
+            None => return Ok(None),
+        };
+
+        let count = count.min(list.len());
+        let values: Vec<Bytes> = if left {
+            list.drain(..count).collect()
+        } else {
+            list.drain(list.len() - count..).rev().collect()
+        };
+
+        //  빈 리스트는 저장하지 않는다. 'remove_entry'는 만료 정보도 함께 삭제한다.
+        if list.is_empty() {
+            state.remove_entry(key);
+        } else if count > 0 {
+            state.resize(key, 0, values.iter().map(Bytes::len).sum());
+            state.touch(key);
+        }
+
+        Ok(Some(values))
+    }
+
+    ///  리스트의 길이를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+    ///  
+    ///  키에 리스트가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn llen(&self, key: &str) -> crate::Result<usize> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_list().ok_or(WRONGTYPE)?.len()),
+            None => Ok(0),
+        }
+    }
+
+    ///  리스트의 'start'부터 'stop'까지의 원소를 반환한다.
+    ///  
+    ///  'start'와 'stop'은 양 끝을 포함하며, 음수는 리스트의 끝에서부터의 위치를 나타낸다. 범위가 리스트를
+    ///  벗어나면 리스트의 범위로 제한된다. 키가 존재하지 않거나 범위가 비어있으면 빈 목록을 반환한다.
+    ///  
+    ///  키에 리스트가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn lrange(&self, key: &str, start: i64, stop: i64) -> crate::Result<Vec<Bytes>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let list = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_list().ok_or(WRONGTYPE)?,
+            None => return Ok(vec![]),
+        };
+
+        let len = list.len() as i64;
+
+        //  음수 인덱스를 리스트의 끝에서부터의 위치로 바꾸고, 리스트의 범위로 제한한다.
+        let start = if start < 0 { (len + start).max(0) } else { start };
+        let stop = if stop < 0 { len + stop } else { stop.min(len - 1) };
+
+        if start > stop || start >= len {
+            return Ok(vec![]);
+        }
+
+        //  'Bytes'의 clone은 shallow이므로 원소의 데이터는 복사되지 않는다.
+        Ok(list
+            .range(start as usize..=stop as usize)
+            .cloned()
+            .collect())
+    }
+
+    ///  'src' 리스트의 앞('from_left'가 'true'인 경우) 혹은 뒤에서 원소 하나를 꺼내, 'dst' 리스트의
+    ///  앞('to_left'가 'true'인 경우) 혹은 뒤에 삽입하고, 옮긴 원소를 반환한다.
+    ///  
+    ///  두 리스트의 샤드 락을 함께 잡으므로, 다른 커넥션은 원소가 어느 리스트에도 없는 상태를 볼 수 없다.
+    ///  'src'와 'dst'가 같다면 리스트를 회전시킨다. 'src'가 존재하지 않으면 'dst'를 생성하지 않고
+    ///  'None'을 반환한다. 두 키 중 하나에 리스트가 아닌 값이 저장되어 있다면 아무것도 옮기지 않고
+    ///  'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn lmove(
+        &self,
+        src: &str,
+        dst: &str,
+        from_left: bool,
+        to_left: bool,
+    ) -> crate::Result<Option<Bytes>> {
+        let mut shards = self.lock_shards([src, dst].iter().copied());
+        let src_index = self.keyspace().shard_index(src);
+        let dst_index = self.keyspace().shard_index(dst);
+
+        let state = shards.get_mut(src_index);
+        state.remove_if_expired(src);
+        match state.entries.get(src) {
+            Some(entry) if entry.value.as_list().is_none() => return Err(WRONGTYPE.into()),
+            Some(_) => {}
+            None => return Ok(None),
+        }
+
+        //  원소를 꺼내기 전에 대상 키의 타입을 확인하여, 원소가 사라지지 않도록 한다.
+        let state = shards.get_mut(dst_index);
+        state.remove_if_expired(dst);
+        if state.entries.get(dst).is_some_and(|entry| entry.value.as_list().is_none()) {
+            return Err(WRONGTYPE.into());
+        }
+
+        let state = shards.get_mut(src_index);
+        let list = state.entries.get_mut(src).and_then(|entry| entry.value.as_list_mut()).unwrap();
+
+        let value = if from_left { list.pop_front() } else { list.pop_back() };
+        let value = match value {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        //  같은 리스트라면 꺼낸 원소를 반대쪽 끝에 다시 삽입한다. 키를 삭제하지 않으므로 만료 시간도
+        //  유지된다.
+        if src == dst {
+            if to_left {
+                list.push_front(value.clone());
+            } else {
+                list.push_back(value.clone());
+            }
+            state.touch(src);
+
+            return Ok(Some(value));
+        }
+
+        //  빈 리스트는 저장하지 않는다. 'remove_entry'는 만료 정보도 함께 삭제한다.
+        let size = value.len();
+        if list.is_empty() {
+            state.remove_entry(src);
+        } else {
+            state.resize(src, 0, size);
+            state.touch(src);
+        }
+
+        let state = shards.get_mut(dst_index);
+        if !state.entries.contains_key(dst) {
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.insert_entry(dst.to_string(), id, Value::List(VecDeque::new()), None);
+        }
+
+        let list = state.entries.get_mut(dst).and_then(|entry| entry.value.as_list_mut()).unwrap();
+        if to_left {
+            list.push_front(value.clone());
+        } else {
+            list.push_back(value.clone());
+        }
+
+        state.resize(dst, size, 0);
+        state.touch(dst);
+
+        //  리스트를 기다리는 커넥션이 있다면 깨운다.
+        if let Some(notify) = state.list_waiters.get(dst) {
+            notify.notify_waiters();
+        }
+
+        Ok(Some(value))
+    }
+
+    ///  리스트에서 'element'와 같은 원소의 인덱스를 최대 'count'개 반환한다. 'count'가 0이면 일치하는
+    ///  모든 원소의 인덱스를 반환한다.
+    ///  
+    ///  'rank'번째로 일치하는 원소부터 찾는다. 'rank'가 음수이면 리스트의 뒤에서부터 찾으며, 인덱스는 찾은
+    ///  순서대로 반환된다. 키가 존재하지 않거나 일치하는 원소가 없으면 빈 목록을 반환한다.
+    ///  
+    ///  키에 리스트가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn lpos(
+        &self,
+        key: &str,
+        element: &[u8],
+        rank: i64,
+        count: usize,
+    ) -> crate::Result<Vec<usize>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let list = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_list().ok_or(WRONGTYPE)?,
+            None => return Ok(vec![]),
+        };
+
+        let count = if count == 0 { usize::MAX } else { count };
+
+        //  'rank'번째 일치 전까지의 원소는 건너뛴다.
+        let skip = (rank.unsigned_abs() - 1) as usize;
+
+        let matches = |(_, value): &(usize, &Bytes)| &value[..] == element;
+        let positions = if rank > 0 {
+            list.iter().enumerate().filter(matches).skip(skip).take(count).map(|(i, _)| i).collect()
+        } else {
+            list.iter()
+                .enumerate()
+                .rev()
+                .filter(matches)
+                .skip(skip)
+                .take(count)
+                .map(|(i, _)| i)
+                .collect()
+        };
+
+        Ok(positions)
+    }
+
+    ///  리스트에서 'pivot'과 같은 첫 원소의 앞('before'가 'true'인 경우) 혹은 뒤에 'value'를 삽입하고,
+    ///  삽입 후의 리스트의 길이를 반환한다.
+    ///  
+    ///  'pivot'을 찾지 못하면 -1을, 키가 존재하지 않으면 0을 반환한다. 키에 리스트가 아닌 값이 저장되어
+    ///  있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn linsert(
+        &self,
+        key: &str,
+        before: bool,
+        pivot: &[u8],
+        value: Bytes,
+    ) -> crate::Result<i64> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let list = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_list_mut().ok_or(WRONGTYPE)?,
+            None => return Ok(0),
+        };
+
+        let index = match list.iter().position(|elem| &elem[..] == pivot) {
+            Some(index) if before => index,
+            Some(index) => index + 1,
+            None => return Ok(-1),
+        };
+
+        let added = value.len();
+        list.insert(index, value);
+
+        let len = list.len();
+        state.resize(key, added, 0);
+        state.touch(key);
+
+        Ok(len as i64)
+    }
+
+    ///  리스트의 'index' 위치의 원소를 'value'로 바꾼다. 음수는 리스트의 끝에서부터의 위치를 나타낸다.
+    ///  
+    ///  키가 존재하지 않거나 인덱스가 리스트의 범위를 벗어나면 에러를 반환한다. 키에 리스트가 아닌 값이
+    ///  저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn lset(&self, key: &str, index: i64, value: Bytes) -> crate::Result<()> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let list = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_list_mut().ok_or(WRONGTYPE)?,
+            None => return Err("ERR no such key".into()),
+        };
+
+        let len = list.len() as i64;
+        let index = if index < 0 { len + index } else { index };
+
+        if index < 0 || index >= len {
+            return Err("ERR index out of range".into());
+        }
+
+        let added = value.len();
+        let prev = std::mem::replace(&mut list[index as usize], value);
+
+        state.resize(key, added, prev.len());
+        state.touch(key);
+
+        Ok(())
+    }
+
+    ///  리스트에서 'element'와 같은 원소를 최대 'count'개 삭제하고, 삭제한 원소의 수를 반환한다.
+    ///  
+    ///  'count'가 양수이면 리스트의 앞에서부터, 음수이면 뒤에서부터 삭제한다. 0이면 같은 원소를 모두
+    ///  삭제한다. 리스트의 모든 원소를 삭제하면 키를 삭제한다. 키가 존재하지 않으면 0을 반환한다.
+    ///  
+    ///  키에 리스트가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn lrem(&self, key: &str, count: i64, element: &[u8]) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let list = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_list_mut().ok_or(WRONGTYPE)?,
+            None => return Ok(0),
+        };
+
+        let limit = if count == 0 { usize::MAX } else { count.unsigned_abs() as usize };
+
+        //  삭제할 원소의 인덱스를 찾는 방향대로 모은다.
+        let mut positions: Vec<usize> = if count >= 0 {
+            list.iter()
+                .enumerate()
+                .filter(|(_, elem)| &elem[..] == element)
+                .map(|(i, _)| i)
+                .take(limit)
+                .collect()
+        } else {
+            list.iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, elem)| &elem[..] == element)
+                .map(|(i, _)| i)
+                .take(limit)
+                .collect()
+        };
+
+        if positions.is_empty() {
+            return Ok(0);
+        }
+
+        //  원소를 한 번에 걸러내어, 삭제할 때마다 나머지 원소를 옮기지 않는다.
+        positions.sort_unstable();
+        let mut remove = positions.iter().peekable();
+        let mut index = 0;
+        list.retain(|_| {
+            let keep = remove.next_if_eq(&&index).is_none();
+            index += 1;
+            keep
+        });
+
+        let removed = positions.len() * element.len();
+
+        if list.is_empty() {
+            state.remove_entry(key);
+        } else {
+            state.resize(key, 0, removed);
+            state.touch(key);
+        }
+
+        Ok(positions.len())
+    }
+
+    ///  리스트를 'start'부터 'stop'까지의 원소만 남도록 자른다.
+    ///  
+    ///  'start'와 'stop'은 'lrange'와 같이 해석한다. 남는 원소가 없으면 키를 삭제한다. 키가 존재하지 않으면
+    ///  아무것도 하지 않는다. 키에 리스트가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn ltrim(&self, key: &str, start: i64, stop: i64) -> crate::Result<()> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let list = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_list_mut().ok_or(WRONGTYPE)?,
+            None => return Ok(()),
+        };
+
+        let len = list.len() as i64;
+
+        //  음수 인덱스를 리스트의 끝에서부터의 위치로 바꾸고, 리스트의 범위로 제한한다.
+        let start = if start < 0 { (len + start).max(0) } else { start };
+        let stop = if stop < 0 { len + stop } else { stop.min(len - 1) };
+
+        //  남는 원소가 없다면 빈 리스트는 저장하지 않으므로 키를 삭제한다.
+        if start > stop || start >= len {
+            state.remove_entry(key);
+            return Ok(());
+        }
+
+        let prev_len = list.len();
+        let mut removed: usize = list.drain(stop as usize + 1..).map(|elem| elem.len()).sum();
+        removed += list.drain(..start as usize).map(|elem| elem.len()).sum::<usize>();
+
+        if list.len() < prev_len {
+            state.resize(key, 0, removed);
+            state.touch(key);
+        }
+
+        Ok(())
+    }
+
+    ///  해시에 필드-값 쌍들을 저장하고, 새로 생성된 필드의 수를 반환한다.
+    ///  
+    ///  이미 존재하는 필드의 값은 덮어쓴다. 키가 존재하지 않으면 새로운 해시를 만료 시간 없이 저장한다.
+    ///  키에 해시가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn hset(&self, key: &str, pairs: Vec<(String, Bytes)>) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        if !state.entries.contains_key(key) {
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.insert_entry(key.to_string(), id, Value::Hash(HashMap::new()), None);
+        }
+
+        let hash = state
+            .entries
+            .get_mut(key)
+            .and_then(|entry| entry.value.as_hash_mut())
+            .ok_or(WRONGTYPE)?;
+
+        let mut created = 0;
+        let (mut added, mut removed) = (0, 0);
+        for (field, value) in pairs {
+            let field_len = field.len();
+            added += value.len();
+
+            match hash.insert(field, value) {
+                Some(prev) => removed += prev.len(),
+                None => {
+                    created += 1;
+                    added += field_len;
+                }
+            }
+        }
+
+        state.resize(key, added, removed);
+        state.touch(key);
+
+        Ok(created)
+    }
+
+    ///  해시에서 필드의 값을 가져온다. 키나 필드가 존재하지 않으면 'None'을 반환한다.
+    ///  
+    ///  키에 해시가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn hget(&self, key: &str, field: &str) -> crate::Result<Option<Bytes>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_hash().ok_or(WRONGTYPE)?.get(field).cloned()),
+            None => Ok(None),
+        }
+    }
+
+    ///  해시의 필드에 저장된 값을 십진수 정수로 해석하여 'delta'만큼 더하고, 그 결과를 반환한다.
+    ///  
+    ///  필드가 존재하지 않으면 0에서 시작하며, 키가 존재하지 않으면 새로운 해시를 만료 시간 없이
+    ///  저장한다. 필드의 값이 정수가 아니거나 결과가 'i64'의 범위를 벗어나면 에러를 반환한다. 키에 해시가
+    ///  아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn hincr_by(&self, key: &str, field: &str, delta: i64) -> crate::Result<i64> {
+        self.hupdate(key, field, |prev| {
+            let value = match prev {
+                Some(data) => std::str::from_utf8(data)
+                    .ok()
+                    .and_then(|s| s.parse::<i64>().ok())
+                    .ok_or("ERR hash value is not an integer")?,
+                None => 0,
+            };
+            let value = value
+                .checked_add(delta)
+                .ok_or("ERR increment or decrement would overflow")?;
+
+            Ok((value, Bytes::from(value.to_string())))
+        })
+    }
+
+    ///  해시의 필드에 저장된 값을 실수로 해석하여 'delta'만큼 더하고, 그 결과를 반환한다.
+    ///  
+    ///  'hincr_by'와 같지만, 필드의 값이 실수가 아니거나 결과가 NaN 또는 무한대라면 에러를 반환한다.
+    ///  결과는 레디스와 같이 유효숫자 17자리로 반올림하고 끝에 붙은 0을 지운 십진수 표현으로 저장된다.
+    pub(crate) fn hincr_by_float(
+        &self,
+        key: &str,
+        field: &str,
+        delta: f64,
+    ) -> crate::Result<Bytes> {
+        self.hupdate(key, field, |prev| {
+            let value = match prev {
+                Some(data) => std::str::from_utf8(data)
+                    .ok()
+                    .and_then(|s| s.parse::<f64>().ok())
+                    .filter(|value| value.is_finite())
+                    .ok_or("ERR hash value is not a float")?,
+                None => 0.0,
+            };
+            if !(value + delta).is_finite() {
+                return Err("ERR increment would produce NaN or Infinity".into());
+            }
+
+            let data = Bytes::from(crate::decimal::add(value, delta));
+            Ok((data.clone(), data))
+        })
+    }
+
+    ///  해시의 필드에 저장된 값을 'update'가 반환하는 값으로 교체하고, 'update'가 함께 반환한 결과를
+    ///  반환한다.
+    ///  
+    ///  'update'는 필드의 기존 값을 받으며, 필드가 존재하지 않으면 'None'을 받는다. 'update'가 에러를
+    ///  반환하면 해시는 변경되지 않는다. 키가 존재하지 않으면 새로운 해시를 만료 시간 없이 저장한다.
+    fn hupdate<T>(
+        &self,
+        key: &str,
+        field: &str,
+        update: impl FnOnce(Option<&[u8]>) -> crate::Result<(T, Bytes)>,
+    ) -> crate::Result<T> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        if let Some(entry) = state.entries.get_mut(key) {
+            let hash = entry.value.as_hash_mut().ok_or(WRONGTYPE)?;
+            let prev = hash.get(field);
+            let (ret, data) = update(prev.map(|prev| &prev[..]))?;
+
+            let (added, removed) = match prev {
+                Some(prev) => (data.len(), prev.len()),
+                None => (field.len() + data.len(), 0),
+            };
+            hash.insert(field.to_string(), data);
+
+            state.resize(key, added, removed);
+            state.touch(key);
+            return Ok(ret);
+        }
+
+        //  키가 존재하지 않는다. 필드 하나만을 가진 새로운 해시를 저장한다.
+        let (ret, data) = update(None)?;
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        let mut hash = HashMap::new();
+        hash.insert(field.to_string(), data);
+        state.insert_entry(key.to_string(), id, Value::Hash(hash), None);
+
+        Ok(ret)
+    }
+
+    ///  해시에서 필드들을 삭제하고, 실제로 삭제된 필드의 수를 반환한다.
+    ///  
+    ///  해시의 마지막 필드가 삭제되면 키도 삭제한다. 키에 해시가 아닌 값이 저장되어 있다면 'WRONGTYPE'
+    ///  에러를 반환한다.
+    pub(crate) fn hdel(&self, key: &str, fields: &[String]) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let hash = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_hash_mut().ok_or(WRONGTYPE)?,
+            None => return Ok(0),
+        };
+
+        let mut deleted = 0;
+        let mut removed = 0;
+        for field in fields {
+            if let Some(value) = hash.remove(field) {
+                deleted += 1;
+                removed += field.len() + value.len();
+            }
+        }
+
+        //  빈 해시는 저장하지 않는다.
+        if hash.is_empty() {
+            state.remove_entry(key);
+        } else if deleted > 0 {
+            state.resize(key, 0, removed);
+            state.touch(key);
+        }
+
+        Ok(deleted)
+    }
+
+    ///  해시의 모든 필드-값 쌍을 반환한다. 순서는 정해져 있지 않다.
+    ///  
+    ///  키가 존재하지 않으면 빈 목록을 반환한다. 키에 해시가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를
+    ///  반환한다.
+    pub(crate) fn hgetall(&self, key: &str) -> crate::Result<Vec<(String, Bytes)>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry
+                .value
+                .as_hash()
+                .ok_or(WRONGTYPE)?
+                .iter()
+                .map(|(field, value)| (field.clone(), value.clone()))
+                .collect()),
+            None => Ok(vec![]),
+        }
+    }
+
+    ///  해시에서 무작위로 고른 필드-값 쌍들을 반환한다.
+    ///  
+    ///  'count'가 양수라면 서로 다른 필드를 최대 'count'개, 음수라면 중복을 허용하여 정확히 '-count'개를
+    ///  고른다. 키가 존재하지 않으면 빈 목록을 반환한다. 키에 해시가 아닌 값이 저장되어 있다면
+    ///  'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn hrandfield(
+        &self,
+        key: &str,
+        count: i64,
+    ) -> crate::Result<Vec<(String, Bytes)>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => {
+                let hash = entry.value.as_hash().ok_or(WRONGTYPE)?;
+
+                Ok(sample(hash.iter(), count, &mut Rng::new())
+                    .into_iter()
+                    .map(|(field, value)| (field.clone(), value.clone()))
+                    .collect())
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    ///  해시의 필드 수를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+    ///  
+    ///  키에 해시가 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn hlen(&self, key: &str) -> crate::Result<usize> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_hash().ok_or(WRONGTYPE)?.len()),
+            None => Ok(0),
+        }
+    }
+
+    ///  셋에 멤버들을 추가하고, 새로 추가된 멤버의 수를 반환한다.
+    ///  
+    ///  이미 존재하는 멤버는 무시한다. 키가 존재하지 않으면 새로운 셋을 만료 시간 없이 저장한다. 키에 셋이
+    ///  아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn sadd(&self, key: &str, members: Vec<Bytes>) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        if !state.entries.contains_key(key) {
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.insert_entry(key.to_string(), id, Value::Set(HashSet::new()), None);
+        }
+
+        let set = state
+            .entries
+            .get_mut(key)
+            .and_then(|entry| entry.value.as_set_mut())
+            .ok_or(WRONGTYPE)?;
+
+        let mut added = 0;
+        let mut size = 0;
+        for member in members {
+            let len = member.len();
+
+            if set.insert(member) {
+                added += 1;
+                size += len;
+            }
+        }
+
+        if added > 0 {
+            state.resize(key, size, 0);
+            state.touch(key);
+        }
+
+        Ok(added)
+    }
+
+    ///  셋에서 멤버들을 삭제하고, 실제로 삭제된 멤버의 수를 반환한다.
+    ///  
+    ///  셋의 마지막 멤버가 삭제되면 키도 삭제한다. 키에 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를
+    ///  반환한다.
+    pub(crate) fn srem(&self, key: &str, members: &[Bytes]) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        let set = match state.entries.get_mut(key) {
+            Some(entry) => entry.value.as_set_mut().ok_or(WRONGTYPE)?,
+            None => return Ok(0),
+        };
+
+        let mut removed = 0;
+        let mut size = 0;
+        for member in members {
+            if set.remove(member) {
+                removed += 1;
+                size += member.len();
+            }
+        }
+
+        //  빈 셋은 저장하지 않는다.
+        if set.is_empty() {
+            state.remove_entry(key);
+        } else if removed > 0 {
+            state.resize(key, 0, size);
+            state.touch(key);
+        }
+
+        Ok(removed)
+    }
+
+    ///  셋의 모든 멤버를 반환한다. 순서는 정해져 있지 않다.
+    ///  
+    ///  키가 존재하지 않으면 빈 목록을 반환한다. 키에 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를
+    ///  반환한다.
+    pub(crate) fn smembers(&self, key: &str) -> crate::Result<Vec<Bytes>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_set().ok_or(WRONGTYPE)?.iter().cloned().collect()),
+            None => Ok(vec![]),
+        }
+    }
+
+    ///  셋에 멤버가 포함되어 있는지 확인한다. 키가 존재하지 않으면 'false'를 반환한다.
+    ///  
+    ///  키에 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn sismember(&self, key: &str, member: &[u8]) -> crate::Result<bool> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_set().ok_or(WRONGTYPE)?.contains(member)),
+            None => Ok(false),
+        }
+    }
+
+    ///  셋에서 무작위로 고른 멤버들을 반환한다.
+    ///  
+    ///  'count'가 양수라면 서로 다른 멤버를 최대 'count'개, 음수라면 중복을 허용하여 정확히 '-count'개를
+    ///  고른다. 키가 존재하지 않으면 빈 목록을 반환한다. 키에 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE'
+    ///  에러를 반환한다.
+    pub(crate) fn srandmember(&self, key: &str, count: i64) -> crate::Result<Vec<Bytes>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => {
+                let set = entry.value.as_set().ok_or(WRONGTYPE)?;
+
+                Ok(sample(set.iter(), count, &mut Rng::new()).into_iter().cloned().collect())
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    ///  셋의 멤버 수를 반환한다. 키가 존재하지 않으면 0을 반환한다.
+    ///  
+    ///  키에 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn scard(&self, key: &str) -> crate::Result<usize> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_set().ok_or(WRONGTYPE)?.len()),
+            None => Ok(0),
+        }
+    }
+
+    ///  'keys'의 셋들에 셋 연산을 수행한다.
+    ///  
+    ///  'dest'가 주어지지 않으면 연산 결과의 멤버 목록을 반환한다. 주어지면 결과를 'dest'에 만료 시간
+    ///  없이 저장하고, 저장한 셋의 멤버 수를 반환한다. 결과가 빈 셋이라면 'dest'를 삭제한다. 'dest'에
+    ///  저장되어 있던 값은 타입과 관계 없이 대체된다.
+    ///  
+    ///  존재하지 않는 키는 빈 셋으로 취급한다. 셋이 아닌 값이 저장된 키가 있다면 'WRONGTYPE' 에러를
+    ///  반환한다. 관련된 모든 샤드의 락을 잡은 상태에서 연산하므로, 결과는 한 시점의 셋들로 계산된다.
+    pub(crate) fn set_op(
+        &self,
+        op: SetOp,
+        keys: &[String],
+        dest: Option<&str>,
+    ) -> crate::Result<SetOpResult> {
+        let keyspace = self.keyspace();
+        let mut shards = self.lock_shards(keys.iter().map(String::as_str).chain(dest));
+
+        for key in keys {
+            shards.get_mut(keyspace.shard_index(key)).remove_if_expired(key);
+        }
+
+        let mut sets = Vec::with_capacity(keys.len());
+        for key in keys {
+            match shards.get(keyspace.shard_index(key)).entries.get(key) {
+                Some(entry) => sets.push(Some(entry.value.as_set().ok_or(WRONGTYPE)?)),
+                None => sets.push(None),
+            }
+        }
+
+        //  'sets'는 샤드의 락이 보호하는 셋을 빌린다. 결과를 저장하기 전에 빌림을 끝낸다.
+        let (first, rest) = sets.split_first().expect("at least one key");
+        let result: HashSet<Bytes> = match op {
+            SetOp::Inter => match first {
+                Some(first) if rest.iter().all(Option::is_some) => first
+                    .iter()
+                    .filter(|member| rest.iter().flatten().all(|set| set.contains(*member)))
+                    .cloned()
+                    .collect(),
+                //  빈 셋과의 교집합은 빈 셋이다.
+                _ => HashSet::new(),
+            },
+            SetOp::Union => sets.iter().flatten().flat_map(|set| set.iter().cloned()).collect(),
+            SetOp::Diff => match first {
+                Some(first) => first
+                    .iter()
+                    .filter(|member| !rest.iter().flatten().any(|set| set.contains(*member)))
+                    .cloned()
+                    .collect(),
+                None => HashSet::new(),
+            },
+        };
+
+        let dest = match dest {
+            Some(dest) => dest,
+            None => return Ok(SetOpResult::Members(result.into_iter().collect())),
+        };
+
+        let len = result.len();
+        let state = shards.get_mut(keyspace.shard_index(dest));
+        let removed = state.remove_entry(dest).is_some();
+
+        //  빈 셋은 저장하지 않는다.
+        let event = if len > 0 {
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.insert_entry(dest.to_string(), id, Value::Set(result), None);
+            Some(op.store_event())
+        } else if removed {
+            Some("del")
+        } else {
+            None
+        };
+
+        drop(shards);
+
+        if let Some(event) = event {
+            self.shared.notify_keyspace_event(self.index, event, dest);
+        }
+
+        Ok(SetOpResult::Stored(len))
+    }
+
+    ///  정렬된 셋에 점수와 멤버의 쌍들을 저장하고, 새로 추가된 멤버의 수를 반환한다.
+    ///  
+    ///  이미 존재하는 멤버는 점수를 갱신한다. 키가 존재하지 않으면 새로운 정렬된 셋을 만료 시간 없이
+    ///  저장한다. 키에 정렬된 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn zadd(&self, key: &str, pairs: Vec<(f64, Bytes)>) -> crate::Result<usize> {
+        let mut state = self.lock_shard(key);
+
+        state.remove_if_expired(key);
+
+        if !state.entries.contains_key(key) {
+            let id = state.next_id;
+            state.next_id += 1;
+
+            state.insert_entry(key.to_string(), id, Value::SortedSet(SortedSet::new()), None);
+        }
+
+        let zset = state
+            .entries
+            .get_mut(key)
+            .and_then(|entry| entry.value.as_zset_mut())
+            .ok_or(WRONGTYPE)?;
+
+        let mut added = 0;
+        let mut size = 0;
+        for (score, member) in pairs {
+            let len = member.len();
+
+            if zset.insert(member, score) {
+                added += 1;
+                size += len + std::mem::size_of::<f64>();
+            }
+        }
+
+        //  점수만 갱신된 경우에도 값은 변경되었다.
+        state.resize(key, size, 0);
+        state.touch(key);
+
+        Ok(added)
+    }
+
+    ///  정렬된 셋에서 멤버의 점수를 반환한다. 키나 멤버가 존재하지 않으면 'None'을 반환한다.
+    ///  
+    ///  키에 정렬된 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn zscore(&self, key: &str, member: &[u8]) -> crate::Result<Option<f64>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_zset().ok_or(WRONGTYPE)?.score(member)),
+            None => Ok(None),
+        }
+    }
+
+    ///  정렬된 셋에서 멤버의 위치(0부터 시작)를 반환한다. 키나 멤버가 존재하지 않으면 'None'을 반환한다.
+    ///  
+    ///  키에 정렬된 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn zrank(&self, key: &str, member: &[u8]) -> crate::Result<Option<usize>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => Ok(entry.value.as_zset().ok_or(WRONGTYPE)?.rank(member)),
+            None => Ok(None),
+        }
+    }
+
+    ///  정렬된 셋의 'start'부터 'stop'까지 위치의 멤버와 점수를 정렬된 순서로 반환한다.
+    ///  
+    ///  위치는 'lrange'와 같이 해석한다. 키가 존재하지 않으면 빈 목록을 반환한다. 키에 정렬된 셋이 아닌
+    ///  값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    pub(crate) fn zrange(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> crate::Result<Vec<(Bytes, f64)>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let zset = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_zset().ok_or(WRONGTYPE)?,
+            None => return Ok(vec![]),
+        };
+
+        let len = zset.len() as i64;
+
+        //  음수 인덱스를 끝에서부터의 위치로 바꾸고, 정렬된 셋의 범위로 제한한다.
+        let start = if start < 0 { (len + start).max(0) } else { start };
+        let stop = if stop < 0 { len + stop } else { stop.min(len - 1) };
+
+        if start > stop || start >= len {
+            return Ok(vec![]);
+        }
+
+        Ok(zset
+            .iter()
+            .skip(start as usize)
+            .take((stop - start + 1) as usize)
+            .map(|(member, score)| (member.clone(), score))
+            .collect())
+    }
+
+    ///  정렬된 셋에서 점수가 'min'과 'max' 사이인 멤버와 점수를 정렬된 순서로 반환한다.
+    ///  
+    ///  'limit'이 주어지면 범위 안의 멤버 중 'offset'개를 건너뛰고 최대 'count'개를 반환한다. 레디스와
+    ///  같이 'offset'이 음수이면 빈 목록을, 'count'가 음수이면 나머지 모든 멤버를 반환한다. 키가 존재하지
+    ///  않으면 빈 목록을 반환한다. 키에 정렬된 셋이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를
+    ///  반환한다.
+    pub(crate) fn zrange_by_score(
+        &self,
+        key: &str,
+        min: Bound<f64>,
+        max: Bound<f64>,
+        limit: Option<(i64, i64)>,
+    ) -> crate::Result<Vec<(Bytes, f64)>> {
+        let state = self.lock_shard(key);
+        let now = Instant::now();
+
+        let zset = match state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+        {
+            Some(entry) => entry.value.as_zset().ok_or(WRONGTYPE)?,
+            None => return Ok(vec![]),
+        };
+
+        let (offset, count) = match limit {
+            None => (0, usize::MAX),
+            Some((offset, _)) if offset < 0 => return Ok(vec![]),
+            Some((offset, count)) if count < 0 => (offset as usize, usize::MAX),
+            Some((offset, count)) => (offset as usize, count as usize),
+        };
+
+        Ok(zset
+            .range_by_score(min, max)
+            .skip(offset)
+            .take(count)
+            .map(|(member, score)| (member.clone(), score))
+            .collect())
+    }
+
+    ///  glob 패턴과 매칭되는 모든 키를 반환한다. 순서는 정해져 있지 않다.
+    ///  
+    ///  모든 샤드의 모든 키를 확인하므로 비용은 키의 수에 비례(O(n))한다. 한 번에 하나의 샤드의 락만을
+    ///  잡지만, 키가 많은 샤드를 확인하는 동안 그 샤드에 대한 다른 연산은 대기한다. 만료되었지만 아직
+    ///  퍼지되지 않은 키는 제외한다.
+    pub(crate) fn keys(&self, pattern: &str) -> Vec<String> {
+        let now = Instant::now();
+
+        self.keyspace()
+            .shards
+            .iter()
+            .flat_map(|shard| {
+                let state = shard.lock().unwrap();
+
+                state
+                    .entries
+                    .iter()
+                    .filter(|(_, entry)| entry.expires_at.map(|when| when > now).unwrap_or(true))
+                    .filter(|(key, _)| glob::matches(pattern.as_bytes(), key.as_bytes()))
+                    .map(|(key, _)| key.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    ///  키 공간을 커서 단위로 나누어 순회한다. 다음 커서와, 이번에 확인한 키 중 'pattern'과 매칭되는 키를
+    ///  반환한다.
+    ///  
+    ///  키는 해시 값의 순서로 순회되며, 커서는 다음에 확인할 해시 값이다. 순회는 커서 0에서 시작하고,
+    ///  반환된 커서가 0이면 모든 키를 확인한 것이다. 키를 정렬해 저장하지 않아도 커서가 키의 위치가 아닌
+    ///  해시 값을 가리키므로, 순회 중에 다른 키가 추가되거나 삭제되어도 순회 내내 존재한 키는 반드시 한 번
+    ///  반환된다.
+    ///  
+    ///  한 번에 최대 'count'개의 키를 확인한다. 해시 값이 같은 키들은 나누어 반환하지 않으므로 이보다 많을
+    ///  수 있다. 레디스와 마찬가지로 'pattern'은 키를 확인한 뒤에 적용되므로, 커서가 0이 아니어도 빈
+    ///  목록이 반환될 수 있다.
+    pub(crate) fn scan(
+        &self,
+        cursor: u64,
+        count: usize,
+        pattern: Option<&str>,
+    ) -> (u64, Vec<String>) {
+        let count = count.max(1);
+        let now = Instant::now();
+
+        //  이번에 확인할 키 이후에도 키가 남아있는지 여부
+        let mut remaining = false;
+
+        //  각 샤드에서 커서 이후의 가장 작은 해시 값을 가진 키들을 모은다. 전체에서 가장 작은 'count'개의
+        //  키는 반드시 이 안에 포함된다.
+        let mut candidates = vec![];
+        for shard in &self.keyspace().shards {
+            let state = shard.lock().unwrap();
+
+            let mut keys: Vec<(u64, &String)> = state
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.expires_at.map(|when| when > now).unwrap_or(true))
+                .map(|(key, _)| (key_hash(key), key))
+                .filter(|(hash, _)| *hash >= cursor)
+                .collect();
+
+            remaining |= take_smallest(&mut keys, count);
+            candidates.extend(keys.into_iter().map(|(hash, key)| (hash, key.clone())));
+        }
+
+        remaining |= take_smallest(&mut candidates, count);
+
+        //  마지막으로 확인한 키의 다음 해시 값부터 순회를 이어간다.
+        let next = match candidates.last() {
+            Some((hash, _)) if remaining => hash.checked_add(1).unwrap_or(0),
+            _ => 0,
+        };
+
+        let keys = candidates
+            .into_iter()
+            .map(|(_, key)| key)
+            .filter(|key| {
+                pattern
+                    .map(|pattern| glob::matches(pattern.as_bytes(), key.as_bytes()))
+                    .unwrap_or(true)
+            })
+            .collect();
+
+        (next, keys)
+    }
+
+    ///  이미 존재하는 키의 만료 시간을 변경한다.
+    ///
+    ///  'expire'가 'Some'이면 지금으로부터 주어진 시간이 지난 뒤 키가 만료되도록 설정한다. 'None'이면
+    ///  키의 만료 시간을 제거하여 키가 만료되지 않도록 한다.
+    ///
+    ///  키가 존재하고 만료 시간이 변경되었다면 'true'를 반환한다. 키가 존재하지 않거나, 만료 시간이 없는
+    ///  키의 만료 시간을 제거하려는 경우 'false'를 반환한다.
+    pub(crate) fn expire(&self, key: &str, expire: Option<Duration>) -> bool {
+        let mut state = self.lock_shard(key);
+
+        //  만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 키로 취급한다.
+        state.remove_if_expired(key);
+
+        let prev = match state.entries.get(key) {
+            Some(entry) => entry.expires_at,
+            None => return false,
+        };
+
+        //  만료 시간을 제거하는 경우, 만료 시간이 없는 키는 변경되지 않는다.
+        if expire.is_none() && prev.is_none() {
+            return false;
+        }
+
+        let notify = state.set_expiration(key, expire.map(|duration| Instant::now() + duration));
+        state.touch(key);
+
+        //  백그라운드 태스크에게 알리기 전에 뮤택스를 해제한다.
+        drop(state);
+
+        if notify {
+            self.shared.background_task.notify_one();
+        }
+
+        let event = if expire.is_some() { "expire" } else { "persist" };
+        self.shared.notify_keyspace_event(self.index, event, key);
+
+        true
+    }
+
+    ///  이미 존재하는 키가 'at' 시각에 만료되도록 설정한다.
+    ///
+    ///  키 공간은 'Instant'로 만료 시간을 다루므로, 지금 시각으로부터 'at'까지 남은 시간을 구해
+    ///  'expire'와 같이 설정한다. 'at'이 이미 지났다면 키를 바로 삭제한다.
+    ///
+    ///  키가 존재하여 만료 시간이 설정되었거나 삭제되었다면 'true'를 반환한다. 이미 만료되었지만 아직
+    ///  퍼지되지 않은 키는 존재하지 않는 키로 취급하여 'false'를 반환한다.
+    pub(crate) fn expire_at(&self, key: &str, at: SystemTime) -> bool {
+        match at.duration_since(SystemTime::now()) {
+            Ok(remaining) if !remaining.is_zero() => self.expire(key, Some(remaining)),
+            _ => self.del(&[key.to_string()]) == 1,
+        }
+    }
+
+    ///  키 목록에 해당하는 값들을 삭제하고, 실제로 삭제된 키의 수를 반환한다.
+    ///
+    ///  존재하지 않는 키는 무시한다. 만료 시간이 지났지만 아직 퍼지되지 않은 키는 존재하지 않는 키로
+    ///  취급한다. 키들이 속한 모든 샤드의 락을 잡은 상태에서 삭제한다.
+    pub(crate) fn del(&self, keys: &[String]) -> u64 {
+        let mut shards = self.lock_shards(keys.iter().map(String::as_str));
+        let mut deleted = vec![];
+
+        for key in keys {
+            let state = shards.get_mut(self.keyspace().shard_index(key));
+            state.remove_if_expired(key);
+
+            if state.remove_entry(key).is_some() {
+                deleted.push(key);
+            }
+        }
+
+        //  키 공간 알림은 샤드 락을 해제한 뒤에 발행한다.
+        drop(shards);
+
+        for key in &deleted {
+            self.shared.notify_keyspace_event(self.index, "del", key);
+        }
+
+        deleted.len() as u64
+    }
+
+    ///  'del'과 같이 키 목록에 해당하는 값들을 삭제하고, 실제로 삭제된 키의 수를 반환한다.
+    ///  
+    ///  키는 락을 잡은 상태에서 즉시 삭제되지만, 삭제된 값의 해제는 락을 해제한 뒤에 이루어진다. 원소가
+    ///  많은 컬렉션을 해제하는 동안 같은 샤드의 다른 커넥션이 기다리지 않는다. 'del'과 같이 만료된
+    ///  키는 삭제한 키로 세지 않는다.
+    pub(crate) fn unlink(&self, keys: &[String]) -> u64 {
+        let mut shards = self.lock_shards(keys.iter().map(String::as_str));
+        let mut deleted = vec![];
+        let mut values = vec![];
+
+        for key in keys {
+            let state = shards.get_mut(self.keyspace().shard_index(key));
+
+            state.remove_if_expired(key);
+
+            if let Some(entry) = state.remove_entry(key) {
+                deleted.push(key);
+                values.push(entry.value);
+            }
+        }
+
+        drop(shards);
+
+        //  락 밖에서 값을 해제한다.
+        drop(values);
+
+        for key in &deleted {
+            self.shared.notify_keyspace_event(self.index, "del", key);
+        }
+
+        deleted.len() as u64
+    }
+
+    ///  'src' 키의 이름을 'dst'로 변경한다. 값과 남은 만료 시간은 그대로 유지된다.
+    ///  
+    ///  'dst'에 이미 값이 있다면 덮어쓴다. 'nx'가 'true'라면 'dst'가 존재할 때 변경하지 않는다. 두 키가
+    ///  속한 샤드의 락을 잡은 상태에서 옮기므로, 다른 커넥션은 두 키가 모두 존재하거나 모두 존재하지 않는
+    ///  상태를 관찰할 수 없다.
+    pub(crate) fn rename(&self, src: &str, dst: &str, nx: bool) -> RenameResult {
+        let mut shards = self.lock_shards([src, dst].iter().copied());
+        let src_index = self.keyspace().shard_index(src);
+        let dst_index = self.keyspace().shard_index(dst);
+
+        let state = shards.get_mut(src_index);
+        state.remove_if_expired(src);
+        if !state.entries.contains_key(src) {
+            return RenameResult::NoSuchKey;
+        }
+
+        let state = shards.get_mut(dst_index);
+        state.remove_if_expired(dst);
+        if nx && state.entries.contains_key(dst) {
+            return RenameResult::DestinationExists;
+        }
+
+        //  자기 자신으로의 변경은 아무것도 하지 않는다.
+        if src == dst {
+            return RenameResult::Renamed;
+        }
+
+        //  'remove_entry'는 원본 키의 만료 정보도 함께 삭제한다.
+        let entry = shards.get_mut(src_index).remove_entry(src).unwrap();
+
+        let state = shards.get_mut(dst_index);
+        state.remove_entry(dst);
+
+        //  식별자는 샤드 안에서만 유니크하므로, 대상 키가 속한 샤드에서 새로운 식별자를 받는다.
+        //  만료 시각은 그대로이므로 백그라운드 태스크에게 알릴 필요는 없다.
+        let id = state.next_id;
+        state.next_id += 1;
+
+        if let Some(when) = entry.expires_at {
+            state.expirations.insert((when, id), dst.to_string());
+        }
+
+        state.insert_entry(dst.to_string(), id, entry.value, entry.expires_at);
+
+        //  리스트를 기다리는 커넥션이 있다면 깨운다.
+        if let Some(notify) = state.list_waiters.get(dst) {
+            notify.notify_waiters();
+        }
+
+        drop(shards);
+
+        self.shared.notify_keyspace_event(self.index, "rename_from", src);
+        self.shared.notify_keyspace_event(self.index, "rename_to", dst);
+
+        RenameResult::Renamed
+    }
+
+    ///  'src' 키의 값을 'dst'에 복사한다. 남은 만료 시간도 함께 복사되므로 두 키는 같은 시각에 만료된다.
+    ///  
+    ///  'dst'에 이미 값이 있다면 'replace'가 'true'일 때만 덮어쓴다. 값이 복사되었다면 'true'를 반환한다.
+    ///  리스트, 해시, 셋은 원소를 모두 복사하지만, 각 원소인 'Bytes'의 clone은 shallow이다.
+    ///  
+    ///  'src'와 'dst'가 같은 키라면 에러를 반환한다.
+    pub(crate) fn copy(&self, src: &str, dst: &str, replace: bool) -> crate::Result<bool> {
+        if src == dst {
+            return Err("ERR source and destination objects are the same".into());
+        }
+
+        let mut shards = self.lock_shards([src, dst].iter().copied());
+
+        let state = shards.get_mut(self.keyspace().shard_index(src));
+        state.remove_if_expired(src);
+
+        let (value, expires_at) = match state.entries.get(src) {
+            Some(entry) => (entry.value.clone(), entry.expires_at),
+            None => return Ok(false),
+        };
+
+        let state = shards.get_mut(self.keyspace().shard_index(dst));
+        state.remove_if_expired(dst);
+
+        if !replace && state.entries.contains_key(dst) {
+            return Ok(false);
+        }
+
+        state.remove_entry(dst);
+
+        //  원본 키가 같은 시각의 만료를 이미 갖고 있으므로 백그라운드 태스크에게 알릴 필요는 없다.
+        let id = state.next_id;
+        state.next_id += 1;
+
+        if let Some(when) = expires_at {
+            state.expirations.insert((when, id), dst.to_string());
+        }
+
+        state.insert_entry(dst.to_string(), id, value, expires_at);
+
+        //  리스트를 기다리는 커넥션이 있다면 깨운다.
+        if let Some(notify) = state.list_waiters.get(dst) {
+            notify.notify_waiters();
+        }
+
+        drop(shards);
+
+        self.shared.notify_keyspace_event(self.index, "copy_to", dst);
+
+        Ok(true)
+    }
+
+    ///  만료되지 않은 키의 수를 반환한다.
+    ///  
+    ///  한 번에 하나의 샤드의 락만을 잡으므로, 다른 커넥션이 키를 변경하는 중이라면 반환 값은 어느 한
+    ///  순간의 정확한 키의 수가 아닐 수 있다.
+    pub(crate) fn dbsize(&self) -> usize {
+        let now = Instant::now();
+
+        self.keyspace()
+            .shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .lock()
+                    .unwrap()
+                    .entries
+                    .values()
+                    .filter(|entry| entry.expires_at.map(|when| when > now).unwrap_or(true))
+                    .count()
+            })
+            .sum()
+    }
+
+    ///  만료되지 않은 키 중 하나를 무작위로 골라 반환한다. 키가 없다면 'None'을 반환한다.
+    ///  
+    ///  키 목록을 모으지 않고, 한 번의 순회로 저수지 표본 추출(reservoir sampling)을 수행한다. n번째로
+    ///  만난 키는 1/n의 확률로 이전에 고른 키를 대신하므로, 모든 키는 같은 확률로 선택된다. 'dbsize'와
+    ///  같이 한 번에 하나의 샤드의 락만을 잡는다.
+    pub(crate) fn random_key(&self) -> Option<String> {
+        let now = Instant::now();
+        let mut rng = Rng::new();
+        let mut seen = 0;
+        let mut chosen = None;
+
+        for shard in &self.keyspace().shards {
+            let state = shard.lock().unwrap();
+
+            let live = state
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.expires_at.map(|when| when > now).unwrap_or(true));
+
+            for (key, _) in live {
+                seen += 1;
+
+                if rng.below(seen) == 0 {
+                    chosen = Some(key.clone());
+                }
+            }
+        }
+
+        chosen
+    }
+
+    ///  이 핸들이 가리키는 데이터베이스의 만료되지 않은 모든 키를 값, 남은 만료 시간과 함께 반환한다.
+    ///  
+    ///  스냅샷을 저장하기 위해 사용한다. 'dbsize'와 같이 한 번에 하나의 샤드의 락만을 잡으므로, 반환 값은
+    ///  어느 한 순간의 정확한 키 공간이 아닐 수 있다.
+    pub(crate) fn export(&self) -> Vec<(String, Value, Option<Duration>)> {
+        let now = Instant::now();
+        let mut entries = vec![];
+
+        for shard in &self.keyspace().shards {
+            let state = shard.lock().unwrap();
+
+            for (key, entry) in &state.entries {
+                //  만료 시간이 지났지만 아직 퍼지되지 않은 키는 제외한다.
+                let ttl = match entry.expires_at {
+                    Some(when) if when <= now => continue,
+                    Some(when) => Some(when - now),
+                    None => None,
+                };
+
+                entries.push((key.clone(), entry.value.clone(), ttl));
+            }
+        }
+
+        entries
+    }
+
+    ///  쓰기 커맨드가 수행되었음을 기록한다. 핸들러가 쓰기 커맨드를 수행한 뒤에 호출한다.
+    pub(crate) fn record_change(&self) {
+        self.shared.changes_since_save.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///  마지막으로 스냅샷을 저장한 뒤 수행된 쓰기 커맨드의 수를 반환한다.
+    pub(crate) fn changes_since_save(&self) -> u64 {
+        self.shared.changes_since_save.load(Ordering::Relaxed)
+    }
+
+    ///  'changes'개의 변경이 스냅샷에 저장되었음을 기록한다.
+    ///  
+    ///  'changes'는 스냅샷을 만들기 전에 'changes_since_save'로 읽은 값이다. 저장 중에 수행된 쓰기
+    ///  커맨드는 스냅샷에 포함되지 않았을 수 있으므로, 값을 0으로 만들지 않고 'changes'만큼 뺀다.
+    pub(crate) fn record_save(&self, changes: u64) {
+        self.shared.changes_since_save.fetch_sub(changes, Ordering::Relaxed);
+    }
+
+    ///  'export'로 얻은 키들을 이 핸들이 가리키는 데이터베이스에 저장한다.
+    ///  
+    ///  남은 만료 시간은 지금을 기준으로 다시 설정된다. 이미 존재하는 키는 덮어쓴다.
+    pub(crate) fn import(&self, entries: Vec<(String, Value, Option<Duration>)>) {
+        let now = Instant::now();
+
+        for (key, value, ttl) in entries {
+            let mut state = self.lock_shard(&key);
+
+            let id = state.next_id;
+            state.next_id += 1;
+
+            let expires_at = ttl.map(|ttl| {
+                let when = now + ttl;
+                state.expirations.insert((when, id), key.clone());
+                when
+            });
+
+            let prev = state.insert_entry(key, id, value, expires_at);
+
+            //  덮어쓴 항목의 만료 정보는 삭제한다.
+            if let Some(prev) = prev {
+                if let Some(when) = prev.expires_at {
+                    state.expirations.remove(&(when, prev.id));
+                }
+            }
+        }
+
+        //  새로운 만료 정보를 반영하도록 백그라운드 태스크에게 알린다.
+        self.shared.background_task.notify_one();
+    }
+
+    ///  이 핸들이 가리키는 데이터베이스의 모든 키와 만료 정보를 삭제한다.
+    ///  
+    ///  pub/sub 채널은 키 공간과 무관하므로 유지된다. 'BLPOP', 'BRPOP'으로 기다리는 커넥션들도 계속
+    ///  기다려야 하므로 'list_waiters' 역시 유지한다. 백그라운드 태스크는 다음에 깨어났을 때 만료 정보가
+    ///  없음을 확인하고 다시 대기한다.
+    pub(crate) fn flush(&self) {
+        self.keyspace().flush();
+    }
+
+    ///  'flush'와 같지만, 모든 데이터베이스의 키를 삭제한다.
+    pub(crate) fn flush_all(&self) {
+        for keyspace in &self.shared.databases {
+            keyspace.flush();
+        }
+    }
+
+    ///  모든 데이터베이스의 키와 값이 사용하는 메모리의 근사값(바이트)을 반환한다.
+    ///  
+    ///  'dbsize'와 같이 한 번에 하나의 샤드의 락만을 잡는다.
+    pub(crate) fn used_memory(&self) -> usize {
+        self.shared.used_memory()
+    }
+
+    ///  메모리 사용량이 'maxmemory' 이하가 될 때까지, 모든 데이터베이스에서 가장 오래전에 접근한 키부터
+    ///  제거한다.
+    ///  
+    ///  메모리 사용량이 'maxmemory' 이하가 되었다면 'true'를, 더 이상 제거할 키가 없다면 'false'를
+    ///  반환한다. 제거된 키에 대해서는 'evicted' 키 공간 알림을 발행한다.
+    pub(crate) fn evict(&self, maxmemory: usize) -> bool {
+        let shared = &self.shared;
+        let mut used = shared.used_memory();
+
+        while used > maxmemory {
+            let candidates = shared.eviction_candidates();
+            if candidates.is_empty() {
+                return false;
+            }
+
+            let mut evicted = vec![];
+
+            for (accessed_at, index, shard, key) in candidates {
+                if used <= maxmemory {
+                    break;
+                }
+
+                let mut state = shared.databases[index].shards[shard].lock().unwrap();
+
+                //  후보를 고른 뒤에 다른 커넥션이 접근한 키는 제거하지 않는다.
+                let idle = state
+                    .entries
+                    .get(&key)
+                    .map(|entry| entry.accessed_at == accessed_at)
+                    .unwrap_or(false);
+
+                if idle {
+                    if let Some(entry) = state.remove_entry(&key) {
+                        used = used.saturating_sub(entry.size);
+                        evicted.push((index, key));
+                    }
+                }
+            }
+
+            //  키 공간 알림은 샤드 락을 해제한 뒤에 발행한다.
+            for (index, key) in &evicted {
+                shared.notify_keyspace_event(*index, "evicted", key);
+            }
+
+            //  제거하는 동안 다른 커넥션이 저장한 값도 반영하도록 다시 합산한다.
+            used = shared.used_memory();
+        }
+
+        true
+    }
+
+    ///  요청된 채널에 대한 'Receiver'를 반환한다.
+    /// 
+    /// 반환되는 'Receiver'는 'PUBLISH' 커맨드로 값을 수신하는 경우에 사용된다.
+    pub(crate) fn subscribe(&self, key: String) -> broadcast::Receiver<Bytes> {
+        use std::collections::hash_map::Entry;
+
+        // 뮤택스를 획득한다.
+        let mut pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        //  요청된 채널에 대한 앤트리가 없을 경우, 새로운 브로드캐스트 채널을 생성하여 키와 연결한다.
+        //  앤트리가 있다면 연결된 리시버를 반환한다.
+        match pub_sub.channels.entry(key) {
+            Entry::Occupied(e) => e.get().subscribe(),
+            Entry::Vacant(e) => {
+                //  브로드캐스트가 없으면 새로 만든다.
+                //  
+                //  채널은 '1024'개의 메시지를 담을 수 있도록 생성한다.
+                //  한 메시지는 모든 수신자들에게 전송될 때까지 보유된다. 
+                //  이는 한 구독자의 수신 속도가 늦는다면 메시지가 사라지지 않고 계속 남아있을 수 있음을 의미한다.
+                //  
+                //  채널이 가득차면 메시지 발행은 오래된 메시지를 우선으로 drop한다. 
+                //  이렇게 함으로써 느린 메시지 수신자로 인해 전체 시스템이 정지되는 경우를 방지한다.
+                let (tx, rx) = broadcast::channel(1024);
+                e.insert(tx);
+                rx
+            }
+        }
+    }
+
+    ///  채널에 메시지를 발행하고, 채널의 수신자의 수를 반환한다.
+    pub(crate) fn publish(&self, key: &str, value: Bytes) -> usize {
+        self.shared.publish(key, value)
+    }
+
+    ///  구독자가 하나 이상 있는 채널의 목록을 반환한다.
+    ///  
+    ///  구독자가 모두 떠난 채널도 'pub_sub'에 남아있을 수 있으므로, 리시버의 수로 활성 여부를 판단한다.
+    pub(crate) fn active_channels(&self) -> Vec<String> {
+        let pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        pub_sub
+            .channels
+            .iter()
+            .filter(|(_, tx)| tx.receiver_count() > 0)
+            .map(|(channel, _)| channel.clone())
+            .collect()
+    }
+
+    ///  채널의 구독자 수를 반환한다. 패턴 구독자는 포함하지 않는다.
+    pub(crate) fn subscriber_count(&self, channel: &str) -> usize {
+        let pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        pub_sub
+            .channels
+            .get(channel)
+            .map(|tx| tx.receiver_count())
+            .unwrap_or(0)
+    }
+
+    ///  구독자가 하나 이상 있는 패턴의 수를 반환한다.
+    pub(crate) fn pattern_count(&self) -> usize {
+        let pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        pub_sub
+            .patterns
+            .values()
+            .filter(|tx| tx.receiver_count() > 0)
+            .count()
+    }
+
+    ///  채널에 구독자가 남아있지 않다면 채널의 브로드캐스트 'Sender'를 삭제한다.
+    ///  
+    ///  구독자가 수신자를 drop한 뒤에 호출한다. 새로운 구독자는 같은 뮤택스를 잡고 수신자를 생성하므로,
+    ///  뮤택스 안에서 수신자의 수를 확인하면 동시에 구독한 구독자의 채널을 삭제하지 않는다.
+    pub(crate) fn release_channel(&self, channel: &str) {
+        let mut pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        if pub_sub.channels.get(channel).is_some_and(|tx| tx.receiver_count() == 0) {
+            pub_sub.channels.remove(channel);
+        }
+    }
+
+    ///  패턴에 구독자가 남아있지 않다면 패턴의 브로드캐스트 'Sender'를 삭제한다. 'release_channel'과 같다.
+    pub(crate) fn release_pattern(&self, pattern: &str) {
+        let mut pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        if pub_sub.patterns.get(pattern).is_some_and(|tx| tx.receiver_count() == 0) {
+            pub_sub.patterns.remove(pattern);
+        }
+    }
+
+    ///  샤드 채널에 대한 'Receiver'를 반환한다. 'subscribe'와 같지만 샤드 채널의 키 공간을 사용한다.
+    pub(crate) fn subscribe_shard(&self, key: String) -> broadcast::Receiver<Bytes> {
+        use std::collections::hash_map::Entry;
+
+        let mut pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        match pub_sub.shard_channels.entry(key) {
+            Entry::Occupied(e) => e.get().subscribe(),
+            Entry::Vacant(e) => {
+                let (tx, rx) = broadcast::channel(1024);
+                e.insert(tx);
+                rx
+            }
+        }
+    }
+
+    ///  샤드 채널에 메시지를 발행하고, 채널의 수신자의 수를 반환한다.
+    ///  
+    ///  일반 채널과 패턴의 구독자에게는 전송하지 않는다.
+    pub(crate) fn spublish(&self, key: &str, value: Bytes) -> usize {
+        let pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        pub_sub
+            .shard_channels
+            .get(key)
+            .map(|tx| tx.send(value).unwrap_or(0))
+            .unwrap_or(0)
+    }
+
+    ///  샤드 채널에 구독자가 남아있지 않다면 채널의 브로드캐스트 'Sender'를 삭제한다.
+    ///  'release_channel'과 같다.
+    pub(crate) fn release_shard_channel(&self, channel: &str) {
+        let mut pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        if pub_sub.shard_channels.get(channel).is_some_and(|tx| tx.receiver_count() == 0) {
+            pub_sub.shard_channels.remove(channel);
+        }
+    }
+
+    ///  구독자의 소비가 지연되어 'dropped'개의 메시지가 버려졌음을 기록한다.
+    pub(crate) fn record_dropped_messages(&self, dropped: u64) {
+        self.shared.dropped_messages.fetch_add(dropped, Ordering::Relaxed);
+    }
+
+    ///  가동 이후 구독자에게 전달되지 못하고 버려진 pub/sub 메시지의 수를 반환한다.
+    pub(crate) fn dropped_messages(&self) -> u64 {
+        self.shared.dropped_messages.load(Ordering::Relaxed)
+    }
+
+    ///  glob 패턴을 구독한다. 반환된 리시버는 패턴과 매칭되는 채널에 발행된 메시지를
+    ///  '(채널 이름, 메시지)'의 형태로 수신한다.
+    pub(crate) fn subscribe_pattern(&self, pattern: String) -> broadcast::Receiver<(String, Bytes)> {
+        use std::collections::hash_map::Entry;
+
+        let mut pub_sub = self.shared.pub_sub.lock().unwrap();
+
+        //  'subscribe'와 마찬가지로, 패턴에 대한 브로드캐스트 채널이 없을 경우 새로 생성한다.
+        match pub_sub.patterns.entry(pattern) {
+            Entry::Occupied(e) => e.get().subscribe(),
+            Entry::Vacant(e) => {
                 let (tx, rx) = broadcast::channel(1024);
                 e.insert(tx);
                 rx
             }
         }
     }
+}
+
+impl Drop for Db {
+    fn drop(&mut self) {
+        //  마지막 'Db' 인스턴스인 경우 백그라운드 태스크는 반드시 셧다운 시그널을 받아야 한다.
+        //  
+        //  먼저, 이 인스턴스가 마지막 'Db' 인스턴스인지 판단한다. 이 판단은 'strong_count'를 확인함으로써 이루어진다.
+        //  마지막 인스턴스인 경우, 카운트는 2가 되어야 한다. 하나는 이 'Db' 인스턴스이고, 다른 하나는 백그라운드 태스크가 잡고있는
+        //  핸들이다.
+        if Arc::strong_count(&self.shared) == 2 {
+            //  백그라운드 태스크는 반드시 셧다운 시그널을 받아야 한다.
+            //  'Shared::shutdown'을 true로 세팅하고 태스크에게 시그널을 보낸다.
+            self.shared.shutdown.store(true, Ordering::SeqCst);
+            self.shared.background_task.notify_one();
+        }
+    }
+}
+
+impl Keyspace {
+    ///  키가 속한 샤드의 인덱스를 반환한다. 같은 키는 프로세스가 실행되는 동안 항상 같은 샤드에 속한다.
+    fn shard_index(&self, key: &str) -> usize {
+        (key_hash(key) % self.shards.len() as u64) as usize
+    }
+
+    ///  모든 샤드의 키와 만료 정보를 삭제한다. 한 번에 하나의 샤드의 락만을 잡는다.
+    fn flush(&self) {
+        for shard in &self.shards {
+            let mut state = shard.lock().unwrap();
+
+            state.entries.clear();
+            state.expirations.clear();
+            state.used_memory = 0;
+        }
+    }
+}
+
+impl Shared {
+    ///  모든 데이터베이스의 모든 샤드의 만료된 키를 퍼지하고, 다음 키 만료 시간을 가리키는 'Instant'를
+    ///  반환한다.
+    fn purge_expired_keys(&self) -> Option<Instant> {
+        if self.is_shutdown() {
+            //  데이터베이스는 셧다운되고, 공유 상태에 대한 모든 핸들은 drop되었다.
+            //  백그라운드 태스크는 정지되어야 한다.
+            return None;
+        }
+
+        let mut next = None;
+        let mut expired = vec![];
+
+        //  한 번에 하나의 샤드의 락만을 잡는다. 퍼지 중에도 다른 샤드에 대한 연산은 대기하지 않는다.
+        for (index, keyspace) in self.databases.iter().enumerate() {
+            for shard in &keyspace.shards {
+                if let Some(when) = shard.lock().unwrap().purge_expired_keys(&mut expired) {
+                    next = Some(next.map_or(when, |next: Instant| next.min(when)));
+                }
+
+                //  만료된 키의 알림은 샤드 락을 해제한 뒤에 발행한다.
+                for key in expired.drain(..) {
+                    self.notify_keyspace_event(index, "expired", &key);
+                }
+            }
+        }
+
+        next
+    }
+
+    ///  모든 데이터베이스의 모든 샤드의 메모리 사용량을 합한다.
+    fn used_memory(&self) -> usize {
+        self.databases
+            .iter()
+            .flat_map(|keyspace| &keyspace.shards)
+            .map(|shard| shard.lock().unwrap().used_memory)
+            .sum()
+    }
+
+    ///  모든 샤드에서 가장 오래전에 접근한 최대 'EVICTION_POOL_SIZE'개의 키를 접근 시각의 순서로
+    ///  반환한다.
+    ///  
+    ///  각 후보는 접근 시각, 데이터베이스 번호, 샤드 인덱스, 키로 이루어진다. 한 번에 하나의 샤드의 락만을
+    ///  잡으며, 샤드마다 가장 오래된 항목들의 키만을 복제한다.
+    fn eviction_candidates(&self) -> Vec<(Instant, usize, usize, String)> {
+        let mut pool = vec![];
+
+        for (index, keyspace) in self.databases.iter().enumerate() {
+            for (shard, state) in keyspace.shards.iter().enumerate() {
+                let state = state.lock().unwrap();
+
+                let mut entries: Vec<_> = state
+                    .entries
+                    .iter()
+                    .map(|(key, entry)| (entry.accessed_at, key))
+                    .collect();
+
+                if entries.len() > EVICTION_POOL_SIZE {
+                    entries.select_nth_unstable(EVICTION_POOL_SIZE);
+                    entries.truncate(EVICTION_POOL_SIZE);
+                }
+
+                pool.extend(
+                    entries
+                        .into_iter()
+                        .map(|(accessed_at, key)| (accessed_at, index, shard, key.clone())),
+                );
+            }
+        }
+
+        pool.sort_unstable();
+        pool.truncate(EVICTION_POOL_SIZE);
+        pool
+    }
+
+    //  데이터베이스가 셧다운 중이라면 'true'를 반환한다.
+    //  
+    //  'shutdown'플래그는 'Db'의 모든 값이 drop되었을 때 설정된다. 이는 공유 상태에 더이상 접근할 수 없음을 나타낸다.
+    fn is_shutdown(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
 
     ///  채널에 메시지를 발행하고, 채널의 수신자의 수를 반환한다.
-    pub(crate) fn publish(&self, key: &str, value: Bytes) -> usize {
-        let state = self.shared.state.lock().unwrap();
+    fn publish(&self, key: &str, value: Bytes) -> usize {
+        let pub_sub = self.pub_sub.lock().unwrap();
 
-        state
-            .pub_sub
+        let num_subscribers = pub_sub
+            .channels
             .get(key)
             //  브로드캐스트 채널을 통한 메시지 전송이 성공하면 수신자의 수를 반환한다.
             //  에러는 수신자가 없음을 의미한다. 이 경우 '0'을 반환해야 한다.
-            .map(|tx| tx.send(value).unwrap_or(0))
+            .map(|tx| tx.send(value.clone()).unwrap_or(0))
             //  키에 연결된 채널이 없다면 이는 수신자가 없는 것이다. 따라서 '0'을 반환한다.
-            .unwrap_or(0)
+            .unwrap_or(0);
+
+        //  채널 이름과 매칭되는 모든 패턴의 구독자에게도 메시지를 전송한다.
+        let num_pattern_subscribers: usize = pub_sub
+            .patterns
+            .iter()
+            .filter(|(pattern, _)| glob::matches(pattern.as_bytes(), key.as_bytes()))
+            .map(|(_, tx)| tx.send((key.to_string(), value.clone())).unwrap_or(0))
+            .sum();
+
+        num_subscribers + num_pattern_subscribers
+    }
+
+    ///  'index'번 데이터베이스의 'key'에 'event'가 발생했음을 알린다.
+    ///  
+    ///  레디스와 같이 두 개의 채널에 발행한다. '__keyspace@<db>__:<key>' 채널에는 이벤트 이름을,
+    ///  '__keyevent@<db>__:<event>' 채널에는 키 이름을 발행한다. 키 공간 알림이 설정되지 않았다면 아무것도
+    ///  하지 않는다.
+    ///  
+    ///  pub/sub 뮤택스를 획득하므로, 샤드 락을 잡은 상태에서 호출하지 않는다.
+    fn notify_keyspace_event(&self, index: usize, event: &str, key: &str) {
+        if !self.notify_keyspace_events {
+            return;
+        }
+
+        let keyspace = format!("__keyspace@{}__:{}", index, key);
+        self.publish(&keyspace, Bytes::from(event.to_string()));
+
+        let keyevent = format!("__keyevent@{}__:{}", index, event);
+        self.publish(&keyevent, Bytes::from(key.to_string()));
     }
 }
 
-impl Drop for Db {
+impl Deref for ShardGuard<'_> {
+    type Target = State;
+
+    fn deref(&self) -> &State {
+        self.guard.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for ShardGuard<'_> {
+    fn deref_mut(&mut self) -> &mut State {
+        self.guard.as_mut().unwrap()
+    }
+}
+
+impl Drop for ShardGuard<'_> {
     fn drop(&mut self) {
-        //  마지막 'Db' 인스턴스인 경우 백그라운드 태스크는 반드시 셧다운 시그널을 받아야 한다.
-        //  
-        //  먼저, 이 인스턴스가 마지막 'Db' 인스턴스인지 판단한다. 이 판단은 'strong_count'를 확인함으로써 이루어진다.
-        //  마지막 인스턴스인 경우, 카운트는 2가 되어야 한다. 하나는 이 'Db' 인스턴스이고, 다른 하나는 백그라운드 태스크가 잡고있는
-        //  핸들이다.
-        if Arc::strong_count(&self.shared) == 2 {
-            //  백그라운드 태스크는 반드시 셧다운 시그널을 받아야 한다.
-            //  'State::shutdown'을 true로 세팅하고 태스크에게 시그널을 보낸다.
-            let mut state = self.shared.state.lock().unwrap();
-            state.shutdown = true;
+        if let Some(mut guard) = self.guard.take() {
+            let expired = mem::take(&mut guard.lazily_expired);
+            drop(guard);
 
-            //  백그라운드 태스크에게 시그널을 보내기 전에 락을 drop한다.
-            //  이 작업은 불필요하게 백그라운드 태스크가 깨어나 뮤택스 획득을 시도하지 않도록 하여 락 경합을 줄인다.
-            drop(state);
-            self.shared.background_task.notify_one();
+            self.db.notify_lazily_expired(expired);
         }
     }
 }
 
-impl Shared {
-    ///  모든 만료된 키를 퍼지하고, 다음 키 만료 시간을 가리키는 'Instant'를 반환한다.
-    fn purge_expired_keys(&self) -> Option<Instant> {
-        let mut state = self.state.lock().unwrap();
+impl LockedShards<'_> {
+    ///  'get_mut'과 같지만, 변경할 수 없는 레퍼런스를 반환한다.
+    fn get(&self, index: usize) -> &State {
+        let pos = self
+            .guards
+            .binary_search_by_key(&index, |(index, _)| *index)
+            .expect("shard is not locked");
 
-        if state.shutdown {
-            //  데이터베이스는 셧다운되고, 공유 상태에 대한 모든 핸들은 drop되었다.
-            //  백그라운드 태스크는 정지되어야 한다.
-            return None;
+        &self.guards[pos].1
+    }
+
+    ///  'index' 샤드의 상태를 반환한다.
+    ///  
+    ///  # Panics
+    ///  
+    ///  'index' 샤드의 락을 획득하지 않은 경우 패닉.
+    fn get_mut(&mut self, index: usize) -> &mut State {
+        let pos = self
+            .guards
+            .binary_search_by_key(&index, |(index, _)| *index)
+            .expect("shard is not locked");
+
+        &mut self.guards[pos].1
+    }
+}
+
+impl Drop for LockedShards<'_> {
+    fn drop(&mut self) {
+        let expired = self
+            .guards
+            .iter_mut()
+            .flat_map(|(_, state)| mem::take(&mut state.lazily_expired))
+            .collect();
+        self.guards.clear();
+
+        self.db.notify_lazily_expired(expired);
+    }
+}
+
+impl SetOp {
+    ///  결과를 저장했을 때 발행하는 키 공간 이벤트의 이름을 반환한다.
+    fn store_event(&self) -> &'static str {
+        match self {
+            SetOp::Inter => "sinterstore",
+            SetOp::Union => "sunionstore",
+            SetOp::Diff => "sdiffstore",
+        }
+    }
+}
+
+impl KeyType {
+    ///  'TYPE' 커맨드가 응답하는 타입 이름을 반환한다.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            KeyType::None => "none",
+            KeyType::String => "string",
+            KeyType::List => "list",
+            KeyType::Hash => "hash",
+            KeyType::Set => "set",
+            KeyType::ZSet => "zset",
+        }
+    }
+}
+
+impl Value {
+    ///  값의 타입을 반환한다.
+    pub(crate) fn key_type(&self) -> KeyType {
+        match self {
+            Value::String(_) => KeyType::String,
+            Value::List(_) => KeyType::List,
+            Value::Hash(_) => KeyType::Hash,
+            Value::Set(_) => KeyType::Set,
+            Value::SortedSet(_) => KeyType::ZSet,
+        }
+    }
+
+    ///  'OBJECT ENCODING'이 응답하는 인코딩 이름을 반환한다.
+    ///  
+    ///  'mini-redis'는 값의 크기와 관계 없이 타입마다 하나의 자료구조를 사용한다. 클라이언트가 기대하는
+    ///  응답을 위해, 레디스가 같은 값을 저장했을 때 사용할 인코딩을 레디스의 기본 설정에 따라 고른다.
+    pub(crate) fn encoding(&self) -> &'static str {
+        //  작은 컬렉션을 압축된 형태로 저장하는 최대 원소 수와 원소의 최대 바이트 길이
+        const MAX_LISTPACK_ENTRIES: usize = 128;
+        const MAX_LISTPACK_VALUE: usize = 64;
+
+        //  정수만을 담은 셋을 'intset'으로 저장하는 최대 멤버 수
+        const MAX_INTSET_ENTRIES: usize = 512;
+
+        //  문자열을 'embstr'로 저장하는 최대 바이트 길이
+        const MAX_EMBSTR_SIZE: usize = 44;
+
+        fn is_int(data: &[u8]) -> bool {
+            std::str::from_utf8(data)
+                .ok()
+                .and_then(|data| data.parse::<i64>().ok())
+                .map(|int| int.to_string().as_bytes() == data)
+                .unwrap_or(false)
+        }
+
+        fn is_small<'a>(len: usize, mut values: impl Iterator<Item = &'a [u8]>) -> bool {
+            len <= MAX_LISTPACK_ENTRIES && values.all(|value| value.len() <= MAX_LISTPACK_VALUE)
+        }
+
+        match self {
+            Value::String(data) if is_int(data) => "int",
+            Value::String(data) if data.len() <= MAX_EMBSTR_SIZE => "embstr",
+            Value::String(_) => "raw",
+            Value::List(list) if is_small(list.len(), list.iter().map(|v| &v[..])) => "listpack",
+            Value::List(_) => "quicklist",
+            Value::Hash(hash)
+                if is_small(
+                    hash.len(),
+                    hash.iter().flat_map(|(field, value)| vec![field.as_bytes(), &value[..]]),
+                ) =>
+            {
+                "listpack"
+            }
+            Value::Hash(_) => "hashtable",
+            Value::Set(set)
+                if set.len() <= MAX_INTSET_ENTRIES && set.iter().all(|member| is_int(member)) =>
+            {
+                "intset"
+            }
+            Value::Set(set) if is_small(set.len(), set.iter().map(|v| &v[..])) => "listpack",
+            Value::Set(_) => "hashtable",
+            Value::SortedSet(zset)
+                if is_small(zset.len(), zset.iter().map(|(member, _)| &member[..])) =>
+            {
+                "listpack"
+            }
+            Value::SortedSet(_) => "skiplist",
+        }
+    }
+
+    ///  값의 바이트 길이를 반환한다. 리스트와 셋은 원소의, 해시는 필드 이름과 값의 길이를 합한다.
+    ///  정렬된 셋은 멤버의 길이에 점수의 크기를 더한다.
+    fn size(&self) -> usize {
+        match self {
+            Value::String(data) => data.len(),
+            Value::List(list) => list.iter().map(Bytes::len).sum(),
+            Value::Hash(hash) => hash.iter().map(|(field, value)| field.len() + value.len()).sum(),
+            Value::Set(set) => set.iter().map(Bytes::len).sum(),
+            Value::SortedSet(zset) => zset.size(),
+        }
+    }
+
+    ///  문자열 값이라면 데이터를 반환한다. 다른 타입의 값이라면 'None'을 반환한다.
+    pub(crate) fn as_string(&self) -> Option<&Bytes> {
+        match self {
+            Value::String(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    ///  'as_string'과 같지만, 값의 소유권을 가져간다.
+    fn into_string(self) -> Option<Bytes> {
+        match self {
+            Value::String(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    ///  리스트 값이라면 리스트를 반환한다. 다른 타입의 값이라면 'None'을 반환한다.
+    fn as_list(&self) -> Option<&VecDeque<Bytes>> {
+        match self {
+            Value::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    ///  'as_list'와 같지만, 변경 가능한 레퍼런스를 반환한다.
+    fn as_list_mut(&mut self) -> Option<&mut VecDeque<Bytes>> {
+        match self {
+            Value::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    ///  해시 값이라면 해시를 반환한다. 다른 타입의 값이라면 'None'을 반환한다.
+    fn as_hash(&self) -> Option<&HashMap<String, Bytes>> {
+        match self {
+            Value::Hash(hash) => Some(hash),
+            _ => None,
+        }
+    }
+
+    ///  'as_hash'와 같지만, 변경 가능한 레퍼런스를 반환한다.
+    fn as_hash_mut(&mut self) -> Option<&mut HashMap<String, Bytes>> {
+        match self {
+            Value::Hash(hash) => Some(hash),
+            _ => None,
+        }
+    }
+
+    ///  셋 값이라면 셋을 반환한다. 다른 타입의 값이라면 'None'을 반환한다.
+    fn as_set(&self) -> Option<&HashSet<Bytes>> {
+        match self {
+            Value::Set(set) => Some(set),
+            _ => None,
         }
+    }
+
+    ///  'as_set'과 같지만, 변경 가능한 레퍼런스를 반환한다.
+    fn as_set_mut(&mut self) -> Option<&mut HashSet<Bytes>> {
+        match self {
+            Value::Set(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    ///  정렬된 셋 값이라면 정렬된 셋을 반환한다. 다른 타입의 값이라면 'None'을 반환한다.
+    fn as_zset(&self) -> Option<&SortedSet> {
+        match self {
+            Value::SortedSet(zset) => Some(zset),
+            _ => None,
+        }
+    }
+
+    ///  'as_zset'과 같지만, 변경 가능한 레퍼런스를 반환한다.
+    fn as_zset_mut(&mut self) -> Option<&mut SortedSet> {
+        match self {
+            Value::SortedSet(zset) => Some(zset),
+            _ => None,
+        }
+    }
+}
 
-        //  이 코드는 borrow checker를 만족시키기 위한 것이다. 간단히 말하자면, 'lock()'은 'MutexGuard'를 반환한다.
-        //  '&mut State'를 반환하지 않는다. borrow checker는 뮤택스 가드를 뚫고 안의 값을 볼 수 없어, 'state.expirations'와 'state.entries'에
-        //  mutably하게 접근하는 일이 안전한지 판단할 수 없다. 때문에 루프 밖에서 'State'에 대한 "진짜" mutable 레퍼런스를 얻도록 한다.
-        let state = &mut *state;
+impl State {
+    ///  키에 문자열이 아닌 값이 저장되어 있다면 'WRONGTYPE' 에러를 반환한다.
+    ///  
+    ///  키가 존재하지 않는 경우는 에러가 아니다. 문자열 값을 변경하는 연산이 값을 변경하기 전에 호출한다.
+    fn check_string(&self, key: &str) -> crate::Result<()> {
+        match self.entries.get(key) {
+            Some(entry) if entry.value.as_string().is_none() => Err(WRONGTYPE.into()),
+            _ => Ok(()),
+        }
+    }
 
+    ///  샤드의 만료된 키를 퍼지하고, 샤드의 다음 키 만료 시간을 가리키는 'Instant'를 반환한다.
+    ///  
+    ///  퍼지된 키는 'expired'에 추가된다.
+    fn purge_expired_keys(&mut self, expired: &mut Vec<String>) -> Option<Instant> {
         // '지금' 전에 만료되도록 스케쥴된 모든 키를 찾는다.
         let now = Instant::now();
         
-        while let Some((&(when, id), key)) = state.expirations.iter().next() {
+        while let Some((&(when, id), key)) = self.expirations.iter().next() {
             if when > now {
                 //  퍼지를 마치면 'when'은 다음 키 만료 시간을 가리키는 Instant가 된다.
                 //  백그라운드 태스크는 이 시간까지 대기할 것이다.
@@ -255,27 +3400,223 @@ impl Shared {
             }
 
             // 만료된 키는 삭제한다.
-            state.entries.remove(key);
-            state.expirations.remove(&(when, id));
+            if let Some(entry) = self.entries.remove(key) {
+                self.used_memory -= entry.size;
+            }
+            let key = self.expirations.remove(&(when, id)).unwrap();
+            expired.push(key);
         }
         None
     }
 
-    //  데이터베이스가 셧다운 중이라면 'true'를 반환한다.
-    //  
-    //  'shutdown'플래그는 'Db'의 모든 값이 drop되었을 때 설정된다. 이는 공유 상태에 더이상 접근할 수 없음을 나타낸다.
-    fn is_shutdown(&self) -> bool {
-        self.state.lock().unwrap().shutdown
-    }
-}
-
-impl State {
     fn next_expiration(&self) -> Option<Instant> {
         self.expirations
             .keys()
             .next()
             .map(|expiration| expiration.0)
     }
+
+    ///  키에 해당하는 항목을 삭제하고, 삭제된 항목을 반환한다.
+    ///  
+    ///  삭제된 항목에 만료 시간이 있었다면 만료 정보 또한 삭제한다. 그렇지 않으면 백그라운드 태스크가
+    ///  이미 삭제된 키에 대한 만료를 처리하게 된다.
+    fn remove_entry(&mut self, key: &str) -> Option<Entry> {
+        let prev = self.entries.remove(key)?;
+        self.used_memory -= prev.size;
+
+        if let Some(when) = prev.expires_at {
+            self.expirations.remove(&(when, prev.id));
+        }
+
+        Some(prev)
+    }
+
+    ///  'key'에 새로운 항목을 저장하고, 덮어쓴 항목을 반환한다.
+    ///  
+    ///  항목의 크기를 계산하여 샤드의 메모리 사용량에 반영한다. 만료 정보는 다루지 않으므로, 호출자가
+    ///  새 항목의 만료를 추가하고 덮어쓴 항목의 만료를 삭제해야 한다.
+    fn insert_entry(
+        &mut self,
+        key: String,
+        id: u64,
+        value: Value,
+        expires_at: Option<Instant>,
+    ) -> Option<Entry> {
+        let size = key.len() + value.size();
+        let entry = Entry {
+            id,
+            value,
+            expires_at,
+            version: id,
+            size,
+            accessed_at: Instant::now(),
+        };
+
+        self.used_memory += size;
+        let prev = self.entries.insert(key, entry)?;
+        self.used_memory -= prev.size;
+
+        Some(prev)
+    }
+
+    ///  키에 해당하는 항목의 만료 시각을 'expires_at'으로 변경한다. 'None'은 만료 시간을 제거한다.
+    ///  
+    ///  기존 만료 정보는 삭제한다. 새로운 만료 시각이 샤드의 다음 만료 항목이 되어 백그라운드 태스크에게
+    ///  알려야 한다면 'true'를 반환한다. 호출자는 락을 해제한 뒤에 알린다.
+    fn set_expiration(&mut self, key: &str, expires_at: Option<Instant>) -> bool {
+        let (id, prev) = match self.entries.get(key) {
+            Some(entry) => (entry.id, entry.expires_at),
+            None => return false,
+        };
+
+        if let Some(when) = prev {
+            self.expirations.remove(&(when, id));
+        }
+
+        let mut notify = false;
+        if let Some(when) = expires_at {
+            notify = self
+                .next_expiration()
+                .map(|expiration| expiration > when)
+                .unwrap_or(true);
+
+            self.expirations.insert((when, id), key.to_string());
+        }
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.expires_at = expires_at;
+        }
+
+        notify
+    }
+
+    ///  만료 시간이 지났지만 아직 백그라운드 태스크에 의해 퍼지되지 않은 항목을 삭제한다.
+    ///  
+    ///  키를 변경하는 연산이 만료된 값을 기반으로 동작하지 않도록, 연산 전에 호출한다.
+    fn remove_if_expired(&mut self, key: &str) {
+        let expired = self
+            .entries
+            .get(key)
+            .and_then(|entry| entry.expires_at)
+            .map(|when| when <= Instant::now())
+            .unwrap_or(false);
+
+        if expired {
+            self.remove_entry(key);
+            self.lazily_expired.push(key.to_string());
+        }
+    }
+
+    ///  항목을 제자리에서 변경하여 값이 'added' 바이트 늘고 'removed' 바이트 줄었음을 기록한다.
+    ///  
+    ///  값 전체의 크기를 다시 계산하지 않도록, 변경한 연산이 늘고 준 크기를 직접 전달한다.
+    fn resize(&mut self, key: &str, added: usize, removed: usize) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.size = entry.size + added - removed;
+            self.used_memory = self.used_memory + added - removed;
+        }
+    }
+
+    ///  키에 해당하는 항목에 새로운 버전을 부여한다. 항목을 제자리에서 변경한 뒤에 호출한다.
+    ///  
+    ///  항목을 새로 저장하거나 삭제하는 경우에는 호출하지 않아도 된다. 새로운 항목은 새로운 식별자를
+    ///  버전으로 갖고, 삭제된 키는 버전을 갖지 않는다. 변경도 접근이므로 접근 시각을 함께 갱신한다.
+    fn touch(&mut self, key: &str) {
+        let version = self.next_id;
+        self.next_id += 1;
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.version = version;
+            entry.accessed_at = Instant::now();
+        }
+    }
+}
+
+///  키의 해시 값을 반환한다. 키가 속할 샤드와 'SCAN'의 순회 순서를 결정한다.
+///  
+///  'DefaultHasher::new()'는 항상 같은 키로 초기화되므로, 같은 키는 프로세스가 실행되는 동안 항상
+///  같은 해시 값을 갖는다.
+fn key_hash(key: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+///  'items'를 해시 값의 순서로 정렬하고, 가장 작은 'count'개만 남긴다. 잘려나간 항목이 있다면 'true'를
+///  반환한다.
+///  
+///  커서가 해시 값을 가리키므로, 해시 값이 같은 항목들은 함께 남기거나 함께 잘라낸다.
+fn take_smallest<T>(items: &mut Vec<(u64, T)>, count: usize) -> bool {
+    items.sort_unstable_by_key(|(hash, _)| *hash);
+
+    if items.len() <= count {
+        return false;
+    }
+
+    let last = items[count - 1].0;
+    let len = count + items[count..].iter().take_while(|(hash, _)| *hash == last).count();
+
+    items.truncate(len);
+    true
+}
+
+///  'items'에서 무작위로 원소들을 고른다. 'HRANDFIELD'와 'SRANDMEMBER'가 사용한다.
+///  
+///  'count'가 양수라면 서로 다른 원소를 최대 'count'개 고른다. 저수지 표본 추출로 한 번만 순회하므로,
+///  컬렉션의 크기와 관계 없이 고른 원소만큼의 메모리만 사용한다.
+///  
+///  'count'가 음수라면 중복을 허용하여 '-count'개를 고른다. 해시 테이블은 위치로 접근할 수 없으므로, 먼저
+///  고를 위치들을 뽑아 정렬한 뒤 한 번의 순회로 해당 위치의 원소를 모은다. 응답의 순서는 위치를 뽑은
+///  순서를 따른다. '-count'는 커맨드를 파싱할 때 'MAX_RANDOM_COUNT' 이하로 제한된다.
+fn sample<I>(items: I, count: i64, rng: &mut Rng) -> Vec<I::Item>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+    let len = items.len();
+
+    if count >= 0 {
+        let count = (count as u64).min(len as u64) as usize;
+        let mut chosen = Vec::with_capacity(count);
+
+        for (i, item) in items.enumerate() {
+            if i < count {
+                chosen.push(item);
+            } else {
+                let j = rng.below(i + 1);
+                if j < count {
+                    chosen[j] = item;
+                }
+            }
+        }
+
+        return chosen;
+    }
+
+    if len == 0 {
+        return vec![];
+    }
+
+    let count = count.unsigned_abs() as usize;
+
+    //  (위치, 응답에서의 순서)
+    let mut picks: Vec<(usize, usize)> = (0..count).map(|slot| (rng.below(len), slot)).collect();
+    picks.sort_unstable();
+
+    let mut chosen = vec![None; count];
+    let mut picks = picks.into_iter().peekable();
+
+    for (i, item) in items.enumerate() {
+        while let Some((_, slot)) = picks.next_if(|(index, _)| *index == i) {
+            chosen[slot] = Some(item.clone());
+        }
+
+        if picks.peek().is_none() {
+            break;
+        }
+    }
+
+    chosen.into_iter().flatten().collect()
 }
 
 ///  백그라운드 태스크의 실행 루틴
@@ -300,4 +3641,169 @@ async fn purge_expired_tasks(shared: Arc<Shared>) {
             shared.background_task.notified().await;
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn del_removes_existing_keys() {
+        let db = Db::new(1, false);
+        db.set_options("a".to_string(), Bytes::from("1"), None, Condition::Always);
+        db.set_options(
+            "b".to_string(),
+            Bytes::from("2"),
+            Some(Duration::from_secs(60)),
+            Condition::Always,
+        );
+
+        let keys = ["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(db.del(&keys), 2);
+        assert!(!db.exists("a"));
+        assert!(!db.exists("b"));
+    }
+
+    #[tokio::test]
+    async fn del_ignores_expired_keys() {
+        let db = Db::new(1, true);
+        let mut deleted = db.subscribe("__keyevent@0__:del".to_string());
+        let mut expired = db.subscribe("__keyevent@0__:expired".to_string());
+
+        let ttl = Some(Duration::from_millis(10));
+        db.set_options("k".to_string(), Bytes::from("v"), ttl, Condition::Always);
+
+        // 런타임 스레드를 블로킹하여, 백그라운드 태스크가 만료된 키를 퍼지하지 못하도록 한다.
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(db.del(&["k".to_string()]), 0);
+        assert_eq!(expired.try_recv().unwrap(), "k");
+        assert!(deleted.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn unlink_ignores_expired_keys() {
+        let db = Db::new(1, true);
+        let mut deleted = db.subscribe("__keyevent@0__:del".to_string());
+
+        let ttl = Some(Duration::from_millis(10));
+        db.set_options("k".to_string(), Bytes::from("v"), ttl, Condition::Always);
+        db.set_options("p".to_string(), Bytes::from("v"), None, Condition::Always);
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(db.unlink(&["k".to_string(), "p".to_string()]), 1);
+        assert_eq!(deleted.try_recv().unwrap(), "p");
+        assert!(deleted.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn lazy_expiry_notifies_expired() {
+        let db = Db::new(1, true);
+        let mut events = db.subscribe("__keyevent@0__:expired".to_string());
+
+        let ttl = Some(Duration::from_millis(10));
+        db.set_options("n".to_string(), Bytes::from("1"), ttl, Condition::Always);
+        db.sadd("s", vec![Bytes::from("a")]).unwrap();
+        db.expire("s", ttl);
+
+        // 런타임 스레드를 블로킹하여, 백그라운드 태스크가 만료된 키를 퍼지하지 못하도록 한다.
+        std::thread::sleep(Duration::from_millis(20));
+
+        // 연산 전에 만료된 키를 삭제하면서 알림을 발행한다.
+        assert_eq!(db.incr_by("n", 1).unwrap(), 1);
+        assert_eq!(events.try_recv().unwrap(), "n");
+
+        // 여러 샤드의 락을 잡는 연산도 락을 해제한 뒤에 알림을 발행한다.
+        let keys = ["s".to_string()];
+        assert!(matches!(
+            db.set_op(SetOp::Union, &keys, None).unwrap(),
+            SetOpResult::Members(members) if members.is_empty()
+        ));
+        assert_eq!(events.try_recv().unwrap(), "s");
+    }
+
+    #[tokio::test]
+    async fn expire_at_ignores_expired_keys() {
+        let db = Db::new(1, false);
+        let ttl = Some(Duration::from_millis(10));
+        db.set_options("k".to_string(), Bytes::from("v"), ttl, Condition::Always);
+        db.set_options("p".to_string(), Bytes::from("v"), ttl, Condition::Always);
+        std::thread::sleep(Duration::from_millis(20));
+
+        let past = SystemTime::now() - Duration::from_secs(1);
+        assert!(!db.expire_at("k", past));
+
+        let future = SystemTime::now() + Duration::from_secs(60);
+        assert!(!db.expire_at("p", future));
+        assert!(!db.exists("p"));
+    }
+
+    #[tokio::test]
+    async fn get_ignores_expired_keys() {
+        let db = Db::new(1, false);
+        let ttl = Some(Duration::from_millis(10));
+        db.set_options("k".to_string(), Bytes::from("v"), ttl, Condition::Always);
+        db.push("l", vec![Bytes::from("a")], true).unwrap();
+        db.expire("l", ttl);
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(db.get("k").unwrap(), None);
+        assert_eq!(db.get("l").unwrap(), None);
+        assert!(db.lock_shard("k").entries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn hincr_by_float_formats_like_redis() {
+        let db = Db::new(1, false);
+
+        assert_eq!(db.hincr_by_float("h", "f", 0.1).unwrap(), "0.1");
+        assert_eq!(db.hincr_by_float("h", "f", 0.2).unwrap(), "0.3");
+        assert_eq!(db.hincr_by_float("h", "f", 2.7).unwrap(), "3");
+        assert_eq!(db.hincr_by_float("h", "f", 1e20).unwrap(), "1e+20");
+    }
+
+    #[tokio::test]
+    async fn copy_rejects_same_key() {
+        let db = Db::new(1, false);
+        db.set_options("k".to_string(), Bytes::from("v"), None, Condition::Always);
+
+        let err = db.copy("k", "k", true).unwrap_err();
+        assert_eq!(err.to_string(), "ERR source and destination objects are the same");
+        assert_eq!(db.get("k").unwrap(), Some(Bytes::from("v")));
+    }
+
+    #[tokio::test]
+    async fn keys_resolve_regardless_of_shard_count() {
+        let keys: Vec<String> = (0..100).map(|i| format!("key:{}", i)).collect();
+
+        for n in [1, 2, 7, 16] {
+            let db = Db::with_shards(1, n, false);
+            for key in &keys {
+                let value = Bytes::from(key.clone());
+                db.set_options(key.clone(), value, None, Condition::Always);
+            }
+
+            for key in &keys {
+                assert_eq!(db.get(key).unwrap(), Some(Bytes::from(key.clone())));
+            }
+
+            let values = db.mget(&keys);
+            assert!(values.iter().zip(&keys).all(|(value, key)| value.as_deref() == Some(key.as_bytes())));
+            assert_eq!(db.dbsize(), keys.len());
+        }
+    }
+
+    #[tokio::test]
+    async fn get_set_clears_ttl() {
+        let db = Db::new(1, false);
+        let ttl = Some(Duration::from_secs(60));
+        db.set_options("k".to_string(), Bytes::from("old"), ttl, Condition::Always);
+
+        let prev = db.get_set("k".to_string(), Bytes::from("new")).unwrap();
+        assert_eq!(prev, Some(Bytes::from("old")));
+
+        let state = db.lock_shard("k");
+        assert_eq!(state.entries.get("k").unwrap().expires_at, None);
+        assert!(state.expirations.is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+//! 실수의 합을 레디스와 같은 형식의 문자열로 만들기 위한 십진 덧셈.
+//!
+//! 레디스의 'HINCRBYFLOAT'은 'long double'로 더한 결과를 유효숫자 17자리('%.17Lg')로 출력하고, 끝에
+//! 붙은 0을 지운다. 'f64'로 더하면 '0.1 + 0.2'가 '0.30000000000000004'가 되어 레디스와 결과가 달라진다.
+//! 대신 두 값의 가장 짧은 십진 표현을 정확히 더한 뒤 유효숫자 17자리로 반올림한다.
+
+use std::cmp::Ordering;
+
+/// 출력할 유효숫자의 수
+const PRECISION: usize = 17;
+
+/// 'a'와 'b'의 합을 유효숫자 17자리로 출력한다. 끝에 붙은 0은 지운다.
+///
+/// 지수가 -4보다 작거나 17 이상이면 '1.5e+20'과 같은 지수 표기를 사용한다. 두 값은 유한해야 한다.
+pub(crate) fn add(a: f64, b: f64) -> String {
+    Decimal::from_f64(a).add(Decimal::from_f64(b)).to_string()
+}
+
+/// 'digits * 10^exp'로 표현되는 십진수
+struct Decimal {
+    /// 음수 여부
+    negative: bool,
+
+    /// 최하위 자리부터의 각 자리 숫자
+    digits: Vec<u8>,
+
+    /// 최하위 자리의 지수
+    exp: i32,
+}
+
+impl Decimal {
+    /// 'value'를 원래의 값으로 되돌릴 수 있는 가장 짧은 십진 표현으로 변환한다.
+    fn from_f64(value: f64) -> Decimal {
+        // 'LowerExp'는 '1.2345e-5'와 같이 가장 짧은 가수와 지수를 출력한다.
+        let repr = format!("{:e}", value.abs());
+        let (mantissa, exp) = repr.split_once('e').unwrap();
+        let exp: i32 = exp.parse().unwrap();
+
+        let mut digits: Vec<u8> = mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|b| b - b'0')
+            .collect();
+        let exp = exp - (digits.len() as i32 - 1);
+        digits.reverse();
+
+        Decimal {
+            negative: value.is_sign_negative(),
+            digits,
+            exp,
+        }
+    }
+
+    /// 두 값을 정확히 더한다.
+    fn add(mut self, mut other: Decimal) -> Decimal {
+        // 최하위 자리의 지수를 맞춘다. 'f64'의 지수 범위 안이므로 자리 수는 수백을 넘지 않는다.
+        let exp = self.exp.min(other.exp);
+        self.align(exp);
+        other.align(exp);
+
+        let (negative, digits) = if self.negative == other.negative {
+            (self.negative, add_digits(&self.digits, &other.digits))
+        } else {
+            match cmp_digits(&self.digits, &other.digits) {
+                Ordering::Less => (other.negative, sub_digits(&other.digits, &self.digits)),
+                _ => (self.negative, sub_digits(&self.digits, &other.digits)),
+            }
+        };
+
+        Decimal {
+            negative,
+            digits,
+            exp,
+        }
+    }
+
+    /// 값을 바꾸지 않고 최하위 자리의 지수가 'exp'가 되도록 0을 채운다.
+    fn align(&mut self, exp: i32) {
+        let shift = (self.exp - exp) as usize;
+        self.digits.splice(0..0, std::iter::repeat_n(0, shift));
+        self.exp = exp;
+    }
+
+    /// 유효숫자가 'PRECISION'자리를 넘지 않도록 반올림하고, 앞뒤의 0을 지운다.
+    ///
+    /// 'printf'와 같이 정확히 중간인 값은 짝수 쪽으로 반올림한다.
+    fn round(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+
+        if self.digits.len() > PRECISION {
+            let cut = self.digits.len() - PRECISION;
+            let first = self.digits[cut - 1];
+            let rest = self.digits[..cut - 1].iter().any(|&d| d != 0);
+            let round_up = first > 5 || (first == 5 && (rest || self.digits[cut] % 2 == 1));
+
+            self.digits.drain(..cut);
+            self.exp += cut as i32;
+
+            if round_up {
+                self.digits = add_digits(&self.digits, &[1]);
+            }
+        }
+
+        let zeros = self.digits.iter().take_while(|&&d| d == 0).count();
+        self.digits.drain(..zeros);
+        self.exp += zeros as i32;
+    }
+}
+
+impl std::fmt::Display for Decimal {
+    /// '%.17g'와 같은 형식으로 출력한다. 'round'로 반올림하지 않은 값은 17자리를 넘게 출력될 수 있다.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut value = Decimal {
+            negative: self.negative,
+            digits: self.digits.clone(),
+            exp: self.exp,
+        };
+        value.round();
+
+        if value.digits.is_empty() {
+            return write!(f, "0");
+        }
+
+        if value.negative {
+            write!(f, "-")?;
+        }
+
+        // 최상위 자리부터의 숫자와 최상위 자리의 지수
+        let digits: String = value.digits.iter().rev().map(|&d| (b'0' + d) as char).collect();
+        let top = value.exp + digits.len() as i32 - 1;
+
+        if top < -4 || top >= PRECISION as i32 {
+            let (first, rest) = digits.split_at(1);
+            write!(f, "{}", first)?;
+            if !rest.is_empty() {
+                write!(f, ".{}", rest)?;
+            }
+
+            let sign = if top < 0 { '-' } else { '+' };
+            write!(f, "e{}{:02}", sign, top.abs())
+        } else if top < 0 {
+            write!(f, "0.{}{}", "0".repeat((-top - 1) as usize), digits)
+        } else if value.exp >= 0 {
+            write!(f, "{}{}", digits, "0".repeat(value.exp as usize))
+        } else {
+            let (int, frac) = digits.split_at(top as usize + 1);
+            write!(f, "{}.{}", int, frac)
+        }
+    }
+}
+
+/// 최하위 자리부터의 숫자로 표현된 두 수의 크기를 비교한다.
+fn cmp_digits(a: &[u8], b: &[u8]) -> Ordering {
+    let a = trim(a);
+    let b = trim(b);
+
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// 최상위 자리의 0을 제외한다.
+fn trim(digits: &[u8]) -> &[u8] {
+    let len = digits.iter().rposition(|&d| d != 0).map_or(0, |pos| pos + 1);
+    &digits[..len]
+}
+
+/// 두 수의 크기를 더한다.
+fn add_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+
+    for i in 0..a.len().max(b.len()) {
+        let d = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        sum.push(d % 10);
+        carry = d / 10;
+    }
+
+    if carry > 0 {
+        sum.push(carry);
+    }
+
+    sum
+}
+
+/// 'a'의 크기에서 'b'의 크기를 뺀다. 'a'는 'b'보다 작지 않아야 한다.
+fn sub_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut diff = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+
+    for (i, &d) in a.iter().enumerate() {
+        let sub = b.get(i).unwrap_or(&0) + borrow;
+        if d >= sub {
+            diff.push(d - sub);
+            borrow = 0;
+        } else {
+            diff.push(d + 10 - sub);
+            borrow = 1;
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_decimal_sum() {
+        assert_eq!(add(0.1, 0.2), "0.3");
+        assert_eq!(add(10.5, 0.1), "10.6");
+        assert_eq!(add(5.0e3, 2.0e2), "5200");
+        assert_eq!(add(0.0, 3.0), "3");
+        assert_eq!(add(1.5, -1.5), "0");
+        assert_eq!(add(-1.0, 0.25), "-0.75");
+    }
+
+    #[test]
+    fn seventeen_significant_digits() {
+        assert_eq!(add(0.1234567890123456, 0.0000000000000000789), "0.12345678901234568");
+        assert_eq!(add(99999999999999999.0, 0.5), "1e+17");
+        assert_eq!(add(1e20, 1.5), "1e+20");
+        assert_eq!(add(1.5e20, 0.0), "1.5e+20");
+        assert_eq!(add(0.00001, 0.0), "1e-05");
+        assert_eq!(add(0.0001, 0.0), "0.0001");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::frame;
+use crate::parse::ParseError;
+
+use std::num::ParseIntError;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+use std::{fmt, io};
+
+/// mini-redis 연산이 반환하는 에러
+///
+/// 서버가 응답한 에러는 메시지의 접두어('WRONGTYPE', 'NOAUTH' 등)에 따라 분류된다. 호출자는 메시지를
+/// 직접 비교하지 않고 변형으로 에러의 종류를 구분할 수 있다. 'Display' 구현은 원래의 메시지를 그대로
+/// 출력하므로, 서버는 에러를 문자열로 변환하여 클라이언트에 응답한다.
+#[derive(Debug)]
+pub enum MiniRedisError {
+    /// 소켓 읽기/쓰기 중 발생한 IO 에러. 'Client'의 타임아웃도 'ErrorKind::TimedOut' 종류의 IO
+    /// 에러로 표현된다.
+    Io(io::Error),
+
+    /// 유효하지 않은 프레임, 예상하지 못한 응답 등 프로토콜 위반
+    Protocol(String),
+
+    /// 키에 저장된 값의 타입과 맞지 않는 연산('WRONGTYPE')
+    WrongType(String),
+
+    /// 상대가 프레임을 전송하지 않고 커넥션을 닫았거나, 커넥션을 리셋했다.
+    ConnectionReset,
+
+    /// 인증이 필요하거나 인증에 실패했다('NOAUTH', 'WRONGPASS' 등).
+    Auth(String),
+
+    /// 그 외 서버가 응답한 에러('ERR ...')
+    Server(String),
+}
+
+impl MiniRedisError {
+    /// 에러 메시지를 접두어에 따라 분류한다.
+    ///
+    /// 레디스 에러 메시지는 대문자로 된 에러 코드로 시작한다('ERR', 'WRONGTYPE', ...). 에러 코드로
+    /// 시작하지 않는 메시지는 mini-redis 내부에서 만든 프로토콜 에러로 취급한다.
+    fn from_message(msg: String) -> MiniRedisError {
+        let code = msg.split(' ').next().unwrap_or("");
+
+        match code {
+            "WRONGTYPE" => MiniRedisError::WrongType(msg),
+            "NOAUTH" | "WRONGPASS" => MiniRedisError::Auth(msg),
+            // mini-redis 서버의 'AUTH' 에러는 'ERR' 코드를 사용한다.
+            "ERR" if msg.starts_with("ERR invalid password")
+                || msg.starts_with("ERR Client sent AUTH") =>
+            {
+                MiniRedisError::Auth(msg)
+            }
+            _ if !code.is_empty() && code.bytes().all(|b| b.is_ascii_uppercase()) => {
+                MiniRedisError::Server(msg)
+            }
+            _ => MiniRedisError::Protocol(msg),
+        }
+    }
+}
+
+impl From<io::Error> for MiniRedisError {
+    fn from(src: io::Error) -> MiniRedisError {
+        // 상대가 커넥션을 끊은 경우는 프레임 없이 스트림이 닫힌 경우와 같은 변형으로 표현한다.
+        match src.kind() {
+            io::ErrorKind::ConnectionReset => MiniRedisError::ConnectionReset,
+            _ => MiniRedisError::Io(src),
+        }
+    }
+}
+
+impl From<String> for MiniRedisError {
+    fn from(src: String) -> MiniRedisError {
+        MiniRedisError::from_message(src)
+    }
+}
+
+impl From<&str> for MiniRedisError {
+    fn from(src: &str) -> MiniRedisError {
+        src.to_string().into()
+    }
+}
+
+impl From<Utf8Error> for MiniRedisError {
+    fn from(_src: Utf8Error) -> MiniRedisError {
+        MiniRedisError::Protocol("protocol error; invalid string".to_string())
+    }
+}
+
+impl From<FromUtf8Error> for MiniRedisError {
+    fn from(_src: FromUtf8Error) -> MiniRedisError {
+        MiniRedisError::Protocol("protocol error; invalid string".to_string())
+    }
+}
+
+impl From<ParseIntError> for MiniRedisError {
+    fn from(_src: ParseIntError) -> MiniRedisError {
+        MiniRedisError::Protocol("protocol error; invalid number".to_string())
+    }
+}
+
+impl From<ParseError> for MiniRedisError {
+    fn from(src: ParseError) -> MiniRedisError {
+        match src {
+            ParseError::Other(err) => err,
+            err => MiniRedisError::Protocol(err.to_string()),
+        }
+    }
+}
+
+impl From<frame::Error> for MiniRedisError {
+    fn from(src: frame::Error) -> MiniRedisError {
+        match src {
+            frame::Error::Other(err) => err,
+            err => MiniRedisError::Protocol(err.to_string()),
+        }
+    }
+}
+
+impl std::error::Error for MiniRedisError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MiniRedisError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MiniRedisError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MiniRedisError::Io(err) => err.fmt(fmt),
+            MiniRedisError::ConnectionReset => "connection reset by peer".fmt(fmt),
+            MiniRedisError::Protocol(msg)
+            | MiniRedisError::WrongType(msg)
+            | MiniRedisError::Auth(msg)
+            | MiniRedisError::Server(msg) => msg.fmt(fmt),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-///! Redis 프로토콜 프레임을 표현하는 타입과, 바이트 배열로부터 프레임을 파싱하기 위한 유틸리티를 제공한다.
+//! Redis 프로토콜 프레임을 표현하는 타입과, 바이트 배열로부터 프레임을 파싱하기 위한 유틸리티를 제공한다.
 
 use bytes::{Buf, Bytes};
 use std::convert::TryInto;
@@ -12,10 +12,21 @@ use std::string::FromUtf8Error;
 pub enum Frame {
     Simple(String),
     Error(String),
-    Integer(u64),
+    Integer(i64),
     Bulk(Bytes),
     Null,
     Array(Vec<Frame>),
+
+    // 이하의 타입들은 RESP3에서 추가되었다. RESP2 커넥션에 쓰여질 때는 RESP2 타입으로 변환된다.
+    /// 키와 값의 쌍을 순서대로 담는다.
+    Map(Vec<(Frame, Frame)>),
+    Set(Vec<Frame>),
+    Double(f64),
+    Boolean(bool),
+    /// 'i64'의 범위를 넘을 수 있는 정수. 십진수 문자열로 담는다.
+    BigNumber(String),
+    /// RESP3의 null. RESP2의 'Null'과 달리 벌크 문자열과 무관한 별도의 타입이다.
+    Null3,
 }
 #[derive(Debug)]
 pub enum Error {
@@ -50,7 +61,7 @@ impl Frame {
     /// # Panics
     /// 
     /// 'self'가 배열이 아닌 경우 패닉.
-    pub(crate) fn push_int(&mut self, value: u64) {
+    pub(crate) fn push_int(&mut self, value: i64) {
         match self {
             Frame::Array(vec) => {
                 vec.push(Frame::Integer(value));
@@ -59,8 +70,29 @@ impl Frame {
         }
     }
 
+    /// "null" 프레임을 배열에 넣는다. 'self'는 반드시 배열 프레임이어야 한다.
+    /// 
+    /// # Panics
+    /// 
+    /// 'self'가 배열이 아닌 경우 패닉.
+    pub(crate) fn push_null(&mut self) {
+        match self {
+            Frame::Array(vec) => {
+                vec.push(Frame::Null);
+            }
+            _ => panic!("not an array frame"),
+        }
+    }
+
     /// 'src'로부터의 전체 메시지가 디코딩될 수 있는지 확인한다.
-    pub fn check(src: &mut Cursor<&[u8]>) -> Result<(), Error> {
+    ///
+    /// 'max_frame_size'보다 긴 벌크 문자열이나 많은 앤트리를 담은 배열은 데이터를 모두 수신하기
+    /// 전에 에러를 반환한다. 'parse'는 'check'를 통과한 메시지만을 파싱하므로, 클라이언트가 주장하는
+    /// 길이만큼 메모리를 할당하지 않는다.
+    ///
+    /// 알려진 타입 바이트로 시작하지 않는 메시지는 인라인 커맨드로 간주한다. 인라인 커맨드는
+    /// telnet 등으로 직접 입력하기 위한, '\r\n'으로 끝나는 평문 라인이다.
+    pub fn check(src: &mut Cursor<&[u8]>, max_frame_size: usize) -> Result<(), Error> {
         match get_u8(src)? {
             b'+' => {
                 get_line(src)?;
@@ -71,7 +103,7 @@ impl Frame {
                 Ok(())
             }
             b':' => {
-                let _ = get_decimal(src)?;
+                let _ = get_int(src)?;
                 Ok(())
             }
             b'$' => {
@@ -81,24 +113,49 @@ impl Frame {
                 } else {
                     // 벌크 문자열을 읽는다.
                     let len: usize = get_decimal(src)?.try_into()?;
+                    if len > max_frame_size {
+                        return Err("ERR Protocol error: invalid bulk length".into());
+                    }
 
                     // 바이트 + 2(\r\n) 의 수만큼 생략한다.
                     skip(src, len + 2)
                 }
             }
-            b'*' => {
-                let len = get_decimal(src)?;
+            b'*' | b'~' => {
+                let len = get_aggregate_len(src, max_frame_size)?;
                 for _ in 0..len {
-                    Frame::check(src)?;
+                    Frame::check(src, max_frame_size)?;
                 }
 
                 Ok(())
             }
-            actual => Err(format!("protocol error; invalid frame type byte '{}'", actual).into()),
+            b'%' => {
+                // 맵은 키와 값, 두 개의 프레임을 한 쌍으로 센다.
+                let len = get_aggregate_len(src, max_frame_size)?;
+                for _ in 0..len * 2 {
+                    Frame::check(src, max_frame_size)?;
+                }
+
+                Ok(())
+            }
+            b',' | b'#' | b'(' | b'_' => {
+                get_line(src)?;
+                Ok(())
+            }
+            _ => {
+                // 인라인 커맨드는 라인 하나로 이루어진다. 첫 바이트가 '\r'인 빈 라인도 있으므로
+                // 첫 바이트부터 라인을 찾는다.
+                src.set_position(src.position() - 1);
+                get_line(src)?;
+                Ok(())
+            }
         }
     }
 
     /// 메시지는 'check'를 통해 이미 검증되었다.
+    ///
+    /// 인라인 커맨드는 공백으로 나눈 각 토큰을 'Bulk'로 담은 배열 프레임으로 파싱된다. 따라서
+    /// 'Command::from_frame'은 인라인 커맨드를 배열 프레임과 구분하지 않는다.
     pub fn parse(src: &mut Cursor<&[u8]>) -> Result<Frame, Error> {
         match get_u8(src)? {
             b'+' => {
@@ -116,8 +173,8 @@ impl Frame {
                 Ok(Frame::Error(string))
             }
             b':' => {
-                let len = get_decimal(src)?;
-                Ok(Frame::Integer(len))
+                let val = get_int(src)?;
+                Ok(Frame::Integer(val))
             }
             b'$' => {
                 if b'-' == peek_u8(src)? {
@@ -154,7 +211,67 @@ impl Frame {
 
                 Ok(Frame::Array(out))
             }
-            _ => unimplemented!(),
+            b'~' => {
+                let len = get_decimal(src)?.try_into()?;
+                let mut out = Vec::with_capacity(len);
+
+                for _ in 0..len {
+                    out.push(Frame::parse(src)?);
+                }
+
+                Ok(Frame::Set(out))
+            }
+            b'%' => {
+                let len = get_decimal(src)?.try_into()?;
+                let mut out = Vec::with_capacity(len);
+
+                for _ in 0..len {
+                    let key = Frame::parse(src)?;
+                    let value = Frame::parse(src)?;
+                    out.push((key, value));
+                }
+
+                Ok(Frame::Map(out))
+            }
+            b',' => {
+                // Rust의 'f64' 파싱은 RESP3의 'inf', '-inf', 'nan' 표기를 모두 허용한다.
+                let line = get_line(src)?.to_vec();
+                let val = String::from_utf8(line)?
+                    .parse()
+                    .map_err(|_| "protocol error; invalid frame format")?;
+
+                Ok(Frame::Double(val))
+            }
+            b'#' => match get_line(src)? {
+                b"t" => Ok(Frame::Boolean(true)),
+                b"f" => Ok(Frame::Boolean(false)),
+                _ => Err("protocol error; invalid frame format".into()),
+            },
+            b'(' => {
+                let line = get_line(src)?.to_vec();
+                let string = String::from_utf8(line)?;
+                Ok(Frame::BigNumber(string))
+            }
+            b'_' => {
+                if !get_line(src)?.is_empty() {
+                    return Err("protocol error; invalid frame format".into());
+                }
+
+                Ok(Frame::Null3)
+            }
+            _ => {
+                // 첫 바이트도 커맨드의 일부이므로 커서를 되돌린다.
+                src.set_position(src.position() - 1);
+
+                let line = get_line(src)?;
+                let out = line
+                    .split(|b| b.is_ascii_whitespace())
+                    .filter(|token| !token.is_empty())
+                    .map(|token| Frame::Bulk(Bytes::copy_from_slice(token)))
+                    .collect();
+
+                Ok(Frame::Array(out))
+            }
         }
     }
 
@@ -186,13 +303,28 @@ impl fmt::Display for Frame {
                 Ok(string) => string.fmt(fmt),
                 Err(_) => write!(fmt, "{:?}", msg),
             },
-            Frame::Null => "(nil)".fmt(fmt),
-            Frame::Array(parts) => {
+            Frame::Null | Frame::Null3 => "(nil)".fmt(fmt),
+            Frame::Double(num) => num.fmt(fmt),
+            Frame::Boolean(val) => write!(fmt, "({})", val),
+            Frame::BigNumber(num) => num.fmt(fmt),
+            Frame::Map(pairs) => {
+                // 각 키와 값을 배열과 같이 공백 하나로 구분하여 출력한다.
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(fmt, " ")?;
+                    }
+                    write!(fmt, "{} {}", key, value)?;
+                }
+
+                Ok(())
+            }
+            Frame::Array(parts) | Frame::Set(parts) => {
+                // 각 원소를 공백 하나로 구분하여 출력한다. 중첩 배열의 원소도 재귀적으로 출력된다.
                 for (i, part) in parts.iter().enumerate() {
                     if i > 0 {
                         write!(fmt, " ")?;
-                        part.fmt(fmt)?;
                     }
+                    part.fmt(fmt)?;
                 }
 
                 Ok(())
@@ -236,6 +368,28 @@ fn get_decimal(src: &mut Cursor<&[u8]>) -> Result<u64, Error> {
     atoi::<u64>(line).ok_or_else(|| "protocol error; invalid frame format".into())
 }
 
+/// 배열, 셋, 맵의 앤트리 수를 읽는다.
+///
+/// 앤트리는 최소 한 바이트 이상이므로, 'max_frame_size'보다 많은 앤트리를 담은 프레임은 허용하지
+/// 않는다.
+fn get_aggregate_len(src: &mut Cursor<&[u8]>, max_frame_size: usize) -> Result<u64, Error> {
+    let len = get_decimal(src)?;
+    if len > max_frame_size as u64 {
+        return Err("ERR Protocol error: invalid multibulk length".into());
+    }
+
+    Ok(len)
+}
+
+/// 부호 있는 정수로 끝나는 새로운 라인을 읽는다. integer 프레임은 음수를 포함할 수 있다.
+fn get_int(src: &mut Cursor<&[u8]>) -> Result<i64, Error> {
+    use atoi::atoi;
+
+    let line = get_line(src)?;
+
+    atoi::<i64>(line).ok_or_else(|| "protocol error; invalid frame format".into())
+}
+
 /// 라인을 찾는다.
 fn get_line<'a>(src: &mut Cursor<&'a [u8]>) -> Result<&'a [u8], Error> {
     // 바이트를 직접 스캔한다.
@@ -289,4 +443,14 @@ impl fmt::Display for Error {
             Error::Other(err) => err.fmt(fmt),
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_array() {
+        let frame = Frame::Array(vec![Frame::Simple("a".into()), Frame::Integer(2)]);
+        assert_eq!(format!("{}", frame), "a 2");
+    }
+}
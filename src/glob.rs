@@ -0,0 +1,101 @@
+//! 레디스 스타일의 glob 패턴 매칭.
+//!
+//! 'PSUBSCRIBE'와 같이 패턴을 인자로 받는 커맨드들이 사용한다. 레디스와 같은 다음의 문법을
+//! 지원한다:
+//!
+//! - '?': 임의의 한 문자와 매칭된다.
+//! - '*': 빈 문자열을 포함한 임의의 문자열과 매칭된다.
+//! - '[abc]', '[a-z]': 괄호 안의 한 문자와 매칭된다. '[^a]'는 괄호 안에 없는 한 문자와 매칭된다.
+//! - '\': 뒤따르는 문자를 특별한 의미 없이 그대로 매칭한다.
+
+/// 'string'이 'pattern'과 매칭되는지 확인한다.
+///
+/// 재귀 없이, 마지막으로 만난 '*'의 위치로 되돌아가는 방식으로 구현한다. 이를 통해 '*'가 많은
+/// 패턴에서도 매칭 비용이 패턴과 문자열 길이의 곱을 넘지 않는다.
+pub(crate) fn matches(pattern: &[u8], string: &[u8]) -> bool {
+    let (mut p, mut s) = (0, 0);
+
+    // 마지막으로 만난 '*'의 패턴 위치와, 그 '*'가 매칭을 시작한 문자열 위치
+    let mut backtrack = None;
+
+    while s < string.len() {
+        if let Some(next) = match_one(pattern, p, string[s]) {
+            p = next;
+            s += 1;
+            continue;
+        }
+
+        if p < pattern.len() && pattern[p] == b'*' {
+            // 우선 '*'가 빈 문자열과 매칭된다고 가정한다.
+            backtrack = Some((p, s));
+            p += 1;
+            continue;
+        }
+
+        // 매칭에 실패했다. 마지막 '*'가 한 문자를 더 소비하도록 하고 다시 시도한다.
+        match backtrack {
+            Some((star, start)) => {
+                p = star + 1;
+                s = start + 1;
+                backtrack = Some((star, start + 1));
+            }
+            None => return false,
+        }
+    }
+
+    // 문자열을 모두 소비했다. 남은 패턴은 '*'로만 이루어져야 한다.
+    pattern[p..].iter().all(|&b| b == b'*')
+}
+
+/// 'pattern[p]'에서 시작하는 패턴 요소 하나를 문자 'c'와 매칭한다.
+///
+/// 매칭에 성공하면 다음 패턴 요소의 위치를 반환한다. 실패하거나 패턴 요소가 '*'인 경우 'None'을
+/// 반환한다.
+fn match_one(pattern: &[u8], p: usize, c: u8) -> Option<usize> {
+    match *pattern.get(p)? {
+        b'*' => None,
+        b'?' => Some(p + 1),
+        b'[' => match_class(pattern, p, c),
+        b'\\' if p + 1 < pattern.len() => (pattern[p + 1] == c).then_some(p + 2),
+        literal => (literal == c).then_some(p + 1),
+    }
+}
+
+/// 'pattern[p]'의 '['에서 시작하는 문자 클래스를 문자 'c'와 매칭한다.
+///
+/// 닫는 ']'가 없다면 패턴의 끝까지를 문자 클래스로 취급한다.
+fn match_class(pattern: &[u8], p: usize, c: u8) -> Option<usize> {
+    let mut i = p + 1;
+
+    let negate = pattern.get(i) == Some(&b'^');
+    if negate {
+        i += 1;
+    }
+
+    let mut matched = false;
+
+    while i < pattern.len() && pattern[i] != b']' {
+        if pattern[i] == b'\\' && i + 1 < pattern.len() {
+            // 이스케이프된 문자는 그대로 비교한다.
+            matched |= pattern[i + 1] == c;
+            i += 2;
+        } else if i + 2 < pattern.len() && pattern[i + 1] == b'-' && pattern[i + 2] != b']' {
+            // 'a-z'와 같은 범위. 범위의 양 끝이 뒤집혀 있어도 허용한다.
+            let (lo, hi) = if pattern[i] <= pattern[i + 2] {
+                (pattern[i], pattern[i + 2])
+            } else {
+                (pattern[i + 2], pattern[i])
+            };
+            matched |= lo <= c && c <= hi;
+            i += 3;
+        } else {
+            matched |= pattern[i] == c;
+            i += 1;
+        }
+    }
+
+    // 닫는 ']'를 건너뛴다.
+    let next = (i + 1).min(pattern.len());
+
+    (matched != negate).then_some(next)
+}
@@ -0,0 +1,48 @@
+use crate::cmd::COMMANDS;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// 커맨드별 호출 횟수. 'INFO commandstats'가 응답한다.
+///
+/// 리스너와 모든 커넥션 핸들러가 'Arc'로 공유한다. 카운터는 생성 시점에 'COMMANDS'에 등록된 모든
+/// 커맨드에 대해 만들어지므로, 이후에는 락 없이 원자적 연산만으로 갱신된다.
+#[derive(Debug)]
+pub(crate) struct Metrics {
+    /// 커맨드 이름별 호출 횟수
+    calls: HashMap<&'static str, AtomicU64>,
+}
+
+impl Metrics {
+    /// 모든 카운터가 0인 새로운 'Metrics'를 생성한다.
+    pub(crate) fn new() -> Metrics {
+        Metrics {
+            calls: COMMANDS
+                .iter()
+                .map(|spec| (spec.name, AtomicU64::new(0)))
+                .collect(),
+        }
+    }
+
+    /// 'name' 커맨드가 한 번 호출되었음을 기록한다.
+    ///
+    /// 지원하지 않는 커맨드는 기록하지 않는다.
+    pub(crate) fn record(&self, name: &str) {
+        if let Some(calls) = self.calls.get(name) {
+            calls.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// 한 번 이상 호출된 커맨드의 이름과 호출 횟수를 이름 순으로 반환한다.
+    pub(crate) fn calls(&self) -> Vec<(&'static str, u64)> {
+        let mut calls: Vec<_> = self
+            .calls
+            .iter()
+            .map(|(name, calls)| (*name, calls.load(Ordering::Relaxed)))
+            .filter(|(_, calls)| *calls > 0)
+            .collect();
+
+        calls.sort_unstable();
+        calls
+    }
+}
@@ -1,6 +1,7 @@
 use crate::Frame;
 
 use bytes::Bytes;
+use std::convert::TryFrom;
 use std::{fmt, str, vec};
 
 /// 커맨드 파싱 유틸리티
@@ -98,7 +99,7 @@ impl Parse {
 
         match self.next()? {
             // integer 타입 프레임은 이미 integer로 저장되어 있다.
-            Frame::Integer(v) => Ok(v),
+            Frame::Integer(v) => u64::try_from(v).map_err(|_| MSG.into()),
             // Simple, Bulk 프레임은 반드시 integer로 파싱해야 한다.
             // 파싱에 실패하면 에러를 반환한다.
             Frame::Simple(data) => atoi::<u64>(data.as_bytes()).ok_or_else(|| MSG.into()),
@@ -107,6 +108,22 @@ impl Parse {
         }
     }
 
+    /// 다음 앤트리를 부호 있는 integer로 반환한다.
+    /// 
+    /// 'next_int'와 같지만, 음수를 허용한다.
+    pub(crate) fn next_signed_int(&mut self) -> Result<i64, ParseError> {
+        use atoi::atoi;
+
+        const MSG: &str = "protocol error; invalid number";
+
+        match self.next()? {
+            Frame::Integer(v) => Ok(v),
+            Frame::Simple(data) => atoi::<i64>(data.as_bytes()).ok_or_else(|| MSG.into()),
+            Frame::Bulk(data) => atoi::<i64>(&data).ok_or_else(|| MSG.into()),
+            frame => Err(format!("protocol error; expected int frame but got {:?}", frame).into()),
+        }
+    }
+
     /// 배열에 다음 앤트리가 남아있지 않음을 보장한다.
     pub(crate) fn finish(&mut self) -> Result<(), ParseError> {
         if self.parts.next().is_none() {
@@ -0,0 +1,41 @@
+//! 무작위 선택을 위한 간단한 의사 난수 생성기.
+//!
+//! 'RANDOMKEY'와 같이 키나 원소를 무작위로 고르는 커맨드가 사용한다. 암호학적으로 안전할 필요가
+//! 없으므로 외부 crate 없이 'xorshift64*'로 구현한다.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// 'xorshift64*' 의사 난수 생성기
+#[derive(Debug)]
+pub(crate) struct Rng {
+    /// 0이 아닌 내부 상태
+    state: u64,
+}
+
+impl Rng {
+    /// 새로운 시드로 초기화된 생성기를 생성한다.
+    ///
+    /// 'RandomState'는 생성될 때마다 다른 키를 가지므로, 그 해시 값을 시드로 사용한다.
+    pub(crate) fn new() -> Rng {
+        let seed = RandomState::new().build_hasher().finish();
+
+        // 상태가 0이면 항상 0만을 생성하므로 피한다.
+        Rng { state: seed | 1 }
+    }
+
+    /// 다음 난수를 반환한다.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// '0 <= n < bound'인 난수를 반환한다. 'bound'는 0이 아니어야 한다.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        debug_assert!(bound > 0);
+
+        (self.next_u64() % bound as u64) as usize
+    }
+}
@@ -1,18 +1,35 @@
-/**
- * ! 미니멀 Redis 서버 구현
- * !
- * ! 인바운드 커넥션을 수신하는 비동기 'run'함수를 제공한다.
- * ! 커넥션마다 태스크를 가동한다.
- */
+//! 미니멀 Redis 서버 구현
+//!
+//! 인바운드 커넥션을 수신하는 비동기 'run'함수를 제공한다.
+//! 커넥션마다 태스크를 가동한다.
 
-use crate::{Command, Connection, Db, Shutdown};
+use crate::connection::{DEFAULT_MAX_FRAME_SIZE, DEFAULT_READ_BUFFER_CAPACITY};
+use crate::metrics::Metrics;
+use crate::slowlog::{self, SlowLog};
+use crate::snapshot::{self, Persistence};
+use crate::stats::Stats;
+use crate::clients::Clients;
+use crate::cmd::{monitor_line, Leave, Reset, Transaction, WatchedKeys};
+use crate::config::RuntimeConfig;
+use crate::{BoxStream, Command, Connection, Db, Frame, MiniRedisError, ProtocolVersion, Shutdown};
 
+use std::convert::TryFrom;
 use std::future::Future;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::{broadcast, mpsc, Semaphore};
+use std::time::Instant;
+use tokio::net::TcpListener;
+#[cfg(unix)]
+use tokio::net::UnixListener;
+use tokio::sync::{broadcast, mpsc, Notify, Semaphore};
 use tokio::time::{self, Duration};
-use tracing::{debug, error, info, instrument};
+#[cfg(feature = "tls")]
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, error, info, instrument, warn};
+
+pub use crate::config::MaxMemoryPolicy;
 
 
 /// 서버 리스너 상태. 'run' 호출 안에서 생성된다. TCP 리스닝을 수행하고 per-connection
@@ -25,8 +42,51 @@ struct Listener {
     ///  상태로 전달된다 ('Handler').
     db: Db,
 
-    /// 'run' 호출자가 제공하는 TCP 리스너.
-    listener: TcpListener,
+    /// 'run' 호출자가 제공하는 TCP 혹은 유닉스 도메인 소켓 리스너.
+    listener: SocketListener,
+
+    /// 각 커넥션의 읽기 버퍼 초기 크기.
+    read_buffer_capacity: usize,
+
+    /// 각 커넥션이 수신할 수 있는 프레임의 최대 크기.
+    max_frame_size: usize,
+
+    /// TLS 핸드셰이크에 사용하는 acceptor. 'run_tls'로 가동한 경우에만 설정된다.
+    #[cfg(feature = "tls")]
+    tls_acceptor: Option<TlsAcceptor>,
+
+    /// 커넥션 인증에 사용하는 비밀번호. 'None'이면 인증 없이 모든 커맨드를 수행할 수 있다.
+    requirepass: Option<Arc<str>>,
+
+    /// 서버 통계. 모든 핸들러와 공유하며, 'INFO' 커맨드가 응답한다.
+    stats: Arc<Stats>,
+
+    /// 커맨드별 호출 횟수. 모든 핸들러와 공유하며, 'INFO commandstats'가 응답한다.
+    metrics: Arc<Metrics>,
+
+    /// 연결된 클라이언트의 목록. 모든 핸들러와 공유하며, 'CLIENT LIST'가 응답한다.
+    clients: Arc<Clients>,
+
+    /// 처리에 오래 걸린 커맨드의 기록. 모든 핸들러와 공유하며, 'SLOWLOG'가 응답한다.
+    slowlog: Arc<SlowLog>,
+
+    /// 핸들러가 수신한 커맨드를 'MONITOR' 상태의 커넥션들에게 브로드캐스팅한다.
+    /// 
+    /// 수신자는 'MONITOR'를 수신한 핸들러가 'subscribe()'로 생성한다. 수신자가 없다면 핸들러는
+    /// 커맨드를 발행하지 않는다.
+    monitor: broadcast::Sender<String>,
+
+    /// 다음으로 수락할 커넥션에 부여할 식별자. 1부터 시작하며, 커넥션마다 1씩 증가한다.
+    next_client_id: u64,
+
+    /// 스냅샷 파일과 저장 상태. 설정되었다면 가동 시 스냅샷 파일로부터 키 공간을 복원한다.
+    persistence: Option<Arc<Persistence>>,
+
+    /// 'CONFIG'로 변경할 수 있는 서버 설정. 모든 핸들러와 공유한다.
+    config: Arc<RuntimeConfig>,
+
+    /// graceful 셧다운 시 커넥션의 처리 완료를 기다리는 최대 시간.
+    shutdown_timeout: Duration,
 
     /// 최대 커넥션 수를 제한한다.
     /// 
@@ -45,6 +105,12 @@ struct Listener {
     /// 값을 전송한다. 각 커넥션은 이 값을 수신하고 안전한 종료 상태가 되며 태스크를 완료한다.
     notify_shutdown: broadcast::Sender<()>,
 
+    /// 'Handle::shutdown'이 graceful 셧다운을 시작하도록 알린다.
+    /// 
+    /// 'run' 호출자가 제공한 'shutdown' future와 함께 기다린다. 'notify_one'은 기다리는 태스크가 없다면
+    /// permit을 저장하므로, 서버가 가동되기 전에 호출된 셧다운도 누락되지 않는다.
+    trigger_shutdown: Arc<Notify>,
+
     /// graceful 셧다운 중 클라이언트 커넥션의 처리 완료를 기다리는 과정에 사용된다.
     /// 
     /// 모든 'Sender' 핸들이 한 번 범위를 벗어나면 Tokio 채널이 닫힌다. 한 채널이 닫히면
@@ -57,6 +123,16 @@ struct Listener {
     shutdown_complete_tx: mpsc::Sender<()>,
 }
 
+/// 'Listener'가 인바운드 커넥션을 수락하는 소켓
+/// 
+/// 수락한 커넥션은 'BoxStream'으로 박싱되므로, 리스너의 종류와 관계 없이 같은 방식으로 핸들링된다.
+/// 'Listener::accept'의 백오프 로직도 모든 리스너가 공유한다.
+enum SocketListener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
 /// 각 커넥션의 핸들러. 'connection'으로부터 요청을 읽어 그 커맨드를 'db'에 수행한다.
 struct Handler {
     /// 공유 데이터베이스 핸들.
@@ -64,8 +140,30 @@ struct Handler {
     /// 'connection'으로부터 커맨드를 수신하면 이를 'db'에 수행한다. 커맨드는 'cmd' 모듈의
     /// 구현체를 사용한다. 각 커맨드는 그 수행을 완료하기 위해 'db' 인스턴스와의 상호작용을
     /// 필요로 한다.
+    /// 
+    /// 핸들은 커넥션이 'SELECT'로 선택한 데이터베이스를 가리킨다. 'SELECT'는 이 필드를 선택한
+    /// 데이터베이스의 핸들로 교체한다.
     db: Db,
 
+    /// 커맨드별 호출 횟수. 'Listener'의 값을 공유한다.
+    metrics: Arc<Metrics>,
+
+    /// 리스너가 이 커넥션에 부여한 식별자. 'CLIENT' 커맨드가 커넥션을 구분하는 데 사용한다.
+    id: u64,
+
+    /// 연결된 클라이언트의 목록. 'Listener'의 값을 공유한다. 핸들러는 생성될 때 자신을 등록하고,
+    /// drop될 때 등록을 해지한다.
+    clients: Arc<Clients>,
+
+    /// 처리에 오래 걸린 커맨드의 기록. 'Listener'의 값을 공유한다.
+    slowlog: Arc<SlowLog>,
+
+    /// 클라이언트의 주소. 'MONITOR'로 발행하는 커맨드와 슬로우 로그에 표시된다.
+    addr: String,
+
+    /// 수신한 커맨드를 'MONITOR' 상태의 커넥션들에게 발행한다. 'Listener'의 값을 공유한다.
+    monitor: broadcast::Sender<String>,
+
     /**
      * 레디스 프로토콜 인코더/디코더를 갖춘 TCP 커넥션.
      * 인코더/디코더는 버퍼링된 'TcpStream'을 사용하여 구현되어 있다.
@@ -84,11 +182,11 @@ struct Handler {
     /// 캡슙화한다.
     connection: Connection,
 
-    /// 최대 커넥션 세마포어.
+    /// 'CONFIG'로 변경할 수 있는 서버 설정. 'Listener'의 값을 공유한다.
     /// 
-    /// 핸들러 drop 시 이 세마포어에 permit을 반환한다. 리스너가 커넥션 중단을 기다리는 중이라면
-    /// 유효한 새 permit 알림을 받아서 커넥션을 수락할 것이다.
-    limit_connections: Arc<Semaphore>,
+    /// 최대 커넥션 세마포어를 관리한다. 핸들러 drop 시 이를 통해 세마포어에 permit을 반환한다.
+    /// 리스너가 커넥션 중단을 기다리는 중이라면 유효한 새 permit 알림을 받아서 커넥션을 수락할 것이다.
+    config: Arc<RuntimeConfig>,
 
     /// 셧다운 알림을 받는다.
     /// 
@@ -98,44 +196,298 @@ struct Handler {
     /// 안전한 상태란 커넥션이 종료되는 시점이다.
     shutdown: Shutdown,
 
+    /// 서버에 설정된 비밀번호. 'Listener'의 값을 공유한다.
+    requirepass: Option<Arc<str>>,
+
+    /// 서버 통계. 'Listener'의 값을 공유한다.
+    stats: Arc<Stats>,
+
+    /// 'SAVE'와 'BGSAVE'가 사용하는 스냅샷 파일과 저장 상태. 'Listener'의 값을 공유한다.
+    persistence: Option<Arc<Persistence>>,
+
+    /// 커넥션이 인증되었는지 여부.
+    /// 
+    /// 비밀번호가 설정되지 않았다면 처음부터 'true'이다. 설정되었다면 'AUTH'로 인증에 성공했을 때
+    /// 'true'가 된다. 'false'인 동안에는 'AUTH'와 'HELLO' 외의 커맨드를 거부한다.
+    authenticated: bool,
+
+    /// 'MULTI'로 시작한 트랜잭션. 'None'이라면 커맨드는 수신하는 대로 수행된다.
+    transaction: Option<Transaction>,
+
+    /// 'WATCH'로 감시하는 키. 'EXEC'는 이 키들이 변경되었다면 트랜잭션을 수행하지 않는다.
+    watched: WatchedKeys,
+
+    /// 'QUIT'을 수신했는지 여부. 'true'가 되면 응답을 소켓에 쓴 뒤 커넥션을 닫는다.
+    quitting: bool,
+
+    /// 'CLIENT KILL'로 이 커넥션을 닫도록 요청받으면 알림을 받는다. 클라이언트 목록에 등록된 값과
+    /// 같다.
+    killed: Arc<Notify>,
+
     /// 직접 사용하지 않는다. 'Handler' drop 시 사용...?
     _shutdown_complete: mpsc::Sender<()>,
 }
 
-/// 레디스 서버가 수용하는 최대 동시 커넥션 수.
+/// 레디스 서버가 수용하는 최대 동시 커넥션 수의 기본값.
 /// 
 /// 동시 커넥션 수가 여기에 도달하면 서버는 유효한 커넥션이 종료될 때까지 새로운 커넥션을 수립하지 않는다.
 /// 
-/// 실제 어플리케이션에서는 이 값을 따로 설정할 수 있어야 하겠지만, 이 예시에서는 하드코딩으로 둔다.
+/// 'Config::max_connections'로 이 값을 변경할 수 있다.
 /// 
 /// 이 값은 이 프로그램을 운영 환경에서 사용하지 않을 것을 권하도록 하는 아주 낮은 값이다 (당신은 이런 모든 
 /// 고지사항들이 이 프로젝트가 진지한 프로젝트가 아님을 확실히 보여준다고 생각하겠지만, 이는 mini-http에서도 
 /// 마찬가지라고 생각한다.)
 const MAX_CONNECTIONS: usize = 250;
 
-/// mini-redis 서버를 가동한다.
+/// 'MONITOR' 채널이 담아두는 커맨드의 수. 모니터 커넥션이 이보다 많이 뒤처지면 놓친 커맨드는
+/// 건너뛴다.
+const MONITOR_CAPACITY: usize = 1024;
+
+/// 슬로우 로그에 기록할 커맨드의 처리 시간의 기본값. 레디스의 'slowlog-log-slower-than'과 같다.
+const DEFAULT_SLOWLOG_LOG_SLOWER_THAN: Duration = Duration::from_millis(10);
+
+/// 슬로우 로그가 유지하는 기록의 최대 개수의 기본값. 레디스의 'slowlog-max-len'과 같다.
+const DEFAULT_SLOWLOG_MAX_LEN: usize = 128;
+
+/// 논리 데이터베이스 수의 기본값. 레디스와 같다.
+const DEFAULT_DATABASES: usize = 16;
+
+/// graceful 셧다운 시 커넥션의 처리 완료를 기다리는 시간의 기본값. 레디스의 'shutdown-timeout'과 같다.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 서버 설정.
+/// 
+/// 'Default' 구현은 'run'이 사용하는 기본 설정을 제공한다. 필요한 필드만 변경하여 'run_with_config'에
+/// 전달한다.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// 동시에 수용하는 최대 커넥션 수.
+    pub max_connections: usize,
+
+    /// 각 커넥션의 읽기 버퍼 초기 크기. 버퍼는 필요에 따라 커진다.
+    pub read_buffer_capacity: usize,
+
+    /// 클라이언트로부터 수신할 수 있는 프레임의 최대 크기(바이트). 이보다 큰 프레임을 전송한
+    /// 클라이언트에게는 에러를 응답하고 커넥션을 종료한다. 기본값은 512MB이다.
+    pub max_frame_size: usize,
+
+    /// 클라이언트가 요청을 보내지 않을 때 커넥션을 종료하기까지의 시간. 'None'이나 0이면 종료하지
+    /// 않는다. 기본값은 'None'이다.
+    /// 
+    /// 구독 중이거나 블로킹 커맨드를 기다리는 커넥션은 종료하지 않는다. 'CONFIG SET timeout'으로
+    /// 가동 중에 변경할 수 있다.
+    pub idle_timeout: Option<Duration>,
+
+    /// 키 공간이 사용할 수 있는 최대 메모리(바이트). 메모리 사용량은 키와 값의 바이트 길이의 합으로
+    /// 어림한다. 0이면 제한이 없다. 기본값은 0이다.
+    /// 
+    /// 'CONFIG SET maxmemory'로 가동 중에 변경할 수 있다.
+    pub maxmemory: u64,
+
+    /// 메모리 사용량이 'maxmemory'를 넘었을 때의 정책. 기본값은 'MaxMemoryPolicy::NoEviction'이다.
+    pub maxmemory_policy: MaxMemoryPolicy,
+
+    /// 커맨드를 처리하는 데 이보다 오래 걸리면 슬로우 로그에 기록한다. 'None'이면 기록하지 않는다.
+    /// 기본값은 10밀리초이다.
+    /// 
+    /// 구독이나 'MONITOR'처럼 커넥션의 상태를 바꾸는 커맨드와 블로킹 커맨드는 기록하지 않는다.
+    /// 'CONFIG SET slowlog-log-slower-than'(마이크로초)으로 가동 중에 변경할 수 있다.
+    pub slowlog_log_slower_than: Option<Duration>,
+
+    /// 슬로우 로그가 유지하는 기록의 최대 개수. 기본값은 128이다.
+    /// 
+    /// 'CONFIG SET slowlog-max-len'으로 가동 중에 변경할 수 있다.
+    pub slowlog_max_len: usize,
+
+    /// graceful 셧다운 시 커넥션들이 처리를 마치기를 기다리는 최대 시간. 기본값은 10초이다.
+    /// 
+    /// 셧다운 시그널에 응답하지 않는 커넥션이 있더라도, 이 시간이 지나면 기다리지 않고 서버를
+    /// 종료한다.
+    pub shutdown_timeout: Duration,
+
+    /// 서버가 수신할 주소. 'run_with_config'는 이미 바인드된 리스너를 받으므로, 이 값은 리스너를
+    /// 생성하는 호출자가 사용한다.
+    pub bind_addr: IpAddr,
+
+    /// 논리 데이터베이스의 수. 'SELECT'는 0부터 이 값 미만의 번호를 받는다. 0이 아니어야 한다.
+    pub databases: usize,
+
+    /// 커넥션 인증에 사용하는 비밀번호. 설정되면 클라이언트는 'AUTH'로 인증한 뒤에 커맨드를 수행할 수
+    /// 있다. 기본값은 'None'으로, 인증을 요구하지 않는다.
+    pub requirepass: Option<String>,
+
+    /// 키가 변경될 때 키 공간 알림을 pub/sub으로 발행할지 여부. 기본값은 'false'이다.
+    /// 
+    /// 설정되면 'SET', 'DEL', 'EXPIRE', 'PERSIST'와 키 만료 시에 '__keyspace@<db>__:<key>' 채널로 이벤트
+    /// 이름을, '__keyevent@<db>__:<event>' 채널로 키 이름을 발행한다. 만료 이벤트의 이름은 'expired'이며,
+    /// 백그라운드 태스크가 만료된 키를 삭제할 때 발행된다.
+    pub notify_keyspace_events: bool,
+
+    /// 스냅샷 파일의 경로. 설정되면 'SAVE'는 이 경로에 키 공간을 저장하고, 서버는 가동 시 이 파일이
+    /// 존재한다면 키 공간을 복원한다. 기본값은 'None'으로, 스냅샷을 사용하지 않는다.
+    pub dbfilename: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            max_connections: MAX_CONNECTIONS,
+            read_buffer_capacity: DEFAULT_READ_BUFFER_CAPACITY,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            idle_timeout: None,
+            maxmemory: 0,
+            maxmemory_policy: MaxMemoryPolicy::default(),
+            slowlog_log_slower_than: Some(DEFAULT_SLOWLOG_LOG_SLOWER_THAN),
+            slowlog_max_len: DEFAULT_SLOWLOG_MAX_LEN,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            bind_addr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            databases: DEFAULT_DATABASES,
+            requirepass: None,
+            notify_keyspace_events: false,
+            dbfilename: None,
+        }
+    }
+}
+
+/// 가동 중인 서버의 상태를 조회하고 셧다운을 시작하는 핸들. 'run_with_handle'이 반환한다.
+/// 
+/// 'INFO' 커맨드를 거치지 않고, 서버를 내장한 프로그램이 직접 서버의 상태를 읽을 수 있도록 한다.
+/// 'shutdown' future를 미리 준비하지 않아도, 원하는 시점에 'shutdown'으로 서버를 종료할 수 있다.
+/// 핸들은 서버와 상태를 'Arc'로 공유하므로 clone하여 여러 태스크에서 사용할 수 있으며, 서버가 종료된
+/// 뒤에도 마지막 상태를 반환한다.
+#[derive(Debug, Clone)]
+pub struct Handle {
+    /// 리스너와 모든 커넥션 핸들러가 갱신하는 서버 통계.
+    stats: Arc<Stats>,
+
+    /// 서버의 graceful 셧다운을 시작하도록 알린다.
+    trigger_shutdown: Arc<Notify>,
+}
+
+impl Handle {
+    /// 현재 연결된 클라이언트의 수를 반환한다. 'INFO'의 'connected_clients'와 같다.
+    /// 
+    /// 리스너는 다음 커넥션을 수락하기 전에 세마포어의 permit을 미리 획득하므로, 남은 permit의 수가 아닌
+    /// 핸들러의 생성과 drop 시점에 갱신되는 값을 사용한다.
+    pub fn active_connections(&self) -> usize {
+        self.stats.connected_clients()
+    }
+
+    /// 가동 이후 수락한 커넥션의 수를 반환한다. 'INFO'의 'total_connections_received'와 같다.
+    pub fn total_accepted(&self) -> u64 {
+        self.stats.total_connections_received()
+    }
+
+    /// 서버의 graceful 셧다운을 시작한다.
+    /// 
+    /// 'run_with_handle'에 전달한 'shutdown' future가 완료된 것과 같이 동작한다. 서버는 새로운 커넥션의
+    /// 수락을 멈추고 유효한 커넥션들이 처리를 마치기를 기다린 뒤, 서버 future를 완료한다. 서버가 가동되기
+    /// 전에 호출하면 서버는 가동 즉시 셧다운한다. 여러 번 호출해도 한 번 호출한 것과 같다.
+    pub fn shutdown(&self) {
+        self.trigger_shutdown.notify_one();
+    }
+}
+
+/// mini-redis 서버를 기본 설정으로 가동한다.
 /// 
 /// 리스너로부터 커넥션을 수락한다. 커넥션 핸들링 태스크를 각 커넥션 당 하나씩 가동한다. 서버는 'shutdown'
 /// future가 완료될 때까지 가동한다. 즉 서버의 graceful 셧다운까지이다.
 /// 
 /// 'tokio::signal::ctrl_c()'를 'shutdown' 아규먼트로 사용할 수 있다. 이것은 SIGINT 시그널이 될 것이다.
 pub async fn run(listener: TcpListener, shutdown: impl Future) -> crate::Result<()> {
-    // 제공된 'shutdown' future가 완료되면, 반드시 셧다운 메시디를 모든 유효 커넥션들에게 전송해야 한다.
-    // 이 작업에는 브로드캐스트 채널을 사용한다. 아래 코드의 호출은 브로드캐스트 페어의 수신자를 무시하고,
-    // 수신자가 필요하면 sender에 subscribe() 메서드를 사용하여 하나를 생성한다.
-    let (notify_shutdown, _) = broadcast::channel(1);
-    let (shutdown_complete_tx, shutdown_complete_rx) = mpsc::channel(1);
-
-    // 리스너 상태를 초기화한다.
-    let mut server = Listener {
-        listener,
-        db: Db::new(),
-        limit_connections: Arc::new(Semaphore::new(MAX_CONNECTIONS)),
-        notify_shutdown,
-        shutdown_complete_tx,
-        shutdown_complete_rx,
+    run_with_config(listener, shutdown, Config::default()).await
+}
+
+/// 주어진 설정으로 mini-redis 서버를 가동한다.
+/// 
+/// 설정을 제외한 동작은 'run'과 같다.
+pub async fn run_with_config(
+    listener: TcpListener,
+    shutdown: impl Future,
+    config: Config,
+) -> crate::Result<()> {
+    serve(Listener::new(SocketListener::Tcp(listener), config), shutdown).await
+}
+
+/// 주어진 설정으로 mini-redis 서버를 준비하고, 서버의 상태를 조회하는 'Handle'과 서버를 가동하는
+/// future를 반환한다.
+/// 
+/// 서버는 반환된 future를 실행해야 가동된다. 'shutdown' future가 완료되거나 'Handle::shutdown'이
+/// 호출되면 graceful 셧다운을 수행한다. 그 외의 동작은 'run_with_config'와 같다.
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use mini_redis::server;
+/// use tokio::net::TcpListener;
+/// use tokio::signal;
+/// 
+/// #[tokio::main]
+/// async fn main() {
+///     let listener = TcpListener::bind("127.0.0.1:6379").await.unwrap();
+///     let (handle, server) =
+///         server::run_with_handle(listener, signal::ctrl_c(), server::Config::default());
+/// 
+///     tokio::spawn(server);
+/// 
+///     println!("active connections: {}", handle.active_connections());
+/// }
+/// ```
+pub fn run_with_handle(
+    listener: TcpListener,
+    shutdown: impl Future,
+    config: Config,
+) -> (Handle, impl Future<Output = crate::Result<()>>) {
+    let server = Listener::new(SocketListener::Tcp(listener), config);
+    let handle = Handle {
+        stats: server.stats.clone(),
+        trigger_shutdown: server.trigger_shutdown.clone(),
     };
 
+    (handle, serve(server, shutdown))
+}
+
+/// 유닉스 도메인 소켓으로 커넥션을 수락하는 mini-redis 서버를 기본 설정으로 가동한다.
+/// 
+/// 같은 호스트의 클라이언트는 TCP 대신 유닉스 도메인 소켓을 사용하여 오버헤드를 줄일 수 있다.
+/// 'listener'가 바인드된 경로에는 ['client::connect_unix'](fn@crate::client::connect_unix)로 연결한다.
+/// 리스너의 종류를 제외한 동작은 'run'과 같다.
+#[cfg(unix)]
+pub async fn run_uds(listener: UnixListener, shutdown: impl Future) -> crate::Result<()> {
+    serve(Listener::new(SocketListener::Unix(listener), Config::default()), shutdown).await
+}
+
+/// TLS로 암호화된 커넥션을 수락하는 mini-redis 서버를 기본 설정으로 가동한다.
+/// 
+/// 수락한 각 TCP 커넥션은 'acceptor'로 TLS 핸드셰이크를 마친 뒤에 핸들링된다. 핸드셰이크는 커넥션
+/// 태스크에서 수행하므로, 핸드셰이크가 느린 클라이언트가 다른 커넥션의 수락을 막지 않는다. 핸드셰이크에
+/// 실패한 커넥션은 닫힌다.
+/// 
+/// 'tls' feature가 필요하다.
+#[cfg(feature = "tls")]
+pub async fn run_tls(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    shutdown: impl Future,
+) -> crate::Result<()> {
+    let mut server = Listener::new(SocketListener::Tcp(listener), Config::default());
+    server.tls_acceptor = Some(acceptor);
+
+    serve(server, shutdown).await
+}
+
+/// 리스너를 가동하고, 'shutdown'이 완료되면 graceful 셧다운을 수행한다.
+/// 
+/// 스냅샷 파일이 설정되어 있다면 커넥션을 수락하기 전에 키 공간을 복원한다.
+async fn serve(mut server: Listener, shutdown: impl Future) -> crate::Result<()> {
+    if let Some(persistence) = &server.persistence {
+        let path = persistence.path();
+
+        if snapshot::load(&server.db, path).await? {
+            info!(path = %path.display(), "loaded snapshot");
+        }
+    }
+
     // 서버 가동과 'shutdown' 시그널 수신을 동시에 수행한다. 서버 태스크는 에러를 만날때까지 실행된다.
     // 
     // 'select!'문은 다음의 형태로 작성한다.
@@ -149,6 +501,8 @@ pub async fn run(listener: TcpListener, shutdown: impl Future) -> crate::Result<
     // 
     // 'select!' 매크로는 비동기 Rust 작성을 위해 기본이 되는 빌딩 블록이다. 자세한 내용은 아래를 본다:
     // https://docs.rs/tokio/*/tokio/macro.select.html
+    let trigger_shutdown = server.trigger_shutdown.clone();
+
     tokio::select! {
         res = server.run() => {
             // 여기서 에러를 수신하면 TCP 리스너로부터의 커넥션 수락이 여러번 실패했다는 의미이며, 서버는 실행을
@@ -163,6 +517,10 @@ pub async fn run(listener: TcpListener, shutdown: impl Future) -> crate::Result<
             // 셧다운 시그널을 수신했다.
             info!("shutting down");
         }
+        _ = trigger_shutdown.notified() => {
+            // 'Handle::shutdown'이 호출되었다.
+            info!("shutting down");
+        }
     }
 
     // 'shutdown_complete' 수신자와 전송기를 추출하여 'shutdown_transmitter'를 명시적으로 drop한다.
@@ -171,6 +529,7 @@ pub async fn run(listener: TcpListener, shutdown: impl Future) -> crate::Result<
         mut shutdown_complete_rx,
         shutdown_complete_tx,
         notify_shutdown,
+        shutdown_timeout,
         ..
     } = server;
 
@@ -182,12 +541,64 @@ pub async fn run(listener: TcpListener, shutdown: impl Future) -> crate::Result<
     // 모든 유효한 커넥션이 처리를 마칠때까지 기다린다. 리스너가 잡고있는 'Sender' 핸들은 위에서 drop
     // 되었기 때문에, 커넥션 핸들러 태스크가 잡고 있는 'Sender'만이 남아있다. 이 drop 작업들을 수행할 때
     // 'mpsc' 채널이 닫히고 'recv()'는 'None'을 반환할 것이다.
-    let _ = shutdown_complete_rx.recv().await;
+    // 
+    // 셧다운 시그널에 응답하지 않는 핸들러가 서버 종료를 막지 않도록, 'shutdown_timeout'이 지나면
+    // 기다리지 않는다. 남은 커넥션 태스크는 런타임이 종료될 때 함께 정리된다.
+    tokio::select! {
+        _ = shutdown_complete_rx.recv() => {}
+        _ = time::sleep(shutdown_timeout) => {
+            warn!(timeout = ?shutdown_timeout, "timed out waiting for connections to close");
+        }
+    }
 
     Ok(())
 }
 
 impl Listener {
+    /// 'config'에 따라 리스너 상태를 초기화한다.
+    fn new(listener: SocketListener, config: Config) -> Listener {
+        // 제공된 'shutdown' future가 완료되면, 반드시 셧다운 메시디를 모든 유효 커넥션들에게 전송해야 한다.
+        // 이 작업에는 브로드캐스트 채널을 사용한다. 아래 코드의 호출은 브로드캐스트 페어의 수신자를 무시하고,
+        // 수신자가 필요하면 sender에 subscribe() 메서드를 사용하여 하나를 생성한다.
+        let (notify_shutdown, _) = broadcast::channel(1);
+        let (shutdown_complete_tx, shutdown_complete_rx) = mpsc::channel(1);
+
+        let limit_connections = Arc::new(Semaphore::new(config.max_connections));
+        let runtime_config = RuntimeConfig::new(
+            config.max_connections,
+            config.maxmemory,
+            config.maxmemory_policy,
+            config.idle_timeout,
+            config.slowlog_log_slower_than,
+            config.slowlog_max_len,
+            limit_connections.clone(),
+        );
+
+        Listener {
+            listener,
+            read_buffer_capacity: config.read_buffer_capacity,
+            max_frame_size: config.max_frame_size,
+            #[cfg(feature = "tls")]
+            tls_acceptor: None,
+            requirepass: config.requirepass.map(Arc::from),
+            stats: Arc::new(Stats::new()),
+            metrics: Arc::new(Metrics::new()),
+            clients: Arc::new(Clients::new()),
+            slowlog: Arc::new(SlowLog::new()),
+            monitor: broadcast::channel(MONITOR_CAPACITY).0,
+            next_client_id: 1,
+            persistence: config.dbfilename.map(|path| Arc::new(Persistence::new(path))),
+            db: Db::new(config.databases, config.notify_keyspace_events),
+            config: Arc::new(runtime_config),
+            shutdown_timeout: config.shutdown_timeout,
+            limit_connections,
+            notify_shutdown,
+            trigger_shutdown: Arc::new(Notify::new()),
+            shutdown_complete_tx,
+            shutdown_complete_rx,
+        }
+    }
+
     /// 서버를 가동한다.
     /// 
     /// 인바운드 커넥션을 수신한다. 각 인바운드 커넥션마다 그 커넥션을 핸들링할 태스크를 시작한다.
@@ -218,28 +629,50 @@ impl Listener {
 
             //  새 소켓을 수락한다. 이는 에러 핸들링을 시도한다. 'accept' 메서드는 내부적으로 에러 복구를 
             //  시도하므로, 여기서 나오는 에러는 복구 불가능한 에러이다.
-            let socket = self.accept().await?;
+            let (socket, addr) = self.accept().await?;
+            self.stats.connection_received();
 
-            // 한 커넥션에 대한 핸들러 상태를 생성한다.
-            let mut handler = Handler {
-                //  공유 데이터베이스로의 핸들을 가져온다. db는 내부적으로 'Arc'이므로 clone은 ref count
-                //  만을 증가시킨다.
-                db: self.db.clone(),
+            //  커넥션에 식별자를 부여한다.
+            let id = self.next_client_id;
+            self.next_client_id += 1;
 
-                //  커넥션 상태를 초기화한다. 이 동작은 레디스 프로토콜 프레임 파싱을 수행하기 위한 읽기/쓰기
-                //   버퍼를 초기화한다.
-                connection: Connection::new(socket),
+            // 한 커넥션에 대한 핸들러를 생성하는 함수를 가져온다.
+            let new_handler = self.new_handler(id, addr);
 
-                //  커넥션 상태는 커넥션 최대치를 제한하는 세마포어를 필요로 한다. 핸들러가 커넥션에 대한 처리를
-                //  마치면, permit은 세마포어로 반환된다.
-                limit_connections: self.limit_connections.clone(),
+            // TLS가 설정되어 있다면 커넥션 태스크에서 핸드셰이크를 마친 뒤에 핸들러를 생성한다. 핸드셰이크를
+            // 태스크에서 수행하므로, 핸드셰이크가 느린 클라이언트가 다른 커넥션의 수락을 막지 않는다.
+            #[cfg(feature = "tls")]
+            if let Some(acceptor) = self.tls_acceptor.clone() {
+                let config = self.config.clone();
+                let capacity = self.read_buffer_capacity;
 
-                // 셧다운 알림을 수신한다.
-                shutdown: Shutdown::new(self.notify_shutdown.subscribe()),
+                tokio::spawn(async move {
+                    let stream = match acceptor.accept(socket).await {
+                        Ok(stream) => stream,
+                        Err(err) => {
+                            error!(cause = ?err, "tls handshake error");
 
-                //  모든 clone이 drop되면 수신자에게 이를 알린다.
-                _shutdown_complete: self.shutdown_complete_tx.clone(),
-            };
+                            // 핸들러가 생성되지 않았으므로 permit을 직접 반환한다.
+                            config.release_connection();
+                            return;
+                        }
+                    };
+
+                    let stream: BoxStream = Box::new(stream);
+                    let mut handler = new_handler(Connection::with_capacity(stream, capacity));
+
+                    if let Err(err) = handler.run().await {
+                        error!(cause = ?err, "connection error");
+                    }
+                });
+
+                continue;
+            }
+
+            //  커넥션 상태를 초기화한다. 이 동작은 레디스 프로토콜 프레임 파싱을 수행하기 위한 읽기/쓰기
+            //   버퍼를 초기화한다.
+            let mut handler =
+                new_handler(Connection::with_capacity(socket, self.read_buffer_capacity));
 
             //  커넥션 처리를 위한 태스크를 가동한다. Tokio 태스크는 비동기 그린 쓰레드에 가까우며, 동시에 실행된다.
             tokio::spawn(async move {
@@ -251,12 +684,83 @@ impl Listener {
         }
     }
 
+    /// 한 커넥션에 대한 핸들러를 생성하는 함수를 반환한다.
+    /// 
+    /// 핸들러가 공유할 상태는 호출 시점에 준비된다. TLS 핸드셰이크처럼 커넥션 태스크 안에서 커넥션을
+    /// 초기화하는 경우, 반환된 함수를 태스크로 옮겨 커넥션이 준비된 뒤에 핸들러를 생성한다.
+    /// 
+    /// 'id'와 'addr'은 커넥션의 식별자와 클라이언트의 주소이다. 핸들러는 생성될 때 이 정보로 클라이언트
+    /// 목록에 등록된다.
+    fn new_handler(
+        &self,
+        id: u64,
+        addr: String,
+    ) -> impl FnOnce(Connection) -> Handler + Send + 'static {
+        //  공유 데이터베이스로의 핸들을 가져온다. db는 내부적으로 'Arc'이므로 clone은 ref count
+        //  만을 증가시킨다. 핸들은 0번 데이터베이스를 가리키며, 'SELECT'로 변경된다.
+        let db = self.db.clone();
+
+        //  커넥션 상태는 커넥션 최대치를 제한하는 세마포어를 필요로 한다. 핸들러가 커넥션에 대한 처리를
+        //  마치면, permit은 설정을 통해 세마포어로 반환된다.
+        let config = self.config.clone();
+
+        // 셧다운 알림을 수신한다.
+        let shutdown = Shutdown::new(self.notify_shutdown.subscribe());
+
+        let requirepass = self.requirepass.clone();
+        let stats = self.stats.clone();
+        let metrics = self.metrics.clone();
+        let clients = self.clients.clone();
+        let monitor = self.monitor.clone();
+        let slowlog = self.slowlog.clone();
+        let persistence = self.persistence.clone();
+        let max_frame_size = self.max_frame_size;
+
+        //  모든 clone이 drop되면 수신자에게 이를 알린다.
+        let shutdown_complete = self.shutdown_complete_tx.clone();
+
+        move |mut connection| {
+            //  연결된 클라이언트의 수는 핸들러가 drop될 때 다시 감소한다.
+            stats.client_connected();
+
+            //  클라이언트 목록에서는 핸들러가 drop될 때 삭제된다.
+            let killed = clients.register(id, addr.clone());
+
+            connection.set_max_frame_size(max_frame_size);
+
+            Handler {
+                db,
+                metrics,
+                id,
+                clients,
+                slowlog,
+                addr,
+                monitor,
+                connection,
+                config,
+                shutdown,
+                //  비밀번호가 설정되어 있다면 커넥션은 인증되지 않은 상태로 시작한다.
+                authenticated: requirepass.is_none(),
+                requirepass,
+                stats,
+                persistence,
+                transaction: None,
+                watched: WatchedKeys::default(),
+                quitting: false,
+                killed,
+                _shutdown_complete: shutdown_complete,
+            }
+        }
+    }
+
     /// 인바운드 커넥션을 수락한다.
     /// 
     /// 에러는 백오프 & 재시도로 핸들링한다. 지수 백오프 전략을 사용한다. 태스크는 첫 실패 후 1초를 
     /// 기다린다. 두 번째 실패에서는 2초 기다린다. 이어지는 실패에 대해서는 대기 시간을 2배씩 늘린다.
     /// 64초 대기 후인 6번째 시도에서 실패하면 이 함수는 에러를 반환한다. 
-    async fn accept(&mut self) -> crate::Result<TcpStream> {
+    /// 
+    /// 수락한 커넥션과 함께 클라이언트의 주소를 반환한다.
+    async fn accept(&mut self) -> crate::Result<(BoxStream, String)> {
         let mut backoff = 1;
 
         // 수락을 몇 번 시도한다.
@@ -264,7 +768,7 @@ impl Listener {
             //  수락 연산을 수행한다. 소켓을 성공적으로 수락하면 이 소켓을 반환한다.
             //  성공하지 못하면 에러를 저장한다.
             match self.listener.accept().await {
-                Ok((socket, _)) => return Ok(socket),
+                Ok(socket) => return Ok(socket),
                 Err(err) => {
                     if backoff > 64 {
                         // 너무 많이 실패했다. 에러를 반환한다.
@@ -282,59 +786,410 @@ impl Listener {
     }
 }
 
+impl SocketListener {
+    /// 인바운드 커넥션 하나를 수락한다. 에러는 그대로 반환하며, 재시도는 호출자의 몫이다.
+    /// 
+    /// 수락한 커넥션과 함께 클라이언트의 주소를 반환한다. 유닉스 도메인 소켓의 클라이언트는 보통 이름
+    /// 없는 소켓을 사용하므로, 레디스와 같이 리스너가 바인드된 경로에 ':0'을 붙여 주소로 사용한다.
+    async fn accept(&self) -> io::Result<(BoxStream, String)> {
+        match self {
+            SocketListener::Tcp(listener) => {
+                let (socket, addr) = listener.accept().await?;
+                Ok((Box::new(socket), addr.to_string()))
+            }
+            #[cfg(unix)]
+            SocketListener::Unix(listener) => {
+                let (socket, _) = listener.accept().await?;
+                let addr = listener.local_addr()?;
+                let path = addr.as_pathname().map(|path| path.display().to_string());
+
+                Ok((Box::new(socket), format!("{}:0", path.unwrap_or_default())))
+            }
+        }
+    }
+}
+
 impl Handler {
     ///  단일 커넥션을 핸들링한다.
     ///  
     ///  소켓으로부터 요청 프레임을 읽어 처리한다. 응답은 다시 소켓에 쓴다.
     ///  
-    ///  현재 파이프라이닝은 구현되어있지않다. 파이프라이닝은 각 커넥션이 프레임을 interleaving 없이도
-    ///  동시에 둘 이상의 요청을 처리할 수 있도록 하는 기능이다. 자세한 내용은 여기에 있다:
+    ///  파이프라이닝을 지원한다. 클라이언트가 응답을 기다리지 않고 여러 요청을 연달아 전송하면, 이미
+    ///  읽기 버퍼에 수신된 요청들을 모두 처리한 뒤 응답을 한 번에 소켓에 쓴다. 응답의 순서는 요청의
+    ///  순서와 같다. 자세한 내용은 여기에 있다:
     ///  https://redis.io/topics/pipelining
     ///  
     ///  셧다운 시그널을 수신하면 커넥션은 안전 상태에 도달할 때까지 처리를 지속한다. 안전 상태는 커넥션을
     ///  종료하는 시점이다.
+    #[instrument(skip(self))]
     async fn run(&mut self) -> crate::Result<()> {
         //  셧다운 시그널을 수신하지 전까지 계속해서 새 요청 프레임을 읽는다.
         while !self.shutdown.is_shutdown() {
+            //  유휴 시간은 프레임을 읽을 때마다 새로 잰다. 'CONFIG SET timeout'은 다음 읽기부터
+            //  적용된다.
+            let idle_timeout = self.config.timeout();
+            let connection = &mut self.connection;
+            let read_frame = async move {
+                match idle_timeout {
+                    Some(idle_timeout) => time::timeout(idle_timeout, connection.read_frame()).await,
+                    None => Ok(connection.read_frame().await),
+                }
+            };
+
             let maybe_frame = tokio::select! {
-                res = self.connection.read_frame() => res?,
+                res = read_frame => match res {
+                    Ok(res) => res,
+                    //  유휴 시간 동안 요청이 없었다. 핸들러가 drop되며 permit이 반환된다.
+                    Err(_) => {
+                        debug!("closing idle connection");
+                        return Ok(());
+                    }
+                },
                 _ = self.shutdown.recv() => {
                     //  셧다운 시그널을 수신하면 'run'함수를 종료한다.
                     //  이는 태스크를 종료하는 결과가 된다.
                     return Ok(());
                 }
+                _ = self.killed.notified() => {
+                    debug!("connection killed");
+                    return Ok(());
+                }
             };
 
             //  read_frame()에서 'None'을 반환하면 상대측은 소켓을 닫는다.
             //  더이상 처리할 내용은 없고, 태스크를 종료할 수 있다.
             let frame = match maybe_frame {
-                Some(frame) => frame,
-                None => return Ok(())
+                Ok(Some(frame)) => frame,
+                Ok(None) => return Ok(()),
+                Err(err) => {
+                    self.reject_frame(&err).await?;
+                    self.connection.flush().await?;
+                    return Err(err);
+                }
             };
 
-            //  레디스 프레임을 커맨드 struct로 변환한다. 프레임이 유효하지 않거나 
-            //  지원하지 않는 커맨드라면 에러를 반환한다.
-            let cmd = Command::from_frame(frame)?;
-
-            //  'cmd' 객체를 로깅한다. 이 문법은 'tracing' crate이 제공하는 축약된
-            //  형태이다. 이는 아래와 유사한 것으로 간주할 수 있다:
+            //  수신한 프레임과, 읽기 버퍼에 이미 수신되어 있는 프레임들을 모두 처리한다. 각 커맨드의
+            //  응답은 쓰기 버퍼에 쓰여진다.
             //  
-            //  ```
-            //  debug!(cmd = format!("{:?}", cmd));
-            //  ```
-            // 
-            // 'tracing'은 구조화된 로깅을 제공한다. 이 내용은 키-값 페어로 로깅된다.
-            debug!(?cmd);
+            //  구독 상태나 블로킹 커맨드처럼 오래 걸리는 커맨드를 수행하는 중에도 'CLIENT KILL'을
+            //  수신하면 커맨드를 중단하고 커넥션을 닫는다. 자신을 닫는 'CLIENT KILL'에는 응답한 뒤에
+            //  닫도록, 커맨드의 완료를 먼저 확인한다.
+            let killed = self.killed.clone();
+            let res = tokio::select! {
+                biased;
+                res = self.apply_pipeline(frame) => res,
+                _ = killed.notified() => {
+                    debug!("connection killed");
+                    return Ok(());
+                }
+            };
+
+            //  처리 결과와 관계 없이 지금까지의 응답을 소켓에 쓴다. 파이프라인 중간에서 에러가
+            //  발생하더라도, 에러 이전의 요청에 대한 응답은 클라이언트에게 전달된다.
+            self.connection.flush().await?;
+
+            //  에러가 발생했다면 커넥션을 종료한다.
+            res?;
+
+            //  'QUIT'에 응답했다면 커넥션을 닫는다. 핸들러가 drop되며 permit이 반환된다.
+            if self.quitting {
+                debug!("closing connection on quit");
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+
+    ///  'frame'을 처리한 뒤, 읽기 버퍼에 남아있는 완전한 프레임들을 차례로 처리한다.
+    ///  
+    ///  읽기 버퍼에 완전한 프레임이 더이상 없거나 'QUIT'을 수신하면 반환한다. 'QUIT' 뒤에 이어지는
+    ///  프레임은 처리하지 않는다. 응답은 flush하지 않는다.
+    async fn apply_pipeline(&mut self, mut frame: Frame) -> crate::Result<()> {
+        loop {
+            self.apply_frame(frame).await?;
+
+            if self.quitting {
+                return Ok(());
+            }
+
+            //  읽기 버퍼에 이미 수신된 다음 프레임을 가져온다. 소켓으로부터 읽기를 기다리지 않는다.
+            //  잘못된 프레임은 지금과 같이 에러를 반환하여 커넥션을 종료한다.
+            frame = match self.connection.read_buffered_frame() {
+                Ok(Some(frame)) => frame,
+                Ok(None) => return Ok(()),
+                Err(err) => {
+                    self.reject_frame(&err).await?;
+                    return Err(err);
+                }
+            };
+        }
+    }
+
+    ///  프레임을 읽는 중 발생한 에러가 레디스 에러 코드를 담고 있다면 클라이언트에게 응답한다.
+    ///  
+    ///  최대 크기를 넘는 프레임처럼 커넥션을 종료하기 전에 그 이유를 클라이언트에게 알려야 하는 에러나,
+    ///  유효하지 않은 인자처럼 커넥션을 유지한 채 응답하는 에러가 해당된다. 응답은 flush하지 않는다.
+    async fn reject_frame(&mut self, err: &MiniRedisError) -> crate::Result<()> {
+        if let MiniRedisError::Server(msg) = err {
+            let response = Frame::Error(msg.clone());
+
+            debug!(?response);
+            self.connection.write_frame_buffered(&response).await?;
+        }
+
+        Ok(())
+    }
+
+    ///  수신한 프레임 하나를 커맨드로 변환하여 처리한다.
+    ///  
+    ///  트랜잭션 중이라면 커맨드를 수행하지 않고 큐에 쌓는다.
+    async fn apply_frame(&mut self, frame: Frame) -> crate::Result<()> {
+        //  레디스와 같이 빈 인라인 커맨드(빈 라인)는 응답 없이 무시한다.
+        if matches!(&frame, Frame::Array(parts) if parts.is_empty()) {
+            return Ok(());
+        }
+
+        //  'MONITOR' 상태의 커넥션이 있다면 수신한 커맨드를 발행한다. 프레임은 아래에서 소비되므로
+        //  파싱하기 전에 발행한다.
+        if self.monitor.receiver_count() > 0 {
+            let line = monitor_line(&frame, self.db.index(), &self.addr);
+            let _ = self.monitor.send(line);
+        }
+
+        //  처리 시간이 기준을 넘으면 슬로우 로그에 기록할 수 있도록 아규먼트를 남겨둔다.
+        let slowlog_args = self
+            .config
+            .slowlog_log_slower_than()
+            .map(|threshold| (threshold, slowlog::args_of(&frame)));
+
+        //  레디스 프레임을 커맨드 struct로 변환한다. 프레임이 유효하지 않거나 
+        //  지원하지 않는 커맨드라면 에러를 반환한다.
+        let cmd = match Command::from_frame(frame) {
+            Ok(cmd) => cmd,
+            //  트랜잭션 중이라면 커넥션을 종료하는 대신 에러를 응답하고 트랜잭션을 중단시킨다.
+            Err(err) => match &mut self.transaction {
+                Some(transaction) => return transaction.abort(err, &mut self.connection).await,
+                //  'SETEX'의 만료 시간처럼 인자의 값이 유효하지 않은 경우는 에러를 응답하고 커넥션을
+                //  유지한다. 그 외의 프로토콜 에러는 커넥션을 종료한다.
+                None if matches!(err, MiniRedisError::Server(_)) => {
+                    return self.reject_frame(&err).await;
+                }
+                None => return Err(err),
+            },
+        };
+
+        //  'cmd' 객체를 로깅한다. 이 문법은 'tracing' crate이 제공하는 축약된
+        //  형태이다. 이는 아래와 유사한 것으로 간주할 수 있다:
+        //  
+        //  ```
+        //  debug!(cmd = format!("{:?}", cmd));
+        //  ```
+        // 
+        // 'tracing'은 구조화된 로깅을 제공한다. 이 내용은 키-값 페어로 로깅된다.
+        debug!(?cmd);
+
+        self.stats.command_processed();
+        self.metrics.record(cmd.get_name());
+        self.clients.command_received(self.id, cmd.get_name(), self.db.index());
+
+        //  구독과 'MONITOR'는 커넥션의 상태를 바꾸고, 블로킹 커맨드는 클라이언트가 정한 시간만큼
+        //  기다린다. 이들의 처리 시간은 서버가 느린 것과 무관하므로 기록하지 않는다.
+        let slowlog_args = slowlog_args.filter(|_| {
+            !matches!(
+                cmd,
+                Command::Subscribe(_)
+                    | Command::PSubscribe(_)
+                    | Command::SSubscribe(_)
+                    | Command::Monitor(_)
+                    | Command::BPop(_)
+            )
+        });
+        let start = Instant::now();
+
+        let res = match cmd {
+            //  트랜잭션은 커넥션별 상태이므로 'MULTI', 'EXEC', 'DISCARD'는 여기서 직접 수행한다.
+            //  인증되지 않은 커넥션은 아래의 'apply_command'에서 거부된다.
+            Command::Multi(cmd) if self.authenticated => {
+                cmd.apply(&mut self.transaction, &mut self.connection).await
+            }
+            Command::Discard(cmd) if self.authenticated => {
+                cmd.apply(&mut self.transaction, &mut self.watched, &mut self.connection).await
+            }
+            Command::Exec(cmd) if self.authenticated => {
+                let transaction = self.transaction.take();
+                let watched = &mut self.watched;
+
+                //  'Exec'는 응답 배열의 헤더만을 쓴다. 각 커맨드의 응답이 배열의 앤트리가 된다.
+                for cmd in cmd.apply(transaction, watched, &mut self.connection).await? {
+                    self.apply_command(cmd).await?;
+                }
+
+                Ok(())
+            }
+            //  'RESET'은 인증 여부와 관계 없이, 트랜잭션 중에도 큐에 쌓지 않고 바로 수행한다.
+            Command::Reset(cmd) => self.reset(cmd).await,
+            //  'QUIT'도 마찬가지로 바로 응답하고, 응답을 쓴 뒤 커넥션을 닫는다.
+            Command::Quit(cmd) => {
+                self.quitting = true;
+                cmd.apply(&mut self.connection).await
+            }
+            cmd => match &mut self.transaction {
+                Some(transaction) => transaction.queue(cmd, &mut self.connection).await,
+                None => self.apply_command(cmd).await,
+            },
+        };
+
+        if let Some((threshold, args)) = slowlog_args {
+            let duration = start.elapsed();
 
+            if duration >= threshold {
+                let name = self.clients.name(self.id).unwrap_or_default();
+                let max_len = self.config.slowlog_max_len();
+                self.slowlog.push(args, duration, self.addr.clone(), name, max_len);
+            }
+        }
+
+        res
+    }
+
+    ///  커맨드 하나를 수행한다. 응답은 쓰기 버퍼에 쓰여진다.
+    async fn apply_command(&mut self, cmd: Command) -> crate::Result<()> {
+        //  'cmd'는 아래에서 소비되므로 쓰기 커맨드 여부를 미리 확인해둔다.
+        let is_write = cmd.is_write();
+        let may_grow = cmd.may_grow();
+
+        match cmd {
+            //  'AUTH'는 커넥션의 인증 상태를 변경하므로 여기서 직접 수행한다.
+            Command::Auth(cmd) => {
+                if cmd.apply(self.requirepass.as_deref(), &mut self.connection).await? {
+                    self.authenticated = true;
+                }
+            }
+            //  인증되지 않은 커넥션은 'HELLO' 외의 커맨드를 수행할 수 없다. 커넥션은 유지하여
+            //  클라이언트가 'AUTH'를 보낼 수 있도록 한다.
+            Command::Hello(_) => {
+                cmd.apply(&mut self.db, &mut self.connection, &mut self.shutdown).await?
+            }
+            _ if !self.authenticated => {
+                let response = Frame::Error("NOAUTH Authentication required.".to_string());
+                self.connection.write_frame_buffered(&response).await?;
+            }
+            //  메모리를 확보하지 못했다면 메모리 사용량을 늘릴 수 있는 커맨드는 수행하지 않는다.
+            _ if may_grow && !self.reclaim_memory() => {
+                let response = Frame::Error(
+                    "OOM command not allowed when used memory > 'maxmemory'.".to_string(),
+                );
+                self.connection.write_frame_buffered(&response).await?;
+            }
+            //  'SAVE'와 'BGSAVE'는 서버에 설정된 스냅샷 파일을 사용하므로 여기서 직접 수행한다.
+            Command::Save(cmd) => {
+                cmd.apply(&self.db, self.persistence.as_ref(), &mut self.connection).await?
+            }
+            Command::BgSave(cmd) => {
+                cmd.apply(&self.db, self.persistence.as_ref(), &mut self.connection).await?
+            }
+            //  감시하는 키는 커넥션별 상태이므로 'WATCH'와 'UNWATCH'는 여기서 직접 수행한다.
+            Command::Watch(cmd) => {
+                cmd.apply(&self.db, &mut self.watched, &mut self.connection).await?
+            }
+            Command::Unwatch(cmd) => cmd.apply(&mut self.watched, &mut self.connection).await?,
+            //  구독 상태에서 'RESET'이나 'QUIT'을 수신하면 구독 루프가 이를 반환한다. 나머지 상태를
+            //  여기서 다룬다.
+            Command::Subscribe(cmd) => {
+                let leave = cmd.apply(&self.db, &mut self.connection, &mut self.shutdown).await?;
+                self.leave_subscriber(leave).await?;
+            }
+            Command::PSubscribe(cmd) => {
+                let leave = cmd.apply(&self.db, &mut self.connection, &mut self.shutdown).await?;
+                self.leave_subscriber(leave).await?;
+            }
+            Command::SSubscribe(cmd) => {
+                let leave = cmd.apply(&self.db, &mut self.connection, &mut self.shutdown).await?;
+                self.leave_subscriber(leave).await?;
+            }
+            //  'MONITOR'는 구독 상태와 마찬가지로 'RESET'이나 'QUIT'을 수신하면 반환한다.
+            Command::Monitor(cmd) => {
+                let shutdown = &mut self.shutdown;
+                let leave = cmd.apply(&self.monitor, &mut self.connection, shutdown).await?;
+                self.leave_subscriber(leave).await?;
+            }
+            //  'CLIENT'는 모든 커넥션이 공유하는 클라이언트 목록을 다루므로 여기서 직접 수행한다.
+            Command::ClientCommand(cmd) => {
+                cmd.apply(&self.clients, self.id, &mut self.connection).await?
+            }
+            //  'SLOWLOG'는 모든 커넥션이 공유하는 슬로우 로그를 다루므로 여기서 직접 수행한다.
+            Command::SlowLog(cmd) => cmd.apply(&self.slowlog, &mut self.connection).await?,
+            //  'CONFIG'는 모든 커넥션이 공유하는 서버 설정을 다루므로 여기서 직접 수행한다.
+            Command::Config(cmd) => cmd.apply(&self.config, &mut self.connection).await?,
+            //  'INFO'는 서버 통계를 응답하므로 여기서 직접 수행한다.
+            Command::Info(cmd) => {
+                let persistence = self.persistence.as_deref();
+                cmd.apply(&self.db, &self.stats, &self.metrics, persistence, &mut self.connection)
+                    .await?
+            }
             //  커맨드 수행에 필요한 작업을 수행한다. 이는 데이터베이스 상태를 변경할 수 있다.
             //  
             //  커넥션을 apply 함수에 전달하여 커맨드가 그 응답 프레임을 커넥션에 직접 쓸 수 있도록
             //  한다. pub/sub의 경우, 다수의 프레임을 상대측으로 전송할 수 있다.
-            cmd.apply(&self.db, &mut self.connection, &mut self.shutdown).await?;
+            _ => cmd.apply(&mut self.db, &mut self.connection, &mut self.shutdown).await?,
+        }
+
+        //  인증된 커넥션이 수행한 쓰기 커맨드는 마지막 스냅샷 이후의 변경으로 기록한다.
+        if is_write && self.authenticated {
+            self.db.record_change();
         }
 
         Ok(())
     }
+
+    ///  구독 루프나 모니터 루프를 마치게 한 커맨드를 마저 수행한다.
+    async fn leave_subscriber(&mut self, leave: Option<Leave>) -> crate::Result<()> {
+        match leave {
+            Some(Leave::Reset(reset)) => self.reset(reset).await,
+            //  구독 루프가 이미 'OK'를 응답했다. 커넥션만 닫는다.
+            Some(Leave::Quit) => {
+                self.quitting = true;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    ///  커넥션별 상태를 커넥션이 수립되었을 때로 되돌리고 'RESET'에 응답한다.
+    ///  
+    ///  트랜잭션과 감시하는 키를 버리고, 인증 상태와 선택한 데이터베이스, 프로토콜 버전을 초기화한다.
+    ///  구독 상태는 'RESET'을 수신한 구독 루프가 반환하며 이미 종료되었다.
+    async fn reset(&mut self, cmd: Reset) -> crate::Result<()> {
+        self.transaction = None;
+        self.watched.clear();
+        self.authenticated = self.requirepass.is_none();
+        self.connection.set_version(ProtocolVersion::Resp2);
+
+        if let Some(db) = self.db.select(0) {
+            self.db = db;
+        }
+
+        cmd.apply(&mut self.connection).await
+    }
+
+    ///  메모리 사용량이 'maxmemory'를 넘었다면 정책에 따라 메모리를 확보한다.
+    ///  
+    ///  레디스와 같이 커맨드를 수행하기 전의 메모리 사용량으로 판단하므로, 커맨드를 수행한 뒤에는
+    ///  메모리 사용량이 잠시 'maxmemory'를 넘을 수 있다. 메모리 사용량이 'maxmemory' 이하라면 'true'를
+    ///  반환한다.
+    fn reclaim_memory(&self) -> bool {
+        let (maxmemory, policy) = match self.config.maxmemory() {
+            Some(maxmemory) => maxmemory,
+            None => return true,
+        };
+        let maxmemory = usize::try_from(maxmemory).unwrap_or(usize::MAX);
+
+        match policy {
+            MaxMemoryPolicy::NoEviction => self.db.used_memory() <= maxmemory,
+            MaxMemoryPolicy::AllKeysLru => self.db.evict(maxmemory),
+        }
+    }
 }
 
 impl Drop for Handler {
@@ -346,6 +1201,11 @@ impl Drop for Handler {
         //  이 작업을 'Drop' 구현에 두어, 만일 태스크를 핸들링하는 커넥션이 panic된 상황이라도
         //   permit 반환을 보장한다. 만약 'add_permit'을 'run'함수의 끝에서 호출할 경우, 어떤
         //  버그는 panic을 유발하고, permit은 세마포어로 반환되지 못한다.
-        self.limit_connections.add_permits(1);
+         
+        //  'CONFIG SET maxclients'로 최대 커넥션 수를 줄였다면 permit은 반환되지 않고 회수된다.
+        self.config.release_connection();
+
+        self.stats.client_disconnected();
+        self.clients.unregister(self.id);
     }
 }
\ No newline at end of file
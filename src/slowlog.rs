@@ -0,0 +1,152 @@
+use crate::Frame;
+
+use bytes::Bytes;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 기록하는 아규먼트의 최대 개수. 레디스와 같다. 넘치는 아규먼트는 마지막 아규먼트 자리에 그 수만
+/// 기록한다.
+const MAX_ARGS: usize = 32;
+
+/// 기록하는 아규먼트 하나의 최대 길이(바이트). 레디스와 같다. 넘치는 바이트는 잘라내고 그 수만
+/// 기록한다.
+const MAX_ARG_LEN: usize = 128;
+
+/// 처리에 오래 걸린 커맨드의 기록. 'SLOWLOG' 커맨드가 사용한다.
+///
+/// 리스너와 모든 커넥션 핸들러가 'Arc'로 공유한다. 핸들러는 커맨드를 처리하는 데 걸린 시간이
+/// 'slowlog-log-slower-than' 이상이면 커맨드를 기록한다. 기록은 최근의 것부터 'slowlog-max-len'개까지
+/// 유지된다.
+#[derive(Debug, Default)]
+pub(crate) struct SlowLog {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// 다음 기록에 부여할 식별자. 'SLOWLOG RESET'으로 기록을 지워도 초기화되지 않는다.
+    next_id: u64,
+
+    /// 기록의 목록. 최근의 기록이 앞에 위치한다.
+    entries: VecDeque<Entry>,
+}
+
+/// 느린 커맨드 하나의 기록
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    /// 기록마다 1씩 증가하는 식별자
+    pub(crate) id: u64,
+
+    /// 커맨드를 기록한 시각(유닉스 시간, 초)
+    pub(crate) timestamp: u64,
+
+    /// 커맨드를 처리하는 데 걸린 시간
+    pub(crate) duration: Duration,
+
+    /// 커맨드 이름을 포함한 아규먼트. 'MAX_ARGS', 'MAX_ARG_LEN'에 따라 잘려 있다.
+    pub(crate) args: Vec<Bytes>,
+
+    /// 커맨드를 전송한 클라이언트의 주소
+    pub(crate) addr: String,
+
+    /// 커맨드를 전송한 클라이언트의 이름. 이름이 없다면 빈 문자열이다.
+    pub(crate) name: String,
+}
+
+impl SlowLog {
+    /// 빈 슬로우 로그를 생성한다.
+    pub(crate) fn new() -> SlowLog {
+        SlowLog::default()
+    }
+
+    /// 커맨드를 기록한다. 기록이 'max_len'개를 넘으면 가장 오래된 기록부터 버린다.
+    ///
+    /// 'args'는 'args_of'로 만든 아규먼트이다.
+    pub(crate) fn push(
+        &self,
+        args: Vec<Bytes>,
+        duration: Duration,
+        addr: String,
+        name: String,
+        max_len: usize,
+    ) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut state = self.state.lock().unwrap();
+
+        let id = state.next_id;
+        state.next_id += 1;
+
+        state.entries.push_front(Entry {
+            id,
+            timestamp,
+            duration,
+            args,
+            addr,
+            name,
+        });
+        state.entries.truncate(max_len);
+    }
+
+    /// 최근의 기록부터 최대 'count'개를 반환한다. 'None'은 모든 기록을 반환한다.
+    pub(crate) fn get(&self, count: Option<usize>) -> Vec<Entry> {
+        let state = self.state.lock().unwrap();
+        let count = count.unwrap_or(state.entries.len());
+
+        state.entries.iter().take(count).cloned().collect()
+    }
+
+    /// 기록의 수를 반환한다.
+    pub(crate) fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// 모든 기록을 지운다.
+    pub(crate) fn reset(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+}
+
+/// 수신한 프레임으로부터 슬로우 로그에 기록할 아규먼트를 만든다.
+///
+/// 레디스와 같이 'MAX_ARGS'개를 넘는 아규먼트는 '... (N more arguments)'로, 'MAX_ARG_LEN'을 넘는
+/// 아규먼트는 '... (N more bytes)'를 붙여 줄인다. 커맨드를 처리하기 전에 호출하며, 'Bytes'의 clone은
+/// 데이터를 복사하지 않는다.
+pub(crate) fn args_of(frame: &Frame) -> Vec<Bytes> {
+    let parts = match frame {
+        Frame::Array(parts) => &parts[..],
+        frame => std::slice::from_ref(frame),
+    };
+
+    let mut args = Vec::with_capacity(parts.len().min(MAX_ARGS));
+
+    for (i, part) in parts.iter().enumerate() {
+        if i == MAX_ARGS - 1 && parts.len() > MAX_ARGS {
+            let more = format!("... ({} more arguments)", parts.len() - i);
+            args.push(Bytes::from(more.into_bytes()));
+            break;
+        }
+
+        let arg = match part {
+            Frame::Bulk(bytes) => bytes.clone(),
+            Frame::Simple(s) => Bytes::from(s.clone().into_bytes()),
+            Frame::Integer(n) => Bytes::from(n.to_string().into_bytes()),
+            _ => Bytes::new(),
+        };
+
+        if arg.len() > MAX_ARG_LEN {
+            let mut truncated = arg[..MAX_ARG_LEN].to_vec();
+            let more = format!("... ({} more bytes)", arg.len() - MAX_ARG_LEN);
+            truncated.extend_from_slice(more.as_bytes());
+            args.push(Bytes::from(truncated));
+        } else {
+            args.push(arg);
+        }
+    }
+
+    args
+}
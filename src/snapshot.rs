@@ -0,0 +1,422 @@
+//! 키 공간 스냅샷의 저장과 복원
+//!
+//! 'SAVE'와 'BGSAVE'는 모든 데이터베이스의 키, 값, 남은 만료 시간을 하나의 파일에 저장하고, 서버는
+//! 가동 시 이 파일이 있다면 키 공간을 복원한다. 파일의 형식은 다음과 같다. 모든 정수는 빅 엔디언이다.
+//!
+//! ```text
+//! "MINIREDIS" version:u8
+//! ( 0xFE db:u32 ( type:u8 key ttl value )* )*
+//! 0xFF
+//! ```
+//!
+//! 'key'와 문자열은 길이('u32')와 바이트열로 쓴다. 'ttl'은 만료 시간이 있다면 1과 남은 밀리초('u64')를,
+//! 없다면 0을 쓴다. 'value'는 'type'에 따라 문자열 하나, 혹은 원소의 수('u32')와 원소들로 이루어진다.
+//! 정렬된 셋의 원소는 멤버와 점수('f64'의 비트 표현, 'u64')이다.
+
+use crate::db::{Db, Value};
+use crate::zset::SortedSet;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::fs;
+use tracing::{error, info};
+
+/// 스냅샷 파일의 시작을 나타내는 매직 바이트
+const MAGIC: &[u8] = b"MINIREDIS";
+
+/// 스냅샷 형식의 버전. 형식이 바뀌면 증가시킨다.
+const VERSION: u8 = 1;
+
+/// 이어지는 항목들이 속한 데이터베이스의 번호를 지정한다.
+const OP_SELECT: u8 = 0xFE;
+
+/// 스냅샷의 끝
+const OP_EOF: u8 = 0xFF;
+
+/// 값의 타입. 항목은 이 값으로 시작한다.
+const TYPE_STRING: u8 = 0;
+const TYPE_LIST: u8 = 1;
+const TYPE_HASH: u8 = 2;
+const TYPE_SET: u8 = 3;
+const TYPE_ZSET: u8 = 4;
+
+/// 스냅샷 파일의 경로와 저장 상태
+///
+/// 서버에 스냅샷 파일이 설정되면 리스너와 모든 핸들러가 'Arc'로 공유한다. 한 번에 하나의 저장만
+/// 수행되도록 하여, 동시에 요청된 'SAVE'와 'BGSAVE'가 같은 파일을 쓰지 않도록 한다.
+#[derive(Debug)]
+pub(crate) struct Persistence {
+    /// 스냅샷 파일의 경로
+    path: PathBuf,
+
+    /// 저장이 진행 중이라면 'true'
+    saving: AtomicBool,
+}
+
+/// 진행 중인 저장. drop되면 'Persistence'의 저장 상태를 해제한다.
+///
+/// 저장하는 태스크가 패닉하더라도 상태가 해제되도록 'Drop'으로 구현한다.
+struct Saving {
+    persistence: Arc<Persistence>,
+}
+
+impl Persistence {
+    /// 'path'에 스냅샷을 저장하는 새로운 'Persistence'를 생성한다.
+    pub(crate) fn new(path: PathBuf) -> Persistence {
+        Persistence {
+            path,
+            saving: AtomicBool::new(false),
+        }
+    }
+
+    /// 스냅샷 파일의 경로를 반환한다.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 저장이 진행 중이라면 'true'를 반환한다.
+    pub(crate) fn is_saving(&self) -> bool {
+        self.saving.load(Ordering::SeqCst)
+    }
+
+    /// 'db'가 속한 모든 데이터베이스의 스냅샷을 저장하고, 저장이 끝나면 반환한다.
+    ///
+    /// 다른 저장이 진행 중이라면 에러를 반환한다.
+    pub(crate) async fn save(self: &Arc<Self>, db: &Db) -> crate::Result<()> {
+        let _saving = self.begin()?;
+        save(db, &self.path).await
+    }
+
+    /// 백그라운드 태스크에서 스냅샷을 저장한다. 저장을 시작하면 바로 반환한다.
+    ///
+    /// 다른 저장이 진행 중이라면 저장을 시작하지 않고 에러를 반환한다. 백그라운드 저장의 실패는
+    /// 로깅된다.
+    pub(crate) fn bgsave(self: &Arc<Self>, db: Db) -> crate::Result<()> {
+        let saving = self.begin()?;
+
+        tokio::spawn(async move {
+            match save(&db, &saving.persistence.path).await {
+                Ok(()) => info!("background saving terminated with success"),
+                Err(err) => error!(cause = %err, "background saving failed"),
+            }
+        });
+
+        Ok(())
+    }
+
+    /// 저장 상태를 설정한다. 이미 저장이 진행 중이라면 에러를 반환한다.
+    fn begin(self: &Arc<Self>) -> crate::Result<Saving> {
+        self.saving
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map_err(|_| "ERR Background save already in progress")?;
+
+        Ok(Saving {
+            persistence: self.clone(),
+        })
+    }
+}
+
+impl Drop for Saving {
+    fn drop(&mut self) {
+        self.persistence.saving.store(false, Ordering::SeqCst);
+    }
+}
+
+/// 'db'가 속한 모든 데이터베이스의 스냅샷을 'path'에 저장한다.
+///
+/// 스냅샷은 임시 파일에 쓴 뒤 'path'로 이름을 바꾼다. 저장 중에 실패하더라도 기존의 스냅샷 파일은
+/// 손상되지 않는다. 저장에 성공하면 스냅샷을 만들기 전까지의 변경을 저장된 것으로 기록한다.
+async fn save(db: &Db, path: &Path) -> crate::Result<()> {
+    let changes = db.changes_since_save();
+    let data = encode(db);
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+
+    fs::write(&tmp, &data).await?;
+    fs::rename(&tmp, path).await?;
+
+    db.record_save(changes);
+
+    Ok(())
+}
+
+/// 'path'의 스냅샷을 'db'가 속한 데이터베이스들에 복원한다.
+///
+/// 파일이 존재하지 않으면 아무것도 하지 않고 'false'를 반환한다. 파일의 형식이 잘못되었거나, 스냅샷의
+/// 데이터베이스 번호가 설정된 데이터베이스 수를 벗어나면 에러를 반환한다.
+pub(crate) async fn load(db: &Db, path: &Path) -> crate::Result<bool> {
+    let data = match fs::read(path).await {
+        Ok(data) => data,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err.into()),
+    };
+
+    decode(db, &data)?;
+    Ok(true)
+}
+
+/// 모든 데이터베이스의 키 공간을 스냅샷 형식으로 인코딩한다.
+fn encode(db: &Db) -> Bytes {
+    let mut buf = BytesMut::new();
+
+    buf.put_slice(MAGIC);
+    buf.put_u8(VERSION);
+
+    // 'select'는 존재하지 않는 번호에 대해 'None'을 반환한다.
+    let databases = (0..).map_while(|index| db.select(index));
+
+    for (index, db) in databases.enumerate() {
+        let entries = db.export();
+
+        // 빈 데이터베이스는 쓰지 않는다.
+        if entries.is_empty() {
+            continue;
+        }
+
+        buf.put_u8(OP_SELECT);
+        buf.put_u32(index as u32);
+
+        for (key, value, ttl) in entries {
+            let kind = match &value {
+                Value::String(_) => TYPE_STRING,
+                Value::List(_) => TYPE_LIST,
+                Value::Hash(_) => TYPE_HASH,
+                Value::Set(_) => TYPE_SET,
+                Value::SortedSet(_) => TYPE_ZSET,
+            };
+            buf.put_u8(kind);
+            put_blob(&mut buf, key.as_bytes());
+
+            match ttl {
+                Some(ttl) => {
+                    buf.put_u8(1);
+                    buf.put_u64(ttl.as_millis() as u64);
+                }
+                None => buf.put_u8(0),
+            }
+
+            match value {
+                Value::String(data) => put_blob(&mut buf, &data),
+                Value::List(list) => {
+                    buf.put_u32(list.len() as u32);
+                    for item in &list {
+                        put_blob(&mut buf, item);
+                    }
+                }
+                Value::Hash(hash) => {
+                    buf.put_u32(hash.len() as u32);
+                    for (field, value) in &hash {
+                        put_blob(&mut buf, field.as_bytes());
+                        put_blob(&mut buf, value);
+                    }
+                }
+                Value::Set(set) => {
+                    buf.put_u32(set.len() as u32);
+                    for member in &set {
+                        put_blob(&mut buf, member);
+                    }
+                }
+                Value::SortedSet(zset) => {
+                    buf.put_u32(zset.len() as u32);
+                    for (member, score) in zset.iter() {
+                        put_blob(&mut buf, member);
+                        buf.put_u64(score.to_bits());
+                    }
+                }
+            }
+        }
+    }
+
+    buf.put_u8(OP_EOF);
+    buf.freeze()
+}
+
+/// 스냅샷을 디코딩하여 각 데이터베이스에 저장한다.
+///
+/// 데이터베이스 하나의 항목을 모두 읽은 뒤에 저장하므로, 형식이 잘못된 스냅샷은 앞쪽의 데이터베이스만
+/// 복원될 수 있다. 서버 가동 시에만 호출되므로, 이 경우 서버는 가동에 실패한다.
+fn decode(db: &Db, mut src: &[u8]) -> crate::Result<()> {
+    if src.len() < MAGIC.len() + 1 || &src[..MAGIC.len()] != MAGIC {
+        return Err("invalid snapshot file; bad header".into());
+    }
+    src.advance(MAGIC.len());
+
+    let version = src.get_u8();
+    if version != VERSION {
+        return Err(format!("invalid snapshot file; unsupported version {}", version).into());
+    }
+
+    // 현재 읽고 있는 데이터베이스와 그 항목들
+    let mut current: Option<(Db, Vec<_>)> = None;
+
+    loop {
+        let op = get_u8(&mut src)?;
+
+        let kind = match op {
+            OP_SELECT | OP_EOF => {
+                if let Some((db, entries)) = current.take() {
+                    db.import(entries);
+                }
+
+                if op == OP_EOF {
+                    return Ok(());
+                }
+
+                let index = get_u32(&mut src)? as usize;
+                let selected = db
+                    .select(index)
+                    .ok_or("invalid snapshot file; database index out of range")?;
+                current = Some((selected, vec![]));
+                continue;
+            }
+            kind => kind,
+        };
+
+        let entries = match &mut current {
+            Some((_, entries)) => entries,
+            None => return Err("invalid snapshot file; entry before database".into()),
+        };
+
+        let key = String::from_utf8(get_blob(&mut src)?.to_vec())?;
+
+        let ttl = match get_u8(&mut src)? {
+            0 => None,
+            _ => Some(Duration::from_millis(get_u64(&mut src)?)),
+        };
+
+        let value = match kind {
+            TYPE_STRING => Value::String(get_blob(&mut src)?),
+            TYPE_LIST => {
+                let len = get_u32(&mut src)?;
+                let mut list = VecDeque::new();
+                for _ in 0..len {
+                    list.push_back(get_blob(&mut src)?);
+                }
+                Value::List(list)
+            }
+            TYPE_HASH => {
+                let len = get_u32(&mut src)?;
+                let mut hash = HashMap::new();
+                for _ in 0..len {
+                    let field = String::from_utf8(get_blob(&mut src)?.to_vec())?;
+                    hash.insert(field, get_blob(&mut src)?);
+                }
+                Value::Hash(hash)
+            }
+            TYPE_SET => {
+                let len = get_u32(&mut src)?;
+                let mut set = HashSet::new();
+                for _ in 0..len {
+                    set.insert(get_blob(&mut src)?);
+                }
+                Value::Set(set)
+            }
+            TYPE_ZSET => {
+                let len = get_u32(&mut src)?;
+                let mut zset = SortedSet::new();
+                for _ in 0..len {
+                    let member = get_blob(&mut src)?;
+                    let score = f64::from_bits(get_u64(&mut src)?);
+                    if score.is_nan() {
+                        return Err("invalid snapshot file; score is not a number".into());
+                    }
+                    zset.insert(member, score);
+                }
+                Value::SortedSet(zset)
+            }
+            kind => return Err(format!("invalid snapshot file; unknown type {}", kind).into()),
+        };
+
+        entries.push((key, value, ttl));
+    }
+}
+
+/// 길이와 바이트열을 쓴다.
+fn put_blob(buf: &mut BytesMut, data: &[u8]) {
+    buf.put_u32(data.len() as u32);
+    buf.put_slice(data);
+}
+
+/// 'put_blob'으로 쓴 바이트열을 읽는다.
+fn get_blob(src: &mut &[u8]) -> crate::Result<Bytes> {
+    let len = get_u32(src)? as usize;
+
+    if src.remaining() < len {
+        return Err(truncated());
+    }
+
+    let data = Bytes::copy_from_slice(&src[..len]);
+    src.advance(len);
+    Ok(data)
+}
+
+fn get_u8(src: &mut &[u8]) -> crate::Result<u8> {
+    if !src.has_remaining() {
+        return Err(truncated());
+    }
+
+    Ok(src.get_u8())
+}
+
+fn get_u32(src: &mut &[u8]) -> crate::Result<u32> {
+    if src.remaining() < 4 {
+        return Err(truncated());
+    }
+
+    Ok(src.get_u32())
+}
+
+fn get_u64(src: &mut &[u8]) -> crate::Result<u64> {
+    if src.remaining() < 8 {
+        return Err(truncated());
+    }
+
+    Ok(src.get_u64())
+}
+
+/// 스냅샷이 중간에 끝났을 때의 에러
+fn truncated() -> crate::Error {
+    "invalid snapshot file; unexpected end of file".into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::db::Condition;
+
+    #[tokio::test]
+    async fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("mini-redis-{}.rdb", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let db = Db::new(1, false);
+        db.set_options("a".to_string(), Bytes::from("1"), None, Condition::Always);
+        let ttl = Some(Duration::from_secs(100));
+        db.set_options("t".to_string(), Bytes::from("2"), ttl, Condition::Always);
+        db.push("l", vec![Bytes::from("x"), Bytes::from("y")], false).unwrap();
+
+        let persistence = Arc::new(Persistence::new(path.clone()));
+        persistence.save(&db).await.unwrap();
+
+        let restored = Db::new(1, false);
+        assert!(load(&restored, &path).await.unwrap());
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(restored.get("a").unwrap(), Some(Bytes::from("1")));
+        assert_eq!(restored.get("t").unwrap(), Some(Bytes::from("2")));
+        assert_eq!(restored.lrange("l", 0, -1).unwrap(), vec!["x", "y"]);
+
+        assert_eq!(restored.ttl("a"), Some(None));
+        match restored.ttl("t") {
+            Some(Some(ttl)) => assert!(ttl > Duration::from_secs(98), "{:?}", ttl),
+            ttl => panic!("unexpected ttl: {:?}", ttl),
+        }
+    }
+}
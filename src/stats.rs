@@ -0,0 +1,76 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// 서버의 통계 정보. 'INFO' 커맨드가 응답한다.
+///
+/// 리스너와 모든 커넥션 핸들러가 'Arc'로 공유한다. 각 값은 독립적인 원자적 카운터이므로, 여러 값을
+/// 함께 읽더라도 어느 한 순간의 일관된 스냅샷이 아닐 수 있다.
+#[derive(Debug)]
+pub(crate) struct Stats {
+    /// 서버가 가동된 시각
+    started_at: Instant,
+
+    /// 현재 연결된 클라이언트의 수
+    ///
+    /// 리스너는 다음 커넥션을 수락하기 전에 세마포어 permit을 미리 획득하므로, 남은 permit의 수로는
+    /// 연결된 클라이언트의 수를 정확히 알 수 없다. 대신 핸들러의 생성과 drop 시점에 값을 갱신한다.
+    connected_clients: AtomicUsize,
+
+    /// 가동 이후 수락한 커넥션의 수
+    total_connections_received: AtomicU64,
+
+    /// 가동 이후 수행한 커맨드의 수
+    total_commands_processed: AtomicU64,
+}
+
+impl Stats {
+    /// 모든 카운터가 0인 새로운 'Stats'를 생성한다. 가동 시간은 이 시점부터 측정한다.
+    pub(crate) fn new() -> Stats {
+        Stats {
+            started_at: Instant::now(),
+            connected_clients: AtomicUsize::new(0),
+            total_connections_received: AtomicU64::new(0),
+            total_commands_processed: AtomicU64::new(0),
+        }
+    }
+
+    /// 커넥션 하나를 수락했음을 기록한다.
+    pub(crate) fn connection_received(&self) {
+        self.total_connections_received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 클라이언트 하나가 연결되었음을 기록한다. 핸들러가 생성될 때 호출한다.
+    pub(crate) fn client_connected(&self) {
+        self.connected_clients.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 클라이언트 하나의 연결이 끊어졌음을 기록한다. 핸들러가 drop될 때 호출한다.
+    pub(crate) fn client_disconnected(&self) {
+        self.connected_clients.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// 커맨드 하나를 수행했음을 기록한다.
+    pub(crate) fn command_processed(&self) {
+        self.total_commands_processed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 서버가 가동된 이후 경과한 시간을 반환한다.
+    pub(crate) fn uptime(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// 현재 연결된 클라이언트의 수를 반환한다.
+    pub(crate) fn connected_clients(&self) -> usize {
+        self.connected_clients.load(Ordering::Relaxed)
+    }
+
+    /// 가동 이후 수락한 커넥션의 수를 반환한다.
+    pub(crate) fn total_connections_received(&self) -> u64 {
+        self.total_connections_received.load(Ordering::Relaxed)
+    }
+
+    /// 가동 이후 수행한 커맨드의 수를 반환한다.
+    pub(crate) fn total_commands_processed(&self) -> u64 {
+        self.total_commands_processed.load(Ordering::Relaxed)
+    }
+}
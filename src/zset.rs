@@ -0,0 +1,133 @@
+//! 정렬된 셋.
+//!
+//! 멤버마다 실수 점수를 가지며, 멤버는 점수의 오름차순으로, 점수가 같다면 멤버의 바이트열의 사전순으로
+//! 정렬된다. 멤버로 점수를 찾는 맵과, '(점수, 멤버)'로 정렬된 트리를 함께 유지한다.
+
+use bytes::Bytes;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Bound;
+
+/// 정렬된 셋의 값. 'Value::SortedSet'에 저장된다.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SortedSet {
+    /// 멤버별 점수
+    scores: HashMap<Bytes, f64>,
+
+    /// '(점수, 멤버)'의 순서로 정렬된 멤버. 'scores'와 항상 같은 멤버를 갖는다.
+    ordered: BTreeSet<(Score, Bytes)>,
+}
+
+/// 'BTreeSet'의 키로 사용할 수 있도록 전순서를 부여한 점수
+///
+/// NaN은 저장하지 않으므로 'f64::total_cmp'의 순서는 일반적인 실수의 순서와 같다. '-0'과 '0'은 서로
+/// 다른 값으로 비교되므로, 저장하기 전에 '0'으로 바꾼다.
+#[derive(Debug, Clone, Copy)]
+struct Score(f64);
+
+impl SortedSet {
+    /// 빈 정렬된 셋을 생성한다.
+    pub(crate) fn new() -> SortedSet {
+        SortedSet::default()
+    }
+
+    /// 멤버의 수를 반환한다.
+    pub(crate) fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// 'member'를 'score' 점수로 저장한다. 새로운 멤버라면 'true'를 반환한다.
+    ///
+    /// 이미 존재하는 멤버라면 점수를 바꾸고, 바뀐 점수에 맞는 위치로 옮긴다. 'score'는 NaN이 아니어야
+    /// 한다.
+    pub(crate) fn insert(&mut self, member: Bytes, score: f64) -> bool {
+        debug_assert!(!score.is_nan());
+
+        // '-0'을 '0'으로 바꾼다.
+        let score = score + 0.0;
+
+        let prev = self.scores.insert(member.clone(), score);
+
+        if let Some(prev) = prev {
+            self.ordered.remove(&(Score(prev), member.clone()));
+        }
+
+        self.ordered.insert((Score(score), member));
+
+        prev.is_none()
+    }
+
+    /// 'member'의 점수를 반환한다. 멤버가 아니라면 'None'을 반환한다.
+    pub(crate) fn score(&self, member: &[u8]) -> Option<f64> {
+        self.scores.get(member).copied()
+    }
+
+    /// 정렬된 순서에서 'member'의 위치(0부터 시작)를 반환한다. 멤버가 아니라면 'None'을 반환한다.
+    ///
+    /// 트리는 위치를 기록하지 않으므로, 앞선 멤버들을 세는 비용은 위치에 비례한다.
+    pub(crate) fn rank(&self, member: &[u8]) -> Option<usize> {
+        let (member, &score) = self.scores.get_key_value(member)?;
+
+        Some(self.ordered.range(..(Score(score), member.clone())).count())
+    }
+
+    /// 정렬된 순서로 멤버와 점수를 순회한다.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&Bytes, f64)> + '_ {
+        self.ordered.iter().map(|(score, member)| (member, score.0))
+    }
+
+    /// 점수가 'min'과 'max' 사이인 멤버와 점수를 정렬된 순서로 순회한다.
+    ///
+    /// 트리에서 'min' 이상의 첫 멤버를 찾아 시작하므로, 범위 앞쪽의 멤버는 순회하지 않는다.
+    pub(crate) fn range_by_score(
+        &self,
+        min: Bound<f64>,
+        max: Bound<f64>,
+    ) -> impl Iterator<Item = (&Bytes, f64)> + '_ {
+        // 빈 바이트열은 같은 점수의 멤버 중 가장 앞에 정렬된다.
+        let start = match min {
+            Bound::Included(min) | Bound::Excluded(min) => {
+                Bound::Included((Score(min), Bytes::new()))
+            }
+            Bound::Unbounded => Bound::Unbounded,
+        };
+
+        self.ordered
+            .range((start, Bound::Unbounded))
+            .map(|(score, member)| (member, score.0))
+            .skip_while(move |&(_, score)| matches!(min, Bound::Excluded(min) if score <= min))
+            .take_while(move |&(_, score)| match max {
+                Bound::Included(max) => score <= max,
+                Bound::Excluded(max) => score < max,
+                Bound::Unbounded => true,
+            })
+    }
+
+    /// 값의 바이트 길이를 반환한다. 멤버의 길이와 점수의 크기를 합한다.
+    pub(crate) fn size(&self) -> usize {
+        self.scores
+            .keys()
+            .map(|member| member.len() + std::mem::size_of::<f64>())
+            .sum()
+    }
+}
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Score) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Score) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Score) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
@@ -0,0 +1,94 @@
+use mini_redis::{client, server};
+use std::io::{BufRead, BufReader};
+use std::net::SocketAddr;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// CLI 바이너리로 보낸 커맨드가 서버에 적용되고, 응답이 'redis-cli'와 같은 형식으로 출력된다.
+#[tokio::test]
+async fn cli_runs_commands_against_server() {
+    let addr = start_server().await;
+    let port = addr.port().to_string();
+
+    tokio::task::spawn_blocking(move || {
+        assert_eq!(run_cli(&port, &["set", "k", "v"]), "OK\n");
+        assert_eq!(run_cli(&port, &["get", "k"]), "\"v\"\n");
+        assert_eq!(run_cli(&port, &["exists", "k", "missing"]), "(integer) 1\n");
+        assert_eq!(run_cli(&port, &["incr", "n"]), "(integer) 1\n");
+        assert_eq!(run_cli(&port, &["expire", "k", "100"]), "(integer) 1\n");
+        assert_eq!(run_cli(&port, &["ttl", "k"]), "(integer) 100\n");
+        assert_eq!(run_cli(&port, &["del", "k", "n", "missing"]), "(integer) 2\n");
+    })
+    .await
+    .unwrap();
+}
+
+/// '--hex'와 '--base64'로 UTF-8이 아닌 값을 저장하고, '--hex'로 읽을 수 있다.
+#[tokio::test]
+async fn cli_decodes_binary_values() {
+    let addr = start_server().await;
+    let port = addr.port().to_string();
+
+    tokio::task::spawn_blocking(move || {
+        assert_eq!(run_cli(&port, &["set", "--hex", "a", "00ff41"]), "OK\n");
+        assert_eq!(run_cli(&port, &["get", "--hex", "a"]), "00ff41\n");
+
+        assert_eq!(run_cli(&port, &["set", "--base64", "b", "AP9B"]), "OK\n");
+        assert_eq!(run_cli(&port, &["get", "--hex", "b"]), "00ff41\n");
+    })
+    .await
+    .unwrap();
+}
+
+/// 'subscribe'로 실행한 CLI는 다른 클라이언트가 발행한 메시지를 '<channel>: <content>'로 출력한다.
+#[tokio::test]
+async fn cli_subscribe_prints_messages() {
+    let addr = start_server().await;
+    let port = addr.port().to_string();
+
+    let mut subscriber = Command::new(env!("CARGO_BIN_EXE_mini-redis-cli"))
+        .args(["--port", &port, "subscribe", "ch"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // CLI가 구독을 마칠 때까지 발행을 반복한다.
+    let mut client = client::connect(addr).await.unwrap();
+    while client.publish("ch", "hello".into()).await.unwrap() == 0 {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    let stdout = subscriber.stdout.take().unwrap();
+    let line = tokio::task::spawn_blocking(move || {
+        let mut line = String::new();
+        BufReader::new(stdout).read_line(&mut line).unwrap();
+        line
+    });
+    let line = tokio::time::timeout(Duration::from_secs(2), line).await.unwrap().unwrap();
+    assert_eq!(line, "ch: hello\n");
+
+    subscriber.kill().unwrap();
+    subscriber.wait().unwrap();
+}
+
+/// 'port'의 서버에 'args'로 CLI 바이너리를 실행하고, 표준 출력을 반환한다. 실패하면 패닉한다.
+fn run_cli(port: &str, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_mini-redis-cli"))
+        .args(["--port", port])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+async fn start_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move { server::run(listener, tokio::signal::ctrl_c()).await });
+
+    addr
+}
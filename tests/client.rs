@@ -0,0 +1,1146 @@
+use bytes::Bytes;
+use mini_redis::client::{self, Event, TimeToLive};
+use mini_redis::cmd::Expiry;
+use mini_redis::{server, Connection, Frame, MiniRedisError};
+use std::collections::HashSet;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpSocket, TcpStream};
+use tokio_stream::StreamExt;
+
+/// 'SETEX'로 저장한 값은 만료 시간이 지나면 사라진다.
+#[tokio::test]
+async fn setex_expires_value() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.setex("k", "v".into(), Duration::from_secs(1)).await.unwrap();
+    assert_eq!(client.get("k").await.unwrap().unwrap(), "v");
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert!(client.get("k").await.unwrap().is_none());
+}
+
+/// 'GETRANGE'는 음수 인덱스를 값의 끝에서부터 세고, 범위를 벗어나거나 뒤집힌 범위는 빈 값이 된다.
+#[tokio::test]
+async fn getrange_clamps_indices() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("k", "This is a string".into()).await.unwrap();
+    assert_eq!(client.getrange("k", 0, 3).await.unwrap(), "This");
+    assert_eq!(client.getrange("k", -3, -1).await.unwrap(), "ing");
+    assert_eq!(client.getrange("k", 10, 100).await.unwrap(), "string");
+    assert_eq!(client.getrange("k", 5, 2).await.unwrap(), "");
+    assert_eq!(client.getrange("k", 100, 200).await.unwrap(), "");
+    assert_eq!(client.getrange("missing", 0, -1).await.unwrap(), "");
+}
+
+/// 'SETRANGE'는 값의 길이를 넘는 오프셋까지 0 바이트로 채운다.
+#[tokio::test]
+async fn setrange_pads_with_zeros() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("k", "Hello World".into()).await.unwrap();
+    assert_eq!(client.setrange("k", 6, "Redis".into()).await.unwrap(), 11);
+    assert_eq!(client.get("k").await.unwrap().unwrap(), "Hello Redis");
+
+    assert_eq!(client.setrange("n", 3, "ab".into()).await.unwrap(), 5);
+    assert_eq!(client.get("n").await.unwrap().unwrap(), &b"\0\0\0ab"[..]);
+}
+
+/// 'LRANGE'는 음수 인덱스를 리스트의 끝에서부터 세고, 범위를 벗어난 인덱스는 리스트의 범위로 제한한다.
+#[tokio::test]
+async fn lrange_wraps_negative_indices() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let elements = vec!["a".into(), "b".into(), "c".into()];
+    assert_eq!(client.rpush("l", elements).await.unwrap(), 3);
+    assert_eq!(client.lpush("l", vec!["x".into()]).await.unwrap(), 4);
+
+    assert_eq!(client.lrange("l", 0, -1).await.unwrap(), vec!["x", "a", "b", "c"]);
+    assert_eq!(client.lrange("l", -2, -1).await.unwrap(), vec!["b", "c"]);
+    assert_eq!(client.lrange("l", -100, 1).await.unwrap(), vec!["x", "a"]);
+    assert!(client.lrange("l", 3, 1).await.unwrap().is_empty());
+    assert!(client.lrange("l", 5, 10).await.unwrap().is_empty());
+}
+
+/// 리스트의 마지막 원소를 꺼내면 키가 삭제된다.
+#[tokio::test]
+async fn pop_last_element_deletes_key() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.rpush("l", vec!["a".into(), "b".into()]).await.unwrap();
+    assert_eq!(client.lpop("l").await.unwrap().unwrap(), "a");
+    assert_eq!(client.rpop("l").await.unwrap().unwrap(), "b");
+
+    assert_eq!(client.exists(&["l"]).await.unwrap(), 0);
+    assert_eq!(client.lpop("l").await.unwrap(), None);
+    assert_eq!(client.llen("l").await.unwrap(), 0);
+}
+
+/// 'HGETALL'의 순서는 정해져 있지 않지만, 모든 필드와 값을 반환한다.
+#[tokio::test]
+async fn hgetall_returns_all_fields() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let fields: Vec<(String, Bytes)> = (0..50)
+        .map(|i| (format!("field:{}", i), Bytes::from(i.to_string())))
+        .collect();
+    let pairs: Vec<(&str, Bytes)> = fields.iter().map(|(f, v)| (f.as_str(), v.clone())).collect();
+    assert_eq!(client.hset("h", &pairs).await.unwrap(), 50);
+
+    let all = client.hgetall("h").await.unwrap();
+    assert_eq!(all.len(), fields.len());
+    for (field, value) in &fields {
+        assert_eq!(&all[field], value);
+    }
+}
+
+/// 'SADD'는 새로 추가된 멤버의 수만 센다.
+#[tokio::test]
+async fn sadd_counts_new_members() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let members = vec!["a".into(), "b".into(), "a".into()];
+    assert_eq!(client.sadd("s", members).await.unwrap(), 2);
+    assert_eq!(client.sadd("s", vec!["b".into(), "c".into()]).await.unwrap(), 1);
+
+    let mut members = client.smembers("s").await.unwrap();
+    members.sort();
+    assert_eq!(members, vec!["a", "b", "c"]);
+    assert!(client.sismember("s", "a".into()).await.unwrap());
+    assert!(!client.sismember("s", "z".into()).await.unwrap());
+}
+
+/// 'KEYS'는 패턴과 일치하는 키만 반환한다.
+#[tokio::test]
+async fn keys_matches_pattern() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    for key in ["user:1", "user:2", "order:1", "hello"] {
+        client.set(key, "v".into()).await.unwrap();
+    }
+
+    let mut keys = client.keys("*").await.unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["hello", "order:1", "user:1", "user:2"]);
+
+    assert_eq!(client.keys("hello").await.unwrap(), vec!["hello"]);
+
+    let mut keys = client.keys("user:*").await.unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["user:1", "user:2"]);
+}
+
+/// 'FLUSHDB'로 만료 시간이 있는 키를 비운 뒤에도 백그라운드 퍼지 태스크는 정상 동작한다.
+#[tokio::test]
+async fn flushdb_clears_keys_with_ttl() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    for i in 0..10 {
+        let ttl = Duration::from_millis(30 + i);
+        client.set_expires(&format!("k:{}", i), "v".into(), ttl).await.unwrap();
+    }
+    client.set("p", "v".into()).await.unwrap();
+    assert_eq!(client.dbsize().await.unwrap(), 11);
+
+    client.flushdb().await.unwrap();
+    assert_eq!(client.dbsize().await.unwrap(), 0);
+
+    // 비운 키들의 만료 시각이 지난 뒤에도 서버는 응답한다.
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    client.set("after", "v".into()).await.unwrap();
+    assert_eq!(client.dbsize().await.unwrap(), 1);
+}
+
+/// 풀은 동시에 요청하는 클라이언트의 수만큼만 커넥션을 만들고, 반환된 커넥션을 재사용한다.
+#[tokio::test]
+async fn pool_reuses_connections() {
+    let addr = start_server().await;
+
+    // 서버로 중계하면서 수락한 커넥션의 수를 센다.
+    let proxy = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy_addr = proxy.local_addr().unwrap();
+    let accepted = Arc::new(AtomicUsize::new(0));
+    let counter = accepted.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut inbound, _) = proxy.accept().await.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let mut outbound = TcpStream::connect(addr).await.unwrap();
+                let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+            });
+        }
+    });
+
+    let pool = client::Pool::builder(proxy_addr.to_string()).max_size(4).build();
+    for _ in 0..5 {
+        let mut handles = vec![];
+        for i in 0..4 {
+            let pool = pool.clone();
+            handles.push(tokio::spawn(async move {
+                let mut client = pool.get().await.unwrap();
+                client.set(&format!("k{}", i), "v".into()).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    }
+
+    assert_eq!(pool.idle_count(), 4);
+    assert_eq!(accepted.load(Ordering::SeqCst), 4);
+}
+
+/// 재연결을 지원하는 클라이언트는 끊어진 커넥션을 다시 연결하고, 멱등한 커맨드만 재시도한다.
+#[tokio::test]
+async fn retry_reconnects_after_dropped_connection() {
+    let policy = client::RetryPolicy {
+        max_attempts: 4,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(50),
+    };
+
+    // 첫 번째 커넥션은 수락하자마자 닫고, 이후의 커넥션은 서버가 처리한다.
+    let addr = start_flaky_server().await;
+    let mut client = client::connect_with_retry(addr, policy.clone()).await.unwrap();
+    assert_eq!(client.get("x").await.unwrap(), None);
+
+    // 'INCR'은 재시도하지 않고 에러를 반환한다. 다음 요청은 새로운 커넥션을 사용한다.
+    let addr = start_flaky_server().await;
+    let mut client = client::connect_with_retry(addr, policy).await.unwrap();
+    assert!(client.incr("n").await.is_err());
+    assert_eq!(client.incr("n").await.unwrap(), 1);
+}
+
+/// 응답이 타임아웃보다 늦으면 타임아웃 에러를 반환하고, 이후 커넥션은 사용할 수 없다.
+#[tokio::test]
+async fn request_times_out() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // 요청을 읽고, 타임아웃이 지난 뒤에 응답한다.
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let mut connection = Connection::new(socket);
+        let _ = connection.read_frame().await;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let _ = connection.write_frame(&Frame::Bulk("late".into())).await;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    });
+
+    let client = client::connect(addr).await.unwrap();
+    let mut client = client.with_timeout(Duration::from_millis(50));
+
+    let err = client.get("a").await.unwrap_err();
+    assert!(matches!(err, MiniRedisError::Io(ref e) if e.kind() == io::ErrorKind::TimedOut));
+
+    // 늦게 도착한 응답을 다음 요청의 응답으로 읽지 않는다.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let err = client.get("a").await.unwrap_err();
+    assert!(matches!(err, MiniRedisError::Io(ref e) if e.kind() == io::ErrorKind::NotConnected));
+}
+
+/// 여러 태스크가 clone한 'Buffer'로 동시에 요청해도, 각 응답은 요청한 태스크에게 전달된다.
+#[tokio::test]
+async fn buffer_routes_concurrent_responses() {
+    let addr = start_server().await;
+    let buffer = mini_redis::buffer(client::connect(addr).await.unwrap());
+
+    let subscriber = client::connect(addr).await.unwrap();
+    let _subscriber = subscriber.subscribe(vec!["ch".into()]).await.unwrap();
+
+    let mut handles = vec![];
+    for i in 0..8 {
+        let mut buffer = buffer.clone();
+        handles.push(tokio::spawn(async move {
+            let key = format!("k{}", i);
+            let value = Bytes::from(format!("v{}", i));
+            buffer.set_expires(&key, value.clone(), Duration::from_secs(60)).await.unwrap();
+            assert_eq!(buffer.get(&key).await.unwrap(), Some(value));
+            assert_eq!(buffer.publish("ch", "m".into()).await.unwrap(), 1);
+            assert_eq!(buffer.del(&[&key, "missing"]).await.unwrap(), 1);
+        }));
+    }
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+}
+
+/// 'Buffer'의 커넥션 태스크는 모든 clone이 drop된 뒤에야 종료되어 커넥션을 닫는다.
+#[tokio::test]
+async fn buffer_task_exits_after_all_clones_dropped() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let config = server::Config::default();
+    let (handle, server) = server::run_with_handle(listener, tokio::signal::ctrl_c(), config);
+    tokio::spawn(server);
+
+    let buffer = mini_redis::buffer(client::connect(addr).await.unwrap());
+
+    let mut handles = vec![];
+    for i in 0..4 {
+        let mut buffer = buffer.clone();
+        handles.push(tokio::spawn(async move {
+            let key = format!("k{}", i);
+            buffer.set(&key, "v".into()).await.unwrap();
+            assert_eq!(buffer.get(&key).await.unwrap().unwrap(), "v");
+        }));
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    // 태스크들이 가진 clone은 drop되었지만, 남은 핸들이 있으므로 커넥션은 유지된다.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(handle.active_connections(), 1);
+
+    drop(buffer);
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while handle.active_connections() > 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// 유닉스 도메인 소켓으로 가동한 서버에 접속하여 값을 저장하고 가져온다.
+#[cfg(unix)]
+#[tokio::test]
+async fn unix_socket_round_trip() {
+    let path = std::env::temp_dir().join(format!("mini-redis-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let listener = tokio::net::UnixListener::bind(&path).unwrap();
+    tokio::spawn(async move { server::run_uds(listener, tokio::signal::ctrl_c()).await });
+
+    let mut client = client::connect_unix(&path).await.unwrap();
+    client.set("hello", "world".into()).await.unwrap();
+    assert_eq!(client.get("hello").await.unwrap().unwrap(), "world");
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// 'DEBUG SLEEP'은 주어진 시간이 지난 뒤에 응답한다.
+#[tokio::test]
+async fn debug_sleep_delays_reply() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let start = Instant::now();
+    client.debug_sleep(Duration::from_millis(300)).await.unwrap();
+
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(300), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(1), "{:?}", elapsed);
+}
+
+/// 'INFO commandstats'는 커맨드별 호출 수를 보고한다.
+#[tokio::test]
+async fn info_reports_command_calls() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    for _ in 0..3 {
+        client.get("a").await.unwrap();
+    }
+    client.set("a", "1".into()).await.unwrap();
+
+    let stats = client.info(Some("commandstats")).await.unwrap();
+    assert!(stats.contains("cmdstat_get:calls=3\r\n"), "{}", stats);
+    assert!(stats.contains("cmdstat_set:calls=1\r\n"), "{}", stats);
+}
+
+/// 키스페이스 알림이 켜져 있으면, 키의 만료 시간이 지난 직후 'expired' 이벤트가 발행된다.
+#[tokio::test]
+async fn expired_keyspace_event() {
+    let config = server::Config {
+        notify_keyspace_events: true,
+        ..server::Config::default()
+    };
+    let addr = start_server_with_config(config).await;
+
+    let subscriber = client::connect(addr).await.unwrap();
+    let channels = vec!["__keyevent@0__:expired".to_string()];
+    let mut subscriber = subscriber.subscribe(channels).await.unwrap();
+
+    let mut client = client::connect(addr).await.unwrap();
+    client.set_expires("k", "v".into(), Duration::from_millis(100)).await.unwrap();
+
+    let message = tokio::time::timeout(Duration::from_secs(1), subscriber.next_message())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.channel, "__keyevent@0__:expired");
+    assert_eq!(message.content, "k");
+}
+
+/// 키스페이스 알림이 켜져 있으면, 'MSET'으로 저장한 키마다 'set' 이벤트가 발행된다.
+#[tokio::test]
+async fn mset_keyspace_event() {
+    let config = server::Config {
+        notify_keyspace_events: true,
+        ..server::Config::default()
+    };
+    let addr = start_server_with_config(config).await;
+
+    let subscriber = client::connect(addr).await.unwrap();
+    let channels = vec!["__keyevent@0__:set".to_string()];
+    let mut subscriber = subscriber.subscribe(channels).await.unwrap();
+
+    let mut client = client::connect(addr).await.unwrap();
+    client.mset(&[("a", "1".into()), ("b", "2".into())]).await.unwrap();
+
+    let mut keys = vec![];
+    for _ in 0..2 {
+        let message = tokio::time::timeout(Duration::from_secs(1), subscriber.next_message())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(message.channel, "__keyevent@0__:set");
+        keys.push(message.content);
+    }
+    keys.sort();
+    assert_eq!(keys, ["a", "b"]);
+}
+
+/// 'BGSAVE'는 바로 응답하고, 저장이 끝나면 변경된 키가 스냅샷 파일에 기록되어 있다.
+#[tokio::test]
+async fn bgsave_writes_snapshot() {
+    let path = std::env::temp_dir().join(format!("mini-redis-bg-{}.rdb", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let config = server::Config {
+        dbfilename: Some(path.clone()),
+        ..server::Config::default()
+    };
+    let addr = start_server_with_config(config).await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("a", "1".into()).await.unwrap();
+    client.rpush("l", vec!["x".into()]).await.unwrap();
+    let info = client.info(Some("persistence")).await.unwrap();
+    assert!(info.contains("rdb_changes_since_last_save:2\r\n"), "{}", info);
+
+    assert_eq!(client.bgsave().await.unwrap(), "Background saving started");
+    loop {
+        let info = client.info(Some("persistence")).await.unwrap();
+        if info.contains("rdb_bgsave_in_progress:0\r\n") {
+            assert!(info.contains("rdb_changes_since_last_save:0\r\n"), "{}", info);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    // 같은 스냅샷 파일로 가동한 서버는 저장된 키를 복원한다.
+    let config = server::Config {
+        dbfilename: Some(path.clone()),
+        ..server::Config::default()
+    };
+    let addr = start_server_with_config(config).await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let mut client = client::connect(addr).await.unwrap();
+    assert_eq!(client.get("a").await.unwrap().unwrap(), "1");
+    assert_eq!(client.lrange("l", 0, -1).await.unwrap(), vec!["x"]);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// 'WATCH'한 키가 다른 커넥션에서 변경되면 'EXEC'는 트랜잭션을 중단하고 nil로 응답한다.
+#[tokio::test]
+async fn exec_aborts_when_watched_key_changes() {
+    let addr = start_server().await;
+    let mut watcher = client::connect(addr).await.unwrap();
+    let mut other = client::connect(addr).await.unwrap();
+
+    watcher.set("k", "1".into()).await.unwrap();
+    watcher.watch(&["k"]).await.unwrap();
+    other.set("k", "2".into()).await.unwrap();
+
+    watcher.multi().await.unwrap();
+    assert!(watcher.exec().await.unwrap().is_none());
+    assert_eq!(watcher.get("k").await.unwrap().unwrap(), "2");
+
+    // 'EXEC' 이후에는 감시가 해제되므로, 다음 트랜잭션은 수행된다.
+    watcher.multi().await.unwrap();
+    assert_eq!(watcher.exec().await.unwrap().unwrap().len(), 0);
+}
+
+/// 'RENAME'은 원본 키의 만료 시간을 새로운 키로 옮긴다.
+#[tokio::test]
+async fn rename_preserves_ttl() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set_expires("a", "1".into(), Duration::from_millis(300)).await.unwrap();
+    client.rename("a", "b").await.unwrap();
+
+    assert_eq!(client.get("a").await.unwrap(), None);
+    assert_eq!(client.get("b").await.unwrap().unwrap(), "1");
+    assert!(matches!(client.pttl("b").await.unwrap(), TimeToLive::ExpiresIn(_)));
+
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(client.get("b").await.unwrap(), None);
+}
+
+/// 'COPY'는 남은 만료 시간도 복사하므로, 원본과 복사본은 같은 시각에 만료된다.
+#[tokio::test]
+async fn copy_preserves_ttl() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set_expires("a", "1".into(), Duration::from_millis(300)).await.unwrap();
+    assert!(client.copy("a", "b", false).await.unwrap());
+    assert!(client.copy("a", "a", false).await.is_err());
+
+    assert_eq!(client.get("b").await.unwrap().unwrap(), "1");
+    assert!(matches!(client.pttl("b").await.unwrap(), TimeToLive::ExpiresIn(_)));
+
+    tokio::time::sleep(Duration::from_millis(350)).await;
+    assert_eq!(client.get("a").await.unwrap(), None);
+    assert_eq!(client.get("b").await.unwrap(), None);
+}
+
+/// 'CONFIG SET'으로 변경한 값을 'CONFIG GET'으로 읽을 수 있다.
+#[tokio::test]
+async fn config_set_get_round_trip() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.config_set("timeout", "30").await.unwrap();
+    let timeout = client.config_get("timeout").await.unwrap();
+    assert_eq!(timeout, vec![("timeout".to_string(), "30".to_string())]);
+
+    client.config_set("maxclients", "100").await.unwrap();
+    let maxclients = client.config_get("maxclients").await.unwrap();
+    assert_eq!(maxclients, vec![("maxclients".to_string(), "100".to_string())]);
+
+    assert!(client.config_get("unknown").await.unwrap().is_empty());
+    assert!(client.config_set("unknown", "1").await.is_err());
+}
+
+/// 'allkeys-lru' 정책에서는 메모리 제한을 넘는 쓰기가 가장 오래 사용되지 않은 키를 제거한다.
+#[tokio::test]
+async fn maxmemory_evicts_least_recently_used() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.config_set("maxmemory-policy", "allkeys-lru").await.unwrap();
+    for i in 0..10 {
+        // 키와 값을 합쳐 20 바이트
+        client.set(&format!("k{}", i), "123456789012345678".into()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(2)).await;
+    }
+
+    // 'k0'을 가장 최근에 사용한 키로 만든다.
+    client.get("k0").await.unwrap();
+    client.config_set("maxmemory", "100").await.unwrap();
+    client.set("z", "123456789012345678x".into()).await.unwrap();
+
+    assert!(client.get("k0").await.unwrap().is_some());
+    assert!(client.get("k1").await.unwrap().is_none());
+    assert!(client.get("k9").await.unwrap().is_some());
+    assert!(client.get("z").await.unwrap().is_some());
+}
+
+/// 'noeviction' 정책에서는 메모리 제한을 넘는 쓰기가 거절되고, 기존 키는 유지된다.
+#[tokio::test]
+async fn maxmemory_rejects_writes_without_eviction() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.config_set("maxmemory", "50").await.unwrap();
+    client.set("a", Bytes::from(vec![b'x'; 60])).await.unwrap();
+
+    let err = client.set("b", "1".into()).await.unwrap_err();
+    assert!(err.to_string().contains("OOM command not allowed"), "{}", err);
+    assert!(client.get("a").await.unwrap().is_some());
+
+    // 키를 삭제하여 메모리를 확보하면 다시 쓸 수 있다.
+    assert_eq!(client.del(&["a"]).await.unwrap(), 1);
+    client.set("b", "1".into()).await.unwrap();
+}
+
+/// 교집합이 비어있으면 'SINTERSTORE'는 대상 키를 삭제한다.
+#[tokio::test]
+async fn sinterstore_empty_result_deletes_destination() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.sadd("a", vec!["1".into(), "2".into(), "3".into()]).await.unwrap();
+    client.sadd("b", vec!["2".into(), "3".into(), "4".into()]).await.unwrap();
+    client.sadd("c", vec!["9".into()]).await.unwrap();
+
+    assert_eq!(client.sinterstore("d", &["a", "b"]).await.unwrap(), 2);
+    assert_eq!(client.scard("d").await.unwrap(), 2);
+
+    assert_eq!(client.sinterstore("d", &["a", "c"]).await.unwrap(), 0);
+    assert_eq!(client.exists(&["d"]).await.unwrap(), 0);
+}
+
+/// 'ZRANGE'는 점수가 같은 멤버를 사전 순으로 정렬하고, 음수 인덱스를 끝에서부터 센다.
+#[tokio::test]
+async fn zrange_orders_ties_lexicographically() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let members = vec![
+        (1.0, "b".into()),
+        (1.0, "a".into()),
+        (0.5, "c".into()),
+        (2.0, "d".into()),
+    ];
+    assert_eq!(client.zadd("z", members).await.unwrap(), 4);
+
+    assert_eq!(client.zrange("z", 0, -1).await.unwrap(), vec!["c", "a", "b", "d"]);
+    assert_eq!(client.zrange("z", -2, -1).await.unwrap(), vec!["b", "d"]);
+    assert_eq!(client.zrange("z", -100, 1).await.unwrap(), vec!["c", "a"]);
+    assert!(client.zrange("z", 3, 1).await.unwrap().is_empty());
+
+    // 점수가 바뀐 멤버는 새로운 위치로 이동한다.
+    assert_eq!(client.zadd("z", vec![(3.0, "a".into())]).await.unwrap(), 0);
+    assert_eq!(client.zrange("z", 0, -1).await.unwrap(), vec!["c", "b", "d", "a"]);
+    assert_eq!(client.zrank("z", "a".into()).await.unwrap(), Some(3));
+}
+
+/// 'OBJECT IDLETIME'은 키에 마지막으로 접근한 뒤 지난 초를 반환한다.
+#[tokio::test]
+async fn object_idletime_counts_seconds() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("k", "v".into()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert!(client.object_idletime("k").await.unwrap() >= 1);
+
+    // 값을 읽으면 유휴 시간이 초기화된다.
+    client.get("k").await.unwrap();
+    assert_eq!(client.object_idletime("k").await.unwrap(), 0);
+
+    assert!(client.object_idletime("missing").await.is_err());
+}
+
+/// 'TOUCH'는 존재하는 키의 수를 반환하고, 존재하지 않는 키를 만들지 않는다.
+#[tokio::test]
+async fn touch_counts_existing_keys() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("a", "1".into()).await.unwrap();
+    assert_eq!(client.touch(&["a", "missing", "a"]).await.unwrap(), 2);
+    assert_eq!(client.exists(&["missing"]).await.unwrap(), 0);
+}
+
+/// 'UNLINK'는 'DEL'과 같이 키를 삭제하고, 삭제한 키의 수를 반환한다.
+#[tokio::test]
+async fn unlink_removes_like_del() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("a", "1".into()).await.unwrap();
+    client.sadd("s", vec!["x".into(), "y".into()]).await.unwrap();
+    client.set("d", "1".into()).await.unwrap();
+
+    assert_eq!(client.unlink(&["a", "s", "missing", "a"]).await.unwrap(), 2);
+    assert_eq!(client.del(&["d", "missing", "d"]).await.unwrap(), 1);
+    assert_eq!(client.exists(&["a", "s", "d"]).await.unwrap(), 0);
+}
+
+/// 'RANDOMKEY'는 빈 데이터베이스에서 nil을 반환하고, 반복하여 호출하면 모든 키를 반환한다.
+#[tokio::test]
+async fn randomkey_returns_every_key() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    assert_eq!(client.randomkey().await.unwrap(), None);
+
+    client.set("a", "1".into()).await.unwrap();
+    client.set("b", "2".into()).await.unwrap();
+
+    let mut seen = HashSet::new();
+    for _ in 0..100 {
+        seen.insert(client.randomkey().await.unwrap().unwrap());
+        if seen.len() == 2 {
+            break;
+        }
+    }
+    assert_eq!(seen.len(), 2);
+}
+
+/// 이벤트 스트림은 메시지 사이에 도착한 구독과 구독 해지의 확인 응답을 순서대로 전달한다.
+#[tokio::test]
+async fn event_stream_yields_subscription_changes() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // 구독 요청에 응답한 뒤, 메시지와 다른 커넥션의 요청에 의한 구독 변경을 섞어 보낸다.
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let mut connection = Connection::new(socket);
+        let _ = connection.read_frame().await;
+
+        let frames = [
+            ("subscribe", "a", Frame::Integer(1)),
+            ("message", "a", Frame::Bulk("hi".into())),
+            ("subscribe", "b", Frame::Integer(2)),
+            ("message", "b", Frame::Bulk("yo".into())),
+            ("unsubscribe", "a", Frame::Integer(1)),
+        ];
+        for (kind, channel, last) in frames {
+            let frame = Frame::Array(vec![Frame::Bulk(kind.into()), Frame::Bulk(channel.into()), last]);
+            connection.write_frame(&frame).await.unwrap();
+        }
+    });
+
+    let subscriber = client::connect(addr).await.unwrap();
+    let subscriber = subscriber.subscribe(vec!["a".into()]).await.unwrap();
+
+    let events: Vec<String> = subscriber
+        .into_event_stream()
+        .map(|event| match event.unwrap() {
+            Event::Message(msg) => format!("message {} {:?}", msg.channel, msg.content),
+            Event::Subscribed { channel, count } => format!("subscribe {} {}", channel, count),
+            Event::Unsubscribed { channel, count } => format!("unsubscribe {} {}", channel, count),
+        })
+        .collect()
+        .await;
+
+    assert_eq!(
+        events,
+        [
+            "message a b\"hi\"",
+            "subscribe b 2",
+            "message b b\"yo\"",
+            "unsubscribe a 1",
+        ]
+    );
+}
+
+/// 'CLIENT SETNAME'으로 설정한 이름을 'CLIENT GETNAME'으로 읽을 수 있고, 공백을 포함한 이름은
+/// 거절된다.
+#[tokio::test]
+async fn client_setname_getname() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    assert_eq!(client.client_getname().await.unwrap(), "");
+    client.client_setname("worker-1").await.unwrap();
+    assert_eq!(client.client_getname().await.unwrap(), "worker-1");
+
+    assert!(client.client_setname("bad name").await.is_err());
+    assert_eq!(client.client_getname().await.unwrap(), "worker-1");
+}
+
+/// 'CLIENT LIST'는 연결된 클라이언트마다 서로 다른 id를 가진 한 줄을 반환한다.
+#[tokio::test]
+async fn client_list_shows_each_connection() {
+    let addr = start_server().await;
+    let mut first = client::connect(addr).await.unwrap();
+    let mut second = client::connect(addr).await.unwrap();
+    second.ping(None).await.unwrap();
+
+    let list = first.client_list().await.unwrap();
+    let ids: HashSet<&str> = list.lines().map(|line| line.split(' ').next().unwrap()).collect();
+    assert_eq!(list.lines().count(), 2, "{}", list);
+    assert_eq!(ids.len(), 2, "{}", list);
+}
+
+/// 'CLIENT KILL'로 다른 커넥션을 종료하면, 종료된 커넥션에서는 더 이상 프레임을 읽을 수 없다.
+#[tokio::test]
+async fn client_kill_closes_other_connection() {
+    let addr = start_server().await;
+    let mut killer = client::connect(addr).await.unwrap();
+
+    let mut victim = Connection::new(TcpStream::connect(addr).await.unwrap());
+    let setname = ["client", "setname", "victim"];
+    let frame = Frame::Array(setname.iter().map(|arg| Frame::Bulk(arg.to_string().into())).collect());
+    victim.write_frame(&frame).await.unwrap();
+    assert!(matches!(victim.read_frame().await.unwrap(), Some(Frame::Simple(_))));
+
+    let list = killer.client_list().await.unwrap();
+    let line = list.lines().find(|line| line.contains("name=victim")).unwrap();
+    let id: u64 = line
+        .split(' ')
+        .next()
+        .and_then(|field| field.strip_prefix("id="))
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    assert!(killer.client_kill(id).await.unwrap());
+    assert!(victim.read_frame().await.unwrap().is_none());
+    assert!(!killer.client_kill(id).await.unwrap());
+}
+
+/// 'MONITOR' 중인 커넥션은 다른 커넥션이 수행한 커맨드를 한 줄씩 수신한다.
+#[tokio::test]
+async fn monitor_receives_other_commands() {
+    let addr = start_server().await;
+    let mut monitor = client::connect(addr).await.unwrap().monitor().await.unwrap();
+
+    // 커맨드 이름은 수신한 그대로 출력된다.
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream.write_all(b"SET k v\r\n").await.unwrap();
+    let mut response = [0; 5];
+    stream.read_exact(&mut response).await.unwrap();
+    assert_eq!(&response, b"+OK\r\n");
+
+    let line = monitor.next_command().await.unwrap().unwrap();
+    assert!(line.contains("\"SET\""), "{}", line);
+}
+
+/// 'DEBUG SLEEP'으로 기준 시간보다 오래 걸린 커맨드는 'SLOWLOG GET'에 기록된다.
+#[tokio::test]
+async fn slowlog_records_slow_command() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.slowlog_reset().await.unwrap();
+    client.debug_sleep(Duration::from_millis(30)).await.unwrap();
+
+    let entries = client.slowlog_get(None).await.unwrap();
+    assert_eq!(entries.len(), 1, "{:?}", entries);
+    assert_eq!(entries[0].args[0], "debug");
+    assert_eq!(entries[0].args[1], "sleep");
+    assert!(entries[0].duration >= Duration::from_millis(30));
+    assert_eq!(client.slowlog_len().await.unwrap(), 1);
+}
+
+/// 'EXPIREAT'으로 1초 뒤의 시각을 지정한 키는 그 시각이 지나면 사라진다.
+#[tokio::test]
+async fn expireat_removes_key_at_timestamp() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set("k", "v".into()).await.unwrap();
+    let at = SystemTime::now() + Duration::from_secs(1);
+    assert!(client.expire_at("k", at).await.unwrap());
+    assert_eq!(client.get("k").await.unwrap().unwrap(), "v");
+
+    tokio::time::sleep(Duration::from_millis(1200)).await;
+    assert!(client.get("k").await.unwrap().is_none());
+}
+
+/// 'GETEX k PERSIST'는 값을 반환하면서 키의 만료 시간을 제거한다.
+#[tokio::test]
+async fn getex_persist_clears_ttl() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.set_expires("k", "v".into(), Duration::from_secs(100)).await.unwrap();
+    assert!(matches!(client.ttl("k").await.unwrap(), TimeToLive::ExpiresIn(_)));
+
+    let value = client.getex("k", Some(Expiry::Persist)).await.unwrap();
+    assert_eq!(value.unwrap(), "v");
+    assert_eq!(client.ttl("k").await.unwrap(), TimeToLive::Persistent);
+}
+
+/// 'active_connections'는 연결된 클라이언트의 수를 반환하고, 클라이언트가 닫히면 줄어든다.
+#[tokio::test]
+async fn handle_counts_active_connections() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let config = server::Config::default();
+    let (handle, server) = server::run_with_handle(listener, tokio::signal::ctrl_c(), config);
+    tokio::spawn(server);
+    assert_eq!(handle.active_connections(), 0);
+
+    let mut clients = vec![];
+    for _ in 0..3 {
+        let mut client = client::connect(addr).await.unwrap();
+        client.ping(None).await.unwrap();
+        clients.push(client);
+    }
+    assert_eq!(handle.active_connections(), 3);
+    assert_eq!(handle.total_accepted(), 3);
+
+    drop(clients);
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while handle.active_connections() > 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(handle.total_accepted(), 3);
+}
+
+/// 'Handle::shutdown'을 호출하면 셧다운 퓨처가 완료되지 않아도 'run'이 반환된다.
+#[tokio::test]
+async fn handle_shutdown_stops_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let config = server::Config::default();
+    let (handle, server) = server::run_with_handle(listener, std::future::pending::<()>(), config);
+    let server = tokio::spawn(server);
+
+    let mut client = client::connect(addr).await.unwrap();
+    client.ping(None).await.unwrap();
+
+    handle.shutdown();
+    let result = tokio::time::timeout(Duration::from_secs(2), server).await;
+    assert!(result.unwrap().unwrap().is_ok());
+}
+
+/// 구독자가 읽는 속도보다 빠르게 발행되어 채널의 버퍼를 넘친 메시지는 'pubsub_dropped_messages'로
+/// 집계된다.
+#[tokio::test]
+async fn lagged_subscriber_counts_dropped_messages() {
+    let addr = start_server().await;
+
+    // 수신 버퍼를 작게 잡아, 서버가 구독자에게 메시지를 쓰는 동안 멈추도록 한다.
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.set_recv_buffer_size(4096).unwrap();
+    let mut subscriber = socket.connect(addr).await.unwrap();
+    subscriber.write_all(b"SUBSCRIBE ch\r\n").await.unwrap();
+
+    let mut client = client::connect(addr).await.unwrap();
+    while client.publish("ch", "ready".into()).await.unwrap() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let payload = Bytes::from(vec![b'x'; 1024]);
+    for _ in 0..4000 {
+        client.publish("ch", payload.clone()).await.unwrap();
+    }
+
+    // 구독자가 밀린 메시지를 모두 읽으면, 서버는 버퍼에서 밀려난 메시지를 확인한다.
+    let mut buf = vec![0; 1 << 16];
+    loop {
+        let read = tokio::time::timeout(Duration::from_millis(500), subscriber.read(&mut buf));
+        match read.await {
+            Ok(Ok(n)) if n > 0 => {}
+            _ => break,
+        }
+    }
+
+    let info = client.info(Some("stats")).await.unwrap();
+    let dropped: u64 = info
+        .lines()
+        .find_map(|line| line.strip_prefix("pubsub_dropped_messages:"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(dropped > 0, "{}", info);
+}
+
+/// 구독자가 모두 떠난 채널은 'PUBSUB CHANNELS'에 더 이상 나타나지 않는다.
+#[tokio::test]
+async fn unsubscribed_channel_is_removed() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let channels = vec!["a".to_string(), "b".to_string()];
+    let mut subscriber = client::connect(addr).await.unwrap().subscribe(channels).await.unwrap();
+    let mut listed = client.pubsub_channels(None).await.unwrap();
+    listed.sort();
+    assert_eq!(listed, ["a", "b"]);
+
+    subscriber.unsubscribe(&["a".to_string()]).await.unwrap();
+    assert_eq!(client.pubsub_channels(None).await.unwrap(), ["b"]);
+}
+
+/// 'SUBSCRIBE'로 구독한 채널은 같은 이름의 샤드 채널에 'SPUBLISH'한 메시지를 받지 않는다.
+#[tokio::test]
+async fn spublish_does_not_reach_regular_subscriber() {
+    let addr = start_server().await;
+    let channels = vec!["ch".to_string()];
+    let mut subscriber = client::connect(addr).await.unwrap().subscribe(channels).await.unwrap();
+
+    let mut client = client::connect(addr).await.unwrap();
+    assert_eq!(client.spublish("ch", "sharded".into()).await.unwrap(), 0);
+    assert_eq!(client.publish("ch", "regular".into()).await.unwrap(), 1);
+
+    let message = subscriber.next_message().await.unwrap().unwrap();
+    assert_eq!(message.content, "regular");
+}
+
+/// 리스트가 저장된 키를 'GET'하면 nil 대신 'WRONGTYPE' 에러를 반환한다.
+#[tokio::test]
+async fn get_list_key_is_wrong_type() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.lpush("l", vec!["a".into()]).await.unwrap();
+    let err = client.get("l").await.unwrap_err();
+    assert!(matches!(err, MiniRedisError::WrongType(_)), "{:?}", err);
+    assert_eq!(client.get("missing").await.unwrap(), None);
+}
+
+/// 중복된 원소가 있는 리스트에서 'LPOS'의 'RANK 2'는 두 번째 일치를, 'COUNT 0'은 모든 일치를
+/// 반환한다.
+#[tokio::test]
+async fn lpos_rank_and_count_with_duplicates() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let values = ["a", "b", "c", "1", "2", "3", "c", "c"];
+    client.rpush("l", values.iter().map(|v| Bytes::from(*v)).collect()).await.unwrap();
+
+    assert_eq!(client.lpos("l", "c".into(), 1).await.unwrap(), Some(2));
+    assert_eq!(client.lpos("l", "c".into(), 2).await.unwrap(), Some(6));
+    assert_eq!(client.lpos("l", "c".into(), -1).await.unwrap(), Some(7));
+    assert_eq!(client.lpos_count("l", "c".into(), 1, 0).await.unwrap(), [2, 6, 7]);
+    assert_eq!(client.lpos_count("l", "c".into(), -1, 0).await.unwrap(), [7, 6, 2]);
+    assert!(client.lpos_count("l", "x".into(), 1, 0).await.unwrap().is_empty());
+}
+
+/// 음수 count의 'LREM'은 끝에서부터 제거하고, 원소를 모두 제거한 'LREM'과 빈 범위의 'LTRIM'은 키를
+/// 삭제한다.
+#[tokio::test]
+async fn lrem_from_tail_and_delete_on_empty() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let elements = vec!["h".into(), "x".into(), "h".into(), "y".into(), "h".into()];
+    client.rpush("l", elements).await.unwrap();
+    assert_eq!(client.lrem("l", -2, "h".into()).await.unwrap(), 2);
+    assert_eq!(client.lrange("l", 0, -1).await.unwrap(), vec!["h", "x", "y"]);
+
+    assert_eq!(client.lrem("l", 0, "h".into()).await.unwrap(), 1);
+    client.ltrim("l", 1, 0).await.unwrap();
+    assert_eq!(client.exists(&["l"]).await.unwrap(), 0);
+
+    client.rpush("r", vec!["q".into(), "q".into()]).await.unwrap();
+    assert_eq!(client.lrem("r", -5, "q".into()).await.unwrap(), 2);
+    assert_eq!(client.exists(&["r"]).await.unwrap(), 0);
+}
+
+/// 'RPOPLPUSH k k'는 리스트를 회전하고, 서로 다른 두 리스트 사이에서는 원소를 옮긴다.
+#[tokio::test]
+async fn rpoplpush_rotates_and_moves() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    client.rpush("k", vec!["a".into(), "b".into(), "c".into()]).await.unwrap();
+    assert_eq!(client.rpoplpush("k", "k").await.unwrap().unwrap(), "c");
+    assert_eq!(client.lrange("k", 0, -1).await.unwrap(), vec!["c", "a", "b"]);
+
+    assert_eq!(client.rpoplpush("k", "d").await.unwrap().unwrap(), "b");
+    assert_eq!(client.lmove("k", "d", true, false).await.unwrap().unwrap(), "c");
+    assert_eq!(client.lrange("k", 0, -1).await.unwrap(), vec!["a"]);
+    assert_eq!(client.lrange("d", 0, -1).await.unwrap(), vec!["b", "c"]);
+}
+
+/// 존재하지 않는 키의 새로운 필드에 'HINCRBY'와 'HINCRBYFLOAT'을 호출하면 0에 더한 값이 저장된다.
+#[tokio::test]
+async fn hincrby_creates_field_on_new_key() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    assert_eq!(client.hincrby("h", "n", 5).await.unwrap(), 5);
+    assert_eq!(client.hget("h", "n").await.unwrap().unwrap(), "5");
+
+    assert_eq!(client.hincrbyfloat("f", "x", 10.5).await.unwrap(), 10.5);
+    assert_eq!(client.hincrbyfloat("f", "x", 0.1).await.unwrap(), 10.6);
+    assert_eq!(client.hget("f", "x").await.unwrap().unwrap(), "10.6");
+}
+
+/// 양수 count는 중복 없이 최대 count개를, 음수 count는 중복을 허용하여 항상 |count|개를 반환한다.
+#[tokio::test]
+async fn random_sampling_count_semantics() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    let pairs = [("a", "1".into()), ("b", "2".into()), ("c", "3".into())];
+    client.hset("h", &pairs).await.unwrap();
+    let members = vec!["x".into(), "y".into(), "z".into()];
+    client.sadd("s", members).await.unwrap();
+
+    let fields = client.hrandfield_count("h", 2).await.unwrap();
+    assert_eq!(fields.iter().collect::<HashSet<_>>().len(), 2);
+    let fields = client.hrandfield_count("h", 10).await.unwrap();
+    assert_eq!(fields.iter().collect::<HashSet<_>>().len(), 3);
+    let fields = client.hrandfield_count("h", -10).await.unwrap();
+    assert_eq!(fields.len(), 10);
+    assert!(fields.iter().all(|field| ["a", "b", "c"].contains(&field.as_str())));
+
+    let sampled = client.srandmember_count("s", 10).await.unwrap();
+    assert_eq!(sampled.len(), 3);
+    assert_eq!(sampled.iter().collect::<HashSet<_>>().len(), 3);
+    let sampled = client.srandmember_count("s", -10).await.unwrap();
+    assert_eq!(sampled.len(), 10);
+    assert!(sampled.iter().collect::<HashSet<_>>().len() <= 3);
+}
+
+/// 'BITCOUNT'은 값 전체 혹은 지정한 바이트 범위에서 1인 비트의 수를 센다.
+#[tokio::test]
+async fn bitcount_counts_set_bits() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    assert_eq!(client.bitcount("missing").await.unwrap(), 0);
+
+    client.set("k", "foobar".into()).await.unwrap();
+    assert_eq!(client.bitcount("k").await.unwrap(), 26);
+    assert_eq!(client.bitcount_range("k", 0, 0, false).await.unwrap(), 4);
+    assert_eq!(client.bitcount_range("k", 1, 1, false).await.unwrap(), 6);
+    assert_eq!(client.bitcount_range("k", -2, -1, false).await.unwrap(), 7);
+}
+
+/// 'SETBIT'은 값의 길이를 넘는 오프셋에 0 바이트를 채워 값을 늘리고, 설정한 비트를 'GETBIT'으로
+/// 읽을 수 있다.
+#[tokio::test]
+async fn setbit_grows_value() {
+    let addr = start_server().await;
+    let mut client = client::connect(addr).await.unwrap();
+
+    assert!(!client.setbit("k", 7, true).await.unwrap());
+    assert_eq!(client.get("k").await.unwrap().unwrap(), &[1u8][..]);
+
+    assert!(!client.setbit("k", 100, true).await.unwrap());
+    assert_eq!(client.strlen("k").await.unwrap(), 13);
+    assert!(client.getbit("k", 100).await.unwrap());
+    assert!(!client.getbit("k", 99).await.unwrap());
+    assert!(!client.getbit("k", 1000).await.unwrap());
+
+    assert!(client.setbit("k", 100, false).await.unwrap());
+    assert!(!client.getbit("k", 100).await.unwrap());
+}
+
+async fn start_server() -> SocketAddr {
+    start_server_with_config(server::Config::default()).await
+}
+
+async fn start_server_with_config(config: server::Config) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        server::run_with_config(listener, tokio::signal::ctrl_c(), config).await
+    });
+
+    addr
+}
+
+/// 첫 번째 커넥션을 수락하자마자 닫는 서버를 시작한다.
+async fn start_flaky_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        drop(socket);
+        server::run(listener, tokio::signal::ctrl_c()).await
+    });
+
+    addr
+}
@@ -0,0 +1,270 @@
+use mini_redis::server;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+/// 'stream'에서 'expected'와 같은 길이의 바이트를 읽어 비교한다.
+async fn assert_response(stream: &mut TcpStream, expected: &[u8]) {
+    let mut response = vec![0; expected.len()];
+    stream.read_exact(&mut response).await.unwrap();
+    assert_eq!(expected, &response[..]);
+}
+
+/// 구독 중인 클라이언트가 구독 모드에서 허용되지 않는 커맨드를 보내면 에러로 응답한다.
+/// 커넥션은 닫히지 않고, 구독도 유지된다.
+#[tokio::test]
+async fn unknown_command_while_subscribed() {
+    let addr = start_server().await;
+
+    let mut subscriber = TcpStream::connect(addr).await.unwrap();
+    subscriber
+        .write_all(b"*2\r\n$9\r\nSUBSCRIBE\r\n$5\r\nhello\r\n")
+        .await
+        .unwrap();
+    assert_response(&mut subscriber, b"*3\r\n$9\r\nsubscribe\r\n$5\r\nhello\r\n:1\r\n").await;
+
+    subscriber
+        .write_all(b"*2\r\n$3\r\nGET\r\n$3\r\nfoo\r\n")
+        .await
+        .unwrap();
+    assert_response(&mut subscriber, b"-ERR unknown command 'get'\r\n").await;
+
+    // 구독이 유지되므로, 발행된 메시지를 계속 수신한다.
+    let mut publisher = TcpStream::connect(addr).await.unwrap();
+    publisher
+        .write_all(b"*3\r\n$7\r\nPUBLISH\r\n$5\r\nhello\r\n$5\r\nworld\r\n")
+        .await
+        .unwrap();
+    assert_response(&mut publisher, b":1\r\n").await;
+
+    let message = b"*3\r\n$7\r\nmessage\r\n$5\r\nhello\r\n$5\r\nworld\r\n";
+    assert_response(&mut subscriber, message).await;
+}
+
+/// 인자의 값이 유효하지 않은 커맨드는 에러로 응답한다. 커넥션은 닫히지 않고 이어지는 커맨드를
+/// 처리한다.
+#[tokio::test]
+async fn invalid_argument_keeps_connection() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream
+        .write_all(b"*4\r\n$5\r\nSETEX\r\n$1\r\nk\r\n$1\r\n0\r\n$1\r\nv\r\n")
+        .await
+        .unwrap();
+    assert_response(&mut stream, b"-ERR invalid expire time in 'setex' command\r\n").await;
+
+    stream
+        .write_all(b"*4\r\n$6\r\nSETBIT\r\n$1\r\nk\r\n$1\r\n0\r\n$1\r\n2\r\n")
+        .await
+        .unwrap();
+    assert_response(&mut stream, b"-ERR bit is not an integer or out of range\r\n").await;
+
+    stream
+        .write_all(b"*3\r\n$11\r\nSRANDMEMBER\r\n$1\r\ns\r\n$20\r\n-9223372036854775807\r\n")
+        .await
+        .unwrap();
+    assert_response(&mut stream, b"-ERR value is out of range\r\n").await;
+
+    stream.write_all(b"*1\r\n$4\r\nPING\r\n").await.unwrap();
+    assert_response(&mut stream, b"+PONG\r\n").await;
+}
+
+/// 최대 커넥션 수에 도달하면 다음 커넥션은 기존 커넥션이 닫힐 때까지 처리되지 않는다.
+#[tokio::test]
+async fn max_connections_blocks_next_connection() {
+    let config = server::Config {
+        max_connections: 1,
+        ..server::Config::default()
+    };
+    let addr = start_server_with_config(config).await;
+
+    let mut first = TcpStream::connect(addr).await.unwrap();
+    first.write_all(b"*1\r\n$4\r\nPING\r\n").await.unwrap();
+    assert_response(&mut first, b"+PONG\r\n").await;
+
+    let mut second = TcpStream::connect(addr).await.unwrap();
+    second.write_all(b"*1\r\n$4\r\nPING\r\n").await.unwrap();
+
+    let mut response = [0; 7];
+    let blocked = timeout(Duration::from_millis(200), second.read_exact(&mut response)).await;
+    assert!(blocked.is_err());
+
+    // 첫 번째 커넥션이 닫히면 두 번째 커넥션의 커맨드가 처리된다.
+    drop(first);
+    assert_response(&mut second, b"+PONG\r\n").await;
+}
+
+/// 최대 크기를 넘는 벌크 문자열의 길이를 받으면, 할당하지 않고 에러로 응답한 뒤 커넥션을 닫는다.
+#[tokio::test]
+async fn oversized_bulk_length_closes_connection() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream
+        .write_all(b"*2\r\n$3\r\nGET\r\n$999999999999\r\n")
+        .await
+        .unwrap();
+
+    let mut response = vec![];
+    stream.read_to_end(&mut response).await.unwrap();
+    assert_eq!(&response[..], b"-ERR Protocol error: invalid bulk length\r\n");
+}
+
+/// 유휴 시간 동안 아무것도 보내지 않은 커넥션은 서버가 닫는다.
+#[tokio::test]
+async fn idle_connection_is_closed() {
+    let config = server::Config {
+        idle_timeout: Some(Duration::from_millis(100)),
+        ..server::Config::default()
+    };
+    let addr = start_server_with_config(config).await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    let mut response = vec![];
+    let read = timeout(Duration::from_secs(1), stream.read_to_end(&mut response)).await;
+    assert_eq!(read.unwrap().unwrap(), 0);
+}
+
+/// 'RESET'은 구독 모드, 트랜잭션, 선택한 데이터베이스를 초기 상태로 되돌린다.
+#[tokio::test]
+async fn reset_clears_connection_state() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream.write_all(b"SUBSCRIBE ch\r\nRESET\r\nPING\r\n").await.unwrap();
+    assert_response(&mut stream, b"*3\r\n$9\r\nsubscribe\r\n$2\r\nch\r\n:1\r\n").await;
+    assert_response(&mut stream, b"+RESET\r\n+PONG\r\n").await;
+
+    stream.write_all(b"MULTI\r\nRESET\r\nEXEC\r\n").await.unwrap();
+    assert_response(&mut stream, b"+OK\r\n+RESET\r\n-ERR EXEC without MULTI\r\n").await;
+
+    stream.write_all(b"SELECT 2\r\nSET k v\r\nRESET\r\nGET k\r\n").await.unwrap();
+    assert_response(&mut stream, b"+OK\r\n+OK\r\n+RESET\r\n$-1\r\n").await;
+}
+
+/// 셧다운이 시작되어도 끝나지 않는 커넥션이 있으면, 셧다운 타임아웃이 지난 뒤 'run'이 반환된다.
+#[tokio::test]
+async fn shutdown_timeout_stops_stuck_connection() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let config = server::Config {
+        shutdown_timeout: Duration::from_millis(300),
+        ..server::Config::default()
+    };
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let server = tokio::spawn(server::run_with_config(listener, shutdown_rx, config));
+
+    // 큰 값을 저장한다.
+    let value = vec![b'x'; 1 << 20];
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let mut request = format!("*3\r\n$3\r\nSET\r\n$1\r\na\r\n${}\r\n", value.len()).into_bytes();
+    request.extend_from_slice(&value);
+    request.extend_from_slice(b"\r\n");
+    stream.write_all(&request).await.unwrap();
+    assert_response(&mut stream, b"+OK\r\n").await;
+
+    // 응답을 읽지 않으므로, 핸들러는 응답을 쓰는 중에 멈춘 채 셧다운 신호를 확인하지 못한다.
+    for _ in 0..64 {
+        stream.write_all(b"GET a\r\n").await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    shutdown_tx.send(()).unwrap();
+    let result = timeout(Duration::from_secs(2), server).await;
+    assert!(result.unwrap().unwrap().is_ok());
+}
+
+/// 'ZRANGEBYSCORE'는 '('로 시작하는 배타적 범위, '-inf'와 '+inf', 'LIMIT'을 지원한다.
+#[tokio::test]
+async fn zrangebyscore_bounds_and_limit() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream.write_all(b"ZADD z 1 a 2 b 3 c 5 e 10 j\r\n").await.unwrap();
+    assert_response(&mut stream, b":5\r\n").await;
+
+    stream.write_all(b"ZRANGEBYSCORE z (1 5\r\n").await.unwrap();
+    assert_response(&mut stream, b"*3\r\n$1\r\nb\r\n$1\r\nc\r\n$1\r\ne\r\n").await;
+
+    stream.write_all(b"ZRANGEBYSCORE z -inf (10\r\n").await.unwrap();
+    let response = b"*4\r\n$1\r\na\r\n$1\r\nb\r\n$1\r\nc\r\n$1\r\ne\r\n";
+    assert_response(&mut stream, response).await;
+
+    stream.write_all(b"ZRANGEBYSCORE z -inf +inf LIMIT 1 2\r\n").await.unwrap();
+    assert_response(&mut stream, b"*2\r\n$1\r\nb\r\n$1\r\nc\r\n").await;
+}
+
+/// 구독 중인 클라이언트는 'PING'과 'QUIT'을 보낼 수 있다. 'QUIT'은 구독을 끝내고 커넥션을 닫는다.
+#[tokio::test]
+async fn ping_and_quit_while_subscribed() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream.write_all(b"SUBSCRIBE a\r\n").await.unwrap();
+    assert_response(&mut stream, b"*3\r\n$9\r\nsubscribe\r\n$1\r\na\r\n:1\r\n").await;
+
+    stream.write_all(b"PING\r\nPING hi\r\n").await.unwrap();
+    assert_response(&mut stream, b"+PONG\r\n$2\r\nhi\r\n").await;
+
+    stream.write_all(b"QUIT\r\n").await.unwrap();
+    let mut response = vec![];
+    stream.read_to_end(&mut response).await.unwrap();
+    assert_eq!(&response[..], b"+OK\r\n");
+}
+
+/// 'QUIT'에 응답한 뒤 서버는 커넥션을 닫고, 이어서 보낸 커맨드는 처리하지 않는다.
+#[tokio::test]
+async fn quit_closes_connection() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    stream.write_all(b"PING\r\nQUIT\r\nPING\r\n").await.unwrap();
+
+    let mut response = vec![];
+    let read = timeout(Duration::from_secs(2), stream.read_to_end(&mut response)).await;
+    read.unwrap().unwrap();
+    assert_eq!(&response[..], b"+PONG\r\n+OK\r\n");
+}
+
+/// 이미 구독 중인 채널을 다시 구독하면 같은 구독 수로 확인 응답하고, 메시지는 한 번만 수신한다.
+#[tokio::test]
+async fn subscribe_twice_to_same_channel() {
+    let addr = start_server().await;
+    let mut subscriber = TcpStream::connect(addr).await.unwrap();
+    let confirmation = b"*3\r\n$9\r\nsubscribe\r\n$2\r\nch\r\n:1\r\n";
+
+    subscriber.write_all(b"SUBSCRIBE ch\r\n").await.unwrap();
+    assert_response(&mut subscriber, confirmation).await;
+    subscriber.write_all(b"SUBSCRIBE ch\r\n").await.unwrap();
+    assert_response(&mut subscriber, confirmation).await;
+
+    let mut publisher = TcpStream::connect(addr).await.unwrap();
+    publisher.write_all(b"PUBLISH ch m\r\n").await.unwrap();
+    assert_response(&mut publisher, b":1\r\n").await;
+
+    let message = b"*3\r\n$7\r\nmessage\r\n$2\r\nch\r\n$1\r\nm\r\n";
+    assert_response(&mut subscriber, message).await;
+
+    // 같은 메시지가 다시 전달되지 않는다.
+    let mut response = [0; 1];
+    let read = timeout(Duration::from_millis(200), subscriber.read(&mut response)).await;
+    assert!(read.is_err());
+}
+
+async fn start_server() -> SocketAddr {
+    start_server_with_config(server::Config::default()).await
+}
+
+async fn start_server_with_config(config: server::Config) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        server::run_with_config(listener, tokio::signal::ctrl_c(), config).await
+    });
+
+    addr
+}